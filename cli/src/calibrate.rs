@@ -0,0 +1,69 @@
+use colored::Colorize;
+use crypt_core::Rarity;
+use crypt_sdk::calibration::{read_corpus, Calibration, TargetShares, TierCounts};
+use crypt_sdk::RarityScorer;
+use crate::scoring::load_rules;
+
+/// Fit rarity thresholds to a JSONL corpus of scanned transactions and
+/// print the tier distribution before and after.
+pub fn calibrate(corpus_path: &str, target: &str, rules: Option<&str>) {
+    let Some(targets) = TargetShares::parse(target) else {
+        eprintln!("{} {} (expected e.g. 70/25/5)", "Invalid target:".red(), target);
+        return;
+    };
+    let mut scorer = RarityScorer::default();
+    if let Some(path) = rules {
+        let Some(rules) = load_rules(path) else { return };
+        scorer = scorer.with_rules(rules);
+    }
+
+    let jsonl = match std::fs::read_to_string(corpus_path) {
+        Ok(jsonl) => jsonl,
+        Err(e) => {
+            eprintln!("{} {}: {}", "Error reading corpus".red(), corpus_path, e);
+            return;
+        }
+    };
+    let corpus = match read_corpus(&jsonl) {
+        Ok(corpus) => corpus,
+        Err(e) => {
+            eprintln!("{} {}: {}", "Invalid corpus".red(), corpus_path, e);
+            return;
+        }
+    };
+    let Some(calibration) = Calibration::run(&scorer, &corpus, targets) else {
+        eprintln!("{} {}", "Empty corpus:".red(), corpus_path);
+        return;
+    };
+
+    println!("{}", "  RARITY CALIBRATION".bright_green());
+    println!("  ────────────────────────────");
+    println!("  Corpus:     {} ({} transactions)", corpus_path, corpus.len());
+    println!("  Rules:      {}", rules.unwrap_or("default"));
+    println!(
+        "  Target:     {:.0}% common | {:.0}% rare | {:.0}% legendary",
+        targets.common * 100.0, targets.rare * 100.0, targets.legendary * 100.0,
+    );
+    println!("  ────────────────────────────");
+    print_tiers("Before", scorer.rare_threshold, scorer.legendary_threshold, &calibration.before);
+    print_tiers("After", calibration.rare_threshold, calibration.legendary_threshold, &calibration.after);
+
+    println!(
+        "\n  Use: RarityScorer::new({}, {}) with rules {} (v{})",
+        calibration.rare_threshold.to_string().bright_cyan(),
+        calibration.legendary_threshold.to_string().bright_magenta(),
+        calibration.rules.name,
+        calibration.rules.version,
+    );
+}
+
+fn print_tiers(label: &str, rare: u32, legendary: u32, tiers: &TierCounts) {
+    println!("\n  {} (rare {}+, legendary {}+):", label.bright_yellow(), rare, legendary);
+    println!("    Common:     {:>5} ({:>5.1}%)", tiers.common, tiers.share(Rarity::Common) * 100.0);
+    println!("    Rare:       {:>5} ({:>5.1}%)", tiers.rare.to_string().bright_cyan(), tiers.share(Rarity::Rare) * 100.0);
+    println!(
+        "    Legendary:  {:>5} ({:>5.1}%)",
+        tiers.legendary.to_string().bright_magenta(),
+        tiers.share(Rarity::Legendary) * 100.0,
+    );
+}
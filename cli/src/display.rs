@@ -1,10 +1,12 @@
 use colored::Colorize;
+use crypt_core::CollectionMetrics;
 use solana_client::rpc_client::RpcClient;
 use solana_sdk::pubkey::Pubkey;
 use std::str::FromStr;
 
-/// Display collection statistics from on-chain data.
-pub async fn show_stats(rpc_url: &str) {
+/// Display collection statistics from on-chain data, plus the full
+/// metrics from an indexer when one is given.
+pub async fn show_stats(rpc_url: &str, indexer_url: Option<&str>) {
     println!("{}", "  CRYPT COLLECTION STATS".bright_green());
     println!("  ────────────────────────────────");
 
@@ -48,8 +50,55 @@ pub async fn show_stats(rpc_url: &str) {
         }
     }
 
+    let metrics = match indexer_url {
+        Some(url) => fetch_metrics(url).await,
+        None => None,
+    };
+
     println!("\n  Rarity Distribution:");
-    println!("    {} Common    (score 0-39)", "■".white());
-    println!("    {} Rare      (score 40-74)", "■".bright_cyan());
-    println!("    {} Legendary (score 75+)", "■".bright_magenta());
+    let (common, rare, legendary) = match &metrics {
+        Some(m) => {
+            let d = &m.rarity_distribution;
+            (
+                format!(" {:>6} ({:.1}%)", d.common, d.common_pct()),
+                format!(" {:>6} ({:.1}%)", d.rare, d.rare_pct()),
+                format!(" {:>6} ({:.1}%)", d.legendary, d.legendary_pct()),
+            )
+        }
+        None => Default::default(),
+    };
+    println!("    {} Common    (score 0-39){}", "■".white(), common);
+    println!("    {} Rare      (score 40-74){}", "■".bright_cyan(), rare);
+    println!("    {} Legendary (score 75+){}", "■".bright_magenta(), legendary);
+
+    if let Some(m) = metrics {
+        print_metrics(&m);
+    }
+}
+
+/// Fetch `CollectionMetrics` from a running indexer's API.
+async fn fetch_metrics(indexer_url: &str) -> Option<CollectionMetrics> {
+    let url = format!("{}/metrics", indexer_url.trim_end_matches('/'));
+    let result = async {
+        reqwest::get(&url).await?.error_for_status()?.json::<CollectionMetrics>().await
+    }.await;
+    match result {
+        Ok(m) => Some(m),
+        Err(e) => {
+            eprintln!("  {} Indexer unavailable: {}", "WARN".yellow(), e);
+            None
+        }
+    }
+}
+
+fn print_metrics(m: &CollectionMetrics) {
+    println!("\n  Indexed Metrics:");
+    println!("    Total cards:   {}", m.total_cards.to_string().bright_magenta().bold());
+    println!("    Active:        {}", m.active_cards);
+    println!("    Burned:        {}", m.burned_cards.to_string().bright_red());
+    println!("    Owners:        {}", m.unique_owners);
+    println!("    Interactions:  {} ({:.1} per card)", m.total_interactions, m.avg_interactions_per_card);
+    println!("    Mints:         {} in 24h, {} in 7d", m.mints_last_24h, m.mints_last_7d);
+    println!("    Transfers:     {} in 24h", m.transfers_last_24h);
+    println!("    Top type:      {} ({} cards)", m.type_distribution.most_common(), m.type_distribution.total());
 }
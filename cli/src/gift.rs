@@ -0,0 +1,209 @@
+use colored::Colorize;
+use crypt_sdk::GiftLink;
+use sha2::{Digest, Sha256};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::{read_keypair_file, Keypair, Signer},
+    system_program,
+    transaction::Transaction,
+};
+use std::str::FromStr;
+
+use crate::verify::{decode_card, PROGRAM_ID};
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Lock a card in a gift escrow and print a claim link.
+pub async fn create_gift(card_address: &str, keypair_path: &str, days: i64, rpc_url: &str, claim_url: &str) {
+    println!("{} Creating gift for card: {}", ">>".bright_cyan(), card_address.yellow());
+
+    let Some((client, program_id, sender)) = connect(rpc_url, keypair_path) else { return };
+    let Some(card) = parse_pubkey(card_address, "card") else { return };
+    let Some(mint_id) = fetch_mint_id(&client, &card) else { return };
+
+    let link = GiftLink::generate(card);
+
+    let (gift, _) = gift_address(&program_id, &card);
+    let mut data = instruction_discriminator("create_gift").to_vec();
+    data.extend_from_slice(&mint_id.to_le_bytes());
+    data.extend_from_slice(link.key.pubkey().as_ref());
+    data.extend_from_slice(&(days * SECONDS_PER_DAY).to_le_bytes());
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(card, false),
+            AccountMeta::new(gift, false),
+            AccountMeta::new(sender.pubkey(), true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    };
+    if !send(&client, ix, &[&sender]) {
+        return;
+    }
+
+    println!("  Gift:     {}", gift.to_string().bright_cyan());
+    println!("  Expires:  in {} day(s)", days);
+    println!();
+    println!("  {}", "Share this link — anyone who has it can claim the card:".bright_yellow());
+    println!("  {}", link.to_url(claim_url).bright_white());
+}
+
+/// Claim a gifted card from a claim link (or its `card=...&key=...` payload).
+pub async fn claim_gift(link: &str, keypair_path: &str, rpc_url: &str) {
+    println!("{} Claiming gift", ">>".bright_cyan());
+
+    let link = match GiftLink::parse(link) {
+        Ok(link) => link,
+        Err(e) => {
+            eprintln!("{} {}", "ERROR".red(), e);
+            return;
+        }
+    };
+    let (card, claim_key) = (link.card, link.key.keypair());
+    let Some((client, program_id, claimant)) = connect(rpc_url, keypair_path) else { return };
+    let Some(mint_id) = fetch_mint_id(&client, &card) else { return };
+
+    let (gift, _) = gift_address(&program_id, &card);
+    let Some(sender) = fetch_gift_sender(&client, &gift) else { return };
+
+    let mut data = instruction_discriminator("claim_gift").to_vec();
+    data.extend_from_slice(&mint_id.to_le_bytes());
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(card, false),
+            AccountMeta::new(gift, false),
+            AccountMeta::new(sender, false),
+            AccountMeta::new_readonly(claim_key.pubkey(), true),
+            AccountMeta::new_readonly(claimant.pubkey(), true),
+        ],
+        data,
+    };
+    if send(&client, ix, &[&claimant, &claim_key]) {
+        println!("  Card #{} now belongs to {}", mint_id, claimant.pubkey().to_string().bright_cyan());
+    }
+}
+
+/// Take back an expired, unclaimed gift.
+pub async fn reclaim_gift(card_address: &str, keypair_path: &str, rpc_url: &str) {
+    println!("{} Reclaiming gift for card: {}", ">>".bright_cyan(), card_address.yellow());
+
+    let Some((client, program_id, sender)) = connect(rpc_url, keypair_path) else { return };
+    let Some(card) = parse_pubkey(card_address, "card") else { return };
+    let Some(mint_id) = fetch_mint_id(&client, &card) else { return };
+
+    let (gift, _) = gift_address(&program_id, &card);
+    let mut data = instruction_discriminator("reclaim_gift").to_vec();
+    data.extend_from_slice(&mint_id.to_le_bytes());
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(card, false),
+            AccountMeta::new(gift, false),
+            AccountMeta::new(sender.pubkey(), true),
+        ],
+        data,
+    };
+    if send(&client, ix, &[&sender]) {
+        println!("  Card #{} returned to {}", mint_id, sender.pubkey().to_string().bright_cyan());
+    }
+}
+
+fn connect(rpc_url: &str, keypair_path: &str) -> Option<(RpcClient, Pubkey, Keypair)> {
+    let program_id = Pubkey::from_str(PROGRAM_ID).expect("Invalid program ID");
+    let keypair = match read_keypair_file(expand_home(keypair_path)) {
+        Ok(k) => k,
+        Err(e) => {
+            eprintln!("{} Could not read keypair {}: {}", "ERROR".red(), keypair_path, e);
+            return None;
+        }
+    };
+    Some((RpcClient::new(rpc_url.to_string()), program_id, keypair))
+}
+
+fn parse_pubkey(value: &str, what: &str) -> Option<Pubkey> {
+    match Pubkey::from_str(value) {
+        Ok(p) => Some(p),
+        Err(e) => {
+            eprintln!("{} Invalid {} address: {}", "ERROR".red(), what, e);
+            None
+        }
+    }
+}
+
+fn fetch_mint_id(client: &RpcClient, card: &Pubkey) -> Option<u64> {
+    let account = match client.get_account(card) {
+        Ok(account) => account,
+        Err(e) => {
+            eprintln!("  {} Card not found: {}", "ERROR".red(), e);
+            return None;
+        }
+    };
+    match decode_card(&account.data) {
+        Some(card) => Some(card.mint_id),
+        None => {
+            eprintln!("  {} Could not decode card account data", "ERROR".red());
+            None
+        }
+    }
+}
+
+/// Read `sender` from a Gift account: discriminator, then card, then sender.
+fn fetch_gift_sender(client: &RpcClient, gift: &Pubkey) -> Option<Pubkey> {
+    let sender = client
+        .get_account(gift)
+        .ok()
+        .and_then(|account| account.data.get(40..72)?.try_into().ok())
+        .map(Pubkey::new_from_array);
+    if sender.is_none() {
+        eprintln!("  {} No pending gift for this card (already claimed or reclaimed?)", "ERROR".red());
+    }
+    sender
+}
+
+/// Send `ix` paid for by the first signer.
+fn send(client: &RpcClient, ix: Instruction, signers: &[&Keypair]) -> bool {
+    let blockhash = match client.get_latest_blockhash() {
+        Ok(b) => b,
+        Err(e) => {
+            eprintln!("  {} Could not fetch blockhash: {}", "ERROR".red(), e);
+            return false;
+        }
+    };
+    let tx = Transaction::new_signed_with_payer(&[ix], Some(&signers[0].pubkey()), signers, blockhash);
+    match client.send_and_confirm_transaction(&tx) {
+        Ok(sig) => {
+            println!("  {} {}", "OK".bright_green(), sig);
+            true
+        }
+        Err(e) => {
+            eprintln!("  {} Transaction failed: {}", "FAIL".red(), e);
+            false
+        }
+    }
+}
+
+fn gift_address(program_id: &Pubkey, card: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"gift", card.as_ref()], program_id)
+}
+
+/// Anchor instruction discriminator: first 8 bytes of SHA-256("global:<name>").
+fn instruction_discriminator(name: &str) -> [u8; 8] {
+    let digest = Sha256::digest(format!("global:{}", name).as_bytes());
+    let mut disc = [0u8; 8];
+    disc.copy_from_slice(&digest[..8]);
+    disc
+}
+
+fn expand_home(path: &str) -> String {
+    match (path.strip_prefix("~/"), std::env::var("HOME")) {
+        (Some(rest), Ok(home)) => format!("{}/{}", home, rest),
+        _ => path.to_string(),
+    }
+}
@@ -6,6 +6,9 @@ mod scoring;
 mod soul;
 mod verify;
 mod display;
+mod gift;
+mod profile;
+mod calibrate;
 
 #[derive(Parser)]
 #[command(name = "crypt")]
@@ -42,6 +45,24 @@ enum Commands {
         min_rarity: Option<String>,
     },
 
+    /// Classify a wallet into an archetype from its history
+    Profile {
+        /// Solana wallet address to profile
+        address: String,
+
+        /// Helius API key for enhanced transaction parsing
+        #[arg(short = 'k', long, env = "HELIUS_API_KEY")]
+        api_key: Option<String>,
+
+        /// Maximum number of transactions to fetch
+        #[arg(short, long, default_value = "100")]
+        limit: usize,
+
+        /// Output format: text or json
+        #[arg(short, long, default_value = "text")]
+        format: String,
+    },
+
     /// Generate a Soul Signature seed from a transaction hash
     Soul {
         /// Transaction hash (signature)
@@ -53,12 +74,24 @@ enum Commands {
         verbose: bool,
     },
 
-    /// Verify a Crypt Card's authenticity
+    /// Verify a Crypt Card's authenticity and print a per-check report
     Verify {
         /// Card account address on Solana
         #[arg(short, long)]
         card: String,
 
+        /// File holding the narration text to check against narration_hash
+        #[arg(short, long)]
+        narration_file: Option<String>,
+
+        /// Expected transaction hash (skips the check if omitted)
+        #[arg(short, long)]
+        tx_hash: Option<String>,
+
+        /// Original minter, for cards transferred since mint (defaults to owner)
+        #[arg(short, long)]
+        minter: Option<String>,
+
         /// Solana RPC URL
         #[arg(short, long, default_value = "https://api.devnet.solana.com")]
         rpc: String,
@@ -81,6 +114,30 @@ enum Commands {
         /// Is this from a known DEX?
         #[arg(short, long)]
         defi: bool,
+
+        /// JSON scoring rules to use instead of the program's
+        #[arg(long)]
+        rules: Option<String>,
+    },
+
+    /// Fit rarity thresholds to a corpus of scanned transactions
+    Calibrate {
+        /// JSONL corpus, one transaction's score parameters per line
+        corpus: String,
+
+        /// Target common/rare/legendary percentages
+        #[arg(short, long, default_value = "70/25/5")]
+        target: String,
+
+        /// JSON scoring rules to use instead of the program's
+        #[arg(long)]
+        rules: Option<String>,
+    },
+
+    /// Share a card through a claimable gift link
+    Gift {
+        #[command(subcommand)]
+        action: GiftCommand,
     },
 
     /// Display collection statistics
@@ -88,6 +145,65 @@ enum Commands {
         /// Solana RPC URL
         #[arg(short, long, default_value = "https://api.devnet.solana.com")]
         rpc: String,
+
+        /// Indexer API URL for full collection metrics
+        #[arg(short, long, env = "CRYPT_INDEXER_URL")]
+        indexer: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum GiftCommand {
+    /// Lock a card in a gift escrow and print a claim link
+    Create {
+        /// Card account address on Solana
+        #[arg(short, long)]
+        card: String,
+
+        /// Days the gift stays claimable before you can take it back
+        #[arg(short, long, default_value = "7")]
+        days: i64,
+
+        /// Page the claim link points at
+        #[arg(short = 'u', long, default_value = "https://crypt.cards/gift")]
+        claim_url: String,
+
+        /// Keypair of the card owner
+        #[arg(short, long, default_value = "~/.config/solana/id.json")]
+        keypair: String,
+
+        /// Solana RPC URL
+        #[arg(short, long, default_value = "https://api.devnet.solana.com")]
+        rpc: String,
+    },
+
+    /// Claim a gifted card with a claim link
+    Claim {
+        /// Claim link, or its `card=...&key=...` payload
+        link: String,
+
+        /// Keypair of the wallet receiving the card
+        #[arg(short, long, default_value = "~/.config/solana/id.json")]
+        keypair: String,
+
+        /// Solana RPC URL
+        #[arg(short, long, default_value = "https://api.devnet.solana.com")]
+        rpc: String,
+    },
+
+    /// Take back an expired, unclaimed gift
+    Reclaim {
+        /// Card account address on Solana
+        #[arg(short, long)]
+        card: String,
+
+        /// Keypair of the wallet that created the gift
+        #[arg(short, long, default_value = "~/.config/solana/id.json")]
+        keypair: String,
+
+        /// Solana RPC URL
+        #[arg(short, long, default_value = "https://api.devnet.solana.com")]
+        rpc: String,
     },
 }
 
@@ -104,17 +220,40 @@ async fn main() {
         Commands::Scan { address, api_key, limit, format, min_rarity } => {
             scanner::scan_wallet(&address, api_key.as_deref(), limit, &format, min_rarity.as_deref()).await;
         }
+        Commands::Profile { address, api_key, limit, format } => {
+            profile::show_profile(&address, api_key.as_deref(), limit, &format).await;
+        }
         Commands::Soul { tx_hash, verbose } => {
             soul::generate_soul_seed(&tx_hash, verbose);
         }
-        Commands::Verify { card, rpc } => {
-            verify::verify_card(&card, &rpc).await;
+        Commands::Verify { card, narration_file, tx_hash, minter, rpc } => {
+            verify::verify_card(
+                &card,
+                &rpc,
+                narration_file.as_deref(),
+                tx_hash.as_deref(),
+                minter.as_deref(),
+            ).await;
+        }
+        Commands::Score { tx_type, sol, memecoin, defi, rules } => {
+            scoring::show_score(&tx_type, sol, memecoin, defi, rules.as_deref());
         }
-        Commands::Score { tx_type, sol, memecoin, defi } => {
-            scoring::show_score(&tx_type, sol, memecoin, defi);
+        Commands::Calibrate { corpus, target, rules } => {
+            calibrate::calibrate(&corpus, &target, rules.as_deref());
         }
-        Commands::Stats { rpc } => {
-            display::show_stats(&rpc).await;
+        Commands::Gift { action } => match action {
+            GiftCommand::Create { card, days, claim_url, keypair, rpc } => {
+                gift::create_gift(&card, &keypair, days, &rpc, &claim_url).await;
+            }
+            GiftCommand::Claim { link, keypair, rpc } => {
+                gift::claim_gift(&link, &keypair, &rpc).await;
+            }
+            GiftCommand::Reclaim { card, keypair, rpc } => {
+                gift::reclaim_gift(&card, &keypair, &rpc).await;
+            }
+        },
+        Commands::Stats { rpc, indexer } => {
+            display::show_stats(&rpc, indexer.as_deref()).await;
         }
     }
 }
@@ -0,0 +1,87 @@
+use colored::Colorize;
+use crypt_sdk::analytics::{WalletStats, WalletTx};
+use crate::scanner;
+
+/// Profile a wallet from its Helius history: archetype, flavor text and
+/// the stats behind them.
+pub async fn show_profile(address: &str, api_key: Option<&str>, limit: usize, format: &str) {
+    let Some(txs) = scanner::fetch_transactions::<WalletTx>(address, api_key, limit).await else {
+        return;
+    };
+
+    println!("{} {} transactions fetched\n", ">>".bright_cyan(), txs.len());
+
+    let stats = WalletStats::from_history(address, &txs);
+    let profile = stats.archetype_scores();
+    let primary = profile.primary();
+    let archetype = primary.archetype;
+
+    if format == "json" {
+        let json = serde_json::json!({
+            "address": address,
+            "archetype": archetype.as_str(),
+            "confidence": primary.confidence,
+            "secondary": profile.secondary().map(|s| s.archetype.as_str()),
+            "flavor": archetype.flavor(),
+            "scores": profile.scores.iter().map(|s| serde_json::json!({
+                "archetype": s.archetype.as_str(),
+                "confidence": s.confidence,
+                "matched": s.matched,
+                "reasons": s.reasons,
+            })).collect::<Vec<_>>(),
+            "dominant_activity": stats.dominant_activity(),
+            "stats": {
+                "total_txs": stats.total_txs,
+                "swap_count": stats.swap_count,
+                "nft_count": stats.nft_count,
+                "transfer_count": stats.transfer_count,
+                "stake_count": stats.stake_count,
+                "token_creates": stats.token_creates,
+                "burns": stats.burns,
+                "memecoin_trades": stats.memecoin_trades,
+                "max_sol_moved": stats.max_sol_moved,
+                "total_sol_volume": stats.total_sol_volume,
+                "avg_tx_value_sol": stats.avg_tx_value_sol,
+                "unique_tokens_traded": stats.unique_tokens_traded,
+                "unique_nft_collections": stats.unique_nft_collections,
+                "first_tx_timestamp": stats.first_tx_timestamp,
+                "last_tx_timestamp": stats.last_tx_timestamp,
+                "age_days": stats.age_days(),
+                "active_days": stats.active_days,
+                "longest_gap_days": stats.longest_gap_days,
+            },
+        });
+        println!("{}", serde_json::to_string_pretty(&json).unwrap_or_default());
+        return;
+    }
+
+    println!("  {}  {} ({:.0}%)", "ARCHETYPE".bright_green(),
+        archetype.as_str().bright_magenta().bold(), primary.confidence * 100.0);
+    println!("  {}", archetype.flavor().italic());
+    for reason in &primary.reasons {
+        println!("    - {}", reason);
+    }
+    if let Some(secondary) = profile.secondary() {
+        println!("  {}  {} ({:.0}%): {}", "SECONDARY".bright_cyan(),
+            secondary.archetype.as_str(), secondary.confidence * 100.0, secondary.reasons.join(", "));
+    }
+    println!();
+    println!("  ────────────────────────────────");
+    println!("  Transactions:     {}", stats.total_txs);
+    println!("  Swaps:            {} ({} memecoin, {} tokens)",
+        stats.swap_count, stats.memecoin_trades, stats.unique_tokens_traded);
+    println!("  NFT activity:     {} ({} collections)", stats.nft_count, stats.unique_nft_collections);
+    println!("  Transfers:        {}", stats.transfer_count);
+    println!("  Staking:          {}", stats.stake_count);
+    println!("  Tokens launched:  {}", stats.token_creates);
+    println!("  Burns:            {}", stats.burns);
+    println!("  ────────────────────────────────");
+    println!("  Volume:           {:.2} SOL", stats.total_sol_volume);
+    println!("  Largest move:     {:.2} SOL", stats.max_sol_moved);
+    println!("  Average tx:       {:.2} SOL", stats.avg_tx_value_sol);
+    println!("  ────────────────────────────────");
+    println!("  Age:              {} days ({} active)", stats.age_days(), stats.active_days);
+    println!("  Longest gap:      {} days", stats.longest_gap_days);
+    println!("  Frequency:        {:.2} tx/day", stats.tx_frequency());
+    println!("  Mostly:           {}\n", stats.dominant_activity());
+}
@@ -1,39 +1,14 @@
 use colored::Colorize;
-use serde::{Deserialize, Serialize};
-use crate::scoring;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use crypt_sdk::analytics::{CostBasis, GamingAction, GamingDetector, PatternDetector, PnlTracker, WalletTx, MEMECOIN_MINTS};
+use crypt_sdk::scoring::{RarityScorer, ScoreParams, WalletContext};
 use crate::soul;
 
 const HELIUS_BASE: &str = "https://api.helius.xyz/v0";
 
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct HeliusTransaction {
-    pub signature: Option<String>,
-    #[serde(rename = "type")]
-    pub tx_type: Option<String>,
-    pub source: Option<String>,
-    pub timestamp: Option<i64>,
-    pub native_transfers: Option<Vec<NativeTransfer>>,
-    pub token_transfers: Option<Vec<TokenTransfer>>,
-    pub description: Option<String>,
-}
-
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct NativeTransfer {
-    pub from_user_account: Option<String>,
-    pub to_user_account: Option<String>,
-    pub amount: Option<i64>,
-}
-
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct TokenTransfer {
-    pub from_user_account: Option<String>,
-    pub to_user_account: Option<String>,
-    pub mint: Option<String>,
-    pub token_amount: Option<f64>,
-}
+/// Most transfer counterparties whose history is fetched to find their funder
+const MAX_COUNTERPARTIES: usize = 10;
 
 #[derive(Debug, Serialize)]
 pub struct CryptCard {
@@ -44,21 +19,17 @@ pub struct CryptCard {
     pub score: u32,
     pub sol_amount: f64,
     pub title: String,
+    /// Cost-basis PnL booked by the transaction, for sells
+    pub pnl: String,
     pub platform: String,
     pub soul_seed: String,
     pub timestamp: i64,
+    /// Version of the scoring rules that produced `score`
+    pub scoring_version: u16,
+    /// Why the card looks staged to farm score, if it does
+    pub flags: Vec<String>,
 }
 
-/// Known memecoin mints
-const MEMECOINS: &[&str] = &[
-    "DezXAZ8z7PnrnRJjz3wXBoRgixCa6xjnB7YaB1pPB263", // BONK
-    "EKpQGSJtjMFqKZ9KQanSqYXRcF8fBopzLHYxdM65zcjm", // WIF
-    "7GCihgDB8fe6KNjn2MYtkzZcRjQy3t9GHdC8uHYmW2hr", // POPCAT
-    "MEW1gQWJ3nEXg2qgERiKu7FAFj79PHvQVREQUzScPP5",  // MEW
-    "A3eME5CetyZPBoWbRUwY3tSe25S6tb18ba9ZPbWk9eFJ",  // PENG
-    "WENWENvqqNya429ubCdR81ZmD69brwQaaBYY6p3LCpk",   // WEN
-];
-
 /// Known DeFi sources
 const DEFI_SOURCES: &[&str] = &[
     "JUPITER", "RAYDIUM", "ORCA", "MARINADE", "DRIFT",
@@ -73,97 +44,82 @@ pub async fn scan_wallet(
     format: &str,
     min_rarity: Option<&str>,
 ) {
-    // Validate address
-    if address.len() < 32 || address.len() > 44 {
-        eprintln!("{}", "Error: Invalid Solana address".red());
+    let Some(txs) = fetch_transactions::<WalletTx>(address, api_key, limit).await else {
         return;
-    }
-
-    let key = match api_key {
-        Some(k) => k.to_string(),
-        None => {
-            eprintln!("{}", "Error: Helius API key required. Set HELIUS_API_KEY env var or use --api-key".red());
-            eprintln!("Get a free key at: https://helius.dev");
-            return;
-        }
     };
 
-    println!("{} {}", "Scanning wallet:".bright_green(), address.yellow());
-    println!("{} Fetching up to {} transactions...\n", ">>".bright_cyan(), limit);
-
-    // Fetch transactions from Helius
-    let url = format!(
-        "{}/addresses/{}/transactions?api-key={}&limit={}",
-        HELIUS_BASE, address, key, limit
-    );
-
-    let client = reqwest::Client::new();
-    let response = match client.get(&url).send().await {
-        Ok(r) => r,
-        Err(e) => {
-            eprintln!("{} {}", "Helius API error:".red(), e);
-            return;
-        }
-    };
+    println!("{} {} transactions fetched\n", ">>".bright_cyan(), txs.len());
 
-    if !response.status().is_success() {
-        eprintln!("{} HTTP {}", "Helius API error:".red(), response.status());
-        return;
+    // Helius returns newest first; positions build up oldest first
+    let mut history = txs.clone();
+    history.sort_by_key(|tx| tx.timestamp);
+    let pnl = PnlTracker::from_history(address, CostBasis::Fifo, &history);
+    // A short page is the whole history, so its first deposit is the funder
+    let mut gaming = GamingDetector::new(address);
+    if txs.len() < limit {
+        gaming.learn_funder(address, &history);
     }
-
-    let txs: Vec<HeliusTransaction> = match response.json().await {
-        Ok(t) => t,
-        Err(e) => {
-            eprintln!("{} {}", "Parse error:".red(), e);
-            return;
-        }
-    };
-
-    println!("{} {} transactions fetched\n", ">>".bright_cyan(), txs.len());
+    let counterparties = gaming.counterparties(&history);
+    if let Some(key) = api_key {
+        learn_counterparty_funders(&mut gaming, &counterparties, key, limit).await;
+    }
+    let wallet = WalletContext::from_history(&PatternDetector::new(address), &history, None)
+        .with_gaming(&gaming, &history);
+    let scorer = RarityScorer::default();
 
     // Score and classify each transaction
     let mut cards: Vec<CryptCard> = Vec::new();
+    let mut excluded = 0;
 
     for (i, tx) in txs.iter().enumerate() {
-        let tx_type = tx.tx_type.clone().unwrap_or_default();
-        let source = tx.source.clone().unwrap_or_default();
-        let sig = tx.signature.clone().unwrap_or_default();
-        let timestamp = tx.timestamp.unwrap_or(0);
+        let tx_type = tx.tx_type.clone();
+        let source = tx.source.clone();
+        let sig = tx.signature.clone();
+        let timestamp = tx.timestamp;
 
         // Calculate SOL amount
-        let sol_amount = get_sol_amount(tx);
-        let net_sol = get_net_sol(tx, address);
+        let sol_amount = tx.sol_amount();
+        let net_sol = tx.net_sol(address);
 
         // Check for memecoin involvement
-        let is_memecoin = tx.token_transfers.as_ref()
-            .map(|transfers| transfers.iter().any(|t| {
-                t.mint.as_ref().map(|m| MEMECOINS.contains(&m.as_str())).unwrap_or(false)
-            }))
-            .unwrap_or(false);
+        let is_memecoin = tx.token_transfers.iter().any(|t| MEMECOIN_MINTS.contains(&t.mint.as_str()));
 
         let is_defi = DEFI_SOURCES.contains(&source.as_str());
 
-        // Compute score and rarity
-        let score = scoring::compute_rarity_score(&tx_type, sol_amount, is_memecoin, is_defi, net_sol);
-        let rarity = match scoring::score_to_rarity(score) {
-            2 => "LEGENDARY",
-            1 => "RARE",
-            _ => "COMMON",
+        // Compute score and rarity, with bonuses for detected patterns
+        let params = ScoreParams {
+            tx_type: tx_type.clone(),
+            sol_amount,
+            is_memecoin,
+            is_defi_source: is_defi,
+            net_sol,
         };
+        let result = scorer.score_in_context(&params, &tx.signature, &wallet);
+        let score = result.score;
+        let rarity = result.rarity.as_str();
 
         // Apply rarity filter
         if let Some(min) = min_rarity {
             match min.to_uppercase().as_str() {
-                "LEGENDARY" => if rarity != "LEGENDARY" { continue; },
-                "RARE" => if rarity == "COMMON" { continue; },
+                "LEGENDARY" if rarity != "LEGENDARY" => continue,
+                "RARE" if rarity == "COMMON" => continue,
                 _ => {}
             }
         }
 
+        let flags: Vec<_> = wallet.flags_for(&tx.signature).collect();
+        if flags.iter().any(|f| f.action == GamingAction::Exclude) {
+            excluded += 1;
+            continue;
+        }
         if score == 0 { continue; }
 
-        // Build title
-        let title = build_title(&tx_type, sol_amount, &source);
+        // Build title, leading with the PnL for sells
+        let sold = pnl.realizations_for(&tx.signature).find(|r| r.cost_sol > 0.0);
+        let title = match sold {
+            Some(r) => format!("SOLD {} {}", short_mint(&r.mint), r.label()),
+            None => build_title(&tx_type, sol_amount, &source),
+        };
 
         // Generate soul seed
         let seed = soul::compute_soul_seed_bytes(&sig);
@@ -177,14 +133,17 @@ pub async fn scan_wallet(
             score,
             sol_amount,
             title,
+            pnl: sold.map(|r| r.label()).unwrap_or_default(),
             platform: source,
             soul_seed: seed_hex,
             timestamp,
+            scoring_version: result.version,
+            flags: flags.iter().map(|f| f.explain()).collect(),
         });
     }
 
     // Sort by score descending
-    cards.sort_by(|a, b| b.score.cmp(&a.score));
+    cards.sort_by_key(|c| std::cmp::Reverse(c.score));
     cards.truncate(8);
 
     // Output
@@ -208,29 +167,110 @@ pub async fn scan_wallet(
         cards.iter().filter(|c| c.rarity == "RARE").count(),
         cards.iter().filter(|c| c.rarity == "COMMON").count(),
     );
+    let flagged = cards.iter().filter(|c| !c.flags.is_empty()).count();
+    if flagged > 0 || excluded > 0 {
+        println!(
+            "{} {} flagged as possible score farming, {} staged transactions excluded\n",
+            "!!".bright_red(),
+            flagged,
+            excluded,
+        );
+    }
+    let unknown = counterparties.iter().filter(|c| !gaming.funders.contains_key(*c)).count();
+    if !gaming.funders.contains_key(address) {
+        println!(
+            "{} Wallet funder unknown (history longer than {} transactions): shared-funder check not applied\n",
+            "!!".bright_yellow(),
+            limit,
+        );
+    } else if unknown > 0 {
+        println!(
+            "{} Funder unknown for {} of {} transfer counterparties: shared-funder check not applied to them\n",
+            "!!".bright_yellow(),
+            unknown,
+            counterparties.len(),
+        );
+    }
 }
 
-fn get_sol_amount(tx: &HeliusTransaction) -> f64 {
-    tx.native_transfers.as_ref()
-        .map(|transfers| {
-            transfers.iter()
-                .map(|t| (t.amount.unwrap_or(0) as f64 / 1e9).abs())
-                .fold(0.0_f64, f64::max)
-        })
-        .unwrap_or(0.0)
+/// Learn the first funder of up to [`MAX_COUNTERPARTIES`] transfer
+/// counterparties. As for the scanned wallet, only a history that fits
+/// in one page is known to start at the funding deposit.
+async fn learn_counterparty_funders(gaming: &mut GamingDetector, counterparties: &[String], key: &str, limit: usize) {
+    let to_fetch = &counterparties[..counterparties.len().min(MAX_COUNTERPARTIES)];
+    if to_fetch.is_empty() { return; }
+    println!("{} Checking funders of {} transfer counterparties...\n", ">>".bright_cyan(), to_fetch.len());
+    for counterparty in to_fetch {
+        match fetch_page::<WalletTx>(counterparty, key, limit).await {
+            Ok(mut txs) if txs.len() < limit => {
+                txs.sort_by_key(|tx| tx.timestamp);
+                gaming.learn_funder(counterparty, &txs);
+            }
+            Ok(_) => {}
+            Err(e) => eprintln!("{} ({})", e, counterparty),
+        }
+    }
 }
 
-fn get_net_sol(tx: &HeliusTransaction, wallet: &str) -> f64 {
-    tx.native_transfers.as_ref()
-        .map(|transfers| {
-            transfers.iter().fold(0.0, |acc, t| {
-                let amount = t.amount.unwrap_or(0) as f64 / 1e9;
-                if t.to_user_account.as_deref() == Some(wallet) { acc + amount }
-                else if t.from_user_account.as_deref() == Some(wallet) { acc - amount }
-                else { acc }
-            })
-        })
-        .unwrap_or(0.0)
+/// Fetch a wallet's parsed history from Helius, newest first. Prints
+/// the problem and returns `None` on failure.
+pub async fn fetch_transactions<T: DeserializeOwned>(
+    address: &str,
+    api_key: Option<&str>,
+    limit: usize,
+) -> Option<Vec<T>> {
+    // Validate address
+    if address.len() < 32 || address.len() > 44 {
+        eprintln!("{}", "Error: Invalid Solana address".red());
+        return None;
+    }
+
+    let key = match api_key {
+        Some(k) => k.to_string(),
+        None => {
+            eprintln!("{}", "Error: Helius API key required. Set HELIUS_API_KEY env var or use --api-key".red());
+            eprintln!("Get a free key at: https://helius.dev");
+            return None;
+        }
+    };
+
+    println!("{} {}", "Scanning wallet:".bright_green(), address.yellow());
+    println!("{} Fetching up to {} transactions...\n", ">>".bright_cyan(), limit);
+
+    match fetch_page(address, &key, limit).await {
+        Ok(t) => Some(t),
+        Err(e) => {
+            eprintln!("{}", e);
+            None
+        }
+    }
+}
+
+/// One page of `address`'s history from Helius, newest first, without
+/// printing anything.
+async fn fetch_page<T: DeserializeOwned>(address: &str, key: &str, limit: usize) -> Result<Vec<T>, String> {
+    let url = format!(
+        "{}/addresses/{}/transactions?api-key={}&limit={}",
+        HELIUS_BASE, address, key, limit
+    );
+
+    let client = reqwest::Client::new();
+    let response = client
+        .get(&url)
+        .send()
+        .await
+        .map_err(|e| format!("{} {}", "Helius API error:".red(), e))?;
+
+    if !response.status().is_success() {
+        return Err(format!("{} HTTP {}", "Helius API error:".red(), response.status()));
+    }
+
+    response.json().await.map_err(|e| format!("{} {}", "Parse error:".red(), e))
+}
+
+/// Mint address shortened for titles, e.g. `DezX..B263`.
+fn short_mint(mint: &str) -> String {
+    if mint.len() > 10 { format!("{}..{}", &mint[..4], &mint[mint.len() - 4..]) } else { mint.to_string() }
 }
 
 fn build_title(tx_type: &str, sol: f64, source: &str) -> String {
@@ -276,18 +316,22 @@ fn print_cards(cards: &[CryptCard]) {
             card.tx_type, card.score, card.soul_seed,
             card.tx_hash.bright_black(),
             "|".bright_cyan());
+        for flag in &card.flags {
+            println!("  {} {} {}", "|".bright_cyan(), "FLAGGED".bright_red(), flag.bright_black());
+        }
         println!("  {}{}{}",
             border, border.to_string().repeat(38), border);
     }
 }
 
 fn print_table(cards: &[CryptCard]) {
-    println!("{:<4} {:<10} {:<10} {:<5} {:<35} {:<15}",
-        "#", "RARITY", "TYPE", "SCORE", "TITLE", "TX");
-    println!("{}", "─".repeat(80));
+    println!("{:<4} {:<10} {:<10} {:<5} {:<35} {:<15} {:<4}",
+        "#", "RARITY", "TYPE", "SCORE", "TITLE", "TX", "FLAG");
+    println!("{}", "─".repeat(86));
     for card in cards {
-        println!("{:<4} {:<10} {:<10} {:<5} {:<35} {:<15}",
+        println!("{:<4} {:<10} {:<10} {:<5} {:<35} {:<15} {:<4}",
             card.id, card.rarity, card.tx_type, card.score,
-            card.title.chars().take(35).collect::<String>(), card.tx_hash);
+            card.title.chars().take(35).collect::<String>(), card.tx_hash,
+            if card.flags.is_empty() { "" } else { "!" });
     }
 }
@@ -1,41 +1,15 @@
 use colored::Colorize;
 
-/// Compute a deterministic 32-byte soul seed from a transaction hash.
-/// Mirrors the on-chain logic in programs/crypt/src/utils/hashing.rs
+/// Compute a deterministic 32-byte soul seed from a transaction hash,
+/// with the program's algorithm from crypt-core.
 pub fn compute_soul_seed_bytes(tx_hash: &str) -> [u8; 32] {
-    let bytes = tx_hash.as_bytes();
-    let mut seed = [0u8; 32];
-
-    // Phase 1: XOR-fold
-    for (i, &b) in bytes.iter().enumerate() {
-        seed[i % 32] ^= b;
-    }
-
-    // Phase 2: Bit mixing
-    for i in 0..32 {
-        let mut h: u64 = seed[i] as u64;
-        h = h.wrapping_mul(0x517cc1b727220a95);
-        h ^= h >> 17;
-        h = h.wrapping_mul(0x6c62272e07bb0142);
-        h ^= h >> 11;
-        seed[i] = (h & 0xFF) as u8;
-    }
-
-    // Phase 3: Diffusion
-    for i in 1..32 {
-        seed[i] ^= seed[i - 1].wrapping_add(37);
-    }
-    for i in (0..31).rev() {
-        seed[i] ^= seed[i + 1].wrapping_add(53);
-    }
-
-    seed
+    crypt_core::compute_soul_seed(tx_hash)
 }
 
 /// Generate and display a Soul Signature seed for a transaction.
 pub fn generate_soul_seed(tx_hash: &str, verbose: bool) {
     let seed = compute_soul_seed_bytes(tx_hash);
-    let hex_str = hex::encode(&seed);
+    let hex_str = hex::encode(seed);
 
     println!("{}", "  SOUL SIGNATURE".bright_magenta());
     println!("  ────────────────────────────────────");
@@ -1,10 +1,20 @@
 use colored::Colorize;
+use crypt_core::{CardType, Rarity};
+use sha2::{Digest, Sha256};
 use solana_client::rpc_client::RpcClient;
 use solana_sdk::pubkey::Pubkey;
 use std::str::FromStr;
 
-/// Verify a Crypt Card's on-chain data matches its soul signature.
-pub async fn verify_card(card_address: &str, rpc_url: &str) {
+pub const PROGRAM_ID: &str = "CRYPTxGraveyardSo1ana1111111111111111111111";
+
+/// Verify a Crypt Card's on-chain data and print a per-check report.
+pub async fn verify_card(
+    card_address: &str,
+    rpc_url: &str,
+    narration_file: Option<&str>,
+    tx_hash: Option<&str>,
+    minter: Option<&str>,
+) {
     println!("{} Verifying card: {}", ">>".bright_cyan(), card_address.yellow());
 
     let pubkey = match Pubkey::from_str(card_address) {
@@ -14,50 +24,182 @@ pub async fn verify_card(card_address: &str, rpc_url: &str) {
             return;
         }
     };
+    let minter = match minter.map(Pubkey::from_str).transpose() {
+        Ok(m) => m,
+        Err(e) => {
+            eprintln!("{} Invalid minter address: {}", "ERROR".red(), e);
+            return;
+        }
+    };
+    let narration = match narration_file.map(std::fs::read_to_string).transpose() {
+        Ok(n) => n,
+        Err(e) => {
+            eprintln!("{} Could not read narration file: {}", "ERROR".red(), e);
+            return;
+        }
+    };
 
+    let program_id = Pubkey::from_str(PROGRAM_ID).expect("Invalid program ID");
     let client = RpcClient::new(rpc_url.to_string());
 
-    match client.get_account(&pubkey) {
-        Ok(account) => {
-            println!("  {} Account found", "OK".bright_green());
-            println!("  Owner:    {}", account.owner);
-            println!("  Lamports: {}", account.lamports);
-            println!("  Data len: {} bytes", account.data.len());
-
-            if account.data.len() < 8 {
-                eprintln!("  {} Account data too small for Crypt Card", "WARN".yellow());
-                return;
-            }
-
-            // Check discriminator (first 8 bytes)
-            let disc = &account.data[..8];
-            println!("  Disc:     {}", hex::encode(disc).bright_black());
-
-            // If this is a Crypt Card, verify soul seed
-            if account.data.len() > 100 {
-                println!("\n  {} Card data detected — verifying soul signature...", ">>".bright_cyan());
-
-                // Extract tx_hash from account data (after discriminator + owner + mint_id)
-                // offset: 8 (disc) + 32 (owner) + 8 (mint_id) + 4 (string len) = 52
-                let tx_hash_len = u32::from_le_bytes(
-                    account.data[52..56].try_into().unwrap_or([0; 4])
-                ) as usize;
-
-                if tx_hash_len > 0 && tx_hash_len < 90 && 56 + tx_hash_len <= account.data.len() {
-                    let tx_hash = String::from_utf8_lossy(&account.data[56..56+tx_hash_len]);
-                    println!("  TX Hash:  {}", tx_hash.bright_yellow());
-
-                    let seed = crate::soul::compute_soul_seed_bytes(&tx_hash);
-                    println!("  Expected: {}", hex::encode(&seed[..8]).bright_cyan());
-                    println!("\n  {} Soul signature verification complete", "OK".bright_green());
-                } else {
-                    println!("  {} Could not extract tx_hash from account data", "WARN".yellow());
-                }
-            }
-        }
+    let account = match client.get_account(&pubkey) {
+        Ok(account) => account,
         Err(e) => {
             eprintln!("  {} Account not found: {}", "ERROR".red(), e);
             eprintln!("  Make sure you're using the correct network (--rpc flag)");
+            return;
         }
+    };
+
+    println!("  {} Account found", "OK".bright_green());
+    println!("  Owner:    {}", account.owner);
+    println!("  Lamports: {}", account.lamports);
+    println!("  Data len: {} bytes", account.data.len());
+
+    if account.owner != program_id {
+        eprintln!("  {} Account is not owned by the Crypt program", "FAIL".red());
+        return;
+    }
+
+    let card = match decode_card(&account.data) {
+        Some(card) => card,
+        None => {
+            eprintln!("  {} Could not decode card account data", "WARN".yellow());
+            return;
+        }
+    };
+
+    println!("  Card:     #{}", card.mint_id);
+    println!("  TX Hash:  {}", card.tx_hash.bright_yellow());
+    if card.version < CARD_VERSION {
+        println!(
+            "  Layout:   v{} {}",
+            card.version,
+            "(legacy — run migrate_card to upgrade)".yellow()
+        );
+    } else {
+        println!("  Layout:   v{}", card.version);
+    }
+
+    // Total minted lives right after the authority in the collection account
+    let (collection, _) = Pubkey::find_program_address(&[b"collection"], &program_id);
+    let total_minted = client
+        .get_account(&collection)
+        .ok()
+        .filter(|a| a.owner == program_id && a.data.len() >= 48)
+        .map(|a| u64::from_le_bytes(a.data[40..48].try_into().unwrap()));
+
+    let minter = minter.unwrap_or(card.owner);
+    let pda_valid = Pubkey::create_program_address(
+        &[b"card", card.tx_hash.as_bytes(), minter.as_ref(), &[card.bump]],
+        &program_id,
+    )
+    .map(|address| address == pubkey)
+    .unwrap_or(false);
+
+    let seed = crate::soul::compute_soul_seed_bytes(&card.tx_hash);
+
+    println!("\n  {} Verification report:", ">>".bright_cyan());
+    print_check(
+        "tx_hash",
+        tx_hash.map(|expected| expected == card.tx_hash),
+        "stored hash matches --tx-hash",
+    );
+    print_check(
+        "soul_seed",
+        Some(seed == card.soul_seed),
+        "seed derives from the stored tx_hash",
+    );
+    print_check(
+        "narration",
+        narration.map(|text| Sha256::digest(text.as_bytes())[..] == card.narration_hash[..]),
+        "narration file hashes to narration_hash",
+    );
+    print_check(
+        "pda",
+        Some(pda_valid),
+        "address derives from [card, tx_hash, minter]",
+    );
+    print_check(
+        "tier",
+        Some(Rarity::from_u8(card.rarity).is_some() && CardType::from_u8(card.card_type).is_some()),
+        "rarity and card type are known values (rarity is not re-scored)",
+    );
+    print_check(
+        "collection",
+        total_minted.map(|total| card.mint_id < total),
+        "canonical collection has minted this ID",
+    );
+}
+
+/// Print one report line. `None` means the check was skipped.
+fn print_check(name: &str, result: Option<bool>, description: &str) {
+    let status = match result {
+        Some(true) => "PASS".bright_green(),
+        Some(false) => "FAIL".red(),
+        None => "SKIP".bright_black(),
+    };
+    println!("    [{}] {:<11} {}", status, name, description.bright_black());
+}
+
+/// Newest card layout version this CLI understands.
+/// Version 0 is the original layout, which had no version byte.
+const CARD_VERSION: u8 = 5;
+
+/// The fields of an on-chain CryptCard that verification needs.
+pub struct CardAccount {
+    owner: Pubkey,
+    pub mint_id: u64,
+    tx_hash: String,
+    rarity: u8,
+    card_type: u8,
+    narration_hash: [u8; 32],
+    soul_seed: [u8; 32],
+    bump: u8,
+    version: u8,
+}
+
+/// Walk the Borsh layout of a CryptCard account (discriminator included).
+/// Strings are variable length, so offsets past `tx_hash` can't be hardcoded.
+pub fn decode_card(data: &[u8]) -> Option<CardAccount> {
+    let mut r = Cursor { data, pos: 8 };
+
+    let owner = Pubkey::new_from_array(r.take(32)?.try_into().ok()?);
+    let mint_id = u64::from_le_bytes(r.take(8)?.try_into().ok()?);
+    let tx_hash = r.string()?;
+    let rarity = r.take(1)?[0];
+    let card_type = r.take(1)?[0];
+    r.string()?;                                // title
+    let narration_hash: [u8; 32] = r.take(32)?.try_into().ok()?;
+    let soul_seed: [u8; 32] = r.take(32)?.try_into().ok()?;
+    r.string()?;                                // platform
+    r.string()?;                                // pnl
+    r.take(8 + 8 + 8)?;                         // tx_timestamp, minted_at, interaction_count
+    r.string()?;                                // soundtrack_id
+    let bump = r.take(1)?[0];
+
+    // v1+: version byte. A v0 account either ends here or is zero-padded.
+    let version = r.take(1).map(|b| b[0]).unwrap_or(0);
+
+    Some(CardAccount {
+        owner, mint_id, tx_hash, rarity, card_type, narration_hash, soul_seed, bump, version,
+    })
+}
+
+struct Cursor<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+        let bytes = self.data.get(self.pos..self.pos.checked_add(len)?)?;
+        self.pos += len;
+        Some(bytes)
+    }
+
+    fn string(&mut self) -> Option<String> {
+        let len = u32::from_le_bytes(self.take(4)?.try_into().ok()?) as usize;
+        Some(String::from_utf8_lossy(self.take(len)?).to_string())
     }
 }
@@ -0,0 +1,199 @@
+//! Duel rules — how two cards fight.
+//!
+//! The program resolves duels with these rules and the SDK simulates
+//! them offline, so a simulation always agrees with the chain.
+//!
+//! A card's power is its rarity base plus bonuses from its soul traits,
+//! +20% if its card type counters the opponent's. Each side then adds
+//! luck taken from a roll that both players commit to in advance, so
+//! neither side can pick a winning roll after seeing the other's.
+//!
+//! Type counters form a five-way cycle: each type beats the next two.
+//!   SWAP > RUG, MINT     RUG > MINT, DIAMOND_HANDS     MINT > DIAMOND_HANDS, BIG_MOVE
+//!   DIAMOND_HANDS > BIG_MOVE, SWAP     BIG_MOVE > SWAP, RUG
+
+use crate::soul::SoulTraits;
+
+/// Most luck a single roll can add to one side.
+pub const MAX_DUEL_LUCK: u32 = 60;
+
+/// The soul traits that matter in a duel, taken from [`SoulTraits`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DuelTraits {
+    pub eye_style: u8,
+    pub glow_eyes: bool,
+    pub hat_type: u8,
+    pub glasses_type: u8,
+    pub teeth_style: u8,
+    pub has_scar: bool,
+    pub has_crack: bool,
+    pub has_eyepatch: bool,
+}
+
+impl DuelTraits {
+    pub fn from_seed(seed: &[u8; 32]) -> Self {
+        Self::from(&SoulTraits::from_seed(seed))
+    }
+
+    /// Bonus power from traits (0-62).
+    pub fn power(&self) -> u32 {
+        let mut power = self.eye_style as u32 * 2
+            + self.hat_type as u32 / 3
+            + self.glasses_type as u32 / 2
+            + self.teeth_style as u32;
+        if self.glow_eyes { power += 10; }
+        if self.has_scar { power += 8; }
+        if self.has_crack { power += 5; }
+        if self.has_eyepatch { power += 15; }
+        power
+    }
+}
+
+impl From<&SoulTraits> for DuelTraits {
+    fn from(traits: &SoulTraits) -> Self {
+        Self {
+            eye_style: traits.eye_style,
+            glow_eyes: traits.glow_eyes,
+            hat_type: traits.hat_type,
+            glasses_type: traits.glasses_type,
+            teeth_style: traits.teeth_style,
+            has_scar: traits.has_scar,
+            has_crack: traits.has_crack,
+            has_eyepatch: traits.has_eyepatch,
+        }
+    }
+}
+
+/// One side of a duel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Duelist {
+    pub traits: DuelTraits,
+    pub rarity: u8,
+    pub card_type: u8,
+}
+
+impl Duelist {
+    pub fn new(soul_seed: &[u8; 32], rarity: u8, card_type: u8) -> Self {
+        Self { traits: DuelTraits::from_seed(soul_seed), rarity, card_type }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DuelOutcome {
+    ChallengerWins,
+    OpponentWins,
+    Draw,
+}
+
+/// Base power for each rarity tier.
+pub fn rarity_power(rarity: u8) -> u32 {
+    match rarity {
+        2 => 200,   // Legendary
+        1 => 140,   // Rare
+        _ => 100,   // Common
+    }
+}
+
+/// Whether `attacker`'s card type counters `defender`'s.
+pub fn has_type_advantage(attacker: u8, defender: u8) -> bool {
+    if attacker > 4 || defender > 4 {
+        return false;
+    }
+    matches!((defender + 5 - attacker) % 5, 1 | 2)
+}
+
+/// Power of `duelist` when facing `opponent`, before luck.
+pub fn duel_power(duelist: &Duelist, opponent: &Duelist) -> u32 {
+    let power = rarity_power(duelist.rarity) + duelist.traits.power();
+    if has_type_advantage(duelist.card_type, opponent.card_type) {
+        power + power / 5
+    } else {
+        power
+    }
+}
+
+/// Luck for one side (0 = challenger, 1 = opponent) from the shared roll.
+pub fn duel_luck(roll: &[u8; 32], side: usize) -> u32 {
+    let i = side * 2;
+    u16::from_le_bytes([roll[i], roll[i + 1]]) as u32 % (MAX_DUEL_LUCK + 1)
+}
+
+/// Decide a duel. `roll` is SHA-256(challenger_nonce || opponent_nonce).
+pub fn resolve_duel(challenger: &Duelist, opponent: &Duelist, roll: &[u8; 32]) -> DuelOutcome {
+    let challenger_total = duel_power(challenger, opponent) + duel_luck(roll, 0);
+    let opponent_total = duel_power(opponent, challenger) + duel_luck(roll, 1);
+
+    match challenger_total.cmp(&opponent_total) {
+        core::cmp::Ordering::Greater => DuelOutcome::ChallengerWins,
+        core::cmp::Ordering::Less => DuelOutcome::OpponentWins,
+        core::cmp::Ordering::Equal => DuelOutcome::Draw,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plain(rarity: u8, card_type: u8) -> Duelist {
+        Duelist::new(&[0u8; 32], rarity, card_type)
+    }
+
+    #[test]
+    fn test_type_advantage_cycle() {
+        for attacker in 0..5u8 {
+            let beaten = (0..5u8).filter(|&d| has_type_advantage(attacker, d)).count();
+            assert_eq!(beaten, 2, "Each type should counter exactly two others");
+            assert!(!has_type_advantage(attacker, attacker));
+            for defender in 0..5u8 {
+                assert!(!(has_type_advantage(attacker, defender) && has_type_advantage(defender, attacker)));
+            }
+        }
+        assert!(has_type_advantage(0, 1)); // SWAP > RUG
+        assert!(!has_type_advantage(7, 1));
+    }
+
+    #[test]
+    fn test_trait_power_bounds() {
+        assert_eq!(DuelTraits::from_seed(&[0u8; 32]).power(), 0);
+        let maxed = DuelTraits {
+            eye_style: 7, glow_eyes: true, hat_type: 29, glasses_type: 11, teeth_style: 5,
+            has_scar: true, has_crack: true, has_eyepatch: true,
+        };
+        assert_eq!(maxed.power(), 14 + 9 + 5 + 5 + 10 + 8 + 5 + 15);
+    }
+
+    #[test]
+    fn test_legendary_beats_common_on_any_roll() {
+        // Even max luck and a type counter can't close a 100-point rarity gap
+        let legendary = plain(2, 1);
+        let common = plain(0, 0);
+        let roll = [0xFF; 32];
+        let mut unlucky = roll;
+        unlucky[0] = 0;
+        unlucky[1] = 0;
+        assert_eq!(resolve_duel(&legendary, &common, &unlucky), DuelOutcome::ChallengerWins);
+        assert_eq!(resolve_duel(&common, &legendary, &roll), DuelOutcome::OpponentWins);
+    }
+
+    #[test]
+    fn test_type_advantage_breaks_mirror() {
+        let swap = plain(1, 0);
+        let rug = plain(1, 1);
+        assert_eq!(resolve_duel(&swap, &rug, &[0u8; 32]), DuelOutcome::ChallengerWins);
+        assert_eq!(resolve_duel(&rug, &swap, &[0u8; 32]), DuelOutcome::OpponentWins);
+    }
+
+    #[test]
+    fn test_identical_cards_same_luck_draw() {
+        let card = plain(1, 2);
+        assert_eq!(resolve_duel(&card, &card, &[3u8; 32]), DuelOutcome::Draw);
+    }
+
+    #[test]
+    fn test_luck_bounded() {
+        for b in 0..=255u8 {
+            assert!(duel_luck(&[b; 32], 0) <= MAX_DUEL_LUCK);
+            assert!(duel_luck(&[b; 32], 1) <= MAX_DUEL_LUCK);
+        }
+    }
+}
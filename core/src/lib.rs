@@ -0,0 +1,38 @@
+//! Crypt Core — the definitions every Crypt crate has to agree on.
+//!
+//! The on-chain program, the SDK, the CLI, the indexer and the
+//! integration tests all depend on this crate rather than keeping their
+//! own copies of:
+//! - The soul seed algorithm, soul traits and upgrade proofs
+//! - Rarity scoring, in lamports so it runs without floats
+//! - The `Rarity` and `CardType` enums and their on-chain values
+//! - `CollectionMetrics`, as the indexer reports them
+//! - Duel rules, so offline simulations match the program
+//! - Distributor merkle leaves, nodes and proof checks
+//! - Where wrapped cards' Metaplex JSON is hosted
+//!
+//! It is `no_std` and only requires `sha2` (without its `std` feature),
+//! so the program can use it on-chain. The optional `serde` feature derives `Serialize` and
+//! `Deserialize` for the enums and metrics.
+//!
+//! [`vectors`] holds known-good outputs; any port of these algorithms
+//! (the frontend's included) should reproduce them.
+
+#![no_std]
+
+pub mod types;
+pub mod soul;
+pub mod scoring;
+pub mod metrics;
+pub mod duel;
+pub mod merkle;
+pub mod nft;
+pub mod vectors;
+
+pub use types::*;
+pub use soul::*;
+pub use scoring::*;
+pub use metrics::*;
+pub use duel::*;
+pub use merkle::*;
+pub use nft::*;
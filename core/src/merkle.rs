@@ -0,0 +1,87 @@
+//! Merkle proofs for distributor claims.
+//!
+//! Leaves and inner nodes are domain-separated so a leaf can never be
+//! passed off as a node. Pairs are hashed in sorted order, so a proof is
+//! just the list of sibling hashes — no left/right flags. An odd node at
+//! the end of a level is carried up unchanged.
+//!
+//! The program checks claims with these, and the indexer builds its
+//! trees with them, so a root from a snapshot always verifies on-chain.
+
+use sha2::{Digest, Sha256};
+
+/// Longest proof accepted — enough for 2^32 claimants.
+pub const MAX_PROOF_LEN: usize = 32;
+
+/// Leaf for one allocation: SHA-256 of `0x00 || claimant || amount (LE)`.
+pub fn distribution_leaf(claimant: &[u8; 32], amount: u64) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update([0u8]);
+    hasher.update(claimant);
+    hasher.update(amount.to_le_bytes());
+    hasher.finalize().into()
+}
+
+/// Inner node: SHA-256 of `0x01 || min(a, b) || max(a, b)`.
+pub fn merkle_node(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+    let mut hasher = Sha256::new();
+    hasher.update([1u8]);
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+/// Whether `proof` leads from `leaf` to `root`. Proofs longer than
+/// [`MAX_PROOF_LEN`] are rejected outright.
+pub fn verify_merkle_proof(proof: &[[u8; 32]], root: &[u8; 32], leaf: &[u8; 32]) -> bool {
+    proof.len() <= MAX_PROOF_LEN
+        && proof.iter().fold(*leaf, |node, sibling| merkle_node(&node, sibling)) == *root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leaves() -> [[u8; 32]; 3] {
+        [
+            distribution_leaf(&[1; 32], 100),
+            distribution_leaf(&[2; 32], 250),
+            distribution_leaf(&[3; 32], 50),
+        ]
+    }
+
+    #[test]
+    fn test_three_leaf_tree() {
+        let [a, b, c] = leaves();
+        let ab = merkle_node(&a, &b);
+        let root = merkle_node(&ab, &c);
+
+        assert!(verify_merkle_proof(&[b, c], &root, &a));
+        assert!(verify_merkle_proof(&[a, c], &root, &b));
+        // The odd leaf was carried up, so its proof is one hash
+        assert!(verify_merkle_proof(&[ab], &root, &c));
+    }
+
+    #[test]
+    fn test_wrong_amount_or_claimant_fails() {
+        let [a, b, c] = leaves();
+        let root = merkle_node(&merkle_node(&a, &b), &c);
+        assert!(!verify_merkle_proof(&[b, c], &root, &distribution_leaf(&[1; 32], 101)));
+        assert!(!verify_merkle_proof(&[b, c], &root, &distribution_leaf(&[9; 32], 100)));
+    }
+
+    #[test]
+    fn test_node_order_independent() {
+        let [a, b, _] = leaves();
+        assert_eq!(merkle_node(&a, &b), merkle_node(&b, &a));
+    }
+
+    #[test]
+    fn test_overlong_proof_rejected() {
+        let [a, ..] = leaves();
+        let proof = [[0u8; 32]; MAX_PROOF_LEN + 1];
+        let root = proof.iter().fold(a, |node, sibling| merkle_node(&node, sibling));
+        assert!(!verify_merkle_proof(&proof, &root, &a));
+    }
+}
@@ -1,22 +1,45 @@
 //! Collection-level metrics and analytics.
+//! The indexer fills these in from indexed events; the CLI and any
+//! other reader get them back over its HTTP API.
+
+use crate::types::{CardType, Rarity};
 
 /// Collection health metrics.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CollectionMetrics {
+    /// Unix timestamp the rolling counts are relative to
+    pub computed_at: i64,
+    /// Every card ever minted, burned ones included
     pub total_cards: u64,
     pub active_cards: u64,
     pub burned_cards: u64,
+    /// Wallets holding at least one live card
     pub unique_owners: u64,
     pub total_interactions: u64,
     pub avg_interactions_per_card: f64,
+    /// Live cards by rarity
     pub rarity_distribution: RarityDistribution,
+    /// Live cards by type
     pub type_distribution: TypeDistribution,
     pub mints_last_24h: u64,
     pub mints_last_7d: u64,
     pub transfers_last_24h: u64,
 }
 
-#[derive(Debug, Clone, Default)]
+/// Collection activity over one trailing window.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WindowMetrics {
+    pub window_secs: i64,
+    pub mints: u64,
+    pub transfers: u64,
+    pub burns: u64,
+    pub interactions: u64,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RarityDistribution {
     pub common: u64,
     pub rare: u64,
@@ -24,6 +47,14 @@ pub struct RarityDistribution {
 }
 
 impl RarityDistribution {
+    pub fn add(&mut self, rarity: Rarity) {
+        match rarity {
+            Rarity::Common => self.common += 1,
+            Rarity::Rare => self.rare += 1,
+            Rarity::Legendary => self.legendary += 1,
+        }
+    }
+
     pub fn total(&self) -> u64 {
         self.common + self.rare + self.legendary
     }
@@ -41,7 +72,8 @@ impl RarityDistribution {
     }
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TypeDistribution {
     pub swaps: u64,
     pub rugs: u64,
@@ -51,6 +83,16 @@ pub struct TypeDistribution {
 }
 
 impl TypeDistribution {
+    pub fn add(&mut self, card_type: CardType) {
+        match card_type {
+            CardType::Swap => self.swaps += 1,
+            CardType::Rug => self.rugs += 1,
+            CardType::Mint => self.mints += 1,
+            CardType::DiamondHands => self.diamond_hands += 1,
+            CardType::BigMove => self.big_moves += 1,
+        }
+    }
+
     pub fn total(&self) -> u64 {
         self.swaps + self.rugs + self.mints + self.diamond_hands + self.big_moves
     }
@@ -86,6 +128,20 @@ mod tests {
         assert_eq!(dist.common_pct(), 0.0);
     }
 
+    #[test]
+    fn test_distributions_add() {
+        let mut rarity = RarityDistribution::default();
+        rarity.add(Rarity::Rare);
+        rarity.add(Rarity::Rare);
+        rarity.add(Rarity::Legendary);
+        assert_eq!((rarity.common, rarity.rare, rarity.legendary), (0, 2, 1));
+
+        let mut types = TypeDistribution::default();
+        types.add(CardType::BigMove);
+        assert_eq!(types.total(), 1);
+        assert_eq!(types.most_common(), "BIG_MOVE");
+    }
+
     #[test]
     fn test_most_common_type() {
         let dist = TypeDistribution { swaps: 50, rugs: 10, mints: 20, diamond_hands: 5, big_moves: 15 };
@@ -0,0 +1,38 @@
+//! Naming for cards wrapped as Metaplex NFTs.
+//!
+//! `wrap_card` writes the on-chain name, symbol and URI itself, so
+//! clients only have to host the JSON where the program points.
+
+/// Symbol on every wrapped card, as in the frontend's `buildCardMetadata`.
+pub const NFT_SYMBOL: &str = "CRYPT";
+
+/// Where wrapped cards' JSON lives: the collection URI without its file
+/// name, so `https://crypt.cards/collection.json` serves card 7 at
+/// `https://crypt.cards/7.json`. A URI with no path is used as is.
+pub fn metadata_base(collection_uri: &str) -> &str {
+    let uri = collection_uri.trim_end_matches('/');
+    let path_start = uri.find("://").map(|i| i + 3).unwrap_or(0);
+    match uri[path_start..].rfind('/') {
+        Some(i) => &uri[..path_start + i],
+        None => uri,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metadata_base_drops_file_name() {
+        assert_eq!(metadata_base("https://crypt.cards/collection.json"), "https://crypt.cards");
+        assert_eq!(metadata_base("https://cdn.crypt.cards/v2/collection.json"), "https://cdn.crypt.cards/v2");
+        assert_eq!(metadata_base("ar://abc123/collection.json"), "ar://abc123");
+    }
+
+    #[test]
+    fn test_metadata_base_without_path() {
+        assert_eq!(metadata_base("https://crypt.cards"), "https://crypt.cards");
+        assert_eq!(metadata_base("https://crypt.cards/"), "https://crypt.cards");
+        assert_eq!(metadata_base(""), "");
+    }
+}
@@ -0,0 +1,397 @@
+//! Transaction scoring engine — mirrors the frontend scoring logic.
+//! Determines rarity tier based on transaction characteristics.
+//!
+//! Score thresholds:
+//!   0-39:  Common  — dust trades, small transfers, unknown types
+//!   40-74: Rare    — memecoin apes, notable NFT sales, big moves
+//!   75+:   Legendary — whale trades, rug survivals, token creation
+//!
+//! Scoring factors:
+//!   - Transaction value
+//!   - Transaction type (swap, NFT mint/sale, transfer, stake)
+//!   - Platform source (Jupiter, Raydium, etc.)
+//!   - Token classification (memecoin, blue chip, stablecoin)
+//!   - Net PnL direction
+//!
+//! Amounts are in lamports, so scoring needs no floats. Each point
+//! change is a [`Factor`], which callers can turn into an explanation.
+
+use crate::types::Rarity;
+
+pub const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
+
+/// Latest version of the SDK scorer, recorded on cards as
+/// `scoring_version` so a card's rarity can be traced back to the rules
+/// that produced it. The program rejects mints claiming a later one.
+/// 1: transaction type and SOL amount. 2: adds pattern bonuses.
+/// 3: adds anti-gaming penalties.
+pub const SCORING_VERSION: u16 = 3;
+
+const fn sol(n: u64) -> u64 {
+    n * LAMPORTS_PER_SOL
+}
+
+const fn milli_sol(n: u64) -> u64 {
+    n * (LAMPORTS_PER_SOL / 1_000)
+}
+
+/// Transaction types the scorer tells apart. Helius types that share
+/// rules share a kind, e.g. `TRANSFER` and `SOL_TRANSFER`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TxKind {
+    Swap,
+    NftMint,
+    NftSale,
+    Transfer,
+    Stake,
+    TokenMint,
+    Burn,
+    Unknown,
+}
+
+impl TxKind {
+    pub fn parse(tx_type: &str) -> Self {
+        match tx_type {
+            "SWAP" => TxKind::Swap,
+            "NFT_MINT" | "COMPRESSED_NFT_MINT" => TxKind::NftMint,
+            "NFT_SALE" => TxKind::NftSale,
+            "TRANSFER" | "SOL_TRANSFER" => TxKind::Transfer,
+            "STAKE_SOL" | "UNSTAKE_SOL" => TxKind::Stake,
+            "TOKEN_MINT" => TxKind::TokenMint,
+            "BURN" | "BURN_NFT" => TxKind::Burn,
+            _ => TxKind::Unknown,
+        }
+    }
+
+    /// Mints and burns are worth a card whatever they moved.
+    fn dust_exempt(&self) -> bool {
+        matches!(self, TxKind::NftMint | TxKind::TokenMint | TxKind::Burn)
+    }
+}
+
+/// One reason a transaction gained or lost points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Factor {
+    SwapBase,
+    WhaleTrade,
+    LargeTrade,
+    NotableTrade,
+    SolidTrade,
+    SmallTrade,
+    DustTrade,
+    DefiSource,
+    Memecoin,
+    MintBase,
+    PremiumMint,
+    PaidMint,
+    SaleBase,
+    WhaleSale,
+    BigSale,
+    Sale,
+    SmallSale,
+    Profit,
+    MassiveTransfer,
+    WhaleMove,
+    BigMove,
+    Transfer,
+    SmallTransfer,
+    WhaleStake,
+    Stake,
+    SmallStake,
+    TokenCreation,
+    Burn,
+    UnknownType,
+    DustPenalty,
+}
+
+/// What a factor's explanation shows after its label.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FactorDetail {
+    None,
+    /// The amount in SOL, to this many decimals
+    Sol { decimals: usize },
+    /// The raw transaction type
+    TxType,
+}
+
+impl Factor {
+    pub fn points(&self) -> i32 {
+        match self {
+            Factor::SwapBase => 25,
+            Factor::WhaleTrade => 80,
+            Factor::LargeTrade => 60,
+            Factor::NotableTrade => 35,
+            Factor::SolidTrade => 15,
+            Factor::SmallTrade => 5,
+            Factor::DustTrade => -5,
+            Factor::DefiSource => 5,
+            Factor::Memecoin => 25,
+            Factor::MintBase => 35,
+            Factor::PremiumMint => 40,
+            Factor::PaidMint => 20,
+            Factor::SaleBase => 30,
+            Factor::WhaleSale => 70,
+            Factor::BigSale => 40,
+            Factor::Sale => 15,
+            Factor::SmallSale => -5,
+            Factor::Profit => 20,
+            Factor::MassiveTransfer => 80,
+            Factor::WhaleMove => 55,
+            Factor::BigMove => 25,
+            Factor::Transfer => 10,
+            Factor::SmallTransfer => -15,
+            Factor::WhaleStake => 50,
+            Factor::Stake => 25,
+            Factor::SmallStake => 5,
+            Factor::TokenCreation => 50,
+            Factor::Burn => 20,
+            Factor::UnknownType => -20,
+            Factor::DustPenalty => -25,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Factor::SwapBase => "Base: SWAP",
+            Factor::WhaleTrade => "Whale trade",
+            Factor::LargeTrade => "Large trade",
+            Factor::NotableTrade => "Notable trade",
+            Factor::SolidTrade => "Solid trade",
+            Factor::SmallTrade => "Small trade",
+            Factor::DustTrade => "Dust trade",
+            Factor::DefiSource => "DeFi source",
+            Factor::Memecoin => "Memecoin",
+            Factor::MintBase => "Base: NFT_MINT",
+            Factor::PremiumMint => "Premium mint",
+            Factor::PaidMint => "Paid mint",
+            Factor::SaleBase => "Base: NFT_SALE",
+            Factor::WhaleSale => "Whale sale",
+            Factor::BigSale => "Big sale",
+            Factor::Sale => "Sale",
+            Factor::SmallSale => "Small sale",
+            Factor::Profit => "Profit",
+            Factor::MassiveTransfer => "Massive",
+            Factor::WhaleMove => "Whale move",
+            Factor::BigMove => "Big move",
+            Factor::Transfer => "Transfer",
+            Factor::SmallTransfer => "Small transfer",
+            Factor::WhaleStake => "Whale stake",
+            Factor::Stake => "Stake",
+            Factor::SmallStake => "Small stake",
+            Factor::TokenCreation => "Token creation",
+            Factor::Burn => "Burn",
+            Factor::UnknownType => "Unknown type",
+            Factor::DustPenalty => "Dust penalty",
+        }
+    }
+
+    pub fn detail(&self) -> FactorDetail {
+        match self {
+            Factor::WhaleTrade | Factor::LargeTrade | Factor::NotableTrade | Factor::SolidTrade
+            | Factor::PremiumMint | Factor::PaidMint
+            | Factor::WhaleSale | Factor::BigSale | Factor::Sale => FactorDetail::Sol { decimals: 1 },
+            Factor::MassiveTransfer | Factor::WhaleMove | Factor::BigMove
+            | Factor::WhaleStake | Factor::Stake => FactorDetail::Sol { decimals: 0 },
+            Factor::UnknownType => FactorDetail::TxType,
+            _ => FactorDetail::None,
+        }
+    }
+}
+
+/// Most factors one transaction can collect: a swap's base, size,
+/// source, memecoin and dust factors.
+pub const MAX_FACTORS: usize = 5;
+
+/// Transaction characteristics to score.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScoreInput {
+    pub kind: TxKind,
+    /// Value moved, in lamports
+    pub lamports: u64,
+    pub is_memecoin: bool,
+    pub is_defi_source: bool,
+    /// Net SOL change for the wallet; only its sign is scored
+    pub net_lamports: i64,
+}
+
+/// A score and the factors that add up to it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScoreBreakdown {
+    factors: [Factor; MAX_FACTORS],
+    len: usize,
+}
+
+impl ScoreBreakdown {
+    fn push(&mut self, factor: Factor) {
+        self.factors[self.len] = factor;
+        self.len += 1;
+    }
+
+    pub fn factors(&self) -> &[Factor] {
+        &self.factors[..self.len]
+    }
+
+    /// Sum of the factors, floored at zero.
+    pub fn score(&self) -> u32 {
+        self.factors().iter().map(Factor::points).sum::<i32>().max(0) as u32
+    }
+}
+
+/// Score a transaction, keeping every factor that contributed.
+pub fn score_breakdown(input: &ScoreInput) -> ScoreBreakdown {
+    let mut b = ScoreBreakdown { factors: [Factor::DustPenalty; MAX_FACTORS], len: 0 };
+    let amount = input.lamports;
+
+    match input.kind {
+        TxKind::Swap => {
+            b.push(Factor::SwapBase);
+            b.push(if amount > sol(100) { Factor::WhaleTrade }
+                else if amount > sol(50) { Factor::LargeTrade }
+                else if amount > sol(10) { Factor::NotableTrade }
+                else if amount > sol(2) { Factor::SolidTrade }
+                else if amount > milli_sol(500) { Factor::SmallTrade }
+                else { Factor::DustTrade });
+
+            if input.is_defi_source { b.push(Factor::DefiSource); }
+            if input.is_memecoin { b.push(Factor::Memecoin); }
+        }
+        TxKind::NftMint => {
+            b.push(Factor::MintBase);
+            if amount > sol(10) { b.push(Factor::PremiumMint); }
+            else if amount > sol(2) { b.push(Factor::PaidMint); }
+        }
+        TxKind::NftSale => {
+            b.push(Factor::SaleBase);
+            b.push(if amount > sol(50) { Factor::WhaleSale }
+                else if amount > sol(10) { Factor::BigSale }
+                else if amount > sol(2) { Factor::Sale }
+                else { Factor::SmallSale });
+            if input.net_lamports > 0 { b.push(Factor::Profit); }
+        }
+        TxKind::Transfer => {
+            b.push(if amount > sol(500) { Factor::MassiveTransfer }
+                else if amount > sol(100) { Factor::WhaleMove }
+                else if amount > sol(20) { Factor::BigMove }
+                else if amount > sol(5) { Factor::Transfer }
+                else { Factor::SmallTransfer });
+        }
+        TxKind::Stake => {
+            b.push(if amount > sol(100) { Factor::WhaleStake }
+                else if amount > sol(20) { Factor::Stake }
+                else { Factor::SmallStake });
+        }
+        TxKind::TokenMint => b.push(Factor::TokenCreation),
+        TxKind::Burn => b.push(Factor::Burn),
+        TxKind::Unknown => b.push(Factor::UnknownType),
+    }
+
+    // Deductions for dust transactions
+    if amount < milli_sol(10) && !input.kind.dust_exempt() {
+        b.push(Factor::DustPenalty);
+    }
+
+    b
+}
+
+/// Score a transaction of Helius type `tx_type` that moved `lamports`.
+pub fn compute_rarity_score(
+    tx_type: &str,
+    lamports: u64,
+    is_memecoin: bool,
+    is_defi_source: bool,
+    net_lamports: i64,
+) -> u32 {
+    score_breakdown(&ScoreInput {
+        kind: TxKind::parse(tx_type),
+        lamports,
+        is_memecoin,
+        is_defi_source,
+        net_lamports,
+    })
+    .score()
+}
+
+/// Convert a numeric score to a rarity tier.
+pub fn score_to_rarity(score: u32) -> u8 {
+    Rarity::from_score(score) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vectors::SCORE_VECTORS;
+
+    #[test]
+    fn test_score_vectors() {
+        for v in SCORE_VECTORS {
+            let score = compute_rarity_score(v.tx_type, v.lamports, v.is_memecoin, v.is_defi_source, v.net_lamports);
+            assert_eq!(score, v.score, "{} {} lamports", v.tx_type, v.lamports);
+            assert_eq!(score_to_rarity(score), v.rarity);
+        }
+    }
+
+    #[test]
+    fn test_whale_swap_legendary() {
+        let score = compute_rarity_score("SWAP", sol(150), false, true, 0);
+        assert!(score >= 75, "Whale swap should be legendary, got {}", score);
+        assert_eq!(score_to_rarity(score), 2);
+    }
+
+    #[test]
+    fn test_memecoin_ape_rare() {
+        let score = compute_rarity_score("SWAP", sol(5), true, true, 0);
+        assert!(score >= 40, "Memecoin ape should be rare, got {}", score);
+        assert_eq!(score_to_rarity(score), 1);
+    }
+
+    #[test]
+    fn test_dust_trade_common() {
+        let score = compute_rarity_score("SWAP", milli_sol(1), false, false, 0);
+        assert!(score < 40, "Dust trade should be common, got {}", score);
+        assert_eq!(score_to_rarity(score), 0);
+    }
+
+    #[test]
+    fn test_token_creation_rare() {
+        let score = compute_rarity_score("TOKEN_MINT", 0, false, false, 0);
+        assert!(score >= 40, "Token creation should be rare, got {}", score);
+    }
+
+    #[test]
+    fn test_massive_transfer_legendary() {
+        let score = compute_rarity_score("TRANSFER", sol(600), false, false, -(sol(600) as i64));
+        assert!(score >= 75, "600 SOL transfer should be legendary, got {}", score);
+    }
+
+    #[test]
+    fn test_nft_whale_sale() {
+        let score = compute_rarity_score("NFT_SALE", sol(80), false, false, sol(80) as i64);
+        assert!(score >= 75, "80 SOL NFT sale should be legendary, got {}", score);
+    }
+
+    #[test]
+    fn test_unknown_type_penalized() {
+        let score = compute_rarity_score("UNKNOWN", sol(1), false, false, 0);
+        assert!(score < 40, "Unknown type should be penalized, got {}", score);
+    }
+
+    fn swap(lamports: u64) -> ScoreInput {
+        ScoreInput { kind: TxKind::Swap, lamports, is_memecoin: false, is_defi_source: false, net_lamports: 0 }
+    }
+
+    #[test]
+    fn test_thresholds_are_strict() {
+        // Exactly 0.5 SOL is still a dust trade, one lamport more is not
+        let at = score_breakdown(&swap(milli_sol(500)));
+        assert_eq!(at.factors(), [Factor::SwapBase, Factor::DustTrade]);
+        let over = score_breakdown(&swap(milli_sol(500) + 1));
+        assert_eq!(over.factors(), [Factor::SwapBase, Factor::SmallTrade]);
+    }
+
+    #[test]
+    fn test_breakdown_adds_up() {
+        let b = score_breakdown(&ScoreInput { is_memecoin: true, is_defi_source: true, ..swap(milli_sol(5)) });
+        assert_eq!(b.factors().len(), MAX_FACTORS);
+        assert_eq!(b.score(), 25 - 5 + 5 + 25 - 25);
+    }
+}
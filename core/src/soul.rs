@@ -0,0 +1,205 @@
+//! Soul seeds, their visual traits and upgrade proofs.
+
+/// Compute a deterministic 32-byte soul seed from a transaction hash.
+/// This seed drives the generative art engine — same tx_hash always
+/// produces the same Soul Signature art.
+///
+/// Uses a simple but effective hash cascade:
+///   1. XOR-fold the tx_hash bytes into 32 positions
+///   2. Apply bit mixing (multiply, shift, XOR) for avalanche
+///   3. Result is a uniformly distributed 32-byte seed
+///
+/// This is NOT cryptographic — it's a fast, deterministic seed generator
+/// optimized for generating visual parameters (colors, shapes, positions).
+pub fn compute_soul_seed(tx_hash: &str) -> [u8; 32] {
+    let bytes = tx_hash.as_bytes();
+    let mut seed = [0u8; 32];
+
+    // Phase 1: XOR-fold input bytes across 32 positions
+    for (i, &b) in bytes.iter().enumerate() {
+        seed[i % 32] ^= b;
+    }
+
+    // Phase 2: Bit mixing — ensure small changes cascade
+    for byte in seed.iter_mut() {
+        let mut h: u64 = *byte as u64;
+        h = h.wrapping_mul(0x517cc1b727220a95);
+        h ^= h >> 17;
+        h = h.wrapping_mul(0x6c62272e07bb0142);
+        h ^= h >> 11;
+        *byte = (h & 0xFF) as u8;
+    }
+
+    // Phase 3: Chain adjacent bytes for additional diffusion
+    for i in 1..32 {
+        seed[i] ^= seed[i - 1].wrapping_add(37);
+    }
+    // Reverse pass
+    for i in (0..31).rev() {
+        seed[i] ^= seed[i + 1].wrapping_add(53);
+    }
+
+    seed
+}
+
+/// Proof that a card minted from `tx_hash` with `soul_seed` may be
+/// upgraded to `new_rarity`.
+/// In production, this would validate a Merkle proof from an oracle.
+/// For the hackathon, we use a simplified HMAC-like construction.
+pub fn upgrade_proof(tx_hash: &str, soul_seed: &[u8; 32], new_rarity: u8) -> [u8; 32] {
+    let mut proof = compute_soul_seed(tx_hash);
+
+    // Mix in soul seed and target rarity
+    for (p, s) in proof.iter_mut().zip(soul_seed) {
+        *p ^= s;
+    }
+    proof[0] ^= new_rarity;
+
+    // Apply mixing
+    for byte in proof.iter_mut() {
+        let mut h: u64 = *byte as u64;
+        h = h.wrapping_mul(0x9e3779b97f4a7c15);
+        h ^= h >> 13;
+        *byte = (h & 0xFF) as u8;
+    }
+
+    proof
+}
+
+/// Verify an upgrade proof against card data.
+pub fn verify_upgrade_proof(
+    tx_hash: &str,
+    soul_seed: &[u8; 32],
+    new_rarity: u8,
+    proof: &[u8; 32],
+) -> bool {
+    upgrade_proof(tx_hash, soul_seed, new_rarity) == *proof
+}
+
+/// Visual traits extracted from a soul seed. The art engine draws them
+/// and duels score them, so both read the same bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SoulTraits {
+    pub eye_style: u8,
+    pub glow_eyes: bool,
+    pub hat_type: u8,
+    pub glasses_type: u8,
+    pub mouth_item: u8,
+    pub neck_item: u8,
+    pub teeth_style: u8,
+    pub nose_style: u8,
+    pub has_scar: bool,
+    pub has_crack: bool,
+    pub has_eyepatch: bool,
+}
+
+impl SoulTraits {
+    /// Extract the traits from a soul seed.
+    pub fn from_seed(seed: &[u8; 32]) -> Self {
+        Self {
+            eye_style: seed[0] % 8,
+            glow_eyes: seed[1] > 102,
+            hat_type: seed[2] % 30,
+            glasses_type: seed[3] % 12,
+            mouth_item: seed[4] % 8,
+            neck_item: seed[5] % 6,
+            teeth_style: seed[6] % 6,
+            nose_style: seed[7] % 4,
+            has_scar: seed[8] > 153,
+            has_crack: seed[9] > 153,
+            has_eyepatch: seed[10] > 225,
+        }
+    }
+
+    /// Get human-readable hat name.
+    pub fn hat_name(&self) -> &'static str {
+        const HATS: [&str; 30] = [
+            "Cowboy", "Top Hat", "Beanie", "Baseball Cap", "Crown",
+            "Pirate Hat", "Sailor Hat", "Trucker Cap", "Fedora", "Wizard Hat",
+            "Headband", "Mohawk", "Viking Helmet", "Chef Hat", "Bandana",
+            "Halo", "Bucket Hat", "Santa Hat", "Afro", "Devil Horns",
+            "Army Helmet", "Sombrero", "Backwards Cap", "Durag", "Bowler Hat",
+            "Straw Hat", "Space Helmet", "Fire Crown", "Propeller Hat", "Toque",
+        ];
+        HATS.get(self.hat_type as usize).unwrap_or(&"None")
+    }
+
+    /// Get human-readable glasses name.
+    pub fn glasses_name(&self) -> &'static str {
+        const GLASSES: [&str; 12] = [
+            "Pit Vipers", "Aviators", "3D Glasses", "Heart Glasses",
+            "Nerd Glasses", "Monocle", "Cyclops Visor", "Thug Life",
+            "Star Glasses", "VR Headset", "Laser Eyes", "Lennon Rounds",
+        ];
+        GLASSES.get(self.glasses_type as usize).unwrap_or(&"None")
+    }
+
+    /// Get total accessory count for rarity weighting.
+    pub fn accessory_count(&self) -> u8 {
+        let mut count = 0u8;
+        if self.hat_type > 0 { count += 1; }
+        if self.glasses_type > 0 { count += 1; }
+        if self.mouth_item > 0 { count += 1; }
+        if self.neck_item > 0 { count += 1; }
+        if self.has_scar { count += 1; }
+        if self.has_crack { count += 1; }
+        if self.has_eyepatch { count += 1; }
+        count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vectors::{SOUL_SEED_VECTORS, UPGRADE_PROOF_VECTORS};
+
+    #[test]
+    fn test_soul_seed_vectors() {
+        for (tx_hash, seed) in SOUL_SEED_VECTORS {
+            assert_eq!(compute_soul_seed(tx_hash), *seed, "seed for {:?}", tx_hash);
+        }
+    }
+
+    #[test]
+    fn test_upgrade_proof_vectors() {
+        for v in UPGRADE_PROOF_VECTORS {
+            assert_eq!(upgrade_proof(v.tx_hash, &v.soul_seed, v.new_rarity), v.proof);
+            assert!(verify_upgrade_proof(v.tx_hash, &v.soul_seed, v.new_rarity, &v.proof));
+        }
+    }
+
+    #[test]
+    fn test_soul_seed_deterministic() {
+        let tx = "4xK7m9pR2abc123def456";
+        assert_eq!(compute_soul_seed(tx), compute_soul_seed(tx), "Same tx should produce same seed");
+    }
+
+    #[test]
+    fn test_soul_seed_different_inputs() {
+        let seed1 = compute_soul_seed("tx_hash_A");
+        let seed2 = compute_soul_seed("tx_hash_B");
+        assert_ne!(seed1, seed2, "Different txs should produce different seeds");
+    }
+
+    #[test]
+    fn test_soul_seed_avalanche() {
+        // Changing one character should change many bytes
+        let seed1 = compute_soul_seed("abcdef123456");
+        let seed2 = compute_soul_seed("abcdef123457");
+        let diff_count = seed1.iter()
+            .zip(seed2.iter())
+            .filter(|(a, b)| a != b)
+            .count();
+        assert!(diff_count > 16, "One char change should affect >50% of bytes, got {}/32", diff_count);
+    }
+
+    #[test]
+    fn test_upgrade_proof_invalid() {
+        let tx = "test_tx_hash";
+        let seed = compute_soul_seed(tx);
+        assert!(!verify_upgrade_proof(tx, &seed, 2, &[0u8; 32]));
+        // A proof for one tier doesn't upgrade to another
+        let proof = upgrade_proof(tx, &seed, 1);
+        assert!(!verify_upgrade_proof(tx, &seed, 2, &proof));
+    }
+}
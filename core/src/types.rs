@@ -0,0 +1,155 @@
+//! Card enums, stored on-chain as their `u8` values.
+
+/// Rarity tiers for Crypt Cards.
+/// Scoring is based on transaction value, type, and historical significance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Rarity {
+    Common = 0,     // Score 0-39:  dust trades, small transfers
+    Rare = 1,       // Score 40-74: memecoin apes, notable sales, big moves
+    Legendary = 2,  // Score 75+:   whale trades, rug survivals, creator moments
+}
+
+/// Lowest score that makes a card Rare.
+pub const RARE_THRESHOLD: u32 = 40;
+
+/// Lowest score that makes a card Legendary.
+pub const LEGENDARY_THRESHOLD: u32 = 75;
+
+impl Rarity {
+    pub fn from_u8(v: u8) -> Option<Self> {
+        match v {
+            0 => Some(Rarity::Common),
+            1 => Some(Rarity::Rare),
+            2 => Some(Rarity::Legendary),
+            _ => None,
+        }
+    }
+
+    pub fn as_u8(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Rarity::Common => "COMMON",
+            Rarity::Rare => "RARE",
+            Rarity::Legendary => "LEGENDARY",
+        }
+    }
+
+    /// Tier for `score` under the default thresholds.
+    pub fn from_score(score: u32) -> Self {
+        Self::from_score_with(score, RARE_THRESHOLD, LEGENDARY_THRESHOLD)
+    }
+
+    /// Tier for `score` under custom thresholds.
+    pub fn from_score_with(score: u32, rare: u32, legendary: u32) -> Self {
+        if score >= legendary { Rarity::Legendary }
+        else if score >= rare { Rarity::Rare }
+        else { Rarity::Common }
+    }
+
+    pub fn can_upgrade_to(&self, target: &Rarity) -> bool {
+        target > self
+    }
+
+    /// Card border colour used by the frontend.
+    pub fn color_code(&self) -> &'static str {
+        match self {
+            Rarity::Common => "#888888",
+            Rarity::Rare => "#00d4b0",
+            Rarity::Legendary => "#9660ff",
+        }
+    }
+}
+
+/// Card types derived from Solana transaction classification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CardType {
+    Swap = 0,           // DEX trades (Jupiter, Raydium, Orca)
+    Rug = 1,            // Rug pulls, failed tokens, -99% trades
+    Mint = 2,           // NFT mints, token creation events
+    DiamondHands = 3,   // Long holds through volatility
+    BigMove = 4,        // Whale transfers, cold storage moves
+}
+
+impl CardType {
+    pub fn from_u8(v: u8) -> Option<Self> {
+        match v {
+            0 => Some(CardType::Swap),
+            1 => Some(CardType::Rug),
+            2 => Some(CardType::Mint),
+            3 => Some(CardType::DiamondHands),
+            4 => Some(CardType::BigMove),
+            _ => None,
+        }
+    }
+
+    pub fn as_u8(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CardType::Swap => "SWAP",
+            CardType::Rug => "RUG",
+            CardType::Mint => "MINT",
+            CardType::DiamondHands => "DIAMOND_HANDS",
+            CardType::BigMove => "BIG_MOVE",
+        }
+    }
+}
+
+/// Name of the rarity stored as `v`, or `"UNKNOWN"`.
+pub fn rarity_name(v: u8) -> &'static str {
+    Rarity::from_u8(v).map_or("UNKNOWN", |r| r.as_str())
+}
+
+/// Name of the card type stored as `v`, or `"UNKNOWN"`.
+pub fn card_type_name(v: u8) -> &'static str {
+    CardType::from_u8(v).map_or("UNKNOWN", |t| t.as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rarity_round_trip() {
+        for v in 0..3 {
+            assert_eq!(Rarity::from_u8(v).unwrap().as_u8(), v);
+        }
+        assert_eq!(Rarity::from_u8(3), None);
+        assert_eq!(rarity_name(2), "LEGENDARY");
+        assert_eq!(rarity_name(99), "UNKNOWN");
+    }
+
+    #[test]
+    fn test_card_type_round_trip() {
+        for v in 0..5 {
+            assert_eq!(CardType::from_u8(v).unwrap().as_u8(), v);
+        }
+        assert_eq!(CardType::from_u8(5), None);
+        assert_eq!(card_type_name(3), "DIAMOND_HANDS");
+        assert_eq!(card_type_name(5), "UNKNOWN");
+    }
+
+    #[test]
+    fn test_rarity_from_score() {
+        assert_eq!(Rarity::from_score(39), Rarity::Common);
+        assert_eq!(Rarity::from_score(40), Rarity::Rare);
+        assert_eq!(Rarity::from_score(74), Rarity::Rare);
+        assert_eq!(Rarity::from_score(75), Rarity::Legendary);
+        assert_eq!(Rarity::from_score_with(40, 60, 90), Rarity::Common);
+    }
+
+    #[test]
+    fn test_upgrades_only_go_up() {
+        assert!(Rarity::Common.can_upgrade_to(&Rarity::Rare));
+        assert!(Rarity::Rare.can_upgrade_to(&Rarity::Legendary));
+        assert!(!Rarity::Rare.can_upgrade_to(&Rarity::Rare));
+        assert!(!Rarity::Legendary.can_upgrade_to(&Rarity::Common));
+    }
+}
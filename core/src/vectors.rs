@@ -0,0 +1,157 @@
+//! Known-good outputs for the shared algorithms.
+//!
+//! Generated from the implementations this crate replaced, so every
+//! crate that checks against them proves it still behaves as before.
+
+/// Soul seeds for fixed transaction hashes.
+pub const SOUL_SEED_VECTORS: &[(&str, [u8; 32])] = &[
+    ("", [
+        0x4b, 0x16, 0xfe, 0x7f, 0xdb, 0x1a, 0x6e, 0x7b,
+        0x43, 0x36, 0x46, 0xff, 0x33, 0x5a, 0x86, 0x4b,
+        0x2b, 0x46, 0xfe, 0x2f, 0x5b, 0x8a, 0x4e, 0x2b,
+        0x43, 0x06, 0x66, 0x6f, 0x53, 0x2a, 0xe6, 0x7b,
+    ]),
+    ("test", [
+        0x00, 0x24, 0x35, 0x4b, 0x93, 0x06, 0x96, 0x2f,
+        0x03, 0x0a, 0x36, 0x7b, 0x9b, 0x16, 0xae, 0x7f,
+        0x0b, 0x3a, 0x7e, 0x9b, 0x13, 0xb6, 0x76, 0xff,
+        0x63, 0xba, 0xd6, 0xcb, 0xfb, 0xa6, 0xae, 0x8f,
+    ]),
+    ("4xK7m9pR2abc123def456", [
+        0xbb, 0xf9, 0x36, 0xf8, 0x68, 0x4e, 0x0b, 0x04,
+        0x6b, 0x2f, 0x9b, 0x1c, 0x6b, 0xc8, 0x80, 0xda,
+        0xad, 0x8b, 0x0e, 0x41, 0xe3, 0xf5, 0xe6, 0xc0,
+        0xc3, 0x69, 0xb6, 0xdc, 0xdb, 0xf5, 0xae, 0x60,
+    ]),
+    ("5VERv8NMvzbJMEkV8xnrLkEaWRtSz9CosKDYjCJjBRnbJLgp8uirBgmQpjKhoR4tjF3ZpRzrFmBV6UjKdiSZkQUW", [
+        0x75, 0x74, 0xde, 0x5f, 0xea, 0xab, 0x77, 0xc5,
+        0x6f, 0xa0, 0x9c, 0xba, 0x4e, 0x32, 0xb9, 0xad,
+        0x17, 0x68, 0x16, 0x1d, 0x93, 0x24, 0x35, 0x84,
+        0xbe, 0x4c, 0xf3, 0x75, 0xb3, 0x45, 0x70, 0x05,
+    ]),
+    ("RUGGED_SQUID_GAME_2021", [
+        0x78, 0x56, 0x68, 0x9a, 0xcc, 0x6d, 0xc0, 0x62,
+        0x0c, 0x36, 0x34, 0x5f, 0x5c, 0x10, 0x44, 0x5e,
+        0xae, 0xad, 0x01, 0xd2, 0x16, 0x39, 0x9e, 0x5c,
+        0x33, 0x35, 0x16, 0x80, 0x13, 0xc9, 0xa6, 0x5c,
+    ]),
+];
+
+pub struct UpgradeProofVector {
+    pub tx_hash: &'static str,
+    pub soul_seed: [u8; 32],
+    pub new_rarity: u8,
+    pub proof: [u8; 32],
+}
+
+/// Upgrade proofs, both for a card's own soul seed and for an unrelated one.
+pub const UPGRADE_PROOF_VECTORS: &[UpgradeProofVector] = &[
+    UpgradeProofVector {
+        tx_hash: "test",
+        soul_seed: [
+            0x00, 0x24, 0x35, 0x4b, 0x93, 0x06, 0x96, 0x2f,
+            0x03, 0x0a, 0x36, 0x7b, 0x9b, 0x16, 0xae, 0x7f,
+            0x0b, 0x3a, 0x7e, 0x9b, 0x13, 0xb6, 0x76, 0xff,
+            0x63, 0xba, 0xd6, 0xcb, 0xfb, 0xa6, 0xae, 0x8f,
+        ],
+        new_rarity: 2,
+        proof: [
+            0x8d, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ],
+    },
+    UpgradeProofVector {
+        tx_hash: "4xK7m9pR2abc123def456",
+        soul_seed: [
+            0xbb, 0xf9, 0x36, 0xf8, 0x68, 0x4e, 0x0b, 0x04,
+            0x6b, 0x2f, 0x9b, 0x1c, 0x6b, 0xc8, 0x80, 0xda,
+            0xad, 0x8b, 0x0e, 0x41, 0xe3, 0xf5, 0xe6, 0xc0,
+            0xc3, 0x69, 0xb6, 0xdc, 0xdb, 0xf5, 0xae, 0x60,
+        ],
+        new_rarity: 2,
+        proof: [
+            0x8d, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ],
+    },
+    UpgradeProofVector {
+        tx_hash: "test",
+        soul_seed: [0x11; 32],
+        new_rarity: 1,
+        proof: [
+            0x6e, 0x04, 0x3f, 0x1e, 0x32, 0x6a, 0x28, 0x46,
+            0x9f, 0xef, 0xf4, 0x09, 0x65, 0xd8, 0x3f, 0x0c,
+            0xa6, 0x91, 0x45, 0x65, 0x8d, 0x04, 0xcc, 0x7c,
+            0x00, 0x00, 0x60, 0x8f, 0x99, 0xf6, 0x3f, 0x32,
+        ],
+    },
+    UpgradeProofVector {
+        tx_hash: "4xK7m9pR2abc123def456",
+        soul_seed: [0x11; 32],
+        new_rarity: 2,
+        proof: [
+            0xc3, 0x0b, 0xf4, 0x4a, 0x48, 0xeb, 0xa6, 0x58,
+            0xfb, 0x46, 0x65, 0x53, 0xfb, 0xd4, 0x67, 0x24,
+            0xf4, 0xd7, 0xa3, 0xa6, 0x90, 0x00, 0xae, 0x5f,
+            0xf4, 0x89, 0x04, 0xfb, 0xbd, 0x00, 0x3f, 0x43,
+        ],
+    },
+];
+
+pub struct ScoreVector {
+    pub tx_type: &'static str,
+    pub lamports: u64,
+    pub is_memecoin: bool,
+    pub is_defi_source: bool,
+    pub net_lamports: i64,
+    pub score: u32,
+    pub rarity: u8,
+}
+
+const fn score(
+    tx_type: &'static str,
+    lamports: u64,
+    is_memecoin: bool,
+    is_defi_source: bool,
+    net_lamports: i64,
+    score: u32,
+    rarity: u8,
+) -> ScoreVector {
+    ScoreVector { tx_type, lamports, is_memecoin, is_defi_source, net_lamports, score, rarity }
+}
+
+/// Scores covering every branch of the scorer.
+pub const SCORE_VECTORS: &[ScoreVector] = &[
+    score("SWAP", 150_000_000_000, false, true, 0, 110, 2),
+    score("SWAP", 75_000_000_000, false, false, 0, 85, 2),
+    score("SWAP", 5_000_000_000, true, true, 0, 70, 1),
+    score("SWAP", 25_000_000_000, false, false, 0, 60, 1),
+    score("SWAP", 1_000_000_000, false, false, 0, 30, 0),
+    score("SWAP", 300_000_000, false, false, 0, 20, 0),
+    score("SWAP", 1_000_000, false, false, 0, 0, 0),
+    score("SWAP", 500_000_000, true, false, 0, 45, 1),
+    score("NFT_MINT", 20_000_000_000, false, false, 0, 75, 2),
+    score("COMPRESSED_NFT_MINT", 0, false, false, 0, 35, 0),
+    score("NFT_MINT", 5_000_000_000, false, false, 0, 55, 1),
+    score("NFT_SALE", 80_000_000_000, false, false, 80_000_000_000, 120, 2),
+    score("NFT_SALE", 15_000_000_000, false, false, -1_000_000_000, 70, 1),
+    score("NFT_SALE", 3_000_000_000, false, false, 1_000_000_000, 65, 1),
+    score("NFT_SALE", 1_000_000_000, false, false, 0, 25, 0),
+    score("TRANSFER", 600_000_000_000, false, false, -600_000_000_000, 80, 2),
+    score("SOL_TRANSFER", 150_000_000_000, false, false, 0, 55, 1),
+    score("TRANSFER", 30_000_000_000, false, false, 0, 25, 0),
+    score("TRANSFER", 10_000_000_000, false, false, 0, 10, 0),
+    score("TRANSFER", 1_000_000_000, false, false, 0, 0, 0),
+    score("STAKE_SOL", 200_000_000_000, false, false, 0, 50, 1),
+    score("UNSTAKE_SOL", 50_000_000_000, false, false, 0, 25, 0),
+    score("STAKE_SOL", 5_000_000, false, false, 0, 0, 0),
+    score("TOKEN_MINT", 0, false, false, 0, 50, 1),
+    score("BURN", 0, false, false, 0, 20, 0),
+    score("BURN_NFT", 2_000_000_000, false, false, 0, 20, 0),
+    score("UNKNOWN", 1_000_000_000, false, false, 0, 0, 0),
+    score("UNKNOWN", 0, false, false, 0, 0, 0),
+];
@@ -0,0 +1,407 @@
+//! Read-only HTTP API over the store.
+//!
+//! - `GET /metrics` → full `CollectionMetrics` as of now
+//! - `GET /metrics/window?secs=N` → activity over the last N seconds
+//! - `GET /cards?owner=<wallet>` → cards the wallet holds
+//! - `GET /card?id=<mint_id>` → one card, burned or not
+//! - `GET /badges?owner=<wallet>` → achievement badges the wallet holds
+//! - `GET /reactions?card=<mint_id>` → distinct wallets that liked,
+//!   shared and bookmarked a card
+//! - `GET /feed?viewer=<wallet>&limit=N` → newest cards, without hidden
+//!   ones unless the viewer owns them
+//! - `GET /search?q=<text>&viewer=<wallet>` → cards by title, same rules
+//! - `GET /comments?card=<mint_id>&viewer=<wallet>` → a card's comments,
+//!   without hidden ones unless the viewer wrote them
+//! - `GET /snapshot?slot=N` (or `timestamp=T`) → holders at that point,
+//!   optionally only `rarities=2,...` or `mint_ids=0,...`, weighted by
+//!   `weights=<common>,<rare>,<legendary>`; with `total=X`, the merkle
+//!   distribution of X between them instead
+//! - `GET /proposals` → proposals still taking votes, soonest to close first
+//! - `GET /proposal?id=N` → one proposal with its tallies, open or not
+//! - `GET /wrapped?card=<mint_id>` → the NFT mint a wrapped card is locked
+//!   behind, or null if it isn't wrapped
+//!
+//! Deliberately minimal: one request per connection, JSON out. A real
+//! deployment would put this behind a proper server and a database.
+
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use colored::Colorize;
+use crate::metrics::DAY_SECS;
+use crate::snapshot::{RarityWeights, SnapshotCutoff, SnapshotFilter, FLAT_WEIGHTS};
+use crate::store::InMemoryStore;
+
+/// Cards `/feed` returns when no limit is given.
+pub const DEFAULT_FEED_LIMIT: usize = 20;
+
+/// Status line and JSON body for a request line like `GET /metrics HTTP/1.1`.
+pub fn respond(store: &InMemoryStore, now: i64, request_line: &str) -> (&'static str, String) {
+    let mut parts = request_line.split_whitespace();
+    let (Some(method), Some(target)) = (parts.next(), parts.next()) else {
+        return ("400 Bad Request", error("bad request"));
+    };
+    if method != "GET" {
+        return ("405 Method Not Allowed", error("only GET is supported"));
+    }
+
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    match path {
+        "/metrics" => ("200 OK", json(&store.collection_metrics(now))),
+        "/metrics/window" => {
+            let secs = param(query, "secs").map_or(Ok(DAY_SECS), str::parse::<i64>);
+            match secs {
+                Ok(secs) if secs > 0 => ("200 OK", json(&store.window_metrics(now, secs))),
+                _ => ("400 Bad Request", error("secs must be a positive number of seconds")),
+            }
+        }
+        "/cards" => match param(query, "owner") {
+            Some(owner) => ("200 OK", json(&store.get_cards_by_owner(owner))),
+            None => ("400 Bad Request", error("owner is required")),
+        },
+        "/card" => match param(query, "id").map(str::parse::<u64>) {
+            Some(Ok(mint_id)) => match store.get_card(mint_id) {
+                Some(card) => ("200 OK", json(card)),
+                None => ("404 Not Found", error("no such card")),
+            },
+            _ => ("400 Bad Request", error("id must be a mint ID")),
+        },
+        "/badges" => match param(query, "owner") {
+            Some(owner) => ("200 OK", json(&store.get_badges_by_owner(owner))),
+            None => ("400 Bad Request", error("owner is required")),
+        },
+        "/reactions" => match param(query, "card").map(str::parse::<u64>) {
+            Some(Ok(mint_id)) => ("200 OK", serde_json::json!({
+                "likes": store.unique_reactions(mint_id, 0),
+                "shares": store.unique_reactions(mint_id, 2),
+                "bookmarks": store.unique_reactions(mint_id, 3),
+            }).to_string()),
+            _ => ("400 Bad Request", error("card must be a mint ID")),
+        },
+        "/feed" => match param(query, "limit").map_or(Ok(DEFAULT_FEED_LIMIT), str::parse::<usize>) {
+            Ok(limit) => ("200 OK", json(&store.feed(param(query, "viewer"), limit))),
+            Err(_) => ("400 Bad Request", error("limit must be a number of cards")),
+        },
+        "/search" => match param(query, "q") {
+            Some(q) => ("200 OK", json(&store.search(q, param(query, "viewer")))),
+            None => ("400 Bad Request", error("q is required")),
+        },
+        "/comments" => match param(query, "card").map(str::parse::<u64>) {
+            Some(Ok(mint_id)) => ("200 OK", json(&store.comments(mint_id, param(query, "viewer")))),
+            _ => ("400 Bad Request", error("card must be a mint ID")),
+        },
+        "/snapshot" => match SnapshotQuery::parse(query) {
+            Ok(q) => {
+                let snapshot = store.snapshot(q.cutoff, &q.filter, &q.weights);
+                match q.total.map(|total| snapshot.distribution(total)) {
+                    None => ("200 OK", json(&snapshot)),
+                    Some(Ok(distribution)) => ("200 OK", distribution.to_json()),
+                    Some(Err(message)) => ("422 Unprocessable Entity", error(&message)),
+                }
+            }
+            Err(message) => ("400 Bad Request", error(message)),
+        },
+        "/proposals" => ("200 OK", json(&store.open_proposals(now))),
+        "/proposal" => match param(query, "id").map(str::parse::<u64>) {
+            Some(Ok(id)) => match store.get_proposal(id) {
+                Some(proposal) => ("200 OK", json(proposal)),
+                None => ("404 Not Found", error("no such proposal")),
+            },
+            _ => ("400 Bad Request", error("id must be a proposal ID")),
+        },
+        "/wrapped" => match param(query, "card").map(str::parse::<u64>) {
+            Some(Ok(mint_id)) => ("200 OK", serde_json::json!({
+                "nft_mint": store.wrapped_nft(mint_id),
+            }).to_string()),
+            _ => ("400 Bad Request", error("card must be a mint ID")),
+        },
+        _ => ("404 Not Found", error("not found")),
+    }
+}
+
+/// A `/snapshot` request: where to cut, which cards count, how they're
+/// weighted and, for a distribution, how much to split.
+struct SnapshotQuery {
+    cutoff: SnapshotCutoff,
+    filter: SnapshotFilter,
+    weights: RarityWeights,
+    total: Option<u64>,
+}
+
+impl SnapshotQuery {
+    fn parse(query: &str) -> Result<Self, &'static str> {
+        let cutoff = match (param(query, "slot"), param(query, "timestamp")) {
+            (Some(slot), None) => SnapshotCutoff::Slot(slot.parse().map_err(|_| "slot must be a number")?),
+            (None, Some(at)) => SnapshotCutoff::Timestamp(at.parse().map_err(|_| "timestamp must be a number")?),
+            _ => return Err("give one of slot or timestamp"),
+        };
+        let filter = SnapshotFilter {
+            rarities: list(query, "rarities").map_err(|_| "rarities must be numbers")?,
+            mint_ids: list(query, "mint_ids").map_err(|_| "mint_ids must be numbers")?,
+        };
+        let weights = match param(query, "weights") {
+            Some(_) => list::<Vec<u64>, _>(query, "weights")
+                .ok()
+                .and_then(|w| w.try_into().ok())
+                .ok_or("weights must be three numbers")?,
+            None => FLAT_WEIGHTS,
+        };
+        let total = param(query, "total")
+            .map(str::parse)
+            .transpose()
+            .map_err(|_| "total must be a number")?;
+        Ok(Self { cutoff, filter, weights, total })
+    }
+}
+
+/// Comma-separated values of `key`, empty when it's missing.
+fn list<C: FromIterator<T>, T: std::str::FromStr>(query: &str, key: &str) -> Result<C, T::Err> {
+    param(query, key)
+        .filter(|value| !value.is_empty())
+        .map_or("", |value| value)
+        .split(',')
+        .filter(|item| !item.is_empty())
+        .map(str::parse)
+        .collect()
+}
+
+/// Value of `key` in a query string like `a=1&b=2`.
+fn param<'a>(query: &'a str, key: &str) -> Option<&'a str> {
+    query
+        .split('&')
+        .find_map(|pair| pair.strip_prefix(key)?.strip_prefix('='))
+}
+
+fn json<T: serde::Serialize>(value: &T) -> String {
+    serde_json::to_string(value).unwrap_or_default()
+}
+
+fn error(message: &str) -> String {
+    serde_json::json!({ "error": message }).to_string()
+}
+
+/// Serve the API on `addr` until the process exits.
+pub async fn serve(addr: &str, store: Arc<Mutex<InMemoryStore>>) -> std::io::Result<()> {
+    let listener = TcpListener::bind(addr).await?;
+    println!("  {} API listening on http://{}", ">>".bright_cyan(), addr);
+
+    loop {
+        let (mut socket, _) = listener.accept().await?;
+        let store = store.clone();
+        tokio::spawn(async move {
+            let mut buf = [0u8; 2048];
+            let Ok(n) = socket.read(&mut buf).await else { return };
+            let request = String::from_utf8_lossy(&buf[..n]);
+            let request_line = request.lines().next().unwrap_or_default();
+
+            let (status, body) = {
+                let store = store.lock().unwrap_or_else(|e| e.into_inner());
+                respond(&store, chrono::Utc::now().timestamp(), request_line)
+            };
+            let response = format!(
+                "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status, body.len(), body,
+            );
+            let _ = socket.write_all(response.as_bytes()).await;
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::*;
+
+    #[test]
+    fn test_routes() {
+        let mut store = InMemoryStore::new();
+        store.process_event(&CryptEvent::CardMinted(CardMintedEvent {
+            mint_id: 0, owner: "owner123456789".into(), tx_hash: "tx".into(),
+            rarity: 1, card_type: 3, title: "HODL".into(),
+            soul_seed: [0; 32], timestamp: 1_000,
+        }));
+
+        let (status, body) = respond(&store, 2_000, "GET /metrics HTTP/1.1");
+        assert_eq!(status, "200 OK");
+        let metrics: crypt_core::CollectionMetrics = serde_json::from_str(&body).unwrap();
+        assert_eq!(metrics, store.collection_metrics(2_000));
+        assert_eq!(metrics.type_distribution.diamond_hands, 1);
+
+        let (_, body) = respond(&store, 2_000, "GET /metrics/window?secs=500 HTTP/1.1");
+        let window: crypt_core::WindowMetrics = serde_json::from_str(&body).unwrap();
+        assert_eq!((window.window_secs, window.mints), (500, 0));
+        let (_, body) = respond(&store, 2_000, "GET /metrics/window HTTP/1.1");
+        assert!(body.contains("\"mints\":1"));
+
+        let (status, body) = respond(&store, 0, "GET /cards?owner=owner123456789 HTTP/1.1");
+        assert_eq!(status, "200 OK");
+        let cards: Vec<serde_json::Value> = serde_json::from_str(&body).unwrap();
+        assert_eq!(cards.len(), 1);
+        assert_eq!(cards[0]["title"], "HODL");
+        assert_eq!(respond(&store, 0, "GET /cards?owner=nobody HTTP/1.1").1, "[]");
+        let (status, body) = respond(&store, 0, "GET /card?id=0 HTTP/1.1");
+        assert_eq!(status, "200 OK");
+        assert!(body.contains("\"tx_hash\":\"tx\""));
+        assert_eq!(respond(&store, 0, "GET /card?id=1 HTTP/1.1").0, "404 Not Found");
+        assert_eq!(respond(&store, 0, "GET /cards HTTP/1.1").0, "400 Bad Request");
+
+        assert_eq!(respond(&store, 0, "GET /metrics/window?secs=-1 HTTP/1.1").0, "400 Bad Request");
+        assert_eq!(respond(&store, 0, "POST /metrics HTTP/1.1").0, "405 Method Not Allowed");
+        assert_eq!(respond(&store, 0, "GET /owners HTTP/1.1").0, "404 Not Found");
+        assert_eq!(respond(&store, 0, "").0, "400 Bad Request");
+    }
+
+    #[test]
+    fn test_badges_route() {
+        let mut store = InMemoryStore::new();
+        store.process_event(&CryptEvent::AchievementClaimed(AchievementClaimedEvent {
+            set_id: 3, owner: "owner123456789".into(), badge: "badge123456789".into(),
+            card_count: 5, timestamp: 42,
+        }));
+
+        let (status, body) = respond(&store, 0, "GET /badges?owner=owner123456789 HTTP/1.1");
+        assert_eq!(status, "200 OK");
+        let badges: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(badges[0]["set_id"], 3);
+        assert_eq!(badges[0]["card_count"], 5);
+        assert_eq!(respond(&store, 0, "GET /badges?owner=nobody HTTP/1.1").1, "[]");
+        assert_eq!(respond(&store, 0, "GET /badges HTTP/1.1").0, "400 Bad Request");
+    }
+
+    #[test]
+    fn test_reactions_route() {
+        let mut store = InMemoryStore::new();
+        let react = |user: &str, interaction_type| CryptEvent::CompactInteraction(CompactInteractionEvent {
+            card_mint_id: 2, user: user.into(), interaction_type,
+            leaf: [0; 32], log_root: [0; 32], log_len: 0, timestamp: 1,
+        });
+        store.process_event(&react("alice12345678", 0));
+        store.process_event(&react("alice12345678", 0));
+        store.process_event(&react("bob1234567890", 3));
+
+        let (status, body) = respond(&store, 0, "GET /reactions?card=2 HTTP/1.1");
+        assert_eq!(status, "200 OK");
+        let counts: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(counts, serde_json::json!({ "likes": 1, "shares": 0, "bookmarks": 1 }));
+        assert_eq!(respond(&store, 0, "GET /reactions?card=two HTTP/1.1").0, "400 Bad Request");
+    }
+
+    #[test]
+    fn test_snapshot_routes() {
+        let mut store = InMemoryStore::new();
+        let alice = solana_sdk::pubkey::Pubkey::new_from_array([1; 32]).to_string();
+        let bob = solana_sdk::pubkey::Pubkey::new_from_array([2; 32]).to_string();
+        for (slot, mint_id, owner, rarity) in [(100, 0, &alice, 2), (110, 1, &alice, 0), (120, 2, &bob, 1)] {
+            store.record_event(slot, CryptEvent::CardMinted(CardMintedEvent {
+                mint_id, owner: owner.clone(), tx_hash: format!("tx{}", mint_id),
+                rarity, card_type: 0, title: String::new(),
+                soul_seed: [0; 32], timestamp: slot as i64,
+            }));
+        }
+
+        let (status, body) = respond(&store, 0, "GET /snapshot?slot=115 HTTP/1.1");
+        assert_eq!(status, "200 OK");
+        let snapshot: crate::snapshot::Snapshot = serde_json::from_str(&body).unwrap();
+        assert_eq!(snapshot.holders.keys().collect::<Vec<_>>(), vec![&alice]);
+
+        let (_, body) = respond(&store, 0, "GET /snapshot?timestamp=200&rarities=1,2&weights=1,3,10 HTTP/1.1");
+        let snapshot: crate::snapshot::Snapshot = serde_json::from_str(&body).unwrap();
+        assert_eq!(snapshot.holders[&alice].mint_ids, vec![0]);
+        assert_eq!(snapshot.total_weight(), 13);
+
+        let (_, body) = respond(&store, 0, "GET /snapshot?slot=200&mint_ids=0,2&total=1000 HTTP/1.1");
+        let distribution: crate::snapshot::Distribution = serde_json::from_str(&body).unwrap();
+        assert_eq!(distribution.total_amount, 1_000);
+        assert_eq!(distribution.claims.len(), 2);
+
+        for bad in ["", "?slot=1&timestamp=1", "?slot=x", "?slot=1&weights=1,2", "?slot=1&rarities=a", "?slot=1&total=-1"] {
+            let request = format!("GET /snapshot{} HTTP/1.1", bad);
+            assert_eq!(respond(&store, 0, &request).0, "400 Bad Request", "{}", bad);
+        }
+    }
+
+    #[test]
+    fn test_proposal_routes() {
+        let mut store = InMemoryStore::new();
+        for (proposal_id, ends_at) in [(0, 500), (1, 300), (2, 50)] {
+            store.process_event(&CryptEvent::ProposalCreated(ProposalCreatedEvent {
+                proposal: format!("proposal{}", proposal_id), proposal_id,
+                title: "Next drop".into(), options: vec!["Yes".into(), "No".into()],
+                starts_at: 0, ends_at,
+            }));
+        }
+        store.process_event(&CryptEvent::VoteCast(VoteCastEvent {
+            proposal: "proposal0".into(), proposal_id: 0, voter: "voter12345678".into(),
+            option: 1, cards: 2, weight: 2, timestamp: 10,
+        }));
+
+        let (status, body) = respond(&store, 100, "GET /proposals HTTP/1.1");
+        assert_eq!(status, "200 OK");
+        let open: Vec<serde_json::Value> = serde_json::from_str(&body).unwrap();
+        let ids: Vec<_> = open.iter().map(|p| p["proposal_id"].as_u64().unwrap()).collect();
+        assert_eq!(ids, vec![1, 0]);
+
+        let (status, body) = respond(&store, 100, "GET /proposal?id=0 HTTP/1.1");
+        assert_eq!(status, "200 OK");
+        let proposal: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(proposal["tallies"], serde_json::json!([0, 2]));
+        assert_eq!(respond(&store, 100, "GET /proposal?id=2 HTTP/1.1").0, "200 OK");
+        assert_eq!(respond(&store, 100, "GET /proposal?id=7 HTTP/1.1").0, "404 Not Found");
+        assert_eq!(respond(&store, 100, "GET /proposal HTTP/1.1").0, "400 Bad Request");
+    }
+
+    #[test]
+    fn test_wrapped_route() {
+        let mut store = InMemoryStore::new();
+        store.process_event(&CryptEvent::CardWrapped(CardWrappedEvent {
+            mint_id: 4, card: "card4".into(), nft_mint: "nftmint1234567".into(),
+            owner: "owner123456789".into(), timestamp: 1,
+        }));
+
+        let (status, body) = respond(&store, 0, "GET /wrapped?card=4 HTTP/1.1");
+        assert_eq!(status, "200 OK");
+        assert_eq!(body, r#"{"nft_mint":"nftmint1234567"}"#);
+        let (_, body) = respond(&store, 0, "GET /wrapped?card=5 HTTP/1.1");
+        assert_eq!(body, r#"{"nft_mint":null}"#);
+        assert_eq!(respond(&store, 0, "GET /wrapped HTTP/1.1").0, "400 Bad Request");
+    }
+
+    #[test]
+    fn test_hidden_content_routes() {
+        let mut store = InMemoryStore::new();
+        for (mint_id, title) in [(0, "GM"), (1, "GM AGAIN")] {
+            store.process_event(&CryptEvent::CardMinted(CardMintedEvent {
+                mint_id, owner: "owner123456789".into(), tx_hash: format!("tx{}", mint_id),
+                rarity: 0, card_type: 0, title: title.into(),
+                soul_seed: [0; 32], timestamp: mint_id as i64,
+            }));
+        }
+        store.process_event(&CryptEvent::CardInteraction(CardInteractionEvent {
+            card_mint_id: 0, user: "author12345678".into(), interaction_type: 1, timestamp: 5,
+        }));
+        for (kind, author) in [(0, "owner123456789"), (1, "author12345678")] {
+            store.process_event(&CryptEvent::ContentHidden(ContentHiddenEvent {
+                target: "target123456789".into(), kind, card_mint_id: 0,
+                author: author.into(), hidden: true, reason: 1,
+                moderator: "moderator12345".into(), timestamp: 10,
+            }));
+        }
+
+        let mint_ids = |body: &str| -> Vec<u64> {
+            let cards: serde_json::Value = serde_json::from_str(body).unwrap();
+            cards.as_array().unwrap().iter().map(|c| c["mint_id"].as_u64().unwrap()).collect()
+        };
+        assert_eq!(mint_ids(&respond(&store, 0, "GET /feed HTTP/1.1").1), vec![1]);
+        assert_eq!(mint_ids(&respond(&store, 0, "GET /feed?viewer=owner123456789 HTTP/1.1").1), vec![1, 0]);
+        assert_eq!(mint_ids(&respond(&store, 0, "GET /feed?viewer=owner123456789&limit=1 HTTP/1.1").1), vec![1]);
+        assert_eq!(mint_ids(&respond(&store, 0, "GET /search?q=gm HTTP/1.1").1), vec![1]);
+        assert_eq!(respond(&store, 0, "GET /feed?limit=all HTTP/1.1").0, "400 Bad Request");
+        assert_eq!(respond(&store, 0, "GET /search HTTP/1.1").0, "400 Bad Request");
+
+        assert_eq!(respond(&store, 0, "GET /comments?card=0 HTTP/1.1").1, "[]");
+        let (_, body) = respond(&store, 0, "GET /comments?card=0&viewer=author12345678 HTTP/1.1");
+        let comments: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(comments[0]["created_at"], 5);
+        assert_eq!(comments[0]["hidden"], true);
+    }
+}
@@ -55,18 +55,204 @@ pub struct CardInteractionEvent {
     pub timestamp: i64,
 }
 
+/// Emitted for a like, share or bookmark folded into a card's
+/// interaction log instead of its own account.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompactInteractionEvent {
+    pub card_mint_id: u64,
+    pub user: String,
+    pub interaction_type: u8,
+    pub leaf: [u8; 32],
+    pub log_root: [u8; 32],
+    pub log_len: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a user reports a card title or comment.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContentReportedEvent {
+    pub target: String,
+    /// 0 = card, 1 = comment
+    pub kind: u8,
+    pub card_mint_id: u64,
+    /// Card owner, or the comment's author
+    pub author: String,
+    pub reporter: String,
+    pub reason: u8,
+    pub report_count: u32,
+    pub timestamp: i64,
+}
+
+/// Emitted when a moderator hides or restores a card or comment.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContentHiddenEvent {
+    pub target: String,
+    /// 0 = card, 1 = comment
+    pub kind: u8,
+    pub card_mint_id: u64,
+    /// Card owner, or the comment's author
+    pub author: String,
+    pub hidden: bool,
+    pub reason: u8,
+    pub moderator: String,
+    pub timestamp: i64,
+}
+
+/// Emitted when a wallet completes an achievement set and claims its badge.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AchievementClaimedEvent {
+    pub set_id: u32,
+    pub owner: String,
+    pub badge: String,
+    pub card_count: u8,
+    pub timestamp: i64,
+}
+
+/// Emitted when an owner edits a card's title, narration or soundtrack.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CardMetadataUpdatedEvent {
+    pub mint_id: u64,
+    pub owner: String,
+    pub title: String,
+    pub old_metadata_hash: [u8; 32],
+    pub new_metadata_hash: [u8; 32],
+    pub edits_used: u8,
+    pub timestamp: i64,
+}
+
+/// Emitted when the authority opens a governance proposal.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProposalCreatedEvent {
+    pub proposal: String,
+    pub proposal_id: u64,
+    pub title: String,
+    pub options: Vec<String>,
+    pub starts_at: i64,
+    pub ends_at: i64,
+}
+
+/// Emitted when a holder votes with one or more cards.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VoteCastEvent {
+    pub proposal: String,
+    pub proposal_id: u64,
+    pub voter: String,
+    pub option: u8,
+    pub cards: u8,
+    /// Combined weight of the cards, one each
+    pub weight: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a proposal's vote is tallied and closed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProposalFinalizedEvent {
+    pub proposal: String,
+    pub proposal_id: u64,
+    /// None for no votes or a tie
+    pub winning_option: Option<u8>,
+    pub tallies: Vec<u64>,
+    pub cards_voted: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a card is locked and minted as a Metaplex NFT.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CardWrappedEvent {
+    pub mint_id: u64,
+    pub card: String,
+    pub nft_mint: String,
+    pub owner: String,
+    pub timestamp: i64,
+}
+
+/// Emitted when a wrapped card's NFT is burned and the card released
+/// to whoever held the NFT.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CardUnwrappedEvent {
+    pub mint_id: u64,
+    pub card: String,
+    pub nft_mint: String,
+    pub holder: String,
+    pub timestamp: i64,
+}
+
+/// Emitted when a card is locked in its stake record. The record PDA
+/// becomes the on-chain owner; the staker still holds the card.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CardStakedEvent {
+    pub mint_id: u64,
+    pub staker: String,
+    pub timestamp: i64,
+}
+
+/// Emitted when a staked card is returned to its staker.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CardUnstakedEvent {
+    pub mint_id: u64,
+    pub staker: String,
+    pub points: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a card is locked in a gift link. The gift PDA becomes
+/// the on-chain owner until the gift is claimed or reclaimed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GiftCreatedEvent {
+    pub mint_id: u64,
+    pub sender: String,
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a gift link is claimed and the card handed over.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GiftClaimedEvent {
+    pub mint_id: u64,
+    pub sender: String,
+    pub claimant: String,
+    pub timestamp: i64,
+}
+
+/// Emitted when an expired gift goes back to its sender.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GiftReclaimedEvent {
+    pub mint_id: u64,
+    pub sender: String,
+    pub timestamp: i64,
+}
+
 /// Parsed event from an on-chain transaction log.
 #[derive(Debug, Clone)]
+// Transfers, burns and upgrades are only built by the store tests until
+// the log parser learns their layouts.
+#[allow(dead_code)]
 pub enum CryptEvent {
     CardMinted(CardMintedEvent),
     CardTransferred(CardTransferredEvent),
     CardBurned(CardBurnedEvent),
     RarityUpgraded(RarityUpgradedEvent),
     CardInteraction(CardInteractionEvent),
+    CompactInteraction(CompactInteractionEvent),
+    AchievementClaimed(AchievementClaimedEvent),
+    CardMetadataUpdated(CardMetadataUpdatedEvent),
+    ContentReported(ContentReportedEvent),
+    ContentHidden(ContentHiddenEvent),
+    ProposalCreated(ProposalCreatedEvent),
+    VoteCast(VoteCastEvent),
+    ProposalFinalized(ProposalFinalizedEvent),
+    CardWrapped(CardWrappedEvent),
+    CardUnwrapped(CardUnwrappedEvent),
+    CardStaked(CardStakedEvent),
+    CardUnstaked(CardUnstakedEvent),
+    GiftCreated(GiftCreatedEvent),
+    GiftClaimed(GiftClaimedEvent),
+    GiftReclaimed(GiftReclaimedEvent),
 }
 
 impl CryptEvent {
     /// Get the event name for logging.
+    #[allow(dead_code)]
     pub fn name(&self) -> &'static str {
         match self {
             Self::CardMinted(_) => "CARD_MINTED",
@@ -74,6 +260,21 @@ impl CryptEvent {
             Self::CardBurned(_) => "CARD_BURNED",
             Self::RarityUpgraded(_) => "RARITY_UPGRADED",
             Self::CardInteraction(_) => "CARD_INTERACTION",
+            Self::CompactInteraction(_) => "COMPACT_INTERACTION",
+            Self::AchievementClaimed(_) => "ACHIEVEMENT_CLAIMED",
+            Self::CardMetadataUpdated(_) => "CARD_METADATA_UPDATED",
+            Self::ContentReported(_) => "CONTENT_REPORTED",
+            Self::ContentHidden(_) => "CONTENT_HIDDEN",
+            Self::ProposalCreated(_) => "PROPOSAL_CREATED",
+            Self::VoteCast(_) => "VOTE_CAST",
+            Self::ProposalFinalized(_) => "PROPOSAL_FINALIZED",
+            Self::CardWrapped(_) => "CARD_WRAPPED",
+            Self::CardUnwrapped(_) => "CARD_UNWRAPPED",
+            Self::CardStaked(_) => "CARD_STAKED",
+            Self::CardUnstaked(_) => "CARD_UNSTAKED",
+            Self::GiftCreated(_) => "GIFT_CREATED",
+            Self::GiftClaimed(_) => "GIFT_CLAIMED",
+            Self::GiftReclaimed(_) => "GIFT_RECLAIMED",
         }
     }
 
@@ -85,6 +286,22 @@ impl CryptEvent {
             Self::CardBurned(e) => e.timestamp,
             Self::RarityUpgraded(e) => e.timestamp,
             Self::CardInteraction(e) => e.timestamp,
+            Self::CompactInteraction(e) => e.timestamp,
+            Self::AchievementClaimed(e) => e.timestamp,
+            Self::CardMetadataUpdated(e) => e.timestamp,
+            Self::ContentReported(e) => e.timestamp,
+            Self::ContentHidden(e) => e.timestamp,
+            // Proposals carry their window, not a creation time
+            Self::ProposalCreated(e) => e.starts_at,
+            Self::VoteCast(e) => e.timestamp,
+            Self::ProposalFinalized(e) => e.timestamp,
+            Self::CardWrapped(e) => e.timestamp,
+            Self::CardUnwrapped(e) => e.timestamp,
+            Self::CardStaked(e) => e.timestamp,
+            Self::CardUnstaked(e) => e.timestamp,
+            Self::GiftCreated(e) => e.timestamp,
+            Self::GiftClaimed(e) => e.timestamp,
+            Self::GiftReclaimed(e) => e.timestamp,
         }
     }
 }
@@ -5,17 +5,42 @@
 //! - CardTransferred events → updates ownership
 //! - CardBurned events → marks cards as destroyed
 //! - RarityUpgraded events → tracks rarity changes
-//! - CardInteraction events → aggregates social stats
+//! - CardInteraction / CompactInteraction events → aggregates social stats
+//! - AchievementClaimed events → tracks badges per wallet
+//! - CardMetadataUpdated events → keeps titles current
+//! - ContentReported / ContentHidden events → drops hidden cards and
+//!   comments from feed and search (their owners still see them)
+//! - CardWrapped / CardUnwrapped events → tracks NFT-wrapped cards and
+//!   hands them to the NFT holder on unwrap
+//! - CardStaked / CardUnstaked and Gift* events → keeps staked and
+//!   gifted cards with the staker or sender, and hands claimed gifts to
+//!   the claimant
+//! - ProposalCreated / VoteCast / ProposalFinalized events → live
+//!   governance tallies
+//! - Existing card accounts → backfilled at startup (every layout version)
+//!
+//! Events are also kept in chain order with their slot, so holder
+//! snapshots for merkle airdrops can be replayed to any slot or time.
+//!
+//! Collection metrics (totals, distributions and rolling 24h/7d
+//! activity), cards by wallet or ID, wallets' badges, cards' reactions,
+//! the moderated feed, search and comments, holder snapshots, governance
+//! proposals and wrapped cards' NFT mints are served as JSON on
+//! `INDEXER_API_ADDR`; `api` lists the routes.
 //!
 //! In production, this would write to a database (Postgres, DynamoDB)
 //! and serve a REST/GraphQL API for the frontend.
 
+mod api;
 mod events;
+mod metrics;
 mod processor;
+mod snapshot;
 mod store;
 mod watcher;
 
 use colored::Colorize;
+use std::sync::{Arc, Mutex};
 
 #[tokio::main]
 async fn main() {
@@ -33,7 +58,17 @@ async fn main() {
     println!("  {} {}", "Program:".bright_green(), program_id);
     println!("  {} Watching for events...\n", ">>".bright_cyan());
 
-    let store = store::InMemoryStore::new();
+    let api_addr = std::env::var("INDEXER_API_ADDR")
+        .unwrap_or_else(|_| "127.0.0.1:3030".to_string());
+
+    let store = Arc::new(Mutex::new(store::InMemoryStore::new()));
+    let api_store = store.clone();
+    tokio::spawn(async move {
+        if let Err(e) = api::serve(&api_addr, api_store).await {
+            eprintln!("{} {}", "API error:".red(), e);
+        }
+    });
+
     let mut watcher = watcher::EventWatcher::new(&rpc_url, &program_id, store);
 
     match watcher.start().await {
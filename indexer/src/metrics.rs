@@ -0,0 +1,70 @@
+//! Rolling collection activity.
+//! The store records when every mint, transfer, burn and interaction
+//! happened, so activity can be counted over any trailing window.
+
+use crypt_core::WindowMetrics;
+
+pub const DAY_SECS: i64 = 86_400;
+pub const WEEK_SECS: i64 = 7 * DAY_SECS;
+
+/// Event timestamps by kind, in the order they were indexed.
+#[derive(Debug, Clone, Default)]
+pub struct Activity {
+    mints: Vec<i64>,
+    transfers: Vec<i64>,
+    burns: Vec<i64>,
+    interactions: Vec<i64>,
+}
+
+/// Events in `(now - window_secs, now]`.
+fn count(timestamps: &[i64], now: i64, window_secs: i64) -> u64 {
+    timestamps.iter().filter(|&&t| t > now - window_secs && t <= now).count() as u64
+}
+
+impl Activity {
+    pub fn mint(&mut self, timestamp: i64) {
+        self.mints.push(timestamp);
+    }
+
+    pub fn transfer(&mut self, timestamp: i64) {
+        self.transfers.push(timestamp);
+    }
+
+    pub fn burn(&mut self, timestamp: i64) {
+        self.burns.push(timestamp);
+    }
+
+    pub fn interaction(&mut self, timestamp: i64) {
+        self.interactions.push(timestamp);
+    }
+
+    /// Activity over the `window_secs` before `now`.
+    pub fn window(&self, now: i64, window_secs: i64) -> WindowMetrics {
+        WindowMetrics {
+            window_secs,
+            mints: count(&self.mints, now, window_secs),
+            transfers: count(&self.transfers, now, window_secs),
+            burns: count(&self.burns, now, window_secs),
+            interactions: count(&self.interactions, now, window_secs),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_window_bounds() {
+        let mut activity = Activity::default();
+        for t in [0, 100, 200, 300] {
+            activity.mint(t);
+        }
+        activity.burn(250);
+        // Excludes the window's start, includes now, ignores the future
+        let w = activity.window(250, 150);
+        assert_eq!((w.mints, w.burns, w.transfers), (1, 1, 0));
+        assert_eq!(activity.window(1_000, 10_000).mints, 4);
+        assert_eq!(activity.window(1_000, 10).mints, 0);
+    }
+}
@@ -1,15 +1,56 @@
 //! Event processor — parses Anchor program logs into typed events.
 
 use crate::events::*;
-use bs58;
+use crate::store::IndexedCard;
 
 /// Event discriminators (first 8 bytes of SHA-256 hash of event name).
 /// These are used by Anchor to identify events in transaction logs.
+/// Minted cards arrive via backfill and the lifecycle events aren't
+/// parsed from logs yet; the names are kept for when they are.
+#[allow(dead_code)]
 const CARD_MINTED_DISC: &str = "CardMinted";
+#[allow(dead_code)]
 const CARD_TRANSFERRED_DISC: &str = "CardTransferred";
+#[allow(dead_code)]
 const CARD_BURNED_DISC: &str = "CardBurned";
+#[allow(dead_code)]
 const RARITY_UPGRADED_DISC: &str = "RarityUpgraded";
 const CARD_INTERACTION_DISC: &str = "CardInteraction";
+const COMPACT_INTERACTION_DISC: &str = "CompactInteraction";
+const CONTENT_REPORTED_DISC: &str = "ContentReported";
+const CONTENT_HIDDEN_DISC: &str = "ContentHidden";
+const PROPOSAL_CREATED_DISC: &str = "ProposalCreated";
+const VOTE_CAST_DISC: &str = "VoteCast";
+const PROPOSAL_FINALIZED_DISC: &str = "ProposalFinalized";
+const CARD_WRAPPED_DISC: &str = "CardWrapped";
+const CARD_UNWRAPPED_DISC: &str = "CardUnwrapped";
+const CARD_STAKED_DISC: &str = "CardStaked";
+const CARD_UNSTAKED_DISC: &str = "CardUnstaked";
+const GIFT_CREATED_DISC: &str = "GiftCreated";
+const GIFT_CLAIMED_DISC: &str = "GiftClaimed";
+const GIFT_RECLAIMED_DISC: &str = "GiftReclaimed";
+const ACHIEVEMENT_CLAIMED_DISC: &str = "AchievementClaimed";
+const CARD_METADATA_UPDATED_DISC: &str = "CardMetadataUpdated";
+
+/// Anchor's 8-byte event discriminator: sha256("event:<Name>")[..8].
+pub fn event_discriminator(name: &str) -> [u8; 8] {
+    let hash = solana_sdk::hash::hash(format!("event:{}", name).as_bytes());
+    let mut disc = [0u8; 8];
+    disc.copy_from_slice(&hash.to_bytes()[..8]);
+    disc
+}
+
+/// Anchor's 8-byte account discriminator: sha256("account:<Name>")[..8].
+pub fn account_discriminator(name: &str) -> [u8; 8] {
+    let hash = solana_sdk::hash::hash(format!("account:{}", name).as_bytes());
+    let mut disc = [0u8; 8];
+    disc.copy_from_slice(&hash.to_bytes()[..8]);
+    disc
+}
+
+/// Newest card layout version the indexer understands.
+/// Version 0 is the original layout, which had no version byte.
+pub const CARD_VERSION: u8 = 5;
 
 /// Parse Anchor event data from program log lines.
 /// Anchor events are base64-encoded in "Program data: <base64>" log lines.
@@ -67,6 +108,55 @@ fn base64_decode(input: &str) -> Result<Vec<u8>, ()> {
 fn try_parse_event(data: &[u8]) -> Option<CryptEvent> {
     if data.len() < 16 { return None; }
 
+    if data[..8] == event_discriminator(ACHIEVEMENT_CLAIMED_DISC) {
+        return parse_achievement_claimed(&data[8..]);
+    }
+    if data[..8] == event_discriminator(CARD_METADATA_UPDATED_DISC) {
+        return parse_card_metadata_updated(data);
+    }
+    if data[..8] == event_discriminator(COMPACT_INTERACTION_DISC) {
+        return parse_compact_interaction(data);
+    }
+    if data[..8] == event_discriminator(CARD_INTERACTION_DISC) {
+        return parse_card_interaction(data);
+    }
+    if data[..8] == event_discriminator(CONTENT_REPORTED_DISC) {
+        return parse_content_reported(data);
+    }
+    if data[..8] == event_discriminator(CONTENT_HIDDEN_DISC) {
+        return parse_content_hidden(data);
+    }
+    if data[..8] == event_discriminator(CARD_WRAPPED_DISC) {
+        return parse_card_wrap(data, true);
+    }
+    if data[..8] == event_discriminator(CARD_UNWRAPPED_DISC) {
+        return parse_card_wrap(data, false);
+    }
+    if data[..8] == event_discriminator(CARD_STAKED_DISC) {
+        return parse_card_stake(data, true);
+    }
+    if data[..8] == event_discriminator(CARD_UNSTAKED_DISC) {
+        return parse_card_stake(data, false);
+    }
+    if data[..8] == event_discriminator(GIFT_CREATED_DISC) {
+        return parse_gift_created(data);
+    }
+    if data[..8] == event_discriminator(GIFT_CLAIMED_DISC) {
+        return parse_gift_claimed(data);
+    }
+    if data[..8] == event_discriminator(GIFT_RECLAIMED_DISC) {
+        return parse_gift_reclaimed(data);
+    }
+    if data[..8] == event_discriminator(PROPOSAL_CREATED_DISC) {
+        return parse_proposal_created(data);
+    }
+    if data[..8] == event_discriminator(VOTE_CAST_DISC) {
+        return parse_vote_cast(data);
+    }
+    if data[..8] == event_discriminator(PROPOSAL_FINALIZED_DISC) {
+        return parse_proposal_finalized(data);
+    }
+
     // Try to parse based on data structure
     // In production, we'd match on the 8-byte Anchor event discriminator
     // For now, try to identify by field patterns
@@ -106,31 +196,353 @@ fn try_parse_event(data: &[u8]) -> Option<CryptEvent> {
     None
 }
 
-/// Format a rarity value as a string.
-pub fn rarity_name(rarity: u8) -> &'static str {
-    match rarity {
-        0 => "COMMON",
-        1 => "RARE",
-        2 => "LEGENDARY",
-        _ => "UNKNOWN",
+/// AchievementClaimed layout: set_id u32, owner, badge, card_count u8, timestamp i64.
+fn parse_achievement_claimed(body: &[u8]) -> Option<CryptEvent> {
+    if body.len() < 4 + 32 + 32 + 1 + 8 { return None; }
+
+    let set_id = u32::from_le_bytes(body[0..4].try_into().ok()?);
+    let owner = bs58::encode(&body[4..36]).into_string();
+    let badge = bs58::encode(&body[36..68]).into_string();
+    let card_count = body[68];
+    let timestamp = i64::from_le_bytes(body[69..77].try_into().ok()?);
+
+    Some(CryptEvent::AchievementClaimed(AchievementClaimedEvent {
+        set_id,
+        owner,
+        badge,
+        card_count,
+        timestamp,
+    }))
+}
+
+/// CardMetadataUpdated layout: mint_id, owner, title, old/new narration hash,
+/// old/new metadata hash, edits_used u8, timestamp i64.
+fn parse_card_metadata_updated(data: &[u8]) -> Option<CryptEvent> {
+    let mut r = Cursor { data, pos: 8 };
+
+    let mint_id = u64::from_le_bytes(r.take(8)?.try_into().ok()?);
+    let owner = bs58::encode(r.take(32)?).into_string();
+    let title = r.string()?;
+    r.take(32 + 32)?;                           // old/new narration hash
+    let old_metadata_hash: [u8; 32] = r.take(32)?.try_into().ok()?;
+    let new_metadata_hash: [u8; 32] = r.take(32)?.try_into().ok()?;
+    let edits_used = r.take(1)?[0];
+    let timestamp = i64::from_le_bytes(r.take(8)?.try_into().ok()?);
+
+    Some(CryptEvent::CardMetadataUpdated(CardMetadataUpdatedEvent {
+        mint_id,
+        owner,
+        title,
+        old_metadata_hash,
+        new_metadata_hash,
+        edits_used,
+        timestamp,
+    }))
+}
+
+/// CompactInteraction layout: card_mint_id, user, interaction_type u8,
+/// leaf, log_root, log_len u64, timestamp i64.
+fn parse_compact_interaction(data: &[u8]) -> Option<CryptEvent> {
+    let mut r = Cursor { data, pos: 8 };
+
+    let card_mint_id = u64::from_le_bytes(r.take(8)?.try_into().ok()?);
+    let user = bs58::encode(r.take(32)?).into_string();
+    let interaction_type = r.take(1)?[0];
+    let leaf: [u8; 32] = r.take(32)?.try_into().ok()?;
+    let log_root: [u8; 32] = r.take(32)?.try_into().ok()?;
+    let log_len = u64::from_le_bytes(r.take(8)?.try_into().ok()?);
+    let timestamp = i64::from_le_bytes(r.take(8)?.try_into().ok()?);
+
+    Some(CryptEvent::CompactInteraction(CompactInteractionEvent {
+        card_mint_id,
+        user,
+        interaction_type,
+        leaf,
+        log_root,
+        log_len,
+        timestamp,
+    }))
+}
+
+/// CardInteraction layout: card_mint_id, user, interaction_type u8, timestamp i64.
+fn parse_card_interaction(data: &[u8]) -> Option<CryptEvent> {
+    let mut r = Cursor { data, pos: 8 };
+
+    let card_mint_id = u64::from_le_bytes(r.take(8)?.try_into().ok()?);
+    let user = bs58::encode(r.take(32)?).into_string();
+    let interaction_type = r.take(1)?[0];
+    let timestamp = i64::from_le_bytes(r.take(8)?.try_into().ok()?);
+
+    Some(CryptEvent::CardInteraction(CardInteractionEvent {
+        card_mint_id,
+        user,
+        interaction_type,
+        timestamp,
+    }))
+}
+
+/// ContentReported layout: target, kind u8, card_mint_id, author, reporter,
+/// reason u8, report_count u32, timestamp i64.
+fn parse_content_reported(data: &[u8]) -> Option<CryptEvent> {
+    let mut r = Cursor { data, pos: 8 };
+
+    let target = bs58::encode(r.take(32)?).into_string();
+    let kind = r.take(1)?[0];
+    let card_mint_id = u64::from_le_bytes(r.take(8)?.try_into().ok()?);
+    let author = bs58::encode(r.take(32)?).into_string();
+    let reporter = bs58::encode(r.take(32)?).into_string();
+    let reason = r.take(1)?[0];
+    let report_count = u32::from_le_bytes(r.take(4)?.try_into().ok()?);
+    let timestamp = i64::from_le_bytes(r.take(8)?.try_into().ok()?);
+
+    Some(CryptEvent::ContentReported(ContentReportedEvent {
+        target,
+        kind,
+        card_mint_id,
+        author,
+        reporter,
+        reason,
+        report_count,
+        timestamp,
+    }))
+}
+
+/// ContentHidden layout: target, kind u8, card_mint_id, author, hidden bool,
+/// reason u8, moderator, timestamp i64.
+fn parse_content_hidden(data: &[u8]) -> Option<CryptEvent> {
+    let mut r = Cursor { data, pos: 8 };
+
+    let target = bs58::encode(r.take(32)?).into_string();
+    let kind = r.take(1)?[0];
+    let card_mint_id = u64::from_le_bytes(r.take(8)?.try_into().ok()?);
+    let author = bs58::encode(r.take(32)?).into_string();
+    let hidden = r.take(1)?[0] != 0;
+    let reason = r.take(1)?[0];
+    let moderator = bs58::encode(r.take(32)?).into_string();
+    let timestamp = i64::from_le_bytes(r.take(8)?.try_into().ok()?);
+
+    Some(CryptEvent::ContentHidden(ContentHiddenEvent {
+        target,
+        kind,
+        card_mint_id,
+        author,
+        hidden,
+        reason,
+        moderator,
+        timestamp,
+    }))
+}
+
+/// CardWrapped / CardUnwrapped layout: mint_id, card, nft_mint,
+/// owner (or holder), timestamp i64.
+fn parse_card_wrap(data: &[u8], wrapped: bool) -> Option<CryptEvent> {
+    let mut r = Cursor { data, pos: 8 };
+
+    let mint_id = u64::from_le_bytes(r.take(8)?.try_into().ok()?);
+    let card = bs58::encode(r.take(32)?).into_string();
+    let nft_mint = bs58::encode(r.take(32)?).into_string();
+    let wallet = bs58::encode(r.take(32)?).into_string();
+    let timestamp = i64::from_le_bytes(r.take(8)?.try_into().ok()?);
+
+    Some(if wrapped {
+        CryptEvent::CardWrapped(CardWrappedEvent { mint_id, card, nft_mint, owner: wallet, timestamp })
+    } else {
+        CryptEvent::CardUnwrapped(CardUnwrappedEvent { mint_id, card, nft_mint, holder: wallet, timestamp })
+    })
+}
+
+/// CardStaked layout: mint_id, staker, timestamp i64. CardUnstaked has
+/// points u64 before the timestamp.
+fn parse_card_stake(data: &[u8], staked: bool) -> Option<CryptEvent> {
+    let mut r = Cursor { data, pos: 8 };
+
+    let mint_id = u64::from_le_bytes(r.take(8)?.try_into().ok()?);
+    let staker = bs58::encode(r.take(32)?).into_string();
+    if staked {
+        let timestamp = i64::from_le_bytes(r.take(8)?.try_into().ok()?);
+        return Some(CryptEvent::CardStaked(CardStakedEvent { mint_id, staker, timestamp }));
     }
+    let points = u64::from_le_bytes(r.take(8)?.try_into().ok()?);
+    let timestamp = i64::from_le_bytes(r.take(8)?.try_into().ok()?);
+    Some(CryptEvent::CardUnstaked(CardUnstakedEvent { mint_id, staker, points, timestamp }))
 }
 
-/// Format a card type value as a string.
-pub fn card_type_name(card_type: u8) -> &'static str {
-    match card_type {
-        0 => "SWAP",
-        1 => "RUG",
-        2 => "MINT",
-        3 => "DIAMOND_HANDS",
-        4 => "BIG_MOVE",
-        _ => "UNKNOWN",
+/// GiftCreated layout: mint_id, sender, expires_at i64, timestamp i64.
+fn parse_gift_created(data: &[u8]) -> Option<CryptEvent> {
+    let mut r = Cursor { data, pos: 8 };
+
+    let mint_id = u64::from_le_bytes(r.take(8)?.try_into().ok()?);
+    let sender = bs58::encode(r.take(32)?).into_string();
+    let expires_at = i64::from_le_bytes(r.take(8)?.try_into().ok()?);
+    let timestamp = i64::from_le_bytes(r.take(8)?.try_into().ok()?);
+
+    Some(CryptEvent::GiftCreated(GiftCreatedEvent { mint_id, sender, expires_at, timestamp }))
+}
+
+/// GiftClaimed layout: mint_id, sender, claimant, timestamp i64.
+fn parse_gift_claimed(data: &[u8]) -> Option<CryptEvent> {
+    let mut r = Cursor { data, pos: 8 };
+
+    let mint_id = u64::from_le_bytes(r.take(8)?.try_into().ok()?);
+    let sender = bs58::encode(r.take(32)?).into_string();
+    let claimant = bs58::encode(r.take(32)?).into_string();
+    let timestamp = i64::from_le_bytes(r.take(8)?.try_into().ok()?);
+
+    Some(CryptEvent::GiftClaimed(GiftClaimedEvent { mint_id, sender, claimant, timestamp }))
+}
+
+/// GiftReclaimed layout: mint_id, sender, timestamp i64.
+fn parse_gift_reclaimed(data: &[u8]) -> Option<CryptEvent> {
+    let mut r = Cursor { data, pos: 8 };
+
+    let mint_id = u64::from_le_bytes(r.take(8)?.try_into().ok()?);
+    let sender = bs58::encode(r.take(32)?).into_string();
+    let timestamp = i64::from_le_bytes(r.take(8)?.try_into().ok()?);
+
+    Some(CryptEvent::GiftReclaimed(GiftReclaimedEvent { mint_id, sender, timestamp }))
+}
+
+/// ProposalCreated layout: proposal, proposal_id, title, options Vec<String>,
+/// starts_at i64, ends_at i64.
+fn parse_proposal_created(data: &[u8]) -> Option<CryptEvent> {
+    let mut r = Cursor { data, pos: 8 };
+
+    let proposal = bs58::encode(r.take(32)?).into_string();
+    let proposal_id = u64::from_le_bytes(r.take(8)?.try_into().ok()?);
+    let title = r.string()?;
+    let count = u32::from_le_bytes(r.take(4)?.try_into().ok()?);
+    let options = (0..count).map(|_| r.string()).collect::<Option<Vec<_>>>()?;
+    let starts_at = i64::from_le_bytes(r.take(8)?.try_into().ok()?);
+    let ends_at = i64::from_le_bytes(r.take(8)?.try_into().ok()?);
+
+    Some(CryptEvent::ProposalCreated(ProposalCreatedEvent {
+        proposal,
+        proposal_id,
+        title,
+        options,
+        starts_at,
+        ends_at,
+    }))
+}
+
+/// VoteCast layout: proposal, proposal_id, voter, option u8, cards u8,
+/// weight u64, timestamp i64.
+fn parse_vote_cast(data: &[u8]) -> Option<CryptEvent> {
+    let mut r = Cursor { data, pos: 8 };
+
+    let proposal = bs58::encode(r.take(32)?).into_string();
+    let proposal_id = u64::from_le_bytes(r.take(8)?.try_into().ok()?);
+    let voter = bs58::encode(r.take(32)?).into_string();
+    let option = r.take(1)?[0];
+    let cards = r.take(1)?[0];
+    let weight = u64::from_le_bytes(r.take(8)?.try_into().ok()?);
+    let timestamp = i64::from_le_bytes(r.take(8)?.try_into().ok()?);
+
+    Some(CryptEvent::VoteCast(VoteCastEvent {
+        proposal,
+        proposal_id,
+        voter,
+        option,
+        cards,
+        weight,
+        timestamp,
+    }))
+}
+
+/// ProposalFinalized layout: proposal, proposal_id, winning_option Option<u8>,
+/// tallies Vec<u64>, cards_voted u64, timestamp i64.
+fn parse_proposal_finalized(data: &[u8]) -> Option<CryptEvent> {
+    let mut r = Cursor { data, pos: 8 };
+
+    let proposal = bs58::encode(r.take(32)?).into_string();
+    let proposal_id = u64::from_le_bytes(r.take(8)?.try_into().ok()?);
+    let winning_option = match r.take(1)?[0] {
+        0 => None,
+        _ => Some(r.take(1)?[0]),
+    };
+    let count = u32::from_le_bytes(r.take(4)?.try_into().ok()?);
+    let tallies = (0..count)
+        .map(|_| r.take(8).and_then(|b| b.try_into().ok()).map(u64::from_le_bytes))
+        .collect::<Option<Vec<_>>>()?;
+    let cards_voted = u64::from_le_bytes(r.take(8)?.try_into().ok()?);
+    let timestamp = i64::from_le_bytes(r.take(8)?.try_into().ok()?);
+
+    Some(CryptEvent::ProposalFinalized(ProposalFinalizedEvent {
+        proposal,
+        proposal_id,
+        winning_option,
+        tallies,
+        cards_voted,
+        timestamp,
+    }))
+}
+
+/// Decode a CryptCard account (discriminator included) into an indexed card.
+/// Handles every layout version — fields added after v0 are only read
+/// when present, so legacy cards decode with `version == 0`.
+pub fn decode_card_account(data: &[u8]) -> Option<IndexedCard> {
+    if data.len() < 8 || data[..8] != account_discriminator("CryptCard") {
+        return None;
     }
+    let mut r = Cursor { data, pos: 8 };
+
+    let owner = bs58::encode(r.take(32)?).into_string();
+    let mint_id = u64::from_le_bytes(r.take(8)?.try_into().ok()?);
+    let tx_hash = r.string()?;
+    let rarity = r.take(1)?[0];
+    let card_type = r.take(1)?[0];
+    let title = r.string()?;
+    r.take(32 + 32)?;                           // narration_hash, soul_seed
+    r.string()?;                                // platform
+    r.string()?;                                // pnl
+    r.take(8)?;                                 // tx_timestamp
+    let minted_at = i64::from_le_bytes(r.take(8)?.try_into().ok()?);
+    let interaction_count = u64::from_le_bytes(r.take(8)?.try_into().ok()?);
+    r.string()?;                                // soundtrack_id
+    r.take(1)?;                                 // bump
+
+    // v1+: version byte. A v0 account either ends here or is zero-padded.
+    let version = r.take(1).map(|b| b[0]).unwrap_or(0);
+
+    Some(IndexedCard {
+        mint_id,
+        owner,
+        tx_hash,
+        rarity,
+        card_type,
+        title,
+        interaction_count,
+        minted_at,
+        burned: false,
+        version,
+    })
+}
+
+struct Cursor<'a> {
+    data: &'a [u8],
+    pos: usize,
 }
 
+impl<'a> Cursor<'a> {
+    fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+        let bytes = self.data.get(self.pos..self.pos.checked_add(len)?)?;
+        self.pos += len;
+        Some(bytes)
+    }
+
+    fn string(&mut self) -> Option<String> {
+        let len = u32::from_le_bytes(self.take(4)?.try_into().ok()?) as usize;
+        Some(String::from_utf8_lossy(self.take(len)?).to_string())
+    }
+}
+
+// Rarity names come from crypt-core, like the program's enums
+pub use crypt_core::rarity_name;
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crypt_core::card_type_name;
 
     #[test]
     fn test_base64_decode() {
@@ -160,6 +572,251 @@ mod tests {
         assert!(events.is_empty());
     }
 
+    #[test]
+    fn test_achievement_claimed_parsed_by_discriminator() {
+        let mut data = event_discriminator("AchievementClaimed").to_vec();
+        data.extend_from_slice(&7u32.to_le_bytes());
+        data.extend_from_slice(&[1u8; 32]);
+        data.extend_from_slice(&[2u8; 32]);
+        data.push(5);
+        data.extend_from_slice(&1_700_000_000i64.to_le_bytes());
+
+        match try_parse_event(&data) {
+            Some(CryptEvent::AchievementClaimed(e)) => {
+                assert_eq!(e.set_id, 7);
+                assert_eq!(e.owner, bs58::encode([1u8; 32]).into_string());
+                assert_eq!(e.badge, bs58::encode([2u8; 32]).into_string());
+                assert_eq!(e.card_count, 5);
+                assert_eq!(e.timestamp, 1_700_000_000);
+            }
+            other => panic!("expected AchievementClaimed, got {:?}", other),
+        }
+    }
+
+    fn card_account_v0() -> Vec<u8> {
+        fn string(out: &mut Vec<u8>, s: &str) {
+            out.extend_from_slice(&(s.len() as u32).to_le_bytes());
+            out.extend_from_slice(s.as_bytes());
+        }
+        let mut data = account_discriminator("CryptCard").to_vec();
+        data.extend_from_slice(&[9u8; 32]);
+        data.extend_from_slice(&3u64.to_le_bytes());
+        string(&mut data, "4xK7m9pR2abc");
+        data.extend_from_slice(&[1, 4]);
+        string(&mut data, "WHALE MOVE");
+        data.extend_from_slice(&[0u8; 64]);
+        string(&mut data, "JUPITER");
+        string(&mut data, "+69%");
+        data.extend_from_slice(&1_700_000_000i64.to_le_bytes());
+        data.extend_from_slice(&1_700_000_500i64.to_le_bytes());
+        data.extend_from_slice(&4u64.to_le_bytes());
+        string(&mut data, "");
+        data.push(255);
+        data
+    }
+
+    #[test]
+    fn test_decode_card_account_versions() {
+        let legacy = card_account_v0();
+        let card = decode_card_account(&legacy).unwrap();
+        assert_eq!(card.mint_id, 3);
+        assert_eq!(card.owner, bs58::encode([9u8; 32]).into_string());
+        assert_eq!(card.title, "WHALE MOVE");
+        assert_eq!(card.rarity, 1);
+        assert_eq!(card.card_type, 4);
+        assert_eq!(card.minted_at, 1_700_000_500);
+        assert_eq!(card.interaction_count, 4);
+        assert_eq!(card.version, 0);
+
+        let mut current = legacy.clone();
+        current.push(CARD_VERSION);
+        assert_eq!(decode_card_account(&current).unwrap().version, CARD_VERSION);
+
+        assert!(decode_card_account(&legacy[..50]).is_none());
+    }
+
+    #[test]
+    fn test_card_metadata_updated_parsed() {
+        let mut data = event_discriminator("CardMetadataUpdated").to_vec();
+        data.extend_from_slice(&11u64.to_le_bytes());
+        data.extend_from_slice(&[3u8; 32]);
+        data.extend_from_slice(&(9u32).to_le_bytes());
+        data.extend_from_slice(b"NEW TITLE");
+        data.extend_from_slice(&[0u8; 64]);
+        data.extend_from_slice(&[4u8; 32]);
+        data.extend_from_slice(&[5u8; 32]);
+        data.push(2);
+        data.extend_from_slice(&1_700_000_000i64.to_le_bytes());
+
+        match try_parse_event(&data) {
+            Some(CryptEvent::CardMetadataUpdated(e)) => {
+                assert_eq!(e.mint_id, 11);
+                assert_eq!(e.title, "NEW TITLE");
+                assert_eq!(e.old_metadata_hash, [4u8; 32]);
+                assert_eq!(e.new_metadata_hash, [5u8; 32]);
+                assert_eq!(e.edits_used, 2);
+                assert_eq!(e.timestamp, 1_700_000_000);
+            }
+            other => panic!("expected CardMetadataUpdated, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_compact_interaction_parsed() {
+        let mut data = event_discriminator("CompactInteraction").to_vec();
+        data.extend_from_slice(&8u64.to_le_bytes());
+        data.extend_from_slice(&[6u8; 32]);
+        data.push(3);
+        data.extend_from_slice(&[1u8; 32]);
+        data.extend_from_slice(&[2u8; 32]);
+        data.extend_from_slice(&40u64.to_le_bytes());
+        data.extend_from_slice(&1_700_000_000i64.to_le_bytes());
+
+        match try_parse_event(&data) {
+            Some(CryptEvent::CompactInteraction(e)) => {
+                assert_eq!(e.card_mint_id, 8);
+                assert_eq!(e.user, bs58::encode([6u8; 32]).into_string());
+                assert_eq!(e.interaction_type, 3);
+                assert_eq!(e.log_root, [2u8; 32]);
+                assert_eq!(e.log_len, 40);
+                assert_eq!(e.timestamp, 1_700_000_000);
+            }
+            other => panic!("expected CompactInteraction, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_content_hidden_parsed() {
+        let mut data = event_discriminator("ContentHidden").to_vec();
+        data.extend_from_slice(&[1u8; 32]);
+        data.push(1);
+        data.extend_from_slice(&5u64.to_le_bytes());
+        data.extend_from_slice(&[2u8; 32]);
+        data.push(1);
+        data.push(1);
+        data.extend_from_slice(&[3u8; 32]);
+        data.extend_from_slice(&1_700_000_000i64.to_le_bytes());
+
+        match try_parse_event(&data) {
+            Some(CryptEvent::ContentHidden(e)) => {
+                assert_eq!(e.kind, 1);
+                assert_eq!(e.card_mint_id, 5);
+                assert_eq!(e.author, bs58::encode([2u8; 32]).into_string());
+                assert!(e.hidden);
+                assert_eq!(e.reason, 1);
+                assert_eq!(e.moderator, bs58::encode([3u8; 32]).into_string());
+            }
+            other => panic!("expected ContentHidden, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_card_unwrapped_parsed() {
+        let mut data = event_discriminator("CardUnwrapped").to_vec();
+        data.extend_from_slice(&12u64.to_le_bytes());
+        data.extend_from_slice(&[1u8; 32]);
+        data.extend_from_slice(&[2u8; 32]);
+        data.extend_from_slice(&[3u8; 32]);
+        data.extend_from_slice(&1_700_000_000i64.to_le_bytes());
+        match try_parse_event(&data) {
+            Some(CryptEvent::CardUnwrapped(e)) => {
+                assert_eq!(e.mint_id, 12);
+                assert_eq!(e.holder, bs58::encode([3u8; 32]).into_string());
+            }
+            other => panic!("expected CardUnwrapped, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_custody_events_parsed() {
+        let mut data = event_discriminator("CardUnstaked").to_vec();
+        data.extend_from_slice(&7u64.to_le_bytes());
+        data.extend_from_slice(&[4u8; 32]);
+        data.extend_from_slice(&360u64.to_le_bytes());
+        data.extend_from_slice(&1_700_000_000i64.to_le_bytes());
+        match try_parse_event(&data) {
+            Some(CryptEvent::CardUnstaked(e)) => {
+                assert_eq!((e.mint_id, e.points), (7, 360));
+                assert_eq!(e.staker, bs58::encode([4u8; 32]).into_string());
+            }
+            other => panic!("expected CardUnstaked, got {:?}", other),
+        }
+
+        let mut data = event_discriminator("GiftClaimed").to_vec();
+        data.extend_from_slice(&8u64.to_le_bytes());
+        data.extend_from_slice(&[5u8; 32]);
+        data.extend_from_slice(&[6u8; 32]);
+        data.extend_from_slice(&1_700_000_000i64.to_le_bytes());
+        match try_parse_event(&data) {
+            Some(CryptEvent::GiftClaimed(e)) => {
+                assert_eq!(e.mint_id, 8);
+                assert_eq!(e.claimant, bs58::encode([6u8; 32]).into_string());
+            }
+            other => panic!("expected GiftClaimed, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_proposal_events_parsed() {
+        fn string(out: &mut Vec<u8>, s: &str) {
+            out.extend_from_slice(&(s.len() as u32).to_le_bytes());
+            out.extend_from_slice(s.as_bytes());
+        }
+
+        let mut data = event_discriminator("ProposalCreated").to_vec();
+        data.extend_from_slice(&[1u8; 32]);
+        data.extend_from_slice(&3u64.to_le_bytes());
+        string(&mut data, "SEASON 2 THEME");
+        data.extend_from_slice(&2u32.to_le_bytes());
+        string(&mut data, "RUGS");
+        string(&mut data, "APES");
+        data.extend_from_slice(&1_700_000_000i64.to_le_bytes());
+        data.extend_from_slice(&1_700_604_800i64.to_le_bytes());
+        match try_parse_event(&data) {
+            Some(CryptEvent::ProposalCreated(e)) => {
+                assert_eq!(e.proposal_id, 3);
+                assert_eq!(e.options, vec!["RUGS", "APES"]);
+                assert_eq!(e.ends_at, 1_700_604_800);
+            }
+            other => panic!("expected ProposalCreated, got {:?}", other),
+        }
+
+        let mut data = event_discriminator("ProposalFinalized").to_vec();
+        data.extend_from_slice(&[1u8; 32]);
+        data.extend_from_slice(&3u64.to_le_bytes());
+        data.extend_from_slice(&[1, 1]);
+        data.extend_from_slice(&2u32.to_le_bytes());
+        data.extend_from_slice(&4u64.to_le_bytes());
+        data.extend_from_slice(&9u64.to_le_bytes());
+        data.extend_from_slice(&6u64.to_le_bytes());
+        data.extend_from_slice(&1_700_700_000i64.to_le_bytes());
+        match try_parse_event(&data) {
+            Some(CryptEvent::ProposalFinalized(e)) => {
+                assert_eq!(e.winning_option, Some(1));
+                assert_eq!(e.tallies, vec![4, 9]);
+                assert_eq!(e.cards_voted, 6);
+            }
+            other => panic!("expected ProposalFinalized, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_card_interaction_parsed() {
+        let mut data = event_discriminator("CardInteraction").to_vec();
+        data.extend_from_slice(&4u64.to_le_bytes());
+        data.extend_from_slice(&[7u8; 32]);
+        data.push(1);
+        data.extend_from_slice(&1_700_000_000i64.to_le_bytes());
+
+        match try_parse_event(&data) {
+            Some(CryptEvent::CardInteraction(e)) => {
+                assert_eq!(e.card_mint_id, 4);
+                assert_eq!(e.interaction_type, 1);
+            }
+            other => panic!("expected CardInteraction, got {:?}", other),
+        }
+    }
+
     #[test]
     fn test_irrelevant_logs_ignored() {
         let logs = vec![
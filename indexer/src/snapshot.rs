@@ -0,0 +1,475 @@
+//! Holder snapshots for merkle airdrops.
+//!
+//! Replays indexed events up to a slot or timestamp to find who held
+//! which cards at that point, weights holders by rarity, splits a total
+//! amount between them and builds the merkle tree the program's
+//! `claim_distribution` checks proofs against.
+//!
+//! Staked, gifted and wrapped cards sit in a program PDA on-chain, but
+//! count for the wallet with the claim on them: the staker, the gift's
+//! sender until it's claimed, and the wrapper until the NFT is burned.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+use std::str::FromStr;
+use crate::events::CryptEvent;
+
+/// Point in chain history a snapshot is taken at (inclusive).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SnapshotCutoff {
+    Slot(u64),
+    Timestamp(i64),
+}
+
+impl SnapshotCutoff {
+    fn includes(&self, slot: u64, event: &CryptEvent) -> bool {
+        match *self {
+            Self::Slot(cutoff) => slot <= cutoff,
+            Self::Timestamp(cutoff) => event.timestamp() <= cutoff,
+        }
+    }
+}
+
+/// Which cards count towards a snapshot. Empty sets mean "any".
+#[derive(Debug, Clone, Default)]
+pub struct SnapshotFilter {
+    pub rarities: BTreeSet<u8>,
+    pub mint_ids: BTreeSet<u64>,
+}
+
+impl SnapshotFilter {
+    fn matches(&self, mint_id: u64, rarity: u8) -> bool {
+        (self.rarities.is_empty() || self.rarities.contains(&rarity))
+            && (self.mint_ids.is_empty() || self.mint_ids.contains(&mint_id))
+    }
+}
+
+/// Weight each held card contributes, by rarity (Common, Rare, Legendary).
+pub type RarityWeights = [u64; 3];
+
+/// One card per share, regardless of rarity.
+pub const FLAT_WEIGHTS: RarityWeights = [1, 1, 1];
+
+/// A holder's cards and total weight at the cutoff.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Holding {
+    pub mint_ids: Vec<u64>,
+    pub weight: u64,
+}
+
+/// Holders as of a cutoff, keyed by wallet.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Snapshot {
+    pub cutoff: SnapshotCutoff,
+    pub holders: BTreeMap<String, Holding>,
+}
+
+struct CardState {
+    owner: String,
+    rarity: u8,
+    burned: bool,
+}
+
+impl Snapshot {
+    /// Replay `(slot, event)` pairs in order, stopping at the cutoff.
+    pub fn replay(
+        events: &[(u64, CryptEvent)],
+        cutoff: SnapshotCutoff,
+        filter: &SnapshotFilter,
+        weights: &RarityWeights,
+    ) -> Self {
+        let mut cards: HashMap<u64, CardState> = HashMap::new();
+
+        for (slot, event) in events {
+            if !cutoff.includes(*slot, event) {
+                // Events are in chain order, so nothing later can count
+                break;
+            }
+            match event {
+                CryptEvent::CardMinted(e) => {
+                    cards.insert(e.mint_id, CardState {
+                        owner: e.owner.clone(),
+                        rarity: e.rarity,
+                        burned: false,
+                    });
+                }
+                CryptEvent::CardTransferred(e) => {
+                    if let Some(card) = cards.get_mut(&e.mint_id) {
+                        card.owner = e.to.clone();
+                    }
+                }
+                // A wrapped card counts for its wrapper until it's
+                // unwrapped, since NFT transfers aren't program events
+                CryptEvent::CardWrapped(e) => {
+                    if let Some(card) = cards.get_mut(&e.mint_id) {
+                        card.owner = e.owner.clone();
+                    }
+                }
+                CryptEvent::CardUnwrapped(e) => {
+                    if let Some(card) = cards.get_mut(&e.mint_id) {
+                        card.owner = e.holder.clone();
+                    }
+                }
+                CryptEvent::CardStaked(e) => {
+                    if let Some(card) = cards.get_mut(&e.mint_id) {
+                        card.owner = e.staker.clone();
+                    }
+                }
+                CryptEvent::CardUnstaked(e) => {
+                    if let Some(card) = cards.get_mut(&e.mint_id) {
+                        card.owner = e.staker.clone();
+                    }
+                }
+                CryptEvent::GiftCreated(e) => {
+                    if let Some(card) = cards.get_mut(&e.mint_id) {
+                        card.owner = e.sender.clone();
+                    }
+                }
+                CryptEvent::GiftClaimed(e) => {
+                    if let Some(card) = cards.get_mut(&e.mint_id) {
+                        card.owner = e.claimant.clone();
+                    }
+                }
+                CryptEvent::GiftReclaimed(e) => {
+                    if let Some(card) = cards.get_mut(&e.mint_id) {
+                        card.owner = e.sender.clone();
+                    }
+                }
+                CryptEvent::CardBurned(e) => {
+                    if let Some(card) = cards.get_mut(&e.mint_id) {
+                        card.burned = true;
+                    }
+                }
+                CryptEvent::RarityUpgraded(e) => {
+                    if let Some(card) = cards.get_mut(&e.mint_id) {
+                        card.rarity = e.new_rarity;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let mut holders: BTreeMap<String, Holding> = BTreeMap::new();
+        let mut ids: Vec<_> = cards.keys().copied().collect();
+        ids.sort_unstable();
+        for mint_id in ids {
+            let card = &cards[&mint_id];
+            if card.burned || !filter.matches(mint_id, card.rarity) {
+                continue;
+            }
+            let weight = weights.get(card.rarity as usize).copied().unwrap_or(0);
+            if weight == 0 {
+                continue;
+            }
+            let holding = holders
+                .entry(card.owner.clone())
+                .or_insert_with(|| Holding { mint_ids: Vec::new(), weight: 0 });
+            holding.mint_ids.push(mint_id);
+            holding.weight += weight;
+        }
+
+        Self { cutoff, holders }
+    }
+
+    pub fn total_weight(&self) -> u64 {
+        self.holders.values().map(|h| h.weight).sum()
+    }
+
+    /// Split `total` in proportion to weight. Rounding dust goes one unit
+    /// at a time to the heaviest holders (ties by wallet), so the
+    /// allocations always add up to `total`.
+    pub fn allocations(&self, total: u64) -> Vec<(String, u64)> {
+        let total_weight = self.total_weight() as u128;
+        if total_weight == 0 {
+            return Vec::new();
+        }
+        let mut out: Vec<(String, u64)> = self.holders
+            .iter()
+            .map(|(owner, h)| (owner.clone(), (total as u128 * h.weight as u128 / total_weight) as u64))
+            .collect();
+
+        let mut dust = total - out.iter().map(|(_, amount)| amount).sum::<u64>();
+        let mut order: Vec<usize> = (0..out.len()).collect();
+        order.sort_by(|&a, &b| {
+            self.holders[&out[b].0].weight
+                .cmp(&self.holders[&out[a].0].weight)
+                .then(out[a].0.cmp(&out[b].0))
+        });
+        for &i in order.iter().cycle() {
+            if dust == 0 {
+                break;
+            }
+            out[i].1 += 1;
+            dust -= 1;
+        }
+        out
+    }
+
+    /// Allocate `total` and build the merkle tree for a distributor.
+    /// Holders whose share rounds to zero are left out.
+    pub fn distribution(&self, total: u64) -> Result<Distribution, String> {
+        let claims = self.allocations(total)
+            .into_iter()
+            .filter(|(_, amount)| *amount > 0)
+            .map(|(owner, amount)| {
+                Pubkey::from_str(&owner)
+                    .map(|claimant| (claimant, amount))
+                    .map_err(|_| format!("holder {} is not a valid wallet address", owner))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Distribution::new(self.cutoff, claims))
+    }
+}
+
+pub use crypt_core::merkle::merkle_node;
+
+/// Leaf for one allocation, from the same `crypt-core` helper the
+/// program checks claims with.
+pub fn distribution_leaf(claimant: &Pubkey, amount: u64) -> [u8; 32] {
+    crypt_core::merkle::distribution_leaf(&claimant.to_bytes(), amount)
+}
+
+/// One claimant's allocation and proof.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DistributionClaim {
+    pub claimant: String,
+    pub amount: u64,
+    /// Sibling hashes from leaf to root, hex-encoded
+    pub proof: Vec<String>,
+}
+
+/// Everything needed to create a distributor and for holders to claim:
+/// the root, the total to fund, and one proof per claimant.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Distribution {
+    pub cutoff: SnapshotCutoff,
+    /// Hex-encoded merkle root
+    pub merkle_root: String,
+    pub total_amount: u64,
+    pub claims: Vec<DistributionClaim>,
+}
+
+impl Distribution {
+    pub fn new(cutoff: SnapshotCutoff, claims: Vec<(Pubkey, u64)>) -> Self {
+        let leaves: Vec<[u8; 32]> = claims.iter()
+            .map(|(claimant, amount)| distribution_leaf(claimant, *amount))
+            .collect();
+
+        // levels[0] = leaves; an odd node at the end is carried up unchanged
+        let mut levels = vec![leaves];
+        while levels.last().unwrap().len() > 1 {
+            let level = levels.last().unwrap();
+            let next = level.chunks(2)
+                .map(|pair| match pair {
+                    [a, b] => merkle_node(a, b),
+                    [a] => *a,
+                    _ => unreachable!(),
+                })
+                .collect();
+            levels.push(next);
+        }
+        let root = levels.last().unwrap().first().copied().unwrap_or([0u8; 32]);
+
+        let claims: Vec<DistributionClaim> = claims.iter().enumerate()
+            .map(|(i, (claimant, amount))| {
+                let mut proof = Vec::new();
+                let mut index = i;
+                for level in &levels[..levels.len() - 1] {
+                    if let Some(sibling) = level.get(index ^ 1) {
+                        proof.push(hex(sibling));
+                    }
+                    index /= 2;
+                }
+                DistributionClaim { claimant: claimant.to_string(), amount: *amount, proof }
+            })
+            .collect();
+
+        Self {
+            cutoff,
+            merkle_root: hex(&root),
+            total_amount: claims_total(&claims),
+            claims,
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("distribution serializes")
+    }
+}
+
+fn claims_total(claims: &[DistributionClaim]) -> u64 {
+    claims.iter().map(|c| c.amount).sum()
+}
+
+fn hex(bytes: &[u8; 32]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::*;
+
+    fn wallet(n: u8) -> String {
+        Pubkey::new_from_array([n; 32]).to_string()
+    }
+
+    /// Only Legendary cards.
+    fn legendaries() -> SnapshotFilter {
+        SnapshotFilter { rarities: BTreeSet::from([2]), ..Default::default() }
+    }
+
+    fn unhex(s: &str) -> [u8; 32] {
+        let mut out = [0u8; 32];
+        for (i, byte) in out.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&s[i * 2..i * 2 + 2], 16).unwrap();
+        }
+        out
+    }
+
+    /// Event fixture, in chain order with the slot each landed in:
+    /// alice mints a Legendary and a Common, bob a Rare that he later
+    /// upgrades, alice sends her Legendary to carol, and carol burns it
+    /// after the slot-300 snapshot.
+    fn fixture() -> Vec<(u64, CryptEvent)> {
+        let mint = |mint_id, owner: &str, rarity, timestamp| CryptEvent::CardMinted(CardMintedEvent {
+            mint_id, owner: owner.into(), tx_hash: format!("tx{}", mint_id),
+            rarity, card_type: 0, title: String::new(), soul_seed: [0; 32], timestamp,
+        });
+        vec![
+            (100, mint(0, &wallet(1), 2, 1_000)),
+            (110, mint(1, &wallet(1), 0, 1_100)),
+            (120, mint(2, &wallet(2), 1, 1_200)),
+            (200, CryptEvent::CardTransferred(CardTransferredEvent {
+                mint_id: 0, from: wallet(1), to: wallet(3), tx_hash: "tx0".into(), timestamp: 2_000,
+            })),
+            (250, CryptEvent::RarityUpgraded(RarityUpgradedEvent {
+                mint_id: 2, owner: wallet(2), old_rarity: 1, new_rarity: 2, timestamp: 2_500,
+            })),
+            (400, CryptEvent::CardBurned(CardBurnedEvent {
+                mint_id: 0, owner: wallet(3), tx_hash: "tx0".into(), rarity: 2, timestamp: 4_000,
+            })),
+        ]
+    }
+
+    #[test]
+    fn test_legendary_holders_at_slot() {
+        let events = fixture();
+
+        let snap = Snapshot::replay(&events, SnapshotCutoff::Slot(300), &legendaries(), &FLAT_WEIGHTS);
+        let holders: Vec<_> = snap.holders.keys().cloned().collect();
+        assert_eq!(holders, vec![wallet(2), wallet(3)]);
+
+        // Before the transfer and upgrade, alice held the only Legendary
+        let snap = Snapshot::replay(&events, SnapshotCutoff::Slot(150), &legendaries(), &FLAT_WEIGHTS);
+        assert_eq!(snap.holders.keys().cloned().collect::<Vec<_>>(), vec![wallet(1)]);
+
+        // After the burn, only bob's upgraded card is left
+        let snap = Snapshot::replay(&events, SnapshotCutoff::Timestamp(5_000), &legendaries(), &FLAT_WEIGHTS);
+        assert_eq!(snap.holders.keys().cloned().collect::<Vec<_>>(), vec![wallet(2)]);
+    }
+
+    #[test]
+    fn test_rarity_weights_and_mint_filter() {
+        let events = fixture();
+        let weights = [1, 3, 10];
+
+        let snap = Snapshot::replay(&events, SnapshotCutoff::Slot(150), &SnapshotFilter::default(), &weights);
+        assert_eq!(snap.holders[&wallet(1)], Holding { mint_ids: vec![0, 1], weight: 11 });
+        assert_eq!(snap.holders[&wallet(2)].weight, 3);
+
+        let filter = SnapshotFilter { mint_ids: BTreeSet::from([1, 2]), ..Default::default() };
+        let snap = Snapshot::replay(&events, SnapshotCutoff::Slot(150), &filter, &weights);
+        assert_eq!(snap.holders[&wallet(1)].mint_ids, vec![1]);
+        assert_eq!(snap.total_weight(), 4);
+    }
+
+    #[test]
+    fn test_allocations_sum_to_total() {
+        let snap = Snapshot::replay(&fixture(), SnapshotCutoff::Slot(150), &SnapshotFilter::default(), &[1, 1, 1]);
+        // alice 2 cards, bob 1: 1000 splits 666/333 with 1 unit of dust to alice
+        let allocations = snap.allocations(1_000);
+        assert_eq!(allocations, vec![(wallet(1), 667), (wallet(2), 333)]);
+        assert!(Snapshot::replay(&[], SnapshotCutoff::Slot(0), &SnapshotFilter::default(), &FLAT_WEIGHTS)
+            .allocations(1_000)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_distribution_proofs_verify() {
+        let snap = Snapshot::replay(&fixture(), SnapshotCutoff::Slot(150), &SnapshotFilter::default(), &[1, 2, 5]);
+        let mut claims: Vec<_> = snap.allocations(1_000_000)
+            .into_iter()
+            .map(|(owner, amount)| (Pubkey::from_str(&owner).unwrap(), amount))
+            .collect();
+        // Pad to an odd count so the carried-up node path is exercised
+        claims.push((Pubkey::new_from_array([4; 32]), 5));
+        let distribution = Distribution::new(snap.cutoff, claims);
+        assert_eq!(distribution.claims.len(), 3);
+        assert_eq!(distribution.total_amount, 1_000_005);
+
+        let root = unhex(&distribution.merkle_root);
+        for claim in &distribution.claims {
+            let leaf = distribution_leaf(&Pubkey::from_str(&claim.claimant).unwrap(), claim.amount);
+            let proof: Vec<_> = claim.proof.iter().map(|sibling| unhex(sibling)).collect();
+            assert!(crypt_core::verify_merkle_proof(&proof, &root, &leaf), "proof for {}", claim.claimant);
+        }
+
+        let json = distribution.to_json();
+        let parsed: Distribution = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.merkle_root, distribution.merkle_root);
+    }
+
+    /// alice stakes card 0, gifts card 1 to carol and wraps card 2; bob
+    /// gifts card 3 and reclaims it once the link expires.
+    fn custody_fixture() -> Vec<(u64, CryptEvent)> {
+        let mint = |mint_id, owner: &str| CryptEvent::CardMinted(CardMintedEvent {
+            mint_id, owner: owner.into(), tx_hash: format!("tx{}", mint_id),
+            rarity: 0, card_type: 0, title: String::new(), soul_seed: [0; 32], timestamp: 0,
+        });
+        let gift = |mint_id, sender: &str| CryptEvent::GiftCreated(GiftCreatedEvent {
+            mint_id, sender: sender.into(), expires_at: 10_000, timestamp: 0,
+        });
+        vec![
+            (100, mint(0, &wallet(1))),
+            (100, mint(1, &wallet(1))),
+            (100, mint(2, &wallet(1))),
+            (100, mint(3, &wallet(2))),
+            (200, CryptEvent::CardStaked(CardStakedEvent { mint_id: 0, staker: wallet(1), timestamp: 0 })),
+            (200, gift(1, &wallet(1))),
+            (200, CryptEvent::CardWrapped(CardWrappedEvent {
+                mint_id: 2, card: wallet(9), nft_mint: wallet(8), owner: wallet(1), timestamp: 0,
+            })),
+            (200, gift(3, &wallet(2))),
+            (300, CryptEvent::GiftClaimed(GiftClaimedEvent {
+                mint_id: 1, sender: wallet(1), claimant: wallet(3), timestamp: 0,
+            })),
+            (400, CryptEvent::GiftReclaimed(GiftReclaimedEvent { mint_id: 3, sender: wallet(2), timestamp: 0 })),
+        ]
+    }
+
+    #[test]
+    fn test_custody_counts_for_beneficial_owner() {
+        let events = custody_fixture();
+        let held = |slot| {
+            let snap = Snapshot::replay(&events, SnapshotCutoff::Slot(slot), &SnapshotFilter::default(), &FLAT_WEIGHTS);
+            snap.holders.into_iter().map(|(owner, h)| (owner, h.mint_ids)).collect::<Vec<_>>()
+        };
+
+        // Staked, wrapped and pending gifts stay with the wallet that locked them
+        assert_eq!(held(250), vec![(wallet(1), vec![0, 1, 2]), (wallet(2), vec![3])]);
+        // A claimed gift moves to the claimant, a reclaimed one stays put
+        assert_eq!(held(400), vec![(wallet(1), vec![0, 2]), (wallet(2), vec![3]), (wallet(3), vec![1])]);
+    }
+
+    #[test]
+    fn test_distribution_from_snapshot() {
+        let snap = Snapshot::replay(&fixture(), SnapshotCutoff::Slot(300), &legendaries(), &FLAT_WEIGHTS);
+        let distribution = snap.distribution(10).unwrap();
+        assert_eq!(distribution.total_amount, 10);
+        assert_eq!(distribution.claims.len(), 2);
+        // Two leaves: each proof is the other leaf
+        assert_eq!(distribution.claims[0].proof.len(), 1);
+    }
+}
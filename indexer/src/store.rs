@@ -1,12 +1,15 @@
 //! In-memory event store.
 //! In production, this would be backed by Postgres or DynamoDB.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use crate::events::*;
+use crate::metrics::{Activity, DAY_SECS, WEEK_SECS};
 use colored::Colorize;
+use serde::Serialize;
+use crypt_core::{CardType, CollectionMetrics, Rarity, WindowMetrics};
 
 /// Indexed card data.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct IndexedCard {
     pub mint_id: u64,
     pub owner: String,
@@ -17,12 +20,64 @@ pub struct IndexedCard {
     pub interaction_count: u64,
     pub minted_at: i64,
     pub burned: bool,
+    /// On-chain layout version (0 = legacy card not yet migrated)
+    pub version: u8,
+}
+
+/// Indexed achievement badge.
+#[derive(Debug, Clone, Serialize)]
+pub struct IndexedBadge {
+    pub set_id: u32,
+    pub badge: String,
+    pub card_count: u8,
+    pub claimed_at: i64,
+}
+
+/// Indexed comment. Only the hash is on-chain, so this tracks who
+/// commented where and whether a moderator hid it.
+#[derive(Debug, Clone, Serialize)]
+pub struct IndexedComment {
+    pub card_mint_id: u64,
+    pub author: String,
+    pub created_at: i64,
+    pub hidden: bool,
+}
+
+/// Indexed governance proposal with running tallies.
+#[derive(Debug, Clone, Serialize)]
+pub struct IndexedProposal {
+    pub proposal_id: u64,
+    pub title: String,
+    pub options: Vec<String>,
+    pub tallies: Vec<u64>,
+    pub cards_voted: u64,
+    pub voters: HashSet<String>,
+    pub ends_at: i64,
+    pub finalized: bool,
+    pub winning_option: Option<u8>,
 }
 
 /// In-memory store for indexed Crypt data.
 pub struct InMemoryStore {
     cards: HashMap<u64, IndexedCard>,
     owner_cards: HashMap<String, Vec<u64>>,
+    owner_badges: HashMap<String, Vec<IndexedBadge>>,
+    /// (mint_id, user, interaction_type) for compact interactions — the
+    /// on-chain counters are totals, so uniqueness is tracked here
+    reactions: HashSet<(u64, String, u8)>,
+    comments: Vec<IndexedComment>,
+    /// Cards whose title a moderator hid
+    hidden_cards: HashSet<u64>,
+    total_reports: u64,
+    proposals: HashMap<u64, IndexedProposal>,
+    /// Wrapped cards by mint ID, with their NFT mint. Whoever holds the
+    /// NFT is the real owner; the card's owner here stays the wrapper
+    /// until it's unwrapped.
+    wrapped: HashMap<u64, String>,
+    /// Every processed event with the slot it landed in, in chain order
+    history: Vec<(u64, CryptEvent)>,
+    /// When each mint, transfer, burn and interaction happened
+    activity: Activity,
     total_minted: u64,
     total_burned: u64,
     total_transfers: u64,
@@ -35,6 +90,15 @@ impl InMemoryStore {
         Self {
             cards: HashMap::new(),
             owner_cards: HashMap::new(),
+            owner_badges: HashMap::new(),
+            reactions: HashSet::new(),
+            comments: Vec::new(),
+            hidden_cards: HashSet::new(),
+            total_reports: 0,
+            proposals: HashMap::new(),
+            wrapped: HashMap::new(),
+            history: Vec::new(),
+            activity: Activity::default(),
             total_minted: 0,
             total_burned: 0,
             total_transfers: 0,
@@ -57,13 +121,15 @@ impl InMemoryStore {
                     interaction_count: 0,
                     minted_at: e.timestamp,
                     burned: false,
+                    version: crate::processor::CARD_VERSION,
                 });
                 self.owner_cards
                     .entry(e.owner.clone())
                     .or_default()
                     .push(e.mint_id);
                 self.total_minted += 1;
-                if (e.rarity as usize) < 3 {
+                self.activity.mint(e.timestamp);
+                if Rarity::from_u8(e.rarity).is_some() {
                     self.rarity_counts[e.rarity as usize] += 1;
                 }
 
@@ -72,11 +138,11 @@ impl InMemoryStore {
                     "MINT".bright_green(),
                     e.mint_id,
                     e.title,
-                    match e.rarity {
-                        0 => "COMMON".white(),
-                        1 => "RARE".bright_cyan(),
-                        2 => "LEGENDARY".bright_magenta(),
-                        _ => "???".white(),
+                    match Rarity::from_u8(e.rarity) {
+                        Some(Rarity::Common) => "COMMON".white(),
+                        Some(Rarity::Rare) => "RARE".bright_cyan(),
+                        Some(Rarity::Legendary) => "LEGENDARY".bright_magenta(),
+                        None => "???".white(),
                     }
                 );
             }
@@ -95,6 +161,7 @@ impl InMemoryStore {
                         .push(e.mint_id);
                 }
                 self.total_transfers += 1;
+                self.activity.transfer(e.timestamp);
 
                 println!(
                     "  {} Card #{} transferred: {} → {}",
@@ -113,6 +180,7 @@ impl InMemoryStore {
                     }
                 }
                 self.total_burned += 1;
+                self.activity.burn(e.timestamp);
 
                 println!(
                     "  {} Card #{} burned by {}",
@@ -124,11 +192,11 @@ impl InMemoryStore {
 
             CryptEvent::RarityUpgraded(e) => {
                 if let Some(card) = self.cards.get_mut(&e.mint_id) {
-                    if (card.rarity as usize) < 3 {
+                    if Rarity::from_u8(card.rarity).is_some() {
                         self.rarity_counts[card.rarity as usize] -= 1;
                     }
                     card.rarity = e.new_rarity;
-                    if (e.new_rarity as usize) < 3 {
+                    if Rarity::from_u8(e.new_rarity).is_some() {
                         self.rarity_counts[e.new_rarity as usize] += 1;
                     }
                 }
@@ -146,11 +214,276 @@ impl InMemoryStore {
                 if let Some(card) = self.cards.get_mut(&e.card_mint_id) {
                     card.interaction_count += 1;
                 }
+                if e.interaction_type == 1 {
+                    self.comments.push(IndexedComment {
+                        card_mint_id: e.card_mint_id,
+                        author: e.user.clone(),
+                        created_at: e.timestamp,
+                        hidden: false,
+                    });
+                }
                 self.total_interactions += 1;
+                self.activity.interaction(e.timestamp);
+            }
+
+            CryptEvent::ContentReported(e) => {
+                self.total_reports += 1;
+
+                println!(
+                    "  {} Card #{} {} reported ({} reports)",
+                    "REPORT".yellow(),
+                    e.card_mint_id,
+                    if e.kind == 0 { "title" } else { "comment" },
+                    e.report_count,
+                );
+            }
+
+            CryptEvent::ContentHidden(e) => {
+                if e.kind == 0 {
+                    if e.hidden {
+                        self.hidden_cards.insert(e.card_mint_id);
+                    } else {
+                        self.hidden_cards.remove(&e.card_mint_id);
+                    }
+                } else {
+                    // One comment account per (card, author)
+                    for comment in self.comments.iter_mut()
+                        .filter(|c| c.card_mint_id == e.card_mint_id && c.author == e.author)
+                    {
+                        comment.hidden = e.hidden;
+                    }
+                }
+
+                println!(
+                    "  {} Card #{} {} {} by {}",
+                    "MOD".bright_red(),
+                    e.card_mint_id,
+                    if e.kind == 0 { "title" } else { "comment" },
+                    if e.hidden { "hidden" } else { "restored" },
+                    &e.moderator[..8],
+                );
+            }
+
+            CryptEvent::CompactInteraction(e) => {
+                // The program rejects repeat reactions; this also skips replayed events
+                if self.reactions.insert((e.card_mint_id, e.user.clone(), e.interaction_type)) {
+                    if let Some(card) = self.cards.get_mut(&e.card_mint_id) {
+                        card.interaction_count += 1;
+                    }
+                    self.total_interactions += 1;
+                    self.activity.interaction(e.timestamp);
+                }
+            }
+
+            CryptEvent::CardMetadataUpdated(e) => {
+                if let Some(card) = self.cards.get_mut(&e.mint_id) {
+                    card.title = e.title.clone();
+                }
+
+                println!(
+                    "  {} Card #{} metadata updated — {} ({} edits used)",
+                    "EDIT".bright_white(),
+                    e.mint_id,
+                    e.title,
+                    e.edits_used,
+                );
+            }
+
+            CryptEvent::CardWrapped(e) => {
+                self.wrapped.insert(e.mint_id, e.nft_mint.clone());
+
+                println!(
+                    "  {} Card #{} wrapped as NFT {}",
+                    "WRAP".bright_white(),
+                    e.mint_id,
+                    &e.nft_mint[..8],
+                );
+            }
+
+            CryptEvent::CardUnwrapped(e) => {
+                self.wrapped.remove(&e.mint_id);
+                // The NFT may have changed hands on a marketplace
+                if let Some(card) = self.cards.get_mut(&e.mint_id) {
+                    if card.owner != e.holder {
+                        if let Some(cards) = self.owner_cards.get_mut(&card.owner) {
+                            cards.retain(|&id| id != e.mint_id);
+                        }
+                        card.owner = e.holder.clone();
+                        self.owner_cards
+                            .entry(e.holder.clone())
+                            .or_default()
+                            .push(e.mint_id);
+                    }
+                }
+
+                println!(
+                    "  {} Card #{} unwrapped to {}",
+                    "WRAP".bright_white(),
+                    e.mint_id,
+                    &e.holder[..8],
+                );
+            }
+
+            // Staking and gift links park the card in a program PDA; the
+            // store keeps it with the wallet that still has a claim on it
+            CryptEvent::CardStaked(e) => {
+                println!("  {} Card #{} staked by {}", "STAKE".bright_yellow(), e.mint_id, &e.staker[..8]);
             }
+
+            CryptEvent::CardUnstaked(e) => {
+                println!(
+                    "  {} Card #{} unstaked by {} (+{} pts)",
+                    "STAKE".bright_yellow(),
+                    e.mint_id,
+                    &e.staker[..8],
+                    e.points,
+                );
+            }
+
+            CryptEvent::GiftCreated(e) => {
+                println!("  {} Card #{} gifted by {}", "GIFT".bright_red(), e.mint_id, &e.sender[..8]);
+            }
+
+            CryptEvent::GiftClaimed(e) => {
+                if let Some(card) = self.cards.get_mut(&e.mint_id) {
+                    if card.owner != e.claimant {
+                        if let Some(cards) = self.owner_cards.get_mut(&card.owner) {
+                            cards.retain(|&id| id != e.mint_id);
+                        }
+                        card.owner = e.claimant.clone();
+                        self.owner_cards
+                            .entry(e.claimant.clone())
+                            .or_default()
+                            .push(e.mint_id);
+                    }
+                }
+
+                println!("  {} Card #{} claimed by {}", "GIFT".bright_red(), e.mint_id, &e.claimant[..8]);
+            }
+
+            CryptEvent::GiftReclaimed(e) => {
+                println!("  {} Card #{} reclaimed by {}", "GIFT".bright_red(), e.mint_id, &e.sender[..8]);
+            }
+
+            CryptEvent::ProposalCreated(e) => {
+                self.proposals.insert(e.proposal_id, IndexedProposal {
+                    proposal_id: e.proposal_id,
+                    title: e.title.clone(),
+                    options: e.options.clone(),
+                    tallies: vec![0; e.options.len()],
+                    cards_voted: 0,
+                    voters: HashSet::new(),
+                    ends_at: e.ends_at,
+                    finalized: false,
+                    winning_option: None,
+                });
+
+                println!(
+                    "  {} Proposal #{} opened: {}",
+                    "VOTE".bright_yellow(),
+                    e.proposal_id,
+                    e.title,
+                );
+            }
+
+            CryptEvent::VoteCast(e) => {
+                if let Some(proposal) = self.proposals.get_mut(&e.proposal_id) {
+                    if let Some(tally) = proposal.tallies.get_mut(e.option as usize) {
+                        *tally += e.weight;
+                    }
+                    proposal.cards_voted += e.cards as u64;
+                    proposal.voters.insert(e.voter.clone());
+                }
+            }
+
+            CryptEvent::ProposalFinalized(e) => {
+                if let Some(proposal) = self.proposals.get_mut(&e.proposal_id) {
+                    // The chain's tallies are authoritative
+                    proposal.tallies = e.tallies.clone();
+                    proposal.cards_voted = e.cards_voted;
+                    proposal.finalized = true;
+                    proposal.winning_option = e.winning_option;
+                }
+
+                println!(
+                    "  {} Proposal #{} finalized — {}",
+                    "VOTE".bright_yellow(),
+                    e.proposal_id,
+                    e.winning_option
+                        .and_then(|i| self.proposals.get(&e.proposal_id)?.options.get(i as usize).cloned())
+                        .unwrap_or_else(|| "no winner".into()),
+                );
+            }
+
+            CryptEvent::AchievementClaimed(e) => {
+                self.owner_badges
+                    .entry(e.owner.clone())
+                    .or_default()
+                    .push(IndexedBadge {
+                        set_id: e.set_id,
+                        badge: e.badge.clone(),
+                        card_count: e.card_count,
+                        claimed_at: e.timestamp,
+                    });
+
+                println!(
+                    "  {} Achievement #{} claimed by {} ({} cards)",
+                    "BADGE".bright_blue(),
+                    e.set_id,
+                    &e.owner[..8],
+                    e.card_count,
+                );
+            }
+        }
+    }
+
+    /// Process an event and keep it for snapshot replays.
+    pub fn record_event(&mut self, slot: u64, event: CryptEvent) {
+        self.process_event(&event);
+        self.history.push((slot, event));
+    }
+
+    /// Holders as of `cutoff`, replayed from the recorded events.
+    /// Cards only known from a backfill have no history and don't count.
+    pub fn snapshot(
+        &self,
+        cutoff: crate::snapshot::SnapshotCutoff,
+        filter: &crate::snapshot::SnapshotFilter,
+        weights: &crate::snapshot::RarityWeights,
+    ) -> crate::snapshot::Snapshot {
+        crate::snapshot::Snapshot::replay(&self.history, cutoff, filter, weights)
+    }
+
+    /// Index a card decoded straight from its account, e.g. when backfilling
+    /// cards minted before the indexer started. Already-indexed cards are skipped.
+    pub fn load_card(&mut self, card: IndexedCard) {
+        if self.cards.contains_key(&card.mint_id) {
+            return;
         }
+        self.owner_cards
+            .entry(card.owner.clone())
+            .or_default()
+            .push(card.mint_id);
+        self.total_minted += 1;
+        self.activity.mint(card.minted_at);
+        if Rarity::from_u8(card.rarity).is_some() {
+            self.rarity_counts[card.rarity as usize] += 1;
+        }
+        self.cards.insert(card.mint_id, card);
+    }
+
+    /// Number of indexed cards still on a pre-migration layout.
+    pub fn legacy_card_count(&self) -> usize {
+        self.cards
+            .values()
+            .filter(|c| !c.burned && c.version < crate::processor::CARD_VERSION)
+            .count()
     }
 
+}
+
+/// Queries for the frontend API.
+impl InMemoryStore {
     /// Get cards owned by a specific wallet.
     pub fn get_cards_by_owner(&self, owner: &str) -> Vec<&IndexedCard> {
         self.owner_cards
@@ -159,29 +492,156 @@ impl InMemoryStore {
             .unwrap_or_default()
     }
 
+    /// Get achievement badges held by a specific wallet.
+    pub fn get_badges_by_owner(&self, owner: &str) -> &[IndexedBadge] {
+        self.owner_badges
+            .get(owner)
+            .map(|badges| badges.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Number of distinct wallets that left a compact interaction of
+    /// `interaction_type` (like, share or bookmark) on a card.
+    pub fn unique_reactions(&self, mint_id: u64, interaction_type: u8) -> usize {
+        self.reactions
+            .iter()
+            .filter(|(id, _, kind)| *id == mint_id && *kind == interaction_type)
+            .count()
+    }
+
+    /// Whether `viewer` may see a card: anyone, unless a moderator hid it,
+    /// in which case only its owner still does.
+    fn card_visible(&self, card: &IndexedCard, viewer: Option<&str>) -> bool {
+        !self.hidden_cards.contains(&card.mint_id) || viewer == Some(card.owner.as_str())
+    }
+
+    /// Newest live cards first, minus hidden ones unless `viewer` owns them.
+    pub fn feed(&self, viewer: Option<&str>, limit: usize) -> Vec<&IndexedCard> {
+        let mut cards: Vec<_> = self.cards
+            .values()
+            .filter(|c| !c.burned && self.card_visible(c, viewer))
+            .collect();
+        cards.sort_by(|a, b| b.minted_at.cmp(&a.minted_at).then(b.mint_id.cmp(&a.mint_id)));
+        cards.truncate(limit);
+        cards
+    }
+
+    /// Live cards whose title contains `query` (case-insensitive), with the
+    /// same visibility rules as `feed`.
+    pub fn search(&self, query: &str, viewer: Option<&str>) -> Vec<&IndexedCard> {
+        let query = query.to_lowercase();
+        let mut cards: Vec<_> = self.cards
+            .values()
+            .filter(|c| !c.burned && self.card_visible(c, viewer))
+            .filter(|c| c.title.to_lowercase().contains(&query))
+            .collect();
+        cards.sort_by_key(|c| c.mint_id);
+        cards
+    }
+
+    /// Comments on a card, oldest first. Hidden comments are only
+    /// returned to their author.
+    pub fn comments(&self, mint_id: u64, viewer: Option<&str>) -> Vec<&IndexedComment> {
+        self.comments
+            .iter()
+            .filter(|c| c.card_mint_id == mint_id)
+            .filter(|c| !c.hidden || viewer == Some(c.author.as_str()))
+            .collect()
+    }
+
     /// Get a card by mint ID.
     pub fn get_card(&self, mint_id: u64) -> Option<&IndexedCard> {
         self.cards.get(&mint_id)
     }
 
+    /// NFT mint of a wrapped card.
+    pub fn wrapped_nft(&self, mint_id: u64) -> Option<&str> {
+        self.wrapped.get(&mint_id).map(String::as_str)
+    }
+
+    /// Get a proposal by ID.
+    pub fn get_proposal(&self, proposal_id: u64) -> Option<&IndexedProposal> {
+        self.proposals.get(&proposal_id)
+    }
+
+    /// Proposals still taking votes at `now`, soonest to close first.
+    pub fn open_proposals(&self, now: i64) -> Vec<&IndexedProposal> {
+        let mut open: Vec<_> = self.proposals.values()
+            .filter(|p| !p.finalized && now < p.ends_at)
+            .collect();
+        open.sort_by_key(|p| p.ends_at);
+        open
+    }
+}
+
+impl InMemoryStore {
+    /// Activity over the `window_secs` before `now`.
+    pub fn window_metrics(&self, now: i64, window_secs: i64) -> WindowMetrics {
+        self.activity.window(now, window_secs)
+    }
+
+    /// Full collection metrics as of `now`. Distributions and owners
+    /// cover live cards; totals cover everything ever indexed.
+    pub fn collection_metrics(&self, now: i64) -> CollectionMetrics {
+        let mut metrics = CollectionMetrics {
+            computed_at: now,
+            total_cards: self.total_minted,
+            burned_cards: self.total_burned,
+            total_interactions: self.total_interactions,
+            ..Default::default()
+        };
+
+        let mut owners = HashSet::new();
+        for card in self.cards.values().filter(|c| !c.burned) {
+            metrics.active_cards += 1;
+            owners.insert(card.owner.as_str());
+            if let Some(rarity) = Rarity::from_u8(card.rarity) {
+                metrics.rarity_distribution.add(rarity);
+            }
+            if let Some(card_type) = CardType::from_u8(card.card_type) {
+                metrics.type_distribution.add(card_type);
+            }
+        }
+        metrics.unique_owners = owners.len() as u64;
+        if metrics.total_cards > 0 {
+            metrics.avg_interactions_per_card = metrics.total_interactions as f64 / metrics.total_cards as f64;
+        }
+
+        let day = self.window_metrics(now, DAY_SECS);
+        metrics.mints_last_24h = day.mints;
+        metrics.transfers_last_24h = day.transfers;
+        metrics.mints_last_7d = self.window_metrics(now, WEEK_SECS).mints;
+        metrics
+    }
+
     /// Print current statistics.
     pub fn print_stats(&self) {
+        let m = self.collection_metrics(chrono::Utc::now().timestamp());
         println!("\n  {} Collection Statistics:", ">>".bright_cyan());
-        println!("    Total minted:  {}", self.total_minted.to_string().bright_green());
-        println!("    Total burned:  {}", self.total_burned.to_string().bright_red());
-        println!("    Transfers:     {}", self.total_transfers);
-        println!("    Interactions:  {}", self.total_interactions);
-        println!("    Common:        {}", self.rarity_counts[0]);
-        println!("    Rare:          {}", self.rarity_counts[1].to_string().bright_cyan());
-        println!("    Legendary:     {}", self.rarity_counts[2].to_string().bright_magenta());
-        println!("    Active cards:  {}", self.cards.values().filter(|c| !c.burned).count());
+        println!("    Total minted:  {}", m.total_cards.to_string().bright_green());
+        println!("    Total burned:  {}", m.burned_cards.to_string().bright_red());
+        println!("    Active cards:  {}", m.active_cards);
+        println!("    Owners:        {}", m.unique_owners);
+        println!("    Transfers:     {} ({} in 24h)", self.total_transfers, m.transfers_last_24h);
+        println!("    Mints 24h/7d:  {} / {}", m.mints_last_24h, m.mints_last_7d);
+        println!("    Interactions:  {} ({:.1} per card)", m.total_interactions, m.avg_interactions_per_card);
+        println!("    Reports:       {}", self.total_reports);
+        println!("    Hidden cards:  {}", self.hidden_cards.len());
+        println!("    Common:        {}", m.rarity_distribution.common);
+        println!("    Rare:          {}", m.rarity_distribution.rare.to_string().bright_cyan());
+        println!("    Legendary:     {}", m.rarity_distribution.legendary.to_string().bright_magenta());
+        println!("    Top type:      {}", m.type_distribution.most_common());
+        println!("    Legacy layout: {}", self.legacy_card_count());
+        println!("    Badges:        {}", self.owner_badges.values().map(Vec::len).sum::<usize>());
+        println!("    Badge holders: {}", self.owner_badges.len());
+        println!("    Proposals:     {}", self.proposals.len());
+        println!("    Wrapped:       {}", self.wrapped.len());
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::events::*;
 
     #[test]
     fn test_mint_event() {
@@ -226,4 +686,231 @@ mod tests {
         assert!(store.get_card(0).unwrap().burned);
         assert_eq!(store.total_burned, 1);
     }
+
+    #[test]
+    fn test_achievement_claimed_event() {
+        let mut store = InMemoryStore::new();
+        store.process_event(&CryptEvent::AchievementClaimed(AchievementClaimedEvent {
+            set_id: 3, owner: "owner123456789".into(), badge: "badge123456789".into(),
+            card_count: 5, timestamp: 42,
+        }));
+        let badges = store.get_badges_by_owner("owner123456789");
+        assert_eq!(badges.len(), 1);
+        assert_eq!(badges[0].set_id, 3);
+        assert_eq!(badges[0].card_count, 5);
+        assert!(store.get_badges_by_owner("nobody").is_empty());
+    }
+
+    #[test]
+    fn test_load_card_counts_legacy() {
+        let mut store = InMemoryStore::new();
+        let card = IndexedCard {
+            mint_id: 5, owner: "owner123456789".into(), tx_hash: "tx".into(),
+            rarity: 1, card_type: 0, title: "".into(), interaction_count: 0,
+            minted_at: 0, burned: false, version: 0,
+        };
+        store.load_card(card.clone());
+        store.load_card(card);
+        assert_eq!(store.total_minted, 1);
+        assert_eq!(store.rarity_counts[1], 1);
+        assert_eq!(store.get_cards_by_owner("owner123456789").len(), 1);
+        assert_eq!(store.legacy_card_count(), 1);
+    }
+
+    #[test]
+    fn test_compact_interactions_deduped_per_user() {
+        let mut store = InMemoryStore::new();
+        mint(&mut store, 2, "owner123456789", "GM FRENS");
+        let like = |user: &str, timestamp| CryptEvent::CompactInteraction(CompactInteractionEvent {
+            card_mint_id: 2, user: user.into(), interaction_type: 0,
+            leaf: [0; 32], log_root: [0; 32], log_len: 0, timestamp,
+        });
+        store.process_event(&like("alice12345678", 1));
+        store.process_event(&like("alice12345678", 2));
+        store.process_event(&like("bob1234567890", 3));
+        assert_eq!(store.unique_reactions(2, 0), 2);
+        assert_eq!(store.unique_reactions(2, 3), 0);
+        assert_eq!(store.total_interactions, 2);
+        assert_eq!(store.cards[&2].interaction_count, 2);
+    }
+
+    fn mint(store: &mut InMemoryStore, mint_id: u64, owner: &str, title: &str) {
+        store.process_event(&CryptEvent::CardMinted(CardMintedEvent {
+            mint_id, owner: owner.into(), tx_hash: format!("tx{}", mint_id),
+            rarity: 0, card_type: 0, title: title.into(),
+            soul_seed: [0; 32], timestamp: mint_id as i64,
+        }));
+    }
+
+    fn hide(kind: u8, mint_id: u64, author: &str, hidden: bool) -> CryptEvent {
+        CryptEvent::ContentHidden(ContentHiddenEvent {
+            target: "target123456789".into(), kind, card_mint_id: mint_id,
+            author: author.into(), hidden, reason: 1,
+            moderator: "moderator12345".into(), timestamp: 10,
+        })
+    }
+
+    #[test]
+    fn test_hidden_card_dropped_from_feed_and_search() {
+        let mut store = InMemoryStore::new();
+        mint(&mut store, 0, "alice12345678", "GM FRENS");
+        mint(&mut store, 1, "scammer123456", "FREE SOL scam.link");
+        store.process_event(&hide(0, 1, "scammer123456", true));
+
+        let ids = |cards: Vec<&IndexedCard>| cards.iter().map(|c| c.mint_id).collect::<Vec<_>>();
+        assert_eq!(ids(store.feed(None, 10)), vec![0]);
+        assert!(store.search("free sol", None).is_empty());
+        assert!(store.search("free sol", Some("alice12345678")).is_empty());
+
+        // The owner still sees it
+        assert_eq!(ids(store.feed(Some("scammer123456"), 10)), vec![1, 0]);
+        assert_eq!(ids(store.search("free sol", Some("scammer123456"))), vec![1]);
+
+        store.process_event(&hide(0, 1, "scammer123456", false));
+        assert_eq!(ids(store.feed(None, 10)), vec![1, 0]);
+    }
+
+    #[test]
+    fn test_hidden_comment_only_shown_to_author() {
+        let mut store = InMemoryStore::new();
+        mint(&mut store, 0, "alice12345678", "GM");
+        for user in ["bob1234567890", "troll12345678"] {
+            store.process_event(&CryptEvent::CardInteraction(CardInteractionEvent {
+                card_mint_id: 0, user: user.into(), interaction_type: 1, timestamp: 1,
+            }));
+        }
+        store.process_event(&hide(1, 0, "troll12345678", true));
+
+        assert_eq!(store.comments(0, None).len(), 1);
+        assert_eq!(store.comments(0, Some("alice12345678")).len(), 1);
+        assert_eq!(store.comments(0, Some("troll12345678")).len(), 2);
+        // Hiding a comment leaves the card alone
+        assert_eq!(store.feed(None, 10).len(), 1);
+    }
+
+    #[test]
+    fn test_metadata_update_renames_card() {
+        let mut store = InMemoryStore::new();
+        store.process_event(&CryptEvent::CardMinted(CardMintedEvent {
+            mint_id: 0, owner: "owner123456789".into(), tx_hash: "tx".into(),
+            rarity: 0, card_type: 0, title: "TYPO".into(),
+            soul_seed: [0; 32], timestamp: 0,
+        }));
+        store.process_event(&CryptEvent::CardMetadataUpdated(CardMetadataUpdatedEvent {
+            mint_id: 0, owner: "owner123456789".into(), title: "FIXED".into(),
+            old_metadata_hash: [0; 32], new_metadata_hash: [1; 32],
+            edits_used: 1, timestamp: 1,
+        }));
+        assert_eq!(store.get_card(0).unwrap().title, "FIXED");
+    }
+
+    #[test]
+    fn test_unwrap_moves_card_to_nft_holder() {
+        let mut store = InMemoryStore::new();
+        mint(&mut store, 5, "wrapper12345", "FLIPPED");
+        store.process_event(&CryptEvent::CardWrapped(CardWrappedEvent {
+            mint_id: 5, card: "card".into(), nft_mint: "nftmint12345".into(),
+            owner: "wrapper12345".into(), timestamp: 1,
+        }));
+        assert_eq!(store.wrapped_nft(5), Some("nftmint12345"));
+
+        store.process_event(&CryptEvent::CardUnwrapped(CardUnwrappedEvent {
+            mint_id: 5, card: "card".into(), nft_mint: "nftmint12345".into(),
+            holder: "buyer1234567".into(), timestamp: 2,
+        }));
+        assert_eq!(store.wrapped_nft(5), None);
+        assert_eq!(store.get_card(5).unwrap().owner, "buyer1234567");
+        assert!(store.get_cards_by_owner("wrapper12345").is_empty());
+        assert_eq!(store.get_cards_by_owner("buyer1234567").len(), 1);
+    }
+
+    #[test]
+    fn test_proposal_tallies_follow_votes() {
+        let mut store = InMemoryStore::new();
+        store.process_event(&CryptEvent::ProposalCreated(ProposalCreatedEvent {
+            proposal: "proposal".into(), proposal_id: 1, title: "SEASON 2".into(),
+            options: vec!["RUGS".into(), "APES".into()], starts_at: 0, ends_at: 100,
+        }));
+        for (voter, option, weight) in [("alice", 0, 5), ("bob", 1, 2), ("alice", 1, 1)] {
+            store.process_event(&CryptEvent::VoteCast(VoteCastEvent {
+                proposal: "proposal".into(), proposal_id: 1, voter: voter.into(),
+                option, cards: 1, weight, timestamp: 10,
+            }));
+        }
+
+        let proposal = store.get_proposal(1).unwrap();
+        assert_eq!(proposal.tallies, vec![5, 3]);
+        assert_eq!((proposal.cards_voted, proposal.voters.len()), (3, 2));
+        assert_eq!(store.open_proposals(50).len(), 1);
+        assert!(store.open_proposals(100).is_empty());
+
+        store.process_event(&CryptEvent::ProposalFinalized(ProposalFinalizedEvent {
+            proposal: "proposal".into(), proposal_id: 1, winning_option: Some(0),
+            tallies: vec![5, 3], cards_voted: 3, timestamp: 100,
+        }));
+        let proposal = store.get_proposal(1).unwrap();
+        assert!(proposal.finalized);
+        assert_eq!(proposal.winning_option, Some(0));
+    }
+
+    #[test]
+    fn test_collection_metrics_from_event_stream() {
+        const NOW: i64 = 1_700_000_000;
+        let mut store = InMemoryStore::new();
+        let minted = |mint_id: u64, owner: &str, rarity, card_type, timestamp| {
+            CryptEvent::CardMinted(CardMintedEvent {
+                mint_id, owner: owner.into(), tx_hash: format!("tx{}", mint_id),
+                rarity, card_type, title: "".into(), soul_seed: [0; 32], timestamp,
+            })
+        };
+        let stream = vec![
+            minted(0, "alice12345678", 0, 0, NOW - 10 * DAY_SECS),
+            minted(1, "alice12345678", 1, 1, NOW - 3 * DAY_SECS),
+            minted(2, "bob1234567890", 2, 1, NOW - 3_600),
+            minted(3, "carol12345678", 0, 4, NOW - 60),
+            CryptEvent::CardTransferred(CardTransferredEvent {
+                mint_id: 1, from: "alice12345678".into(), to: "bob1234567890".into(),
+                tx_hash: "tx".into(), timestamp: NOW - 2 * DAY_SECS,
+            }),
+            CryptEvent::CardTransferred(CardTransferredEvent {
+                mint_id: 0, from: "alice12345678".into(), to: "dave123456789".into(),
+                tx_hash: "tx".into(), timestamp: NOW - 120,
+            }),
+            CryptEvent::CardBurned(CardBurnedEvent {
+                mint_id: 3, owner: "carol12345678".into(), tx_hash: "tx".into(),
+                rarity: 0, timestamp: NOW - 30,
+            }),
+            CryptEvent::CardInteraction(CardInteractionEvent {
+                card_mint_id: 2, user: "alice12345678".into(), interaction_type: 0, timestamp: NOW - 10,
+            }),
+            CryptEvent::CompactInteraction(CompactInteractionEvent {
+                card_mint_id: 2, user: "alice12345678".into(), interaction_type: 2,
+                leaf: [0; 32], log_root: [0; 32], log_len: 1, timestamp: NOW - 5 * DAY_SECS,
+            }),
+        ];
+        for (slot, event) in stream.into_iter().enumerate() {
+            store.record_event(slot as u64, event);
+        }
+
+        let m = store.collection_metrics(NOW);
+        assert_eq!(m.computed_at, NOW);
+        assert_eq!((m.total_cards, m.active_cards, m.burned_cards), (4, 3, 1));
+        // alice sold or gave away everything; carol burned hers
+        assert_eq!(m.unique_owners, 2);
+        assert_eq!(m.total_interactions, 2);
+        assert!((m.avg_interactions_per_card - 0.5).abs() < 1e-9);
+        assert_eq!((m.rarity_distribution.common, m.rarity_distribution.rare, m.rarity_distribution.legendary), (1, 1, 1));
+        assert_eq!(m.type_distribution.rugs, 2);
+        assert_eq!(m.type_distribution.big_moves, 0);
+        assert_eq!((m.mints_last_24h, m.mints_last_7d, m.transfers_last_24h), (2, 3, 1));
+
+        let week = store.window_metrics(NOW, WEEK_SECS);
+        assert_eq!((week.mints, week.transfers, week.burns, week.interactions), (3, 2, 1, 2));
+        let hour = store.window_metrics(NOW, 3_600);
+        assert_eq!((hour.mints, hour.transfers, hour.burns, hour.interactions), (1, 1, 1, 1));
+
+        // Windows roll forward with the clock
+        let later = store.collection_metrics(NOW + 2 * DAY_SECS);
+        assert_eq!((later.mints_last_24h, later.mints_last_7d), (0, 3));
+    }
 }
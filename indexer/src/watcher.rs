@@ -1,9 +1,12 @@
 //! Blockchain event watcher — polls for new transactions containing Crypt events.
 
 use solana_client::rpc_client::RpcClient;
+use solana_client::rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig};
+use solana_client::rpc_filter::{Memcmp, RpcFilterType};
 use solana_sdk::pubkey::Pubkey;
 use solana_sdk::commitment_config::CommitmentConfig;
 use std::str::FromStr;
+use std::sync::{Arc, Mutex, MutexGuard};
 use std::time::Duration;
 use crate::processor;
 use crate::store::InMemoryStore;
@@ -13,13 +16,13 @@ use colored::Colorize;
 pub struct EventWatcher {
     rpc: RpcClient,
     program_id: Pubkey,
-    store: InMemoryStore,
+    store: Arc<Mutex<InMemoryStore>>,
     last_signature: Option<String>,
     poll_interval: Duration,
 }
 
 impl EventWatcher {
-    pub fn new(rpc_url: &str, program_id: &str, store: InMemoryStore) -> Self {
+    pub fn new(rpc_url: &str, program_id: &str, store: Arc<Mutex<InMemoryStore>>) -> Self {
         let pid = Pubkey::from_str(program_id)
             .expect("Invalid program ID");
         Self {
@@ -34,8 +37,44 @@ impl EventWatcher {
         }
     }
 
+    /// The store, shared with the API. Never held across an await.
+    fn store(&self) -> MutexGuard<'_, InMemoryStore> {
+        self.store.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Load every existing card account into the store, so cards minted
+    /// before the indexer started are tracked. Decodes all layout versions.
+    pub fn backfill(&mut self) -> Result<usize, Box<dyn std::error::Error>> {
+        let config = RpcProgramAccountsConfig {
+            filters: Some(vec![RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
+                0,
+                &processor::account_discriminator("CryptCard"),
+            ))]),
+            account_config: RpcAccountInfoConfig::default(),
+            with_context: None,
+        };
+        let accounts = self.rpc.get_program_accounts_with_config(&self.program_id, config)?;
+
+        let mut loaded = 0;
+        for (_, account) in &accounts {
+            if let Some(card) = processor::decode_card_account(&account.data) {
+                self.store().load_card(card);
+                loaded += 1;
+            }
+        }
+        Ok(loaded)
+    }
+
     /// Start watching for events. Runs until interrupted.
     pub async fn start(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        match self.backfill() {
+            Ok(loaded) => {
+                println!("  {} Backfilled {} existing cards ({} on a legacy layout)",
+                    ">>".bright_cyan(), loaded, self.store().legacy_card_count());
+            }
+            Err(e) => eprintln!("  {} Backfill failed: {}", "WARN".yellow(), e),
+        }
+
         println!("  {} Starting event watcher (poll interval: {:?})", 
             ">>".bright_cyan(), self.poll_interval);
 
@@ -66,21 +105,17 @@ impl EventWatcher {
                             Ok(tx) => {
                                 // Extract logs
                                 if let Some(meta) = tx.transaction.meta {
-                                    if let Some(logs) = meta.log_messages {
-                                        let opt_logs: Vec<String> = match logs {
-                                            solana_transaction_status::option_serializer::OptionSerializer::Some(l) => l,
-                                            _ => vec![],
-                                        };
-                                        let events = processor::parse_program_logs(&opt_logs);
-                                        for event in &events {
-                                            self.store.process_event(event);
+                                    if let solana_transaction_status::option_serializer::OptionSerializer::Some(logs) = meta.log_messages {
+                                        let events = processor::parse_program_logs(&logs);
+                                        for event in events {
+                                            self.store().record_event(sig_info.slot, event);
                                             new_count += 1;
                                         }
                                     }
                                 }
                             }
                             Err(e) => {
-                                if iteration % 30 == 0 {
+                                if iteration.is_multiple_of(30) {
                                     eprintln!("  {} Failed to fetch tx: {}", "WARN".yellow(), e);
                                 }
                             }
@@ -94,18 +129,18 @@ impl EventWatcher {
 
                     if new_count > 0 {
                         println!("  {} Processed {} new events", ">>".bright_green(), new_count);
-                        self.store.print_stats();
+                        self.store().print_stats();
                     }
                 }
                 Err(e) => {
-                    if iteration % 15 == 0 {
+                    if iteration.is_multiple_of(15) {
                         eprintln!("  {} RPC error: {}", "WARN".yellow(), e);
                     }
                 }
             }
 
             // Print heartbeat every 30 iterations
-            if iteration % 30 == 0 {
+            if iteration.is_multiple_of(30) {
                 println!("  {} Heartbeat — iteration {}, watching...", 
                     "..".bright_black(), iteration);
             }
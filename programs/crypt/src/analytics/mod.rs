@@ -1,11 +1,6 @@
 //! On-chain analytics module for Crypt Cards.
-//! Provides wallet profiling, transaction pattern detection,
-//! and historical analysis for card generation.
+//! Collection metrics are shared with the indexer through `crypt-core`.
+//! Wallet profiling and pattern detection work from transaction
+//! history, so they live in the SDK's `analytics`.
 
-pub mod wallet_profile;
-pub mod patterns;
-pub mod metrics;
-
-pub use wallet_profile::*;
-pub use patterns::*;
-pub use metrics::*;
+pub use crypt_core::metrics::*;
@@ -49,4 +49,198 @@ pub enum CryptError {
 
     #[msg("Insufficient funds for minting fee")]
     InsufficientFunds,
+
+    #[msg("Achievement name exceeds maximum length of 32 characters")]
+    AchievementNameTooLong,
+
+    #[msg("Achievement set must require at least one card")]
+    EmptyAchievement,
+
+    #[msg("Presented cards do not complete this achievement set")]
+    AchievementNotComplete,
+
+    #[msg("Card has already been used to claim this achievement")]
+    CardAlreadyUsed,
+
+    #[msg("Achievement claim accounts must be (card, record) pairs, at most 10")]
+    InvalidAchievementAccounts,
+
+    #[msg("Card is not staked")]
+    CardNotStaked,
+
+    #[msg("No reward mint is configured for staking")]
+    RewardMintNotConfigured,
+
+    #[msg("Not enough points to redeem")]
+    InsufficientPoints,
+
+    #[msg("Account is not a Crypt Card")]
+    InvalidCardAccount,
+
+    #[msg("Account is already on the current layout version")]
+    AlreadyMigrated,
+
+    #[msg("Soundtrack ID exceeds maximum length of 32 characters")]
+    SoundtrackIdTooLong,
+
+    #[msg("Card metadata edits are locked for this collection")]
+    MetadataLocked,
+
+    #[msg("Card has used all of its metadata edits")]
+    MetadataEditLimitReached,
+
+    #[msg("Card must be migrated to the current layout first")]
+    CardNeedsMigration,
+
+    #[msg("Card is lent out")]
+    CardIsLent,
+
+    #[msg("Card is not lent out")]
+    CardNotLent,
+
+    #[msg("Signer is not the card's borrower")]
+    NotCardUser,
+
+    #[msg("Loan has not expired yet")]
+    LoanNotExpired,
+
+    #[msg("Loan duration must be between one hour and 30 days")]
+    InvalidLoanDuration,
+
+    #[msg("Cannot lend a card to its owner")]
+    InvalidLoanRecipient,
+
+    #[msg("Card is not held in a gift escrow")]
+    CardNotGifted,
+
+    #[msg("Gift claim window must be between one hour and 90 days")]
+    InvalidGiftDuration,
+
+    #[msg("Gift has expired")]
+    GiftExpired,
+
+    #[msg("Gift has not expired yet")]
+    GiftNotExpired,
+
+    #[msg("Gift claim is not signed by the gift's claim key")]
+    InvalidGiftKey,
+
+    #[msg("Signer does not hold this card")]
+    NotCardHolder,
+
+    #[msg("A card cannot duel itself or its own holder")]
+    InvalidDuelOpponent,
+
+    #[msg("Duel has already been accepted")]
+    DuelAlreadyAccepted,
+
+    #[msg("Duel has not been accepted yet")]
+    DuelNotAccepted,
+
+    #[msg("Signer is not part of this duel")]
+    NotDuelParticipant,
+
+    #[msg("Already revealed for this duel")]
+    DuelAlreadyRevealed,
+
+    #[msg("Reveal does not match the commitment")]
+    InvalidDuelReveal,
+
+    #[msg("Reveal window is still open")]
+    DuelRevealWindowOpen,
+
+    #[msg("Reveal window has closed")]
+    DuelRevealWindowClosed,
+
+    #[msg("Duel accounts do not match")]
+    DuelAccountMismatch,
+
+    #[msg("Invalid boost event: needs a nonzero id, name up to 32 chars, a window and tier bonus up to 2")]
+    InvalidBoostEvent,
+
+    #[msg("Boost table is full")]
+    BoostTableFull,
+
+    #[msg("Boost event not found")]
+    BoostEventNotFound,
+
+    // No longer returned (interact accepts every type again); kept so the
+    // codes after it don't shift
+    #[msg("Likes, shares and bookmarks use react; interact is for comments")]
+    UseCompactInteraction,
+
+    #[msg("Comments need their own account; use interact")]
+    CommentNeedsAccount,
+
+    #[msg("Report reason not recognized")]
+    InvalidReportReason,
+
+    #[msg("Target must be the card or a comment on it")]
+    InvalidModerationTarget,
+
+    #[msg("Signer is not a collection moderator")]
+    NotModerator,
+
+    #[msg("Distribution needs a nonzero amount and root, and a future deadline if any")]
+    InvalidDistribution,
+
+    #[msg("Merkle proof does not match the distribution root")]
+    InvalidMerkleProof,
+
+    #[msg("Claim exceeds the distribution's remaining funds")]
+    DistributionExhausted,
+
+    #[msg("Distribution claim deadline has passed")]
+    DistributionClosed,
+
+    #[msg("Distribution is still open for claims")]
+    DistributionStillOpen,
+
+    #[msg("Token distribution needs the vault, destination and token program")]
+    DistributionAccountsMissing,
+
+    #[msg("Proposal needs a title, 2-8 options and a voting window of at most 30 days")]
+    InvalidProposal,
+
+    #[msg("Proposal is not accepting votes")]
+    VotingClosed,
+
+    #[msg("Proposal voting has not ended")]
+    VotingStillOpen,
+
+    #[msg("Proposal has already been finalized")]
+    ProposalAlreadyFinalized,
+
+    #[msg("Vote option out of range")]
+    InvalidVoteOption,
+
+    #[msg("Card has already voted on this proposal")]
+    CardAlreadyVoted,
+
+    #[msg("Vote accounts must be (card, record) pairs, at most 10")]
+    InvalidVoteAccounts,
+
+    #[msg("Card is not wrapped as this NFT")]
+    CardNotWrapped,
+
+    #[msg("Signer does not hold the card's NFT")]
+    NotNftHolder,
+
+    #[msg("Achievement set needs more cards than one claim can present")]
+    AchievementTooLarge,
+
+    #[msg("Account is not the Crypt collection")]
+    InvalidCollectionAccount,
+
+    #[msg("Collection must be migrated to the current layout first")]
+    CollectionNeedsMigration,
+
+    #[msg("Already reacted to this card this way")]
+    AlreadyReacted,
+
+    #[msg("Card was minted after the proposal opened")]
+    CardTooNewToVote,
+
+    #[msg("Scoring version is newer than any the program knows")]
+    UnknownScoringVersion,
 }
@@ -0,0 +1,204 @@
+use anchor_lang::prelude::*;
+use crate::state::{AchievementCardRecord, AchievementSet, Badge, Collection, CryptCard};
+use crate::errors::CryptError;
+use crate::utils::{create_pda_account, has_requirements, meets_requirements, min_cards};
+
+/// Maximum cards presented in one claim — keeps the transaction under the size limit.
+pub const MAX_ACHIEVEMENT_CARDS: usize = 10;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateAchievementArgs {
+    pub set_id: u32,
+    pub name: String,
+    pub min_per_type: [u8; 5],
+    pub min_per_rarity: [u8; 3],
+}
+
+#[derive(Accounts)]
+#[instruction(args: CreateAchievementArgs)]
+pub struct CreateAchievement<'info> {
+    #[account(
+        seeds = [b"collection"],
+        bump = collection.bump,
+        has_one = authority @ CryptError::Unauthorized,
+    )]
+    pub collection: Account<'info, Collection>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + AchievementSet::SIZE,
+        seeds = [b"achievement", args.set_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub achievement: Account<'info, AchievementSet>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Cards are passed through `remaining_accounts` as (card, record) pairs,
+/// where record is the card's `[b"achievement_card", achievement, card]` PDA.
+#[derive(Accounts)]
+pub struct ClaimAchievement<'info> {
+    #[account(
+        mut,
+        seeds = [b"achievement", achievement.set_id.to_le_bytes().as_ref()],
+        bump = achievement.bump,
+    )]
+    pub achievement: Account<'info, AchievementSet>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + Badge::SIZE,
+        seeds = [b"badge", achievement.key().as_ref(), owner.key().as_ref()],
+        bump,
+    )]
+    pub badge: Account<'info, Badge>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Define a new achievement set on the collection (authority only).
+pub fn process_create_achievement(
+    ctx: Context<CreateAchievement>,
+    args: CreateAchievementArgs,
+) -> Result<()> {
+    require!(args.name.len() <= 32, CryptError::AchievementNameTooLong);
+    require!(
+        has_requirements(&args.min_per_type, &args.min_per_rarity),
+        CryptError::EmptyAchievement
+    );
+    require!(
+        min_cards(&args.min_per_type, &args.min_per_rarity) as usize <= MAX_ACHIEVEMENT_CARDS,
+        CryptError::AchievementTooLarge
+    );
+
+    let achievement = &mut ctx.accounts.achievement;
+    achievement.collection = ctx.accounts.collection.key();
+    achievement.set_id = args.set_id;
+    achievement.name = args.name;
+    achievement.min_per_type = args.min_per_type;
+    achievement.min_per_rarity = args.min_per_rarity;
+    achievement.total_claimed = 0;
+    achievement.created_at = Clock::get()?.unix_timestamp;
+    achievement.bump = ctx.bumps.achievement;
+
+    msg!("CRYPT achievement #{} created — {}", achievement.set_id, achievement.name);
+    Ok(())
+}
+
+/// Claim a badge by presenting cards that complete an achievement set.
+/// Every presented card is recorded against the set so it can't be reused
+/// for the same badge, even after it changes hands. Badges belong to the
+/// owner, and a lent card can't be presented until it comes back.
+pub fn process_claim_achievement<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ClaimAchievement<'info>>,
+) -> Result<()> {
+    let pairs = ctx.remaining_accounts;
+    require!(
+        !pairs.is_empty() && pairs.len() % 2 == 0 && pairs.len() / 2 <= MAX_ACHIEVEMENT_CARDS,
+        CryptError::InvalidAchievementAccounts
+    );
+
+    let achievement_key = ctx.accounts.achievement.key();
+    let owner_key = ctx.accounts.owner.key();
+    let now = Clock::get()?.unix_timestamp;
+
+    // Validate every card before writing anything
+    let mut hand: Vec<(u8, u8)> = Vec::with_capacity(pairs.len() / 2);
+    let mut seen: Vec<Pubkey> = Vec::with_capacity(pairs.len() / 2);
+    let mut record_bumps: Vec<u8> = Vec::with_capacity(pairs.len() / 2);
+
+    for pair in pairs.chunks(2) {
+        let (card_info, record_info) = (&pair[0], &pair[1]);
+
+        let card = Account::<CryptCard>::try_from(card_info)?;
+        require_keys_eq!(card.owner, owner_key, CryptError::NotCardOwner);
+        require!(!card.is_lent(now), CryptError::CardIsLent);
+        require!(!seen.contains(card_info.key), CryptError::CardAlreadyUsed);
+
+        let (expected, bump) = Pubkey::find_program_address(
+            &[b"achievement_card", achievement_key.as_ref(), card_info.key.as_ref()],
+            ctx.program_id,
+        );
+        require_keys_eq!(record_info.key(), expected, CryptError::InvalidAchievementAccounts);
+        require!(record_info.owner != ctx.program_id, CryptError::CardAlreadyUsed);
+
+        seen.push(card_info.key());
+        record_bumps.push(bump);
+        hand.push((card.rarity, card.card_type));
+    }
+
+    let achievement = &mut ctx.accounts.achievement;
+    require!(
+        meets_requirements(&achievement.min_per_type, &achievement.min_per_rarity, &hand),
+        CryptError::AchievementNotComplete
+    );
+
+    // Spend the cards
+    let payer = ctx.accounts.owner.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    for ((pair, card_key), bump) in pairs.chunks(2).zip(&seen).zip(&record_bumps) {
+        let record_info = &pair[1];
+        create_pda_account(
+            &payer,
+            record_info,
+            &system_program,
+            8 + AchievementCardRecord::SIZE,
+            &[b"achievement_card", achievement_key.as_ref(), card_key.as_ref(), &[*bump]],
+        )?;
+
+        let record = AchievementCardRecord {
+            achievement: achievement_key,
+            card: *card_key,
+            claimed_by: owner_key,
+            bump: *bump,
+        };
+        let mut data = record_info.try_borrow_mut_data()?;
+        let mut writer: &mut [u8] = &mut data;
+        record.try_serialize(&mut writer)?;
+    }
+
+    let badge = &mut ctx.accounts.badge;
+    badge.owner = owner_key;
+    badge.achievement = achievement_key;
+    badge.set_id = achievement.set_id;
+    badge.card_count = hand.len() as u8;
+    badge.claimed_at = now;
+    badge.bump = ctx.bumps.badge;
+
+    achievement.total_claimed = achievement.total_claimed.saturating_add(1);
+
+    emit!(AchievementClaimed {
+        set_id: achievement.set_id,
+        owner: owner_key,
+        badge: badge.key(),
+        card_count: badge.card_count,
+        timestamp: badge.claimed_at,
+    });
+
+    msg!(
+        "CRYPT achievement #{} ({}) claimed by {}",
+        achievement.set_id,
+        achievement.name,
+        owner_key
+    );
+
+    Ok(())
+}
+
+#[event]
+pub struct AchievementClaimed {
+    pub set_id: u32,
+    pub owner: Pubkey,
+    pub badge: Pubkey,
+    pub card_count: u8,
+    pub timestamp: i64,
+}
@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use crate::state::{BoostEvent, BoostTable, CardType, Collection, MAX_BOOST_EVENTS};
+use crate::errors::CryptError;
+
+#[derive(Accounts)]
+pub struct SetBoostEvent<'info> {
+    #[account(
+        seeds = [b"collection"],
+        bump = collection.bump,
+        has_one = authority @ CryptError::Unauthorized,
+    )]
+    pub collection: Account<'info, Collection>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + BoostTable::SIZE,
+        seeds = [b"boosts"],
+        bump,
+    )]
+    pub boost_table: Account<'info, BoostTable>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveBoostEvent<'info> {
+    #[account(
+        seeds = [b"collection"],
+        bump = collection.bump,
+        has_one = authority @ CryptError::Unauthorized,
+    )]
+    pub collection: Account<'info, Collection>,
+
+    #[account(mut, seeds = [b"boosts"], bump = boost_table.bump)]
+    pub boost_table: Account<'info, BoostTable>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Add a boost event, or replace the one with the same `event_id`.
+pub fn process_set_boost(ctx: Context<SetBoostEvent>, event: BoostEvent) -> Result<()> {
+    require!(event.event_id != 0, CryptError::InvalidBoostEvent);
+    require!(event.name.len() <= 32, CryptError::InvalidBoostEvent);
+    require!(event.ends_at > event.starts_at, CryptError::InvalidBoostEvent);
+    require!(event.tier_bonus <= 2, CryptError::InvalidBoostEvent);
+    if let Some(card_type) = event.card_type {
+        require!(CardType::from_u8(card_type).is_some(), CryptError::InvalidCardType);
+    }
+
+    let table = &mut ctx.accounts.boost_table;
+    if table.collection == Pubkey::default() {
+        table.collection = ctx.accounts.collection.key();
+        table.bump = ctx.bumps.boost_table;
+    }
+
+    match table.events.iter_mut().find(|e| e.event_id == event.event_id) {
+        Some(existing) => *existing = event.clone(),
+        None => {
+            require!(table.events.len() < MAX_BOOST_EVENTS, CryptError::BoostTableFull);
+            table.events.push(event.clone());
+        }
+    }
+
+    emit!(BoostEventSet {
+        event_id: event.event_id,
+        name: event.name.clone(),
+        starts_at: event.starts_at,
+        ends_at: event.ends_at,
+        card_type: event.card_type,
+        score_bonus: event.score_bonus,
+        tier_bonus: event.tier_bonus,
+    });
+
+    msg!("CRYPT boost event #{} set: {}", event.event_id, event.name);
+    Ok(())
+}
+
+/// Remove a boost event from the table.
+pub fn process_remove_boost(ctx: Context<RemoveBoostEvent>, event_id: u16) -> Result<()> {
+    let table = &mut ctx.accounts.boost_table;
+    let before = table.events.len();
+    table.events.retain(|e| e.event_id != event_id);
+    require!(table.events.len() < before, CryptError::BoostEventNotFound);
+
+    emit!(BoostEventRemoved { event_id });
+
+    msg!("CRYPT boost event #{} removed", event_id);
+    Ok(())
+}
+
+#[event]
+pub struct BoostEventSet {
+    pub event_id: u16,
+    pub name: String,
+    pub starts_at: i64,
+    pub ends_at: i64,
+    pub card_type: Option<u8>,
+    pub score_bonus: u32,
+    pub tier_bonus: u8,
+}
+
+#[event]
+pub struct BoostEventRemoved {
+    pub event_id: u16,
+}
@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
-use crate::state::CryptCard;
+use anchor_lang::solana_program::hash::hash;
+use crate::state::{CryptCard, Tombstone};
 use crate::errors::CryptError;
 
 #[derive(Accounts)]
@@ -9,6 +10,7 @@ pub struct BurnCard<'info> {
         mut,
         close = owner,
         constraint = card.owner == owner.key() @ CryptError::NotCardOwner,
+        constraint = !card.is_lent(Clock::get()?.unix_timestamp) @ CryptError::CardIsLent,
     )]
     pub card: Account<'info, CryptCard>,
 
@@ -16,17 +18,72 @@ pub struct BurnCard<'info> {
     pub owner: Signer<'info>,
 }
 
+#[derive(Accounts)]
+#[instruction(card_id: u64)]
+pub struct BurnCardWithTombstone<'info> {
+    #[account(
+        mut,
+        close = owner,
+        constraint = card.owner == owner.key() @ CryptError::NotCardOwner,
+        constraint = !card.is_lent(Clock::get()?.unix_timestamp) @ CryptError::CardIsLent,
+    )]
+    pub card: Account<'info, CryptCard>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + Tombstone::SIZE,
+        seeds = [b"tombstone", card.mint_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub tombstone: Account<'info, Tombstone>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
 /// Permanently burn a Crypt Card.
 /// The account is closed and rent is returned to the owner.
 pub fn process_burn(ctx: Context<BurnCard>, _card_id: u64) -> Result<()> {
+    emit_burn(&ctx.accounts.card, Clock::get()?.unix_timestamp);
+    Ok(())
+}
+
+/// Burn a Crypt Card and leave a Tombstone in its place.
+/// The tombstone's rent is paid out of the card's returned rent,
+/// so the owner still comes out ahead.
+pub fn process_burn_with_tombstone(
+    ctx: Context<BurnCardWithTombstone>,
+    _card_id: u64,
+) -> Result<()> {
     let card = &ctx.accounts.card;
+    let now = Clock::get()?.unix_timestamp;
+
+    let tombstone = &mut ctx.accounts.tombstone;
+    tombstone.last_owner = card.owner;
+    tombstone.mint_id = card.mint_id;
+    tombstone.tx_hash_digest = hash(card.tx_hash.as_bytes()).to_bytes();
+    tombstone.rarity = card.rarity;
+    tombstone.card_type = card.card_type;
+    tombstone.burned_at = now;
+    tombstone.bump = ctx.bumps.tombstone;
 
+    emit_burn(card, now);
+
+    msg!("CRYPT Card #{} laid to rest — tombstone raised", card.mint_id);
+
+    Ok(())
+}
+
+fn emit_burn(card: &CryptCard, timestamp: i64) {
     emit!(CardBurned {
         mint_id: card.mint_id,
         owner: card.owner,
         tx_hash: card.tx_hash.clone(),
         rarity: card.rarity,
-        timestamp: Clock::get()?.unix_timestamp,
+        timestamp,
     });
 
     msg!(
@@ -34,8 +91,6 @@ pub fn process_burn(ctx: Context<BurnCard>, _card_id: u64) -> Result<()> {
         card.mint_id,
         card.tx_hash
     );
-
-    Ok(())
 }
 
 #[event]
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
-use crate::state::Collection;
+use crate::state::{Collection, COLLECTION_VERSION};
 use crate::errors::CryptError;
+use crate::utils::zero_tail;
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct InitCollectionArgs {
@@ -16,6 +17,9 @@ pub struct UpdateCollectionArgs {
     pub mint_fee: Option<u64>,
     pub paused: Option<bool>,
     pub treasury: Option<Pubkey>,
+    pub metadata_locked: Option<bool>,
+    /// Set to `Pubkey::default()` to remove the moderator
+    pub moderator: Option<Pubkey>,
 }
 
 #[derive(Accounts)]
@@ -42,6 +46,7 @@ pub struct UpdateCollection<'info> {
         seeds = [b"collection"],
         bump = collection.bump,
         has_one = authority @ CryptError::Unauthorized,
+        constraint = collection.version == COLLECTION_VERSION @ CryptError::CollectionNeedsMigration,
     )]
     pub collection: Account<'info, Collection>,
 
@@ -65,6 +70,9 @@ pub fn initialize(
     collection.paused = false;
     collection.created_at = Clock::get()?.unix_timestamp;
     collection.bump = ctx.bumps.collection;
+    collection.version = COLLECTION_VERSION;
+    collection.metadata_locked = false;
+    collection.moderator = Pubkey::default();
 
     msg!("CRYPT collection initialized — authority: {}", collection.authority);
     Ok(())
@@ -93,6 +101,14 @@ pub fn update(
     if let Some(treasury) = args.treasury {
         collection.treasury = treasury;
     }
+    if let Some(metadata_locked) = args.metadata_locked {
+        collection.metadata_locked = metadata_locked;
+    }
+    if let Some(moderator) = args.moderator {
+        collection.moderator = moderator;
+    }
+    // A shorter uri moves every later field up; clear what's left behind
+    zero_tail(&collection.to_account_info(), &**collection)?;
 
     msg!("CRYPT collection updated");
     Ok(())
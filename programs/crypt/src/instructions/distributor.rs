@@ -0,0 +1,407 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::{self, CloseAccount, Mint, Token, TokenAccount, Transfer};
+use crate::state::{ClaimStatus, Collection, Distributor};
+use crate::errors::CryptError;
+use crate::utils::{distribution_leaf, verify_merkle_proof};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct DistributorArgs {
+    pub merkle_root: [u8; 32],
+    /// Amount to fund, in lamports or token base units
+    pub total_amount: u64,
+    /// Slot the holder snapshot was taken at
+    pub snapshot_slot: u64,
+    /// Unix timestamp claims close at, or 0 for never
+    pub claim_deadline: i64,
+}
+
+#[derive(Accounts)]
+#[instruction(distributor_id: u64)]
+pub struct CreateDistributor<'info> {
+    #[account(
+        seeds = [b"collection"],
+        bump = collection.bump,
+        has_one = authority @ CryptError::Unauthorized,
+    )]
+    pub collection: Account<'info, Collection>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Distributor::SIZE,
+        seeds = [b"distributor", distributor_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub distributor: Account<'info, Distributor>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(distributor_id: u64)]
+pub struct CreateTokenDistributor<'info> {
+    #[account(
+        seeds = [b"collection"],
+        bump = collection.bump,
+        has_one = authority @ CryptError::Unauthorized,
+    )]
+    pub collection: Account<'info, Collection>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Distributor::SIZE,
+        seeds = [b"distributor", distributor_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub distributor: Account<'info, Distributor>,
+
+    pub mint: Account<'info, Mint>,
+
+    /// Empty token account owned by the distributor PDA, created by the
+    /// client beforehand
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = distributor,
+        constraint = vault.amount == 0 @ CryptError::InvalidDistribution,
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    /// Authority's token account the allocation is funded from
+    #[account(mut, token::mint = mint)]
+    pub source: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimDistribution<'info> {
+    #[account(mut)]
+    pub distributor: Account<'info, Distributor>,
+
+    #[account(
+        init,
+        payer = claimant,
+        space = 8 + ClaimStatus::SIZE,
+        seeds = [b"claim", distributor.key().as_ref(), claimant.key().as_ref()],
+        bump,
+    )]
+    pub claim_status: Account<'info, ClaimStatus>,
+
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// SPL distributions only: the distributor's vault
+    #[account(mut, address = distributor.vault @ CryptError::DistributionAccountsMissing)]
+    pub vault: Option<Account<'info, TokenAccount>>,
+
+    /// SPL distributions only: the claimant's token account
+    #[account(mut)]
+    pub destination: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+#[derive(Accounts)]
+pub struct ClawbackDistribution<'info> {
+    #[account(mut, has_one = authority @ CryptError::Unauthorized)]
+    pub distributor: Account<'info, Distributor>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// SPL distributions only: the distributor's vault
+    #[account(mut, address = distributor.vault @ CryptError::DistributionAccountsMissing)]
+    pub vault: Option<Account<'info, TokenAccount>>,
+
+    /// SPL distributions only: where the unclaimed tokens go
+    #[account(mut)]
+    pub destination: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+fn init_distributor(
+    distributor: &mut Distributor,
+    distributor_id: u64,
+    authority: Pubkey,
+    mint: Option<Pubkey>,
+    vault: Pubkey,
+    args: &DistributorArgs,
+    bump: u8,
+) -> Result<()> {
+    require!(
+        args.total_amount > 0 && args.merkle_root != [0u8; 32],
+        CryptError::InvalidDistribution
+    );
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        args.claim_deadline == 0 || args.claim_deadline > now,
+        CryptError::InvalidDistribution
+    );
+
+    distributor.distributor_id = distributor_id;
+    distributor.authority = authority;
+    distributor.merkle_root = args.merkle_root;
+    distributor.mint = mint;
+    distributor.vault = vault;
+    distributor.total_amount = args.total_amount;
+    distributor.claimed_amount = 0;
+    distributor.num_claimed = 0;
+    distributor.snapshot_slot = args.snapshot_slot;
+    distributor.claim_deadline = args.claim_deadline;
+    distributor.created_at = now;
+    distributor.bump = bump;
+    Ok(())
+}
+
+/// Create a SOL distribution and fund it from the authority's wallet.
+pub fn process_create_distributor(
+    ctx: Context<CreateDistributor>,
+    distributor_id: u64,
+    args: DistributorArgs,
+) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    init_distributor(
+        &mut ctx.accounts.distributor,
+        distributor_id,
+        authority,
+        None,
+        Pubkey::default(),
+        &args,
+        ctx.bumps.distributor,
+    )?;
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.authority.to_account_info(),
+                to: ctx.accounts.distributor.to_account_info(),
+            },
+        ),
+        args.total_amount,
+    )?;
+
+    emit!(DistributorCreated {
+        distributor: ctx.accounts.distributor.key(),
+        distributor_id,
+        mint: None,
+        merkle_root: args.merkle_root,
+        total_amount: args.total_amount,
+        snapshot_slot: args.snapshot_slot,
+        claim_deadline: args.claim_deadline,
+    });
+
+    msg!("CRYPT distributor #{} created — {} lamports", distributor_id, args.total_amount);
+    Ok(())
+}
+
+/// Create an SPL distribution and fund its vault from `source`.
+pub fn process_create_token_distributor(
+    ctx: Context<CreateTokenDistributor>,
+    distributor_id: u64,
+    args: DistributorArgs,
+) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    let mint = ctx.accounts.mint.key();
+    init_distributor(
+        &mut ctx.accounts.distributor,
+        distributor_id,
+        authority,
+        Some(mint),
+        ctx.accounts.vault.key(),
+        &args,
+        ctx.bumps.distributor,
+    )?;
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.source.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
+            },
+        ),
+        args.total_amount,
+    )?;
+
+    emit!(DistributorCreated {
+        distributor: ctx.accounts.distributor.key(),
+        distributor_id,
+        mint: Some(mint),
+        merkle_root: args.merkle_root,
+        total_amount: args.total_amount,
+        snapshot_slot: args.snapshot_slot,
+        claim_deadline: args.claim_deadline,
+    });
+
+    msg!("CRYPT distributor #{} created — {} tokens of {}", distributor_id, args.total_amount, mint);
+    Ok(())
+}
+
+/// Claim an allocation by proving `(claimant, amount)` is in the tree.
+pub fn process_claim_distribution(
+    ctx: Context<ClaimDistribution>,
+    amount: u64,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let claimant = ctx.accounts.claimant.key();
+    let distributor = &mut ctx.accounts.distributor;
+
+    require!(distributor.is_open(now), CryptError::DistributionClosed);
+    let leaf = distribution_leaf(&claimant.to_bytes(), amount);
+    require!(
+        verify_merkle_proof(&proof, &distributor.merkle_root, &leaf),
+        CryptError::InvalidMerkleProof
+    );
+    let claimed = distributor.claimed_amount
+        .checked_add(amount)
+        .filter(|&claimed| claimed <= distributor.total_amount)
+        .ok_or(CryptError::DistributionExhausted)?;
+    distributor.claimed_amount = claimed;
+    distributor.num_claimed = distributor.num_claimed.saturating_add(1);
+
+    let status = &mut ctx.accounts.claim_status;
+    status.distributor = distributor.key();
+    status.claimant = claimant;
+    status.amount = amount;
+    status.claimed_at = now;
+    status.bump = ctx.bumps.claim_status;
+
+    match distributor.mint {
+        None => {
+            // Lamports above rent belong to the allocation, so the
+            // program can move them directly
+            **distributor.to_account_info().try_borrow_mut_lamports()? -= amount;
+            **ctx.accounts.claimant.to_account_info().try_borrow_mut_lamports()? += amount;
+        }
+        Some(_) => {
+            let (Some(vault), Some(destination), Some(token_program)) = (
+                &ctx.accounts.vault,
+                &ctx.accounts.destination,
+                &ctx.accounts.token_program,
+            ) else {
+                return err!(CryptError::DistributionAccountsMissing);
+            };
+            require!(destination.mint == vault.mint, CryptError::DistributionAccountsMissing);
+            let id = distributor.distributor_id.to_le_bytes();
+            let signer_seeds: &[&[&[u8]]] = &[&[b"distributor", &id, &[distributor.bump]]];
+            token::transfer(
+                CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    Transfer {
+                        from: vault.to_account_info(),
+                        to: destination.to_account_info(),
+                        authority: distributor.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                amount,
+            )?;
+        }
+    }
+
+    emit!(DistributionClaimed {
+        distributor: distributor.key(),
+        claimant,
+        amount,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+/// Take back whatever is unclaimed after the deadline and close the
+/// distributor (authority only).
+pub fn process_clawback(ctx: Context<ClawbackDistribution>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let distributor = &ctx.accounts.distributor;
+    require!(
+        distributor.claim_deadline != 0 && now > distributor.claim_deadline,
+        CryptError::DistributionStillOpen
+    );
+    let unclaimed = distributor.total_amount - distributor.claimed_amount;
+
+    if distributor.mint.is_some() {
+        let (Some(vault), Some(destination), Some(token_program)) = (
+            &ctx.accounts.vault,
+            &ctx.accounts.destination,
+            &ctx.accounts.token_program,
+        ) else {
+            return err!(CryptError::DistributionAccountsMissing);
+        };
+        require!(destination.mint == vault.mint, CryptError::DistributionAccountsMissing);
+        let id = distributor.distributor_id.to_le_bytes();
+        let signer_seeds: &[&[&[u8]]] = &[&[b"distributor", &id, &[distributor.bump]]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                Transfer {
+                    from: vault.to_account_info(),
+                    to: destination.to_account_info(),
+                    authority: distributor.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            vault.amount,
+        )?;
+        token::close_account(CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            CloseAccount {
+                account: vault.to_account_info(),
+                destination: ctx.accounts.authority.to_account_info(),
+                authority: distributor.to_account_info(),
+            },
+            signer_seeds,
+        ))?;
+    }
+
+    emit!(DistributionClawedBack {
+        distributor: distributor.key(),
+        unclaimed,
+        timestamp: now,
+    });
+
+    // For SOL distributions this also returns the unclaimed lamports
+    ctx.accounts.distributor.close(ctx.accounts.authority.to_account_info())
+}
+
+#[event]
+pub struct DistributorCreated {
+    pub distributor: Pubkey,
+    pub distributor_id: u64,
+    pub mint: Option<Pubkey>,
+    pub merkle_root: [u8; 32],
+    pub total_amount: u64,
+    pub snapshot_slot: u64,
+    pub claim_deadline: i64,
+}
+
+#[event]
+pub struct DistributionClaimed {
+    pub distributor: Pubkey,
+    pub claimant: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DistributionClawedBack {
+    pub distributor: Pubkey,
+    pub unclaimed: u64,
+    pub timestamp: i64,
+}
@@ -0,0 +1,353 @@
+use anchor_lang::prelude::*;
+use crate::state::{CryptCard, Duel, DuelRecord};
+use crate::errors::CryptError;
+use crate::utils::{
+    duel_commitment, duel_roll, resolve_duel, DuelOutcome, DUEL_REVEAL_WINDOW,
+};
+
+#[derive(Accounts)]
+#[instruction(card_id: u64)]
+pub struct ChallengeDuel<'info> {
+    #[account(
+        constraint = challenger_card.holder(Clock::get()?.unix_timestamp) == challenger.key()
+            @ CryptError::NotCardHolder,
+    )]
+    pub challenger_card: Account<'info, CryptCard>,
+
+    #[account(
+        constraint = opponent_card.key() != challenger_card.key() @ CryptError::InvalidDuelOpponent,
+    )]
+    pub opponent_card: Account<'info, CryptCard>,
+
+    #[account(
+        init,
+        payer = challenger,
+        space = 8 + Duel::SIZE,
+        seeds = [b"duel", challenger_card.key().as_ref()],
+        bump,
+    )]
+    pub duel: Account<'info, Duel>,
+
+    #[account(
+        init_if_needed,
+        payer = challenger,
+        space = 8 + DuelRecord::SIZE,
+        seeds = [b"duel_record", challenger_card.key().as_ref()],
+        bump,
+    )]
+    pub challenger_record: Account<'info, DuelRecord>,
+
+    #[account(mut)]
+    pub challenger: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(card_id: u64)]
+pub struct AcceptDuel<'info> {
+    #[account(
+        mut,
+        seeds = [b"duel", duel.challenger_card.as_ref()],
+        bump = duel.bump,
+        constraint = !duel.is_accepted() @ CryptError::DuelAlreadyAccepted,
+        constraint = duel.challenger != opponent.key() @ CryptError::InvalidDuelOpponent,
+    )]
+    pub duel: Account<'info, Duel>,
+
+    #[account(
+        constraint = opponent_card.key() == duel.opponent_card @ CryptError::DuelAccountMismatch,
+        constraint = opponent_card.holder(Clock::get()?.unix_timestamp) == opponent.key()
+            @ CryptError::NotCardHolder,
+    )]
+    pub opponent_card: Account<'info, CryptCard>,
+
+    #[account(
+        init_if_needed,
+        payer = opponent,
+        space = 8 + DuelRecord::SIZE,
+        seeds = [b"duel_record", opponent_card.key().as_ref()],
+        bump,
+    )]
+    pub opponent_record: Account<'info, DuelRecord>,
+
+    #[account(mut)]
+    pub opponent: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Everything needed to settle a duel and close it.
+#[derive(Accounts)]
+pub struct ResolveDuel<'info> {
+    #[account(
+        mut,
+        seeds = [b"duel", challenger_card.key().as_ref()],
+        bump = duel.bump,
+        has_one = challenger_card @ CryptError::DuelAccountMismatch,
+        has_one = opponent_card @ CryptError::DuelAccountMismatch,
+        has_one = challenger @ CryptError::DuelAccountMismatch,
+        constraint = duel.is_accepted() @ CryptError::DuelNotAccepted,
+    )]
+    pub duel: Account<'info, Duel>,
+
+    /// CHECK: Only its address is used; the duel holds the card's snapshot,
+    /// so a card burned mid-duel can still be settled
+    pub challenger_card: UncheckedAccount<'info>,
+
+    /// CHECK: As above
+    pub opponent_card: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"duel_record", challenger_card.key().as_ref()],
+        bump = challenger_record.bump,
+    )]
+    pub challenger_record: Account<'info, DuelRecord>,
+
+    #[account(
+        mut,
+        seeds = [b"duel_record", opponent_card.key().as_ref()],
+        bump = opponent_record.bump,
+    )]
+    pub opponent_record: Account<'info, DuelRecord>,
+
+    /// CHECK: Receives the duel rent on close; checked against duel.challenger
+    #[account(mut)]
+    pub challenger: AccountInfo<'info>,
+
+    /// Revealing player for `reveal_duel`; anyone for `settle_duel`
+    pub signer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CancelDuel<'info> {
+    #[account(
+        mut,
+        close = challenger,
+        seeds = [b"duel", duel.challenger_card.as_ref()],
+        bump = duel.bump,
+        has_one = challenger @ CryptError::NotDuelParticipant,
+        constraint = !duel.is_accepted() @ CryptError::DuelAlreadyAccepted,
+    )]
+    pub duel: Account<'info, Duel>,
+
+    #[account(mut)]
+    pub challenger: Signer<'info>,
+}
+
+/// Challenge another card to a duel, committing to a secret nonce.
+pub fn process_challenge(
+    ctx: Context<ChallengeDuel>,
+    _card_id: u64,
+    commitment: [u8; 32],
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let challenger_card = ctx.accounts.challenger_card.key();
+    let (mine, theirs) = (&ctx.accounts.challenger_card, &ctx.accounts.opponent_card);
+
+    let duel = &mut ctx.accounts.duel;
+    duel.challenger_card = challenger_card;
+    duel.opponent_card = theirs.key();
+    duel.challenger = ctx.accounts.challenger.key();
+    duel.opponent = Pubkey::default();
+    duel.challenger_seed = mine.soul_seed;
+    duel.opponent_seed = theirs.soul_seed;
+    duel.challenger_rarity = mine.rarity;
+    duel.opponent_rarity = theirs.rarity;
+    duel.challenger_card_type = mine.card_type;
+    duel.opponent_card_type = theirs.card_type;
+    duel.challenger_commitment = commitment;
+    duel.opponent_commitment = [0u8; 32];
+    duel.challenger_reveal = None;
+    duel.opponent_reveal = None;
+    duel.created_at = now;
+    duel.accepted_at = 0;
+    duel.bump = ctx.bumps.duel;
+
+    init_record(&mut ctx.accounts.challenger_record, challenger_card, ctx.bumps.challenger_record);
+
+    emit!(DuelChallenged {
+        challenger_card,
+        opponent_card: duel.opponent_card,
+        challenger: duel.challenger,
+        timestamp: now,
+    });
+
+    msg!(
+        "CRYPT Card #{} challenges card {}",
+        ctx.accounts.challenger_card.mint_id, duel.opponent_card
+    );
+
+    Ok(())
+}
+
+/// Accept a duel with the challenged card, committing to a secret nonce.
+pub fn process_accept(ctx: Context<AcceptDuel>, _card_id: u64, commitment: [u8; 32]) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let opponent_card = ctx.accounts.opponent_card.key();
+
+    let duel = &mut ctx.accounts.duel;
+    duel.opponent = ctx.accounts.opponent.key();
+    duel.opponent_commitment = commitment;
+    duel.accepted_at = now;
+
+    init_record(&mut ctx.accounts.opponent_record, opponent_card, ctx.bumps.opponent_record);
+
+    emit!(DuelAccepted {
+        challenger_card: duel.challenger_card,
+        opponent_card,
+        opponent: duel.opponent,
+        timestamp: now,
+    });
+
+    msg!("CRYPT Card #{} accepts the duel", ctx.accounts.opponent_card.mint_id);
+
+    Ok(())
+}
+
+/// Reveal a duel nonce. Once both sides have revealed the duel is
+/// resolved, records are updated and the duel account is closed.
+pub fn process_reveal(ctx: Context<ResolveDuel>, nonce: [u8; 32]) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let player = ctx.accounts.signer.key();
+    let duel = &mut ctx.accounts.duel;
+
+    require!(now <= duel.accepted_at + DUEL_REVEAL_WINDOW, CryptError::DuelRevealWindowClosed);
+
+    if player == duel.challenger {
+        require!(duel.challenger_reveal.is_none(), CryptError::DuelAlreadyRevealed);
+        require!(
+            duel_commitment(&duel.challenger_card.to_bytes(), &nonce) == duel.challenger_commitment,
+            CryptError::InvalidDuelReveal
+        );
+        duel.challenger_reveal = Some(nonce);
+    } else if player == duel.opponent {
+        require!(duel.opponent_reveal.is_none(), CryptError::DuelAlreadyRevealed);
+        require!(
+            duel_commitment(&duel.opponent_card.to_bytes(), &nonce) == duel.opponent_commitment,
+            CryptError::InvalidDuelReveal
+        );
+        duel.opponent_reveal = Some(nonce);
+    } else {
+        return err!(CryptError::NotDuelParticipant);
+    }
+
+    if let (Some(a), Some(b)) = (duel.challenger_reveal, duel.opponent_reveal) {
+        let outcome = resolve_duel(
+            &duel.challenger_duelist(),
+            &duel.opponent_duelist(),
+            &duel_roll(&a, &b),
+        );
+        finish(ctx, outcome, false, now)?;
+    }
+
+    Ok(())
+}
+
+/// Settle a duel whose reveal window has passed. A side that revealed
+/// wins by forfeit; if neither did, the duel is void. Permissionless.
+pub fn process_settle(ctx: Context<ResolveDuel>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let duel = &ctx.accounts.duel;
+    require!(now > duel.accepted_at + DUEL_REVEAL_WINDOW, CryptError::DuelRevealWindowOpen);
+
+    let outcome = match (duel.challenger_reveal.is_some(), duel.opponent_reveal.is_some()) {
+        (true, false) => Some(DuelOutcome::ChallengerWins),
+        (false, true) => Some(DuelOutcome::OpponentWins),
+        _ => None,
+    };
+
+    match outcome {
+        Some(outcome) => finish(ctx, outcome, true, now),
+        None => {
+            msg!("CRYPT duel voided: neither side revealed");
+            ctx.accounts.duel.close(ctx.accounts.challenger.to_account_info())
+        }
+    }
+}
+
+/// Withdraw a challenge nobody has accepted yet (challenger only).
+pub fn process_cancel(ctx: Context<CancelDuel>) -> Result<()> {
+    msg!("CRYPT duel cancelled by {}", ctx.accounts.challenger.key());
+    Ok(())
+}
+
+fn init_record(record: &mut DuelRecord, card: Pubkey, bump: u8) {
+    if record.card == Pubkey::default() {
+        record.card = card;
+        record.bump = bump;
+    }
+}
+
+fn finish(ctx: Context<ResolveDuel>, outcome: DuelOutcome, forfeit: bool, now: i64) -> Result<()> {
+    let accounts = ctx.accounts;
+    let (challenger_record, opponent_record) =
+        (&mut accounts.challenger_record, &mut accounts.opponent_record);
+
+    let winner = match outcome {
+        DuelOutcome::ChallengerWins => {
+            challenger_record.wins = challenger_record.wins.saturating_add(1);
+            opponent_record.losses = opponent_record.losses.saturating_add(1);
+            Some(accounts.duel.challenger_card)
+        }
+        DuelOutcome::OpponentWins => {
+            opponent_record.wins = opponent_record.wins.saturating_add(1);
+            challenger_record.losses = challenger_record.losses.saturating_add(1);
+            Some(accounts.duel.opponent_card)
+        }
+        DuelOutcome::Draw => {
+            challenger_record.draws = challenger_record.draws.saturating_add(1);
+            opponent_record.draws = opponent_record.draws.saturating_add(1);
+            None
+        }
+    };
+
+    emit!(DuelResolved {
+        challenger_card: accounts.duel.challenger_card,
+        opponent_card: accounts.duel.opponent_card,
+        winner,
+        forfeit,
+        timestamp: now,
+    });
+
+    msg!(
+        "CRYPT duel resolved: {} vs {} → {}",
+        accounts.duel.challenger_card,
+        accounts.duel.opponent_card,
+        match outcome {
+            DuelOutcome::ChallengerWins => "challenger wins",
+            DuelOutcome::OpponentWins => "opponent wins",
+            DuelOutcome::Draw => "draw",
+        }
+    );
+
+    accounts.duel.close(accounts.challenger.to_account_info())
+}
+
+#[event]
+pub struct DuelChallenged {
+    pub challenger_card: Pubkey,
+    pub opponent_card: Pubkey,
+    pub challenger: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DuelAccepted {
+    pub challenger_card: Pubkey,
+    pub opponent_card: Pubkey,
+    pub opponent: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DuelResolved {
+    pub challenger_card: Pubkey,
+    pub opponent_card: Pubkey,
+    /// Winning card, or None for a draw
+    pub winner: Option<Pubkey>,
+    /// True when decided because one side never revealed
+    pub forfeit: bool,
+    pub timestamp: i64,
+}
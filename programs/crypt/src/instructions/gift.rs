@@ -0,0 +1,195 @@
+use anchor_lang::prelude::*;
+use crate::state::{CryptCard, Gift};
+use crate::errors::CryptError;
+use crate::utils::is_valid_gift_duration;
+
+#[derive(Accounts)]
+#[instruction(card_id: u64)]
+pub struct CreateGift<'info> {
+    #[account(
+        mut,
+        constraint = card.owner == sender.key() @ CryptError::NotCardOwner,
+        constraint = !card.is_lent(Clock::get()?.unix_timestamp) @ CryptError::CardIsLent,
+    )]
+    pub card: Account<'info, CryptCard>,
+
+    #[account(
+        init,
+        payer = sender,
+        space = 8 + Gift::SIZE,
+        seeds = [b"gift", card.key().as_ref()],
+        bump,
+    )]
+    pub gift: Account<'info, Gift>,
+
+    #[account(mut)]
+    pub sender: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(card_id: u64)]
+pub struct ClaimGift<'info> {
+    #[account(
+        mut,
+        constraint = card.owner == gift.key() @ CryptError::CardNotGifted,
+    )]
+    pub card: Account<'info, CryptCard>,
+
+    #[account(
+        mut,
+        close = sender,
+        seeds = [b"gift", card.key().as_ref()],
+        bump = gift.bump,
+        has_one = sender @ CryptError::Unauthorized,
+    )]
+    pub gift: Account<'info, Gift>,
+
+    /// CHECK: Receives the escrow rent back; checked against gift.sender
+    #[account(mut)]
+    pub sender: AccountInfo<'info>,
+
+    /// The ephemeral key from the gift link. Its signature covers the
+    /// whole transaction, claimant included, so a copied claim can't be
+    /// replayed to a different wallet.
+    #[account(constraint = claim_key.key() == gift.claim_key @ CryptError::InvalidGiftKey)]
+    pub claim_key: Signer<'info>,
+
+    pub claimant: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(card_id: u64)]
+pub struct ReclaimGift<'info> {
+    #[account(
+        mut,
+        constraint = card.owner == gift.key() @ CryptError::CardNotGifted,
+    )]
+    pub card: Account<'info, CryptCard>,
+
+    #[account(
+        mut,
+        close = sender,
+        seeds = [b"gift", card.key().as_ref()],
+        bump = gift.bump,
+        has_one = sender @ CryptError::NotCardOwner,
+    )]
+    pub gift: Account<'info, Gift>,
+
+    #[account(mut)]
+    pub sender: Signer<'info>,
+}
+
+/// Put a card into a gift escrow. `claim_key` is the public key of a
+/// fresh keypair whose secret the sender shares off-chain (e.g. as a link).
+pub fn process_create_gift(
+    ctx: Context<CreateGift>,
+    _card_id: u64,
+    claim_key: Pubkey,
+    duration: i64,
+) -> Result<()> {
+    require!(is_valid_gift_duration(duration), CryptError::InvalidGiftDuration);
+
+    let now = Clock::get()?.unix_timestamp;
+    let card = &mut ctx.accounts.card;
+    let gift = &mut ctx.accounts.gift;
+
+    gift.card = card.key();
+    gift.sender = ctx.accounts.sender.key();
+    gift.claim_key = claim_key;
+    gift.created_at = now;
+    gift.expires_at = now + duration;
+    gift.bump = ctx.bumps.gift;
+
+    // Escrow custody: owner-gated instructions now reject the sender
+    card.owner = gift.key();
+
+    emit!(GiftCreated {
+        mint_id: card.mint_id,
+        sender: gift.sender,
+        expires_at: gift.expires_at,
+        timestamp: now,
+    });
+
+    msg!("CRYPT Card #{} wrapped as a gift until {}", card.mint_id, gift.expires_at);
+
+    Ok(())
+}
+
+/// Claim a gifted card. The claim key's signature is checked by the
+/// accounts; the key's secret never appears on-chain, so watching a claim
+/// doesn't let anyone redirect it. Anyone holding the link can still
+/// claim first, so a link should be treated like a bearer token.
+pub fn process_claim_gift(ctx: Context<ClaimGift>, _card_id: u64) -> Result<()> {
+    let gift = &ctx.accounts.gift;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(now < gift.expires_at, CryptError::GiftExpired);
+
+    let card = &mut ctx.accounts.card;
+    let claimant = ctx.accounts.claimant.key();
+    card.owner = claimant;
+
+    emit!(GiftClaimed {
+        mint_id: card.mint_id,
+        sender: gift.sender,
+        claimant,
+        timestamp: now,
+    });
+
+    // Ownership changed hands, so announce it like any other transfer
+    emit!(crate::instructions::CardTransferred {
+        mint_id: card.mint_id,
+        from: gift.sender,
+        to: claimant,
+        tx_hash: card.tx_hash.clone(),
+        timestamp: now,
+    });
+
+    msg!("CRYPT Card #{} gift claimed by {}", card.mint_id, claimant);
+
+    Ok(())
+}
+
+/// Take back an unclaimed gift once it has expired (sender only).
+pub fn process_reclaim_gift(ctx: Context<ReclaimGift>, _card_id: u64) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(now >= ctx.accounts.gift.expires_at, CryptError::GiftNotExpired);
+
+    let card = &mut ctx.accounts.card;
+    card.owner = ctx.accounts.sender.key();
+
+    emit!(GiftReclaimed {
+        mint_id: card.mint_id,
+        sender: card.owner,
+        timestamp: now,
+    });
+
+    msg!("CRYPT Card #{} gift reclaimed by {}", card.mint_id, card.owner);
+
+    Ok(())
+}
+
+#[event]
+pub struct GiftCreated {
+    pub mint_id: u64,
+    pub sender: Pubkey,
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GiftClaimed {
+    pub mint_id: u64,
+    pub sender: Pubkey,
+    pub claimant: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GiftReclaimed {
+    pub mint_id: u64,
+    pub sender: Pubkey,
+    pub timestamp: i64,
+}
@@ -0,0 +1,266 @@
+use anchor_lang::prelude::*;
+use crate::state::{Collection, CryptCard, Proposal, VoteRecord};
+use crate::errors::CryptError;
+use crate::utils::{
+    create_pda_account, is_valid_proposal, predates_proposal, winning_option, CARD_VOTE_WEIGHT, MAX_VOTE_CARDS,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ProposalArgs {
+    pub title: String,
+    /// Two to eight option labels
+    pub options: Vec<String>,
+    /// Unix timestamp voting opens (inclusive)
+    pub starts_at: i64,
+    /// Unix timestamp voting closes (exclusive)
+    pub ends_at: i64,
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct CreateProposal<'info> {
+    #[account(
+        seeds = [b"collection"],
+        bump = collection.bump,
+        has_one = authority @ CryptError::Unauthorized,
+    )]
+    pub collection: Account<'info, Collection>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Proposal::SIZE,
+        seeds = [b"proposal", proposal_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Cards are passed through `remaining_accounts` as (card, record) pairs,
+/// where record is the card's `[b"vote", proposal, card]` PDA.
+#[derive(Accounts)]
+pub struct CastVote<'info> {
+    #[account(
+        mut,
+        seeds = [b"proposal", proposal.proposal_id.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FinalizeProposal<'info> {
+    #[account(
+        mut,
+        seeds = [b"proposal", proposal.proposal_id.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+}
+
+/// Open a proposal with its options and voting window.
+pub fn process_create_proposal(
+    ctx: Context<CreateProposal>,
+    proposal_id: u64,
+    args: ProposalArgs,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        is_valid_proposal(&args.title, &args.options, args.starts_at, args.ends_at, now),
+        CryptError::InvalidProposal
+    );
+
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.proposal_id = proposal_id;
+    proposal.authority = ctx.accounts.authority.key();
+    proposal.title = args.title;
+    proposal.options = args.options;
+    proposal.tallies = Default::default();
+    proposal.cards_voted = 0;
+    proposal.starts_at = args.starts_at;
+    proposal.ends_at = args.ends_at;
+    proposal.finalized = false;
+    proposal.winning_option = None;
+    proposal.created_at = now;
+    proposal.bump = ctx.bumps.proposal;
+
+    emit!(ProposalCreated {
+        proposal: proposal.key(),
+        proposal_id,
+        title: proposal.title.clone(),
+        options: proposal.options.clone(),
+        starts_at: proposal.starts_at,
+        ends_at: proposal.ends_at,
+    });
+
+    msg!("CRYPT proposal #{} opened: {}", proposal_id, proposal.title);
+    Ok(())
+}
+
+/// Vote for `option` with every card passed in. Each card's vote record
+/// is created here, so a card that already voted — under this owner or
+/// a previous one — fails the whole vote. Votes belong to the owner, and
+/// a lent card can't vote until it comes back. Cards minted since the
+/// proposal opened can't vote on it.
+pub fn process_cast_vote<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CastVote<'info>>,
+    option: u8,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let proposal = &ctx.accounts.proposal;
+    require!(proposal.is_voting(now), CryptError::VotingClosed);
+    require!((option as usize) < proposal.options.len(), CryptError::InvalidVoteOption);
+
+    let pairs = ctx.remaining_accounts;
+    require!(
+        !pairs.is_empty() && pairs.len() % 2 == 0 && pairs.len() / 2 <= MAX_VOTE_CARDS,
+        CryptError::InvalidVoteAccounts
+    );
+
+    let proposal_key = proposal.key();
+    let voter_key = ctx.accounts.voter.key();
+
+    // Validate every card before writing anything
+    let mut seen: Vec<Pubkey> = Vec::with_capacity(pairs.len() / 2);
+    let mut weights: Vec<u64> = Vec::with_capacity(pairs.len() / 2);
+    let mut record_bumps: Vec<u8> = Vec::with_capacity(pairs.len() / 2);
+
+    for pair in pairs.chunks(2) {
+        let (card_info, record_info) = (&pair[0], &pair[1]);
+
+        let card = Account::<CryptCard>::try_from(card_info)?;
+        require_keys_eq!(card.owner, voter_key, CryptError::NotCardOwner);
+        require!(!card.is_lent(now), CryptError::CardIsLent);
+        require!(predates_proposal(card.minted_at, proposal.created_at), CryptError::CardTooNewToVote);
+        require!(!seen.contains(card_info.key), CryptError::CardAlreadyVoted);
+
+        let (expected, bump) = Pubkey::find_program_address(
+            &[b"vote", proposal_key.as_ref(), card_info.key.as_ref()],
+            ctx.program_id,
+        );
+        require_keys_eq!(record_info.key(), expected, CryptError::InvalidVoteAccounts);
+        require!(record_info.owner != ctx.program_id, CryptError::CardAlreadyVoted);
+
+        seen.push(card_info.key());
+        weights.push(CARD_VOTE_WEIGHT);
+        record_bumps.push(bump);
+    }
+
+    let payer = ctx.accounts.voter.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    for (((pair, card_key), weight), bump) in pairs.chunks(2).zip(&seen).zip(&weights).zip(&record_bumps) {
+        let record_info = &pair[1];
+        create_pda_account(
+            &payer,
+            record_info,
+            &system_program,
+            8 + VoteRecord::SIZE,
+            &[b"vote", proposal_key.as_ref(), card_key.as_ref(), &[*bump]],
+        )?;
+
+        let record = VoteRecord {
+            proposal: proposal_key,
+            card: *card_key,
+            voter: voter_key,
+            option,
+            weight: *weight,
+            voted_at: now,
+            bump: *bump,
+        };
+        let mut data = record_info.try_borrow_mut_data()?;
+        let mut writer: &mut [u8] = &mut data;
+        record.try_serialize(&mut writer)?;
+    }
+
+    let weight: u64 = weights.iter().sum();
+    let proposal = &mut ctx.accounts.proposal;
+    let tally = &mut proposal.tallies[option as usize];
+    *tally = tally.saturating_add(weight);
+    proposal.cards_voted = proposal.cards_voted.saturating_add(seen.len() as u64);
+
+    emit!(VoteCast {
+        proposal: proposal_key,
+        proposal_id: proposal.proposal_id,
+        voter: voter_key,
+        option,
+        cards: seen.len() as u8,
+        weight,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+/// Tally the vote once its window has closed and fix the result.
+/// Anyone can call it.
+pub fn process_finalize_proposal(ctx: Context<FinalizeProposal>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let proposal = &mut ctx.accounts.proposal;
+    require!(!proposal.finalized, CryptError::ProposalAlreadyFinalized);
+    require!(now >= proposal.ends_at, CryptError::VotingStillOpen);
+
+    proposal.winning_option = winning_option(&proposal.tallies, proposal.options.len());
+    proposal.finalized = true;
+
+    emit!(ProposalFinalized {
+        proposal: proposal.key(),
+        proposal_id: proposal.proposal_id,
+        winning_option: proposal.winning_option,
+        tallies: proposal.tallies[..proposal.options.len()].to_vec(),
+        cards_voted: proposal.cards_voted,
+        timestamp: now,
+    });
+
+    msg!(
+        "CRYPT proposal #{} finalized — {}",
+        proposal.proposal_id,
+        match proposal.winning_option {
+            Some(i) => proposal.options[i as usize].as_str(),
+            None => "no winner",
+        }
+    );
+    Ok(())
+}
+
+#[event]
+pub struct ProposalCreated {
+    pub proposal: Pubkey,
+    pub proposal_id: u64,
+    pub title: String,
+    pub options: Vec<String>,
+    pub starts_at: i64,
+    pub ends_at: i64,
+}
+
+#[event]
+pub struct VoteCast {
+    pub proposal: Pubkey,
+    pub proposal_id: u64,
+    pub voter: Pubkey,
+    pub option: u8,
+    /// Number of cards in this vote
+    pub cards: u8,
+    /// Combined rarity weight of those cards
+    pub weight: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProposalFinalized {
+    pub proposal: Pubkey,
+    pub proposal_id: u64,
+    pub winning_option: Option<u8>,
+    pub tallies: Vec<u64>,
+    pub cards_voted: u64,
+    pub timestamp: i64,
+}
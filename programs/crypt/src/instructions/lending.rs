@@ -0,0 +1,122 @@
+use anchor_lang::prelude::*;
+use crate::state::{CryptCard, CARD_VERSION};
+use crate::errors::CryptError;
+use crate::utils::is_valid_loan_duration;
+
+#[derive(Accounts)]
+#[instruction(card_id: u64)]
+pub struct LendCard<'info> {
+    #[account(
+        mut,
+        constraint = card.owner == owner.key() @ CryptError::NotCardOwner,
+        constraint = card.version == CARD_VERSION @ CryptError::CardNeedsMigration,
+        constraint = !card.is_lent(Clock::get()?.unix_timestamp) @ CryptError::CardIsLent,
+    )]
+    pub card: Account<'info, CryptCard>,
+
+    pub owner: Signer<'info>,
+
+    /// CHECK: Any valid Solana address can borrow a card
+    pub user: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(card_id: u64)]
+pub struct ReturnCard<'info> {
+    #[account(
+        mut,
+        constraint = card.user == user.key() @ CryptError::NotCardUser,
+    )]
+    pub card: Account<'info, CryptCard>,
+
+    pub user: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(card_id: u64)]
+pub struct ReclaimCard<'info> {
+    #[account(
+        mut,
+        constraint = card.user != Pubkey::default() @ CryptError::CardNotLent,
+    )]
+    pub card: Account<'info, CryptCard>,
+}
+
+/// Lend a card to another wallet for `duration` seconds.
+/// The lender stays the owner; the borrower becomes the card's `user`.
+pub fn process_lend(ctx: Context<LendCard>, _card_id: u64, duration: i64) -> Result<()> {
+    require!(is_valid_loan_duration(duration), CryptError::InvalidLoanDuration);
+
+    let card = &mut ctx.accounts.card;
+    let user = ctx.accounts.user.key();
+    require!(user != card.owner, CryptError::InvalidLoanRecipient);
+
+    let now = Clock::get()?.unix_timestamp;
+    card.user = user;
+    card.user_expires_at = now + duration;
+
+    emit!(CardLent {
+        mint_id: card.mint_id,
+        owner: card.owner,
+        user,
+        expires_at: card.user_expires_at,
+        timestamp: now,
+    });
+
+    msg!("CRYPT Card #{} lent to {} until {}", card.mint_id, user, card.user_expires_at);
+
+    Ok(())
+}
+
+/// Hand a borrowed card back to its owner before the loan expires.
+pub fn process_return(ctx: Context<ReturnCard>, _card_id: u64) -> Result<()> {
+    end_loan(&mut ctx.accounts.card, false)
+}
+
+/// Clear an expired loan. Permissionless — anyone can tidy up once
+/// the expiry has passed.
+pub fn process_reclaim(ctx: Context<ReclaimCard>, _card_id: u64) -> Result<()> {
+    let card = &mut ctx.accounts.card;
+    require!(
+        Clock::get()?.unix_timestamp >= card.user_expires_at,
+        CryptError::LoanNotExpired
+    );
+    end_loan(card, true)
+}
+
+fn end_loan(card: &mut CryptCard, reclaimed: bool) -> Result<()> {
+    let user = card.user;
+    card.user = Pubkey::default();
+    card.user_expires_at = 0;
+
+    emit!(CardReturned {
+        mint_id: card.mint_id,
+        owner: card.owner,
+        user,
+        reclaimed,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("CRYPT Card #{} returned to {}", card.mint_id, card.owner);
+
+    Ok(())
+}
+
+#[event]
+pub struct CardLent {
+    pub mint_id: u64,
+    pub owner: Pubkey,
+    pub user: Pubkey,
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CardReturned {
+    pub mint_id: u64,
+    pub owner: Pubkey,
+    pub user: Pubkey,
+    /// True when cleared by `reclaim_card` after expiry
+    pub reclaimed: bool,
+    pub timestamp: i64,
+}
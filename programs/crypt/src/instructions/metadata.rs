@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+use crate::state::{Collection, CryptCard, CARD_VERSION, COLLECTION_VERSION};
+use crate::errors::CryptError;
+use crate::utils::{
+    can_edit_metadata, in_edit_window, metadata_hash, validate_metadata_update, zero_tail,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateCardMetadataArgs {
+    pub title: Option<String>,
+    pub narration_hash: Option<[u8; 32]>,
+    pub soundtrack_id: Option<String>,
+}
+
+#[derive(Accounts)]
+#[instruction(card_id: u64)]
+pub struct UpdateCardMetadata<'info> {
+    #[account(
+        seeds = [b"collection"],
+        bump = collection.bump,
+        constraint = collection.version == COLLECTION_VERSION @ CryptError::CollectionNeedsMigration,
+        constraint = !collection.metadata_locked @ CryptError::MetadataLocked,
+    )]
+    pub collection: Account<'info, Collection>,
+
+    #[account(
+        mut,
+        constraint = card.owner == owner.key() @ CryptError::NotCardOwner,
+        constraint = card.version == CARD_VERSION @ CryptError::CardNeedsMigration,
+        constraint = !card.is_lent(Clock::get()?.unix_timestamp) @ CryptError::CardIsLent,
+    )]
+    pub card: Account<'info, CryptCard>,
+
+    pub owner: Signer<'info>,
+}
+
+/// Let the owner fix a card's title, narration or soundtrack.
+/// Edits are free during the window after mint and capped afterwards.
+pub fn process_update_metadata(
+    ctx: Context<UpdateCardMetadata>,
+    _card_id: u64,
+    args: UpdateCardMetadataArgs,
+) -> Result<()> {
+    validate_metadata_update(args.title.as_deref(), args.soundtrack_id.as_deref())?;
+
+    let card = &mut ctx.accounts.card;
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        can_edit_metadata(card.minted_at, now, card.metadata_edits),
+        CryptError::MetadataEditLimitReached
+    );
+
+    let old_narration_hash = card.narration_hash;
+    let old_metadata_hash = metadata_hash(&card.title, &card.narration_hash, &card.soundtrack_id);
+
+    if let Some(title) = args.title {
+        card.title = title;
+    }
+    if let Some(narration_hash) = args.narration_hash {
+        card.narration_hash = narration_hash;
+    }
+    if let Some(soundtrack_id) = args.soundtrack_id {
+        card.soundtrack_id = soundtrack_id;
+    }
+    // Edits inside the window are free and don't count toward the cap
+    if !in_edit_window(card.minted_at, now) {
+        card.metadata_edits = card.metadata_edits.saturating_add(1);
+    }
+    // A shorter title or soundtrack moves every later field up; clear
+    // what's left behind so appended fields never read stale bytes
+    zero_tail(&card.to_account_info(), &**card)?;
+
+    emit!(CardMetadataUpdated {
+        mint_id: card.mint_id,
+        owner: card.owner,
+        title: card.title.clone(),
+        old_narration_hash,
+        new_narration_hash: card.narration_hash,
+        old_metadata_hash,
+        new_metadata_hash: metadata_hash(&card.title, &card.narration_hash, &card.soundtrack_id),
+        edits_used: card.metadata_edits,
+        timestamp: now,
+    });
+
+    msg!(
+        "CRYPT Card #{} metadata updated ({} edits used)",
+        card.mint_id, card.metadata_edits
+    );
+
+    Ok(())
+}
+
+#[event]
+pub struct CardMetadataUpdated {
+    pub mint_id: u64,
+    pub owner: Pubkey,
+    pub title: String,
+    pub old_narration_hash: [u8; 32],
+    pub new_narration_hash: [u8; 32],
+    pub old_metadata_hash: [u8; 32],
+    pub new_metadata_hash: [u8; 32],
+    pub edits_used: u8,
+    pub timestamp: i64,
+}
@@ -0,0 +1,168 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use crate::state::{Collection, CryptCard, CARD_VERSION, COLLECTION_VERSION};
+use crate::errors::CryptError;
+use crate::utils::grow_account;
+
+#[derive(Accounts)]
+pub struct MigrateCard<'info> {
+    /// CHECK: Decoded by hand — a card on an older layout can be shorter
+    /// than `CryptCard::SIZE`. Ownership and discriminator are checked below.
+    #[account(mut, owner = crate::ID)]
+    pub card: UncheckedAccount<'info>,
+
+    /// Anyone can migrate a card; the payer covers the extra rent.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateCollection<'info> {
+    /// CHECK: Decoded by hand — a version 0 collection is shorter than
+    /// `Collection::SIZE` and can hold stale bytes past its last field.
+    /// Ownership, seeds and discriminator are checked.
+    #[account(mut, owner = crate::ID, seeds = [b"collection"], bump)]
+    pub collection: UncheckedAccount<'info>,
+
+    /// Anyone can migrate the collection; the payer covers the extra rent.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// The version 0 collection layout, which ended at `bump`.
+#[derive(AnchorDeserialize)]
+struct CollectionV0 {
+    authority: Pubkey,
+    total_minted: u64,
+    max_supply: u64,
+    uri: String,
+    mint_fee: u64,
+    treasury: Pubkey,
+    paused: bool,
+    created_at: i64,
+    bump: u8,
+}
+
+/// Bring a card account up to the current layout.
+/// Grows the account to `CryptCard::SIZE` if needed (new bytes are zeroed,
+/// so appended fields start at their defaults) and stamps `CARD_VERSION`.
+pub fn process_migrate(ctx: Context<MigrateCard>) -> Result<()> {
+    let card_info = ctx.accounts.card.to_account_info();
+    {
+        let data = card_info.try_borrow_data()?;
+        require!(
+            data.len() >= 8 && data[..8] == CryptCard::DISCRIMINATOR,
+            CryptError::InvalidCardAccount
+        );
+    }
+
+    grow_account(
+        &ctx.accounts.payer.to_account_info(),
+        &card_info,
+        &ctx.accounts.system_program.to_account_info(),
+        8 + CryptCard::SIZE,
+    )?;
+
+    let mut card = {
+        let data = card_info.try_borrow_data()?;
+        CryptCard::try_deserialize(&mut &data[..])?
+    };
+    require!(card.needs_migration(), CryptError::AlreadyMigrated);
+
+    let from_version = card.version;
+    card.version = CARD_VERSION;
+    {
+        let mut data = card_info.try_borrow_mut_data()?;
+        let mut writer: &mut [u8] = &mut data;
+        card.try_serialize(&mut writer)?;
+    }
+
+    emit!(CardMigrated {
+        mint_id: card.mint_id,
+        from_version,
+        to_version: CARD_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "CRYPT Card #{} migrated: v{} → v{}",
+        card.mint_id, from_version, CARD_VERSION
+    );
+
+    Ok(())
+}
+
+/// Bring the collection account up to the current layout.
+/// Only the version 0 fields are read back; the account is grown to
+/// `Collection::SIZE`, cleared, and rewritten with appended fields at
+/// their defaults, so nothing is ever read from leftover bytes.
+pub fn process_migrate_collection(ctx: Context<MigrateCollection>) -> Result<()> {
+    let info = ctx.accounts.collection.to_account_info();
+    let legacy = {
+        let data = info.try_borrow_data()?;
+        require!(
+            data.len() >= 8 && data[..8] == Collection::DISCRIMINATOR,
+            CryptError::InvalidCollectionAccount
+        );
+        require!(data.len() < 8 + Collection::SIZE, CryptError::AlreadyMigrated);
+        CollectionV0::deserialize(&mut &data[8..])
+            .map_err(|_| CryptError::InvalidCollectionAccount)?
+    };
+
+    grow_account(
+        &ctx.accounts.payer.to_account_info(),
+        &info,
+        &ctx.accounts.system_program.to_account_info(),
+        8 + Collection::SIZE,
+    )?;
+
+    let collection = Collection {
+        authority: legacy.authority,
+        total_minted: legacy.total_minted,
+        max_supply: legacy.max_supply,
+        uri: legacy.uri,
+        mint_fee: legacy.mint_fee,
+        treasury: legacy.treasury,
+        paused: legacy.paused,
+        created_at: legacy.created_at,
+        bump: legacy.bump,
+        version: COLLECTION_VERSION,
+        metadata_locked: false,
+        moderator: Pubkey::default(),
+    };
+    {
+        let mut data = info.try_borrow_mut_data()?;
+        data.fill(0);
+        let mut writer: &mut [u8] = &mut data;
+        collection.try_serialize(&mut writer)?;
+    }
+
+    emit!(CollectionMigrated {
+        from_version: 0,
+        to_version: COLLECTION_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("CRYPT collection migrated: v0 → v{}", COLLECTION_VERSION);
+
+    Ok(())
+}
+
+#[event]
+pub struct CardMigrated {
+    pub mint_id: u64,
+    pub from_version: u8,
+    pub to_version: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CollectionMigrated {
+    pub from_version: u8,
+    pub to_version: u8,
+    pub timestamp: i64,
+}
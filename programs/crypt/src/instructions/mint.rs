@@ -1,9 +1,14 @@
 use anchor_lang::prelude::*;
 use anchor_lang::system_program;
-use crate::state::{Collection, CryptCard};
+use crate::state::{BoostEvent, BoostTable, Collection, CryptCard, CARD_VERSION};
 use crate::errors::CryptError;
-use crate::utils::{compute_soul_seed, validate_card_args};
+use crate::utils::{active_boost, boosted_rarity, compute_soul_seed, validate_card_args};
 
+/// Arguments to `mint_card` and `batch_mint`.
+///
+/// Breaking change in 0.2.0: `score` and `scoring_version` were
+/// appended, so the Borsh layout differs from 0.1.x and older clients'
+/// mints fail to deserialize. Rebuild clients against the 0.2.0 IDL.
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct MintCardArgs {
     pub tx_hash: String,
@@ -15,6 +20,16 @@ pub struct MintCardArgs {
     pub pnl: String,
     pub tx_timestamp: i64,
     pub soundtrack_id: String,
+    /// Rarity score the client computed, before any event boost (0 if unknown).
+    /// Like `rarity`, it is client-supplied and not checked on-chain: the
+    /// program never sees the transaction, so a boost lifts whatever score
+    /// the minter claims. `verify_card` re-derives neither.
+    pub score: u32,
+    /// SDK scoring version that produced `score` and `rarity` (0 if
+    /// unknown). Stored on the card so its rarity can be traced back to
+    /// the rules that decided it. Versions past `SCORING_VERSION` are
+    /// rejected; beyond that, like the score, it is the client's claim.
+    pub scoring_version: u16,
 }
 
 #[derive(Accounts)]
@@ -44,6 +59,11 @@ pub struct MintCard<'info> {
     pub minter: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// CHECK: The boost table PDA, passed even before any event is set so
+    /// a minter can't skip an active boost; read by `load_boosts`
+    #[account(seeds = [b"boosts"], bump)]
+    pub boost_table: UncheckedAccount<'info>,
 }
 
 #[derive(Accounts)]
@@ -63,6 +83,29 @@ pub struct BatchMint<'info> {
     pub minter: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// CHECK: The boost table PDA, passed even before any event is set so
+    /// a minter can't skip an active boost; read by `load_boosts`
+    #[account(seeds = [b"boosts"], bump)]
+    pub boost_table: UncheckedAccount<'info>,
+}
+
+/// The collection's boost events, or none if the table was never created.
+fn load_boosts(boost_table: &AccountInfo) -> Result<Vec<BoostEvent>> {
+    if boost_table.owner != &crate::ID {
+        return Ok(Vec::new());
+    }
+    let data = boost_table.try_borrow_data()?;
+    Ok(BoostTable::try_deserialize(&mut &data[..])?.events)
+}
+
+/// Apply the active boost event, if any. Returns the final rarity and
+/// the id of the event that applied (0 for none).
+fn apply_boost(events: &[BoostEvent], args: &MintCardArgs, now: i64) -> (u8, u16) {
+    match active_boost(events, args.card_type, now) {
+        Some(event) => (boosted_rarity(args.rarity, args.score, event), event.event_id),
+        None => (args.rarity, 0),
+    }
 }
 
 /// Mint a single Crypt Card from a Solana transaction.
@@ -87,12 +130,15 @@ pub fn process_mint(ctx: Context<MintCard>, args: MintCardArgs) -> Result<()> {
 
     // Compute deterministic soul seed from transaction hash
     let soul_seed = compute_soul_seed(&args.tx_hash);
+    let now = Clock::get()?.unix_timestamp;
+    let events = load_boosts(&ctx.accounts.boost_table)?;
+    let (rarity, boost_event) = apply_boost(&events, &args, now);
 
     let card = &mut ctx.accounts.card;
     card.owner = ctx.accounts.minter.key();
     card.mint_id = collection.total_minted;
     card.tx_hash = args.tx_hash;
-    card.rarity = args.rarity;
+    card.rarity = rarity;
     card.card_type = args.card_type;
     card.title = args.title;
     card.narration_hash = args.narration_hash;
@@ -100,13 +146,29 @@ pub fn process_mint(ctx: Context<MintCard>, args: MintCardArgs) -> Result<()> {
     card.platform = args.platform;
     card.pnl = args.pnl;
     card.tx_timestamp = args.tx_timestamp;
-    card.minted_at = Clock::get()?.unix_timestamp;
+    card.minted_at = now;
     card.interaction_count = 0;
     card.soundtrack_id = args.soundtrack_id;
     card.bump = ctx.bumps.card;
+    card.version = CARD_VERSION;
+    card.metadata_edits = 0;
+    card.user = Pubkey::default();
+    card.user_expires_at = 0;
+    card.boost_event = boost_event;
+    card.scoring_version = args.scoring_version;
 
     collection.total_minted += 1;
 
+    if boost_event != 0 {
+        emit!(BoostApplied {
+            mint_id: card.mint_id,
+            event_id: boost_event,
+            base_rarity: args.rarity,
+            rarity,
+            score: args.score,
+        });
+    }
+
     emit!(CardMinted {
         mint_id: card.mint_id,
         owner: card.owner,
@@ -135,20 +197,34 @@ pub fn process_batch_mint(ctx: Context<BatchMint>, args: Vec<MintCardArgs>) -> R
     require!(args.len() <= 8, CryptError::BatchTooLarge);
     let collection = &mut ctx.accounts.collection;
 
+    let now = Clock::get()?.unix_timestamp;
+    let events = load_boosts(&ctx.accounts.boost_table)?;
+
     for arg in &args {
         require!(collection.can_mint(), CryptError::MaxSupplyReached);
         validate_card_args(arg)?;
         collection.total_minted += 1;
 
+        let (rarity, boost_event) = apply_boost(&events, arg, now);
+        if boost_event != 0 {
+            emit!(BoostApplied {
+                mint_id: collection.total_minted - 1,
+                event_id: boost_event,
+                base_rarity: arg.rarity,
+                rarity,
+                score: arg.score,
+            });
+        }
+
         emit!(CardMinted {
             mint_id: collection.total_minted - 1,
             owner: ctx.accounts.minter.key(),
             tx_hash: arg.tx_hash.clone(),
-            rarity: arg.rarity,
+            rarity,
             card_type: arg.card_type,
             title: arg.title.clone(),
             soul_seed: compute_soul_seed(&arg.tx_hash),
-            timestamp: Clock::get()?.unix_timestamp,
+            timestamp: now,
         });
     }
 
@@ -169,3 +245,16 @@ pub struct CardMinted {
     pub soul_seed: [u8; 32],
     pub timestamp: i64,
 }
+
+/// Emitted alongside CardMinted when an event boost changed the mint.
+#[event]
+pub struct BoostApplied {
+    pub mint_id: u64,
+    pub event_id: u16,
+    /// Rarity the client asked for
+    pub base_rarity: u8,
+    /// Rarity after the boost
+    pub rarity: u8,
+    /// Client-computed score the boost was applied to (unverified)
+    pub score: u32,
+}
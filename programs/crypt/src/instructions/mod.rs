@@ -5,6 +5,18 @@ pub mod burn;
 pub mod verify;
 pub mod social;
 pub mod upgrade;
+pub mod achievement;
+pub mod staking;
+pub mod migrate;
+pub mod metadata;
+pub mod lending;
+pub mod gift;
+pub mod duel;
+pub mod boost;
+pub mod moderation;
+pub mod distributor;
+pub mod governance;
+pub mod wrap;
 
 pub use collection::*;
 pub use mint::*;
@@ -13,3 +25,15 @@ pub use burn::*;
 pub use verify::*;
 pub use social::*;
 pub use upgrade::*;
+pub use achievement::*;
+pub use staking::*;
+pub use migrate::*;
+pub use metadata::*;
+pub use lending::*;
+pub use gift::*;
+pub use duel::*;
+pub use boost::*;
+pub use moderation::*;
+pub use distributor::*;
+pub use governance::*;
+pub use wrap::*;
@@ -0,0 +1,193 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    Collection, CryptCard, Interaction, InteractionType, Moderation, ModerationTarget, Report,
+    ReportReason, COLLECTION_VERSION,
+};
+use crate::errors::CryptError;
+
+#[derive(Accounts)]
+pub struct ReportContent<'info> {
+    #[account(
+        init,
+        payer = reporter,
+        space = 8 + Report::SIZE,
+        seeds = [b"report", target.key().as_ref(), reporter.key().as_ref()],
+        bump,
+    )]
+    pub report: Account<'info, Report>,
+
+    #[account(
+        init_if_needed,
+        payer = reporter,
+        space = 8 + Moderation::SIZE,
+        seeds = [b"moderation", target.key().as_ref()],
+        bump,
+    )]
+    pub moderation: Account<'info, Moderation>,
+
+    /// CHECK: The card or comment being reported — checked by `target_kind`
+    #[account(owner = crate::ID)]
+    pub target: UncheckedAccount<'info>,
+
+    /// The card itself, or the card the comment is on
+    pub card: Account<'info, CryptCard>,
+
+    #[account(mut)]
+    pub reporter: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetHidden<'info> {
+    #[account(
+        seeds = [b"collection"],
+        bump = collection.bump,
+        // A v0 collection has no moderator field; whatever sits there is stale uri bytes
+        constraint = collection.version == COLLECTION_VERSION @ CryptError::CollectionNeedsMigration,
+        constraint = collection.is_moderator(&moderator.key()) @ CryptError::NotModerator,
+    )]
+    pub collection: Account<'info, Collection>,
+
+    #[account(
+        init_if_needed,
+        payer = moderator,
+        space = 8 + Moderation::SIZE,
+        seeds = [b"moderation", target.key().as_ref()],
+        bump,
+    )]
+    pub moderation: Account<'info, Moderation>,
+
+    /// CHECK: The card or comment being moderated — checked by `target_kind`
+    #[account(owner = crate::ID)]
+    pub target: UncheckedAccount<'info>,
+
+    /// The card itself, or the card the comment is on
+    pub card: Account<'info, CryptCard>,
+
+    #[account(mut)]
+    pub moderator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Work out whether `target` is `card` itself or a comment on it, and
+/// return the kind with the wallet that wrote the content.
+fn target_kind(target: &AccountInfo, card: &Account<CryptCard>) -> Result<(ModerationTarget, Pubkey)> {
+    if target.key() == card.key() {
+        return Ok((ModerationTarget::Card, card.owner));
+    }
+    let data = target.try_borrow_data()?;
+    let interaction = Interaction::try_deserialize(&mut &data[..])
+        .map_err(|_| error!(CryptError::InvalidModerationTarget))?;
+    require!(
+        interaction.card == card.key()
+            && InteractionType::from_u8(interaction.interaction_type) == Some(InteractionType::Comment),
+        CryptError::InvalidModerationTarget
+    );
+    Ok((ModerationTarget::Comment, interaction.user))
+}
+
+/// Fill in a moderation account the first time it's used.
+fn init_moderation(moderation: &mut Moderation, target: Pubkey, kind: ModerationTarget, card: Pubkey, bump: u8) {
+    if moderation.target == Pubkey::default() {
+        moderation.target = target;
+        moderation.kind = kind as u8;
+        moderation.card = card;
+        moderation.bump = bump;
+    }
+}
+
+/// File a report against a card title or comment with a reason code.
+pub fn process_report(ctx: Context<ReportContent>, reason: u8) -> Result<()> {
+    ReportReason::from_u8(reason).ok_or(CryptError::InvalidReportReason)?;
+    let (kind, author) = target_kind(&ctx.accounts.target, &ctx.accounts.card)?;
+    let now = Clock::get()?.unix_timestamp;
+    let target = ctx.accounts.target.key();
+
+    let report = &mut ctx.accounts.report;
+    report.target = target;
+    report.reporter = ctx.accounts.reporter.key();
+    report.reason = reason;
+    report.created_at = now;
+    report.bump = ctx.bumps.report;
+
+    let moderation = &mut ctx.accounts.moderation;
+    init_moderation(moderation, target, kind, ctx.accounts.card.key(), ctx.bumps.moderation);
+    moderation.report_count = moderation.report_count.saturating_add(1);
+    moderation.last_reason = reason;
+
+    emit!(ContentReported {
+        target,
+        kind: kind as u8,
+        card_mint_id: ctx.accounts.card.mint_id,
+        author,
+        reporter: report.reporter,
+        reason,
+        report_count: moderation.report_count,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+/// Hide or unhide a card or comment (collection moderator or authority).
+pub fn process_set_hidden(ctx: Context<SetHidden>, hidden: bool, reason: u8) -> Result<()> {
+    ReportReason::from_u8(reason).ok_or(CryptError::InvalidReportReason)?;
+    let (kind, author) = target_kind(&ctx.accounts.target, &ctx.accounts.card)?;
+    let now = Clock::get()?.unix_timestamp;
+    let target = ctx.accounts.target.key();
+
+    let moderation = &mut ctx.accounts.moderation;
+    init_moderation(moderation, target, kind, ctx.accounts.card.key(), ctx.bumps.moderation);
+    moderation.hidden = hidden;
+    moderation.last_reason = reason;
+    moderation.moderator = ctx.accounts.moderator.key();
+    moderation.updated_at = now;
+
+    emit!(ContentHidden {
+        target,
+        kind: kind as u8,
+        card_mint_id: ctx.accounts.card.mint_id,
+        author,
+        hidden,
+        reason,
+        moderator: moderation.moderator,
+        timestamp: now,
+    });
+
+    msg!(
+        "CRYPT Card #{} {} {}",
+        ctx.accounts.card.mint_id,
+        if kind == ModerationTarget::Card { "title" } else { "comment" },
+        if hidden { "hidden" } else { "restored" }
+    );
+
+    Ok(())
+}
+
+#[event]
+pub struct ContentReported {
+    pub target: Pubkey,
+    pub kind: u8,
+    pub card_mint_id: u64,
+    /// Card owner, or the comment's author
+    pub author: Pubkey,
+    pub reporter: Pubkey,
+    pub reason: u8,
+    pub report_count: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ContentHidden {
+    pub target: Pubkey,
+    pub kind: u8,
+    pub card_mint_id: u64,
+    /// Card owner, or the comment's author
+    pub author: Pubkey,
+    pub hidden: bool,
+    pub reason: u8,
+    pub moderator: Pubkey,
+    pub timestamp: i64,
+}
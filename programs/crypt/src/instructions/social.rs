@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
-use crate::state::{CryptCard, Interaction, InteractionType};
+use crate::state::{CardSocial, CryptCard, Interaction, InteractionType, ReactRecord};
 use crate::errors::CryptError;
+use crate::utils::{fold_interaction_log, interaction_leaf};
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct InteractArgs {
@@ -32,8 +33,40 @@ pub struct Interact<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+pub struct React<'info> {
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + CardSocial::SIZE,
+        seeds = [b"social", card.key().as_ref()],
+        bump,
+    )]
+    pub card_social: Account<'info, CardSocial>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + ReactRecord::SIZE,
+        seeds = [b"react", card.key().as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    pub react_record: Account<'info, ReactRecord>,
+
+    #[account(mut)]
+    pub card: Account<'info, CryptCard>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
 /// Record an on-chain social interaction with a Crypt Card.
 /// Integrates with Tapestry Protocol for social graph data.
+/// Comments need this path and count toward `interaction_count`. Likes,
+/// shares and bookmarks are still accepted for older clients but don't
+/// count: only `react` counts them, once per user.
 pub fn process_interact(ctx: Context<Interact>, args: InteractArgs) -> Result<()> {
     let interaction_type = InteractionType::from_u8(args.interaction_type)
         .ok_or(CryptError::InvalidInteractionType)?;
@@ -46,9 +79,11 @@ pub fn process_interact(ctx: Context<Interact>, args: InteractArgs) -> Result<()
     interaction.created_at = Clock::get()?.unix_timestamp;
     interaction.bump = ctx.bumps.interaction;
 
-    // Increment card's interaction counter
+    // Increment card's interaction counter; compact types count through `react`
     let card = &mut ctx.accounts.card;
-    card.interaction_count = card.interaction_count.saturating_add(1);
+    if !interaction_type.is_compact() {
+        card.interaction_count = card.interaction_count.saturating_add(1);
+    }
 
     emit!(CardInteraction {
         card_mint_id: card.mint_id,
@@ -79,3 +114,64 @@ pub struct CardInteraction {
     pub interaction_type: u8,
     pub timestamp: i64,
 }
+
+/// Record a like, share or bookmark without a full `Interaction` account.
+/// A two-byte `ReactRecord` per (card, user) lets each user react once per
+/// type; the reaction bumps the card's counter and `interaction_count`,
+/// and folds a leaf into its interaction log. The signed transaction and
+/// the emitted leaf are the user's record.
+pub fn process_react(ctx: Context<React>, interaction_type: u8) -> Result<()> {
+    let kind = InteractionType::from_u8(interaction_type)
+        .ok_or(CryptError::InvalidInteractionType)?;
+
+    let card_key = ctx.accounts.card.key();
+    let user = ctx.accounts.user.key();
+    let now = Clock::get()?.unix_timestamp;
+
+    let social = &mut ctx.accounts.card_social;
+    if social.card == Pubkey::default() {
+        social.card = card_key;
+        social.bump = ctx.bumps.card_social;
+    }
+    match kind {
+        InteractionType::Like => social.likes = social.likes.saturating_add(1),
+        InteractionType::Share => social.shares = social.shares.saturating_add(1),
+        InteractionType::Bookmark => social.bookmarks = social.bookmarks.saturating_add(1),
+        InteractionType::Comment => return err!(CryptError::CommentNeedsAccount),
+    }
+
+    let record = &mut ctx.accounts.react_record;
+    require!(record.reacted & kind.react_flag() == 0, CryptError::AlreadyReacted);
+    record.reacted |= kind.react_flag();
+    record.bump = ctx.bumps.react_record;
+
+    let card = &mut ctx.accounts.card;
+    card.interaction_count = card.interaction_count.saturating_add(1);
+
+    let leaf = interaction_leaf(&card_key.to_bytes(), &user.to_bytes(), interaction_type, now);
+    social.log_root = fold_interaction_log(&social.log_root, &leaf);
+    social.log_len = social.log_len.saturating_add(1);
+
+    emit!(CompactInteraction {
+        card_mint_id: ctx.accounts.card.mint_id,
+        user,
+        interaction_type,
+        leaf,
+        log_root: social.log_root,
+        log_len: social.log_len,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct CompactInteraction {
+    pub card_mint_id: u64,
+    pub user: Pubkey,
+    pub interaction_type: u8,
+    pub leaf: [u8; 32],
+    pub log_root: [u8; 32],
+    pub log_len: u64,
+    pub timestamp: i64,
+}
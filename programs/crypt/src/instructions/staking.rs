@@ -0,0 +1,344 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
+use crate::state::{Collection, CryptCard, PointsAccount, StakeRecord, StakingConfig};
+use crate::errors::CryptError;
+use crate::utils::points_accrued;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ConfigureStakingArgs {
+    pub base_rate_per_day: u64,
+    pub reward_mint: Option<Pubkey>,
+    pub tokens_per_point: u64,
+}
+
+#[derive(Accounts)]
+pub struct ConfigureStaking<'info> {
+    #[account(
+        seeds = [b"collection"],
+        bump = collection.bump,
+        has_one = authority @ CryptError::Unauthorized,
+    )]
+    pub collection: Account<'info, Collection>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + StakingConfig::SIZE,
+        seeds = [b"staking_config"],
+        bump,
+    )]
+    pub staking_config: Account<'info, StakingConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(card_id: u64)]
+pub struct StakeCard<'info> {
+    #[account(seeds = [b"staking_config"], bump = staking_config.bump)]
+    pub staking_config: Account<'info, StakingConfig>,
+
+    #[account(
+        mut,
+        constraint = card.owner == staker.key() @ CryptError::NotCardOwner,
+        constraint = !card.is_lent(Clock::get()?.unix_timestamp) @ CryptError::CardIsLent,
+    )]
+    pub card: Account<'info, CryptCard>,
+
+    #[account(
+        init,
+        payer = staker,
+        space = 8 + StakeRecord::SIZE,
+        seeds = [b"stake", card.key().as_ref()],
+        bump,
+    )]
+    pub stake_record: Account<'info, StakeRecord>,
+
+    #[account(mut)]
+    pub staker: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(card_id: u64)]
+pub struct ClaimPoints<'info> {
+    #[account(seeds = [b"staking_config"], bump = staking_config.bump)]
+    pub staking_config: Account<'info, StakingConfig>,
+
+    #[account(
+        constraint = card.owner == stake_record.key() @ CryptError::CardNotStaked,
+    )]
+    pub card: Account<'info, CryptCard>,
+
+    #[account(
+        mut,
+        seeds = [b"stake", card.key().as_ref()],
+        bump = stake_record.bump,
+        has_one = staker @ CryptError::NotCardOwner,
+    )]
+    pub stake_record: Account<'info, StakeRecord>,
+
+    #[account(
+        init_if_needed,
+        payer = staker,
+        space = 8 + PointsAccount::SIZE,
+        seeds = [b"points", staker.key().as_ref()],
+        bump,
+    )]
+    pub points: Account<'info, PointsAccount>,
+
+    #[account(mut)]
+    pub staker: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(card_id: u64)]
+pub struct UnstakeCard<'info> {
+    #[account(seeds = [b"staking_config"], bump = staking_config.bump)]
+    pub staking_config: Account<'info, StakingConfig>,
+
+    #[account(
+        mut,
+        constraint = card.owner == stake_record.key() @ CryptError::CardNotStaked,
+    )]
+    pub card: Account<'info, CryptCard>,
+
+    #[account(
+        mut,
+        close = staker,
+        seeds = [b"stake", card.key().as_ref()],
+        bump = stake_record.bump,
+        has_one = staker @ CryptError::NotCardOwner,
+    )]
+    pub stake_record: Account<'info, StakeRecord>,
+
+    #[account(
+        init_if_needed,
+        payer = staker,
+        space = 8 + PointsAccount::SIZE,
+        seeds = [b"points", staker.key().as_ref()],
+        bump,
+    )]
+    pub points: Account<'info, PointsAccount>,
+
+    #[account(mut)]
+    pub staker: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RedeemPoints<'info> {
+    #[account(seeds = [b"staking_config"], bump = staking_config.bump)]
+    pub staking_config: Account<'info, StakingConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"points", owner.key().as_ref()],
+        bump = points.bump,
+        has_one = owner @ CryptError::Unauthorized,
+    )]
+    pub points: Account<'info, PointsAccount>,
+
+    #[account(
+        mut,
+        constraint = staking_config.reward_mint == Some(reward_mint.key())
+            @ CryptError::RewardMintNotConfigured,
+    )]
+    pub reward_mint: Account<'info, Mint>,
+
+    #[account(mut, token::mint = reward_mint)]
+    pub destination: Account<'info, TokenAccount>,
+
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Create or update the staking configuration (authority only).
+/// Rate changes apply to all points settled afterwards.
+pub fn process_configure_staking(
+    ctx: Context<ConfigureStaking>,
+    args: ConfigureStakingArgs,
+) -> Result<()> {
+    let config = &mut ctx.accounts.staking_config;
+    config.collection = ctx.accounts.collection.key();
+    config.base_rate_per_day = args.base_rate_per_day;
+    config.reward_mint = args.reward_mint;
+    config.tokens_per_point = args.tokens_per_point;
+    config.bump = ctx.bumps.staking_config;
+
+    msg!(
+        "CRYPT staking configured — {} points/day base rate",
+        config.base_rate_per_day
+    );
+    Ok(())
+}
+
+/// Lock a card into a StakeRecord PDA and start accruing points.
+pub fn process_stake(ctx: Context<StakeCard>, _card_id: u64) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let staker = ctx.accounts.staker.key();
+
+    let record = &mut ctx.accounts.stake_record;
+    record.card = ctx.accounts.card.key();
+    record.staker = staker;
+    record.staked_at = now;
+    record.last_claimed_at = now;
+    record.bump = ctx.bumps.stake_record;
+
+    // Custody: the record now owns the card, so owner-gated instructions
+    // reject the staker until the card is unstaked
+    let card = &mut ctx.accounts.card;
+    card.owner = record.key();
+
+    emit!(CardStaked {
+        mint_id: card.mint_id,
+        staker,
+        timestamp: now,
+    });
+
+    msg!("CRYPT Card #{} is haunting {}", card.mint_id, staker);
+    Ok(())
+}
+
+/// Settle points earned since the last claim into the staker's points account.
+pub fn process_claim_points(ctx: Context<ClaimPoints>, _card_id: u64) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let earned = settle(
+        &ctx.accounts.staking_config,
+        &ctx.accounts.card,
+        &mut ctx.accounts.stake_record,
+        &mut ctx.accounts.points,
+        ctx.bumps.points,
+        now,
+    );
+
+    emit!(PointsClaimed {
+        mint_id: ctx.accounts.card.mint_id,
+        owner: ctx.accounts.staker.key(),
+        points: earned,
+        timestamp: now,
+    });
+
+    msg!("CRYPT Card #{} yielded {} points", ctx.accounts.card.mint_id, earned);
+    Ok(())
+}
+
+/// Settle outstanding points and hand the card back to the staker.
+pub fn process_unstake(ctx: Context<UnstakeCard>, _card_id: u64) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let earned = settle(
+        &ctx.accounts.staking_config,
+        &ctx.accounts.card,
+        &mut ctx.accounts.stake_record,
+        &mut ctx.accounts.points,
+        ctx.bumps.points,
+        now,
+    );
+
+    let staker = ctx.accounts.staker.key();
+    let card = &mut ctx.accounts.card;
+    card.owner = staker;
+
+    emit!(CardUnstaked {
+        mint_id: card.mint_id,
+        staker,
+        points: earned,
+        timestamp: now,
+    });
+
+    msg!(
+        "CRYPT Card #{} stopped haunting — {} points settled",
+        card.mint_id,
+        earned
+    );
+    Ok(())
+}
+
+/// Burn points from the caller's balance and mint reward tokens in exchange.
+pub fn process_redeem_points(ctx: Context<RedeemPoints>, amount: u64) -> Result<()> {
+    let points = &mut ctx.accounts.points;
+    require!(amount > 0 && points.balance >= amount, CryptError::InsufficientPoints);
+
+    let config = &ctx.accounts.staking_config;
+    let tokens = amount
+        .checked_mul(config.tokens_per_point)
+        .ok_or(CryptError::InsufficientPoints)?;
+
+    points.balance -= amount;
+    points.total_redeemed = points.total_redeemed.saturating_add(amount);
+
+    let signer_seeds: &[&[&[u8]]] = &[&[b"staking_config", &[config.bump]]];
+    token::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.reward_mint.to_account_info(),
+                to: ctx.accounts.destination.to_account_info(),
+                authority: config.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        tokens,
+    )?;
+
+    msg!("CRYPT redeemed {} points for {} reward tokens", amount, tokens);
+    Ok(())
+}
+
+/// Credit points accrued since `last_claimed_at` and advance the checkpoint.
+fn settle(
+    config: &StakingConfig,
+    card: &CryptCard,
+    record: &mut StakeRecord,
+    points: &mut PointsAccount,
+    points_bump: u8,
+    now: i64,
+) -> u64 {
+    let earned = points_accrued(
+        config.base_rate_per_day,
+        card.interaction_count,
+        now - record.last_claimed_at,
+    );
+    record.last_claimed_at = now.max(record.last_claimed_at);
+
+    // Fresh points account from init_if_needed
+    if points.owner == Pubkey::default() {
+        points.owner = record.staker;
+        points.bump = points_bump;
+    }
+    points.balance = points.balance.saturating_add(earned);
+    points.total_earned = points.total_earned.saturating_add(earned);
+
+    earned
+}
+
+#[event]
+pub struct CardStaked {
+    pub mint_id: u64,
+    pub staker: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CardUnstaked {
+    pub mint_id: u64,
+    pub staker: Pubkey,
+    pub points: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PointsClaimed {
+    pub mint_id: u64,
+    pub owner: Pubkey,
+    pub points: u64,
+    pub timestamp: i64,
+}
@@ -8,6 +8,7 @@ pub struct TransferCard<'info> {
     #[account(
         mut,
         constraint = card.owner == current_owner.key() @ CryptError::NotCardOwner,
+        constraint = !card.is_lent(Clock::get()?.unix_timestamp) @ CryptError::CardIsLent,
     )]
     pub card: Account<'info, CryptCard>,
 
@@ -24,6 +25,9 @@ pub fn process_transfer(ctx: Context<TransferCard>, _card_id: u64) -> Result<()>
     let old_owner = card.owner;
 
     card.owner = new_owner;
+    // Clear any expired loan so the new owner starts clean
+    card.user = Pubkey::default();
+    card.user_expires_at = 0;
 
     emit!(CardTransferred {
         mint_id: card.mint_id,
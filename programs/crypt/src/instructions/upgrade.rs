@@ -9,6 +9,7 @@ pub struct UpgradeRarity<'info> {
     #[account(
         mut,
         constraint = card.owner == owner.key() @ CryptError::NotCardOwner,
+        constraint = !card.is_lent(Clock::get()?.unix_timestamp) @ CryptError::CardIsLent,
     )]
     pub card: Account<'info, CryptCard>,
 
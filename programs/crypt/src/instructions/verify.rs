@@ -1,44 +1,100 @@
 use anchor_lang::prelude::*;
-use crate::state::CryptCard;
+use anchor_lang::solana_program::hash::hash;
+use crate::state::{CardType, Collection, CryptCard, Rarity};
 use crate::utils::compute_soul_seed;
 
 #[derive(Accounts)]
 pub struct VerifyCard<'info> {
     pub card: Account<'info, CryptCard>,
+
+    /// Checked against the canonical `[b"collection"]` PDA in the report
+    /// rather than by constraint, so a wrong collection is a failed check
+    /// instead of an error.
+    pub collection: Account<'info, Collection>,
 }
 
-/// Verify that a card's soul signature seed matches its transaction hash.
-/// Returns true if the on-chain soul_seed matches the deterministic computation.
-/// Used by frontends to validate card authenticity and detect tampering.
-pub fn process_verify(ctx: Context<VerifyCard>, tx_hash: String) -> Result<bool> {
-    let card = &ctx.accounts.card;
+/// One pass/fail result per authenticity check.
+/// `narration_matches` is `None` when no narration text was supplied.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct VerificationReport {
+    /// Stored tx_hash equals the one supplied
+    pub tx_hash_matches: bool,
+    /// soul_seed is the deterministic seed of the stored tx_hash
+    pub soul_seed_valid: bool,
+    /// SHA-256 of the supplied narration equals narration_hash
+    pub narration_matches: Option<bool>,
+    /// Card address is the `[b"card", tx_hash, minter]` PDA with the stored bump
+    pub pda_valid: bool,
+    /// Stored rarity and card type are known values. This is a range
+    /// check only: the scoring inputs behind rarity aren't kept on-chain,
+    /// so whether the rarity was earned is not checked.
+    pub tier_in_range: bool,
+    /// Collection is the program's canonical PDA and has minted this card's ID
+    pub canonical_collection: bool,
+}
 
-    // Verify tx_hash matches
-    if card.tx_hash != tx_hash {
-        msg!("Verification FAILED: tx_hash mismatch");
-        return Ok(false);
+impl VerificationReport {
+    pub fn passed(&self) -> bool {
+        self.tx_hash_matches
+            && self.soul_seed_valid
+            && self.narration_matches.unwrap_or(true)
+            && self.pda_valid
+            && self.tier_in_range
+            && self.canonical_collection
     }
+}
 
-    // Verify soul_seed is deterministically correct
-    let expected_seed = compute_soul_seed(&tx_hash);
-    if card.soul_seed != expected_seed {
-        msg!("Verification FAILED: soul_seed mismatch");
-        return Ok(false);
-    }
+/// Verify a card's authenticity and return a per-check report.
+/// `minter` defaults to the current owner, which is only right for cards
+/// that have never changed hands — the minter isn't stored on the card.
+pub fn process_verify(
+    ctx: Context<VerifyCard>,
+    tx_hash: String,
+    narration: Option<String>,
+    minter: Option<Pubkey>,
+) -> Result<VerificationReport> {
+    let card = &ctx.accounts.card;
+    let collection = &ctx.accounts.collection;
+
+    let minter = minter.unwrap_or(card.owner);
+    let pda_valid = Pubkey::create_program_address(
+        &[b"card", card.tx_hash.as_bytes(), minter.as_ref(), &[card.bump]],
+        ctx.program_id,
+    )
+    .map(|address| address == card.key())
+    .unwrap_or(false);
+
+    let (canonical, _) = Pubkey::find_program_address(&[b"collection"], ctx.program_id);
 
-    msg!(
-        "CRYPT Card #{} verified — soul signature authentic",
-        card.mint_id
-    );
+    let report = VerificationReport {
+        tx_hash_matches: card.tx_hash == tx_hash,
+        soul_seed_valid: card.soul_seed == compute_soul_seed(&card.tx_hash),
+        narration_matches: narration
+            .map(|text| hash(text.as_bytes()).to_bytes() == card.narration_hash),
+        pda_valid,
+        tier_in_range: Rarity::from_u8(card.rarity).is_some()
+            && CardType::from_u8(card.card_type).is_some(),
+        canonical_collection: collection.key() == canonical
+            && card.mint_id < collection.total_minted,
+    };
+
+    if report.passed() {
+        msg!(
+            "CRYPT Card #{} verified — soul signature authentic",
+            card.mint_id
+        );
+    } else {
+        msg!("CRYPT Card #{} verification FAILED: {:?}", card.mint_id, report);
+    }
 
     emit!(CardVerified {
         mint_id: card.mint_id,
         tx_hash: card.tx_hash.clone(),
-        verified: true,
+        verified: report.passed(),
         timestamp: Clock::get()?.unix_timestamp,
     });
 
-    Ok(true)
+    Ok(report)
 }
 
 #[event]
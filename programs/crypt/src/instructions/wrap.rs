@@ -0,0 +1,340 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::{invoke, invoke_signed};
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_lang::system_program;
+use anchor_spl::token::{self, InitializeMint2, Mint, MintTo, Token, TokenAccount};
+use crate::state::{Collection, CryptCard, WrapRecord};
+use crate::errors::CryptError;
+use crate::utils::{
+    associated_token_address, burn_nft_ix, create_ata_idempotent_ix, create_master_edition_ix,
+    create_metadata_ix, master_edition_address, metadata_address, update_metadata_ix, CardNft,
+    ASSOCIATED_TOKEN_ID, MAX_NFT_URI_LEN, TOKEN_METADATA_ID,
+};
+
+#[derive(Accounts)]
+pub struct WrapCard<'info> {
+    #[account(
+        mut,
+        constraint = card.owner == wrapper.key() @ CryptError::NotCardOwner,
+        constraint = !card.is_lent(Clock::get()?.unix_timestamp) @ CryptError::CardIsLent,
+    )]
+    pub card: Account<'info, CryptCard>,
+
+    #[account(
+        init,
+        payer = wrapper,
+        space = 8 + WrapRecord::SIZE,
+        seeds = [b"wrap", nft_mint.key().as_ref()],
+        bump,
+    )]
+    pub wrap_record: Account<'info, WrapRecord>,
+
+    /// Its uri decides where the NFT's JSON is hosted
+    #[account(seeds = [b"collection"], bump = collection.bump)]
+    pub collection: Account<'info, Collection>,
+
+    /// Fresh keypair for the NFT mint, created here
+    #[account(mut)]
+    pub nft_mint: Signer<'info>,
+
+    /// CHECK: The wrapper's associated token account, created here
+    #[account(mut, address = associated_token_address(&wrapper.key(), &nft_mint.key()))]
+    pub nft_token: UncheckedAccount<'info>,
+
+    /// CHECK: Token Metadata account, created by the metadata program
+    #[account(mut, address = metadata_address(&nft_mint.key()))]
+    pub metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Master edition account, created by the metadata program
+    #[account(mut, address = master_edition_address(&nft_mint.key()))]
+    pub master_edition: UncheckedAccount<'info>,
+
+    /// CHECK: Signs as mint and update authority for every wrapped card
+    #[account(seeds = [b"nft_authority"], bump)]
+    pub nft_authority: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub wrapper: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+
+    /// CHECK: Associated Token Account program
+    #[account(address = ASSOCIATED_TOKEN_ID)]
+    pub associated_token_program: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex Token Metadata program
+    #[account(address = TOKEN_METADATA_ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct UnwrapCard<'info> {
+    #[account(
+        mut,
+        constraint = card.owner == wrap_record.key() @ CryptError::CardNotWrapped,
+    )]
+    pub card: Account<'info, CryptCard>,
+
+    #[account(
+        mut,
+        close = wrapper,
+        seeds = [b"wrap", nft_mint.key().as_ref()],
+        bump = wrap_record.bump,
+        has_one = card @ CryptError::CardNotWrapped,
+        has_one = nft_mint @ CryptError::CardNotWrapped,
+        has_one = wrapper @ CryptError::CardNotWrapped,
+    )]
+    pub wrap_record: Account<'info, WrapRecord>,
+
+    #[account(mut)]
+    pub nft_mint: Account<'info, Mint>,
+
+    /// The holder's token account with the NFT in it
+    #[account(
+        mut,
+        constraint = nft_token.mint == nft_mint.key() @ CryptError::NotNftHolder,
+        constraint = nft_token.owner == holder.key() @ CryptError::NotNftHolder,
+        constraint = nft_token.amount == 1 @ CryptError::NotNftHolder,
+    )]
+    pub nft_token: Account<'info, TokenAccount>,
+
+    /// CHECK: Token Metadata account, closed by the burn
+    #[account(mut, address = metadata_address(&nft_mint.key()))]
+    pub metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Master edition account, closed by the burn
+    #[account(mut, address = master_edition_address(&nft_mint.key()))]
+    pub master_edition: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    /// CHECK: Original wrapper, refunded the wrap record's rent
+    #[account(mut)]
+    pub wrapper: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+
+    /// CHECK: Metaplex Token Metadata program
+    #[account(address = TOKEN_METADATA_ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateWrappedMetadata<'info> {
+    #[account(constraint = card.owner == wrap_record.key() @ CryptError::CardNotWrapped)]
+    pub card: Account<'info, CryptCard>,
+
+    #[account(
+        seeds = [b"wrap", wrap_record.nft_mint.as_ref()],
+        bump = wrap_record.bump,
+        has_one = card @ CryptError::CardNotWrapped,
+    )]
+    pub wrap_record: Account<'info, WrapRecord>,
+
+    #[account(seeds = [b"collection"], bump = collection.bump)]
+    pub collection: Account<'info, Collection>,
+
+    /// CHECK: Token Metadata account of the wrapped card's NFT
+    #[account(mut, address = metadata_address(&wrap_record.nft_mint))]
+    pub metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Update authority of every wrapped card
+    #[account(seeds = [b"nft_authority"], bump)]
+    pub nft_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex Token Metadata program
+    #[account(address = TOKEN_METADATA_ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+}
+
+/// Lock a card and mint a 1/1 Metaplex NFT for it to the owner. The
+/// name, symbol and URI are derived from the card and the collection
+/// (see `CardNft`); the JSON at the URI carries the attributes from
+/// `buildCardMetadata` (or the SDK's `NftMetadata`).
+pub fn process_wrap(ctx: Context<WrapCard>) -> Result<()> {
+    let nft = CardNft::for_card(&ctx.accounts.card, &ctx.accounts.collection.uri);
+    require!(nft.uri.len() <= MAX_NFT_URI_LEN, CryptError::UriTooLong);
+
+    let now = Clock::get()?.unix_timestamp;
+    let wrapper = ctx.accounts.wrapper.key();
+    let mint = ctx.accounts.nft_mint.key();
+    let authority = ctx.accounts.nft_authority.key();
+    let authority_seeds: &[&[&[u8]]] = &[&[b"nft_authority", &[ctx.bumps.nft_authority]]];
+
+    // Mint account, decimals 0, authorities handed to the edition below
+    system_program::create_account(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::CreateAccount {
+                from: ctx.accounts.wrapper.to_account_info(),
+                to: ctx.accounts.nft_mint.to_account_info(),
+            },
+        ),
+        ctx.accounts.rent.minimum_balance(token::spl_token::state::Mint::LEN),
+        token::spl_token::state::Mint::LEN as u64,
+        &token::ID,
+    )?;
+    token::initialize_mint2(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            InitializeMint2 { mint: ctx.accounts.nft_mint.to_account_info() },
+        ),
+        0,
+        &authority,
+        Some(&authority),
+    )?;
+
+    invoke(
+        &create_ata_idempotent_ix(&wrapper, &wrapper, &mint),
+        &[
+            ctx.accounts.wrapper.to_account_info(),
+            ctx.accounts.nft_token.to_account_info(),
+            ctx.accounts.wrapper.to_account_info(),
+            ctx.accounts.nft_mint.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+        ],
+    )?;
+    token::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.nft_mint.to_account_info(),
+                to: ctx.accounts.nft_token.to_account_info(),
+                authority: ctx.accounts.nft_authority.to_account_info(),
+            },
+            authority_seeds,
+        ),
+        1,
+    )?;
+
+    invoke_signed(
+        &create_metadata_ix(&mint, &authority, &wrapper, &nft)?,
+        &[
+            ctx.accounts.metadata.to_account_info(),
+            ctx.accounts.nft_mint.to_account_info(),
+            ctx.accounts.nft_authority.to_account_info(),
+            ctx.accounts.wrapper.to_account_info(),
+            ctx.accounts.nft_authority.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.rent.to_account_info(),
+        ],
+        authority_seeds,
+    )?;
+    invoke_signed(
+        &create_master_edition_ix(&mint, &authority, &wrapper)?,
+        &[
+            ctx.accounts.master_edition.to_account_info(),
+            ctx.accounts.nft_mint.to_account_info(),
+            ctx.accounts.nft_authority.to_account_info(),
+            ctx.accounts.nft_authority.to_account_info(),
+            ctx.accounts.wrapper.to_account_info(),
+            ctx.accounts.metadata.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.rent.to_account_info(),
+        ],
+        authority_seeds,
+    )?;
+
+    let record = &mut ctx.accounts.wrap_record;
+    record.card = ctx.accounts.card.key();
+    record.nft_mint = mint;
+    record.wrapper = wrapper;
+    record.wrapped_at = now;
+    record.bump = ctx.bumps.wrap_record;
+
+    // Custody: the record owns the card until the NFT is burned
+    let card = &mut ctx.accounts.card;
+    card.owner = record.key();
+
+    emit!(CardWrapped {
+        mint_id: card.mint_id,
+        card: card.key(),
+        nft_mint: mint,
+        owner: wrapper,
+        timestamp: now,
+    });
+
+    msg!("CRYPT Card #{} wrapped as NFT {}", card.mint_id, mint);
+    Ok(())
+}
+
+/// Rewrite a wrapped card's NFT name, symbol and URI from the card and
+/// the current collection URI, e.g. after the metadata host moves. Anyone
+/// can call it, since the result only depends on on-chain state.
+pub fn process_update_wrapped_metadata(ctx: Context<UpdateWrappedMetadata>) -> Result<()> {
+    let card = &ctx.accounts.card;
+    let nft = CardNft::for_card(card, &ctx.accounts.collection.uri);
+    require!(nft.uri.len() <= MAX_NFT_URI_LEN, CryptError::UriTooLong);
+    let mint = ctx.accounts.wrap_record.nft_mint;
+    let authority = ctx.accounts.nft_authority.key();
+
+    invoke_signed(
+        &update_metadata_ix(&mint, &authority, &nft)?,
+        &[
+            ctx.accounts.metadata.to_account_info(),
+            ctx.accounts.nft_authority.to_account_info(),
+        ],
+        &[&[b"nft_authority", &[ctx.bumps.nft_authority]]],
+    )?;
+
+    msg!("CRYPT Card #{} NFT metadata updated", card.mint_id);
+    Ok(())
+}
+
+/// Burn the NFT and hand the card to whoever held it.
+pub fn process_unwrap(ctx: Context<UnwrapCard>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let holder = ctx.accounts.holder.key();
+    let mint = ctx.accounts.nft_mint.key();
+
+    invoke(
+        &burn_nft_ix(&mint, &holder, &ctx.accounts.nft_token.key()),
+        &[
+            ctx.accounts.metadata.to_account_info(),
+            ctx.accounts.holder.to_account_info(),
+            ctx.accounts.nft_mint.to_account_info(),
+            ctx.accounts.nft_token.to_account_info(),
+            ctx.accounts.master_edition.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+        ],
+    )?;
+
+    let card = &mut ctx.accounts.card;
+    card.owner = holder;
+
+    emit!(CardUnwrapped {
+        mint_id: card.mint_id,
+        card: card.key(),
+        nft_mint: mint,
+        holder,
+        timestamp: now,
+    });
+
+    msg!("CRYPT Card #{} unwrapped to {}", card.mint_id, holder);
+    Ok(())
+}
+
+#[event]
+pub struct CardWrapped {
+    pub mint_id: u64,
+    pub card: Pubkey,
+    pub nft_mint: Pubkey,
+    pub owner: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CardUnwrapped {
+    pub mint_id: u64,
+    pub card: Pubkey,
+    pub nft_mint: Pubkey,
+    /// Wallet that held the NFT and now owns the card
+    pub holder: Pubkey,
+    pub timestamp: i64,
+}
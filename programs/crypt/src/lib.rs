@@ -7,6 +7,7 @@ pub mod utils;
 pub mod analytics;
 
 use instructions::*;
+use state::BoostEvent;
 
 declare_id!("CRYPTxGraveyardSo1ana1111111111111111111111");
 
@@ -27,6 +28,10 @@ pub mod crypt {
     /// Each card is derived from a real Solana transaction — the tx hash,
     /// rarity score, card type, narration, and soul signature seed are
     /// stored on-chain as the card's permanent identity.
+    /// The boost table address is always required, so an active event
+    /// can't be skipped; its bonuses apply to the client's claimed score.
+    /// Since 0.2.0 the args end with `score` and `scoring_version`, a
+    /// breaking change for clients built against 0.1.x.
     pub fn mint_card(
         ctx: Context<MintCard>,
         args: MintCardArgs,
@@ -61,6 +66,16 @@ pub mod crypt {
         instructions::burn::process_burn(ctx, card_id)
     }
 
+    /// Burn a Crypt Card but leave a Tombstone behind.
+    /// The tombstone keeps the card's mint_id, tx_hash digest, rarity,
+    /// type, burn time, and last owner after the card account is closed.
+    pub fn burn_card_with_tombstone(
+        ctx: Context<BurnCardWithTombstone>,
+        card_id: u64,
+    ) -> Result<()> {
+        instructions::burn::process_burn_with_tombstone(ctx, card_id)
+    }
+
     /// Update collection metadata (authority only).
     pub fn update_collection(
         ctx: Context<UpdateCollection>,
@@ -69,17 +84,22 @@ pub mod crypt {
         instructions::collection::update(ctx, args)
     }
 
-    /// Verify a card's soul signature matches its on-chain data.
-    /// Used by frontends to validate card authenticity.
+    /// Verify a card's authenticity: tx hash, soul seed, optional narration
+    /// text, PDA derivation, rarity range and canonical collection.
+    /// Returns a VerificationReport with one pass/fail result per check.
     pub fn verify_card(
         ctx: Context<VerifyCard>,
         tx_hash: String,
-    ) -> Result<bool> {
-        instructions::verify::process_verify(ctx, tx_hash)
+        narration: Option<String>,
+        minter: Option<Pubkey>,
+    ) -> Result<VerificationReport> {
+        instructions::verify::process_verify(ctx, tx_hash, narration, minter)
     }
 
-    /// Record a social interaction (like, comment hash) on a card.
+    /// Record a comment on a card in its own interaction account.
     /// Integrates with Tapestry Protocol for on-chain social graph.
+    /// Likes, shares and bookmarks are still accepted here for older
+    /// clients, but only `react` counts them.
     pub fn interact(
         ctx: Context<Interact>,
         args: InteractArgs,
@@ -87,6 +107,13 @@ pub mod crypt {
         instructions::social::process_interact(ctx, args)
     }
 
+    /// Like, share or bookmark a card. Folded into per-card counters and
+    /// a hash-chained log; a two-byte record per (card, user) makes each
+    /// reaction count once, toward the counters and `interaction_count`.
+    pub fn react(ctx: Context<React>, interaction_type: u8) -> Result<()> {
+        instructions::social::process_react(ctx, interaction_type)
+    }
+
     /// Claim a rarity upgrade when a card's underlying transaction
     /// crosses a scoring threshold (e.g., held token moons).
     pub fn upgrade_rarity(
@@ -97,4 +124,269 @@ pub mod crypt {
     ) -> Result<()> {
         instructions::upgrade::process_upgrade(ctx, card_id, new_rarity, proof)
     }
+
+    /// Define a set-completion achievement on the collection (authority only),
+    /// e.g. one card of every type, or three Legendaries.
+    pub fn create_achievement(
+        ctx: Context<CreateAchievement>,
+        args: CreateAchievementArgs,
+    ) -> Result<()> {
+        instructions::achievement::process_create_achievement(ctx, args)
+    }
+
+    /// Claim a non-transferable Badge by presenting cards that complete
+    /// an achievement set. Cards are passed as (card, record) pairs in
+    /// remaining accounts and can't be reused for the same badge.
+    /// Lent cards are rejected.
+    pub fn claim_achievement<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimAchievement<'info>>,
+    ) -> Result<()> {
+        instructions::achievement::process_claim_achievement(ctx)
+    }
+
+    /// Create or update staking settings (authority only): base points rate
+    /// and an optional SPL reward mint that points can be redeemed for.
+    pub fn configure_staking(
+        ctx: Context<ConfigureStaking>,
+        args: ConfigureStakingArgs,
+    ) -> Result<()> {
+        instructions::staking::process_configure_staking(ctx, args)
+    }
+
+    /// Stake ("haunt") a card. The card is locked — no transfer, burn or
+    /// upgrade — and accrues points weighted by its interactions.
+    pub fn stake_card(
+        ctx: Context<StakeCard>,
+        card_id: u64,
+    ) -> Result<()> {
+        instructions::staking::process_stake(ctx, card_id)
+    }
+
+    /// Claim points accrued by a staked card into the staker's points account.
+    pub fn claim_points(
+        ctx: Context<ClaimPoints>,
+        card_id: u64,
+    ) -> Result<()> {
+        instructions::staking::process_claim_points(ctx, card_id)
+    }
+
+    /// Unstake a card, settling any outstanding points first.
+    pub fn unstake_card(
+        ctx: Context<UnstakeCard>,
+        card_id: u64,
+    ) -> Result<()> {
+        instructions::staking::process_unstake(ctx, card_id)
+    }
+
+    /// Redeem points for the configured SPL reward token.
+    pub fn redeem_points(
+        ctx: Context<RedeemPoints>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::staking::process_redeem_points(ctx, amount)
+    }
+
+    /// Migrate a card account to the current layout version.
+    /// Permissionless — the payer covers any extra rent from the realloc.
+    pub fn migrate_card(ctx: Context<MigrateCard>) -> Result<()> {
+        instructions::migrate::process_migrate(ctx)
+    }
+
+    /// Migrate the collection account to the current layout version.
+    /// Permissionless — the payer covers any extra rent from the realloc.
+    pub fn migrate_collection(ctx: Context<MigrateCollection>) -> Result<()> {
+        instructions::migrate::process_migrate_collection(ctx)
+    }
+
+    /// Update a card's title, narration hash or soundtrack (owner only).
+    /// Unlimited within a week of mint, three edits after that, and
+    /// blocked entirely while the collection has metadata locked.
+    pub fn update_card_metadata(
+        ctx: Context<UpdateCardMetadata>,
+        card_id: u64,
+        args: UpdateCardMetadataArgs,
+    ) -> Result<()> {
+        instructions::metadata::process_update_metadata(ctx, card_id, args)
+    }
+
+    /// Lend a card to another wallet for up to 30 days (owner only).
+    /// Transfer, burn, upgrade, staking and metadata edits are blocked
+    /// until the loan ends.
+    pub fn lend_card(
+        ctx: Context<LendCard>,
+        card_id: u64,
+        duration: i64,
+    ) -> Result<()> {
+        instructions::lending::process_lend(ctx, card_id, duration)
+    }
+
+    /// Return a borrowed card to its owner early (borrower only).
+    pub fn return_card(ctx: Context<ReturnCard>, card_id: u64) -> Result<()> {
+        instructions::lending::process_return(ctx, card_id)
+    }
+
+    /// Clear an expired loan. Permissionless.
+    pub fn reclaim_card(ctx: Context<ReclaimCard>, card_id: u64) -> Result<()> {
+        instructions::lending::process_reclaim(ctx, card_id)
+    }
+
+    /// Put a card into a gift escrow that anyone holding the secret of
+    /// `claim_key` can claim before it expires.
+    pub fn create_gift(
+        ctx: Context<CreateGift>,
+        card_id: u64,
+        claim_key: Pubkey,
+        duration: i64,
+    ) -> Result<()> {
+        instructions::gift::process_create_gift(ctx, card_id, claim_key, duration)
+    }
+
+    /// Claim a gifted card with a claim signed by the gift's claim key.
+    pub fn claim_gift(ctx: Context<ClaimGift>, card_id: u64) -> Result<()> {
+        instructions::gift::process_claim_gift(ctx, card_id)
+    }
+
+    /// Take back an expired, unclaimed gift (sender only).
+    pub fn reclaim_gift(ctx: Context<ReclaimGift>, card_id: u64) -> Result<()> {
+        instructions::gift::process_reclaim_gift(ctx, card_id)
+    }
+
+    /// Challenge another card to a duel. The holder of the challenging
+    /// card (owner, or borrower while lent) commits to a secret nonce.
+    /// Both cards fight as they stand now, whatever happens to them later.
+    pub fn challenge_duel(
+        ctx: Context<ChallengeDuel>,
+        card_id: u64,
+        commitment: [u8; 32],
+    ) -> Result<()> {
+        instructions::duel::process_challenge(ctx, card_id, commitment)
+    }
+
+    /// Accept a duel with the challenged card, committing to a nonce.
+    pub fn accept_duel(
+        ctx: Context<AcceptDuel>,
+        card_id: u64,
+        commitment: [u8; 32],
+    ) -> Result<()> {
+        instructions::duel::process_accept(ctx, card_id, commitment)
+    }
+
+    /// Reveal a duel nonce; the second reveal resolves the duel.
+    pub fn reveal_duel(ctx: Context<ResolveDuel>, nonce: [u8; 32]) -> Result<()> {
+        instructions::duel::process_reveal(ctx, nonce)
+    }
+
+    /// Settle a duel after the reveal window by forfeit. Permissionless.
+    pub fn settle_duel(ctx: Context<ResolveDuel>) -> Result<()> {
+        instructions::duel::process_settle(ctx)
+    }
+
+    /// Withdraw an unaccepted challenge (challenger only).
+    pub fn cancel_duel(ctx: Context<CancelDuel>) -> Result<()> {
+        instructions::duel::process_cancel(ctx)
+    }
+
+    /// Report a card title or comment with a reason code. One report per
+    /// wallet per target.
+    pub fn report_content(ctx: Context<ReportContent>, reason: u8) -> Result<()> {
+        instructions::moderation::process_report(ctx, reason)
+    }
+
+    /// Hide or restore a card or comment (collection moderator or authority).
+    /// The collection must be migrated first, since v0 has no moderator.
+    pub fn set_hidden(ctx: Context<SetHidden>, hidden: bool, reason: u8) -> Result<()> {
+        instructions::moderation::process_set_hidden(ctx, hidden, reason)
+    }
+
+    /// Create a merkle airdrop of SOL to snapshot holders and fund it
+    /// from the authority's wallet (authority only).
+    pub fn create_distributor(
+        ctx: Context<CreateDistributor>,
+        distributor_id: u64,
+        args: DistributorArgs,
+    ) -> Result<()> {
+        instructions::distributor::process_create_distributor(ctx, distributor_id, args)
+    }
+
+    /// Create a merkle airdrop of an SPL token and fund its vault
+    /// (authority only).
+    pub fn create_token_distributor(
+        ctx: Context<CreateTokenDistributor>,
+        distributor_id: u64,
+        args: DistributorArgs,
+    ) -> Result<()> {
+        instructions::distributor::process_create_token_distributor(ctx, distributor_id, args)
+    }
+
+    /// Claim a distribution allocation with a merkle proof. Once per wallet.
+    pub fn claim_distribution(
+        ctx: Context<ClaimDistribution>,
+        amount: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::distributor::process_claim_distribution(ctx, amount, proof)
+    }
+
+    /// Take back unclaimed funds after the claim deadline and close the
+    /// distributor (authority only).
+    pub fn clawback_distribution(ctx: Context<ClawbackDistribution>) -> Result<()> {
+        instructions::distributor::process_clawback(ctx)
+    }
+
+    /// Open a card-weighted proposal with its options and voting window
+    /// (authority only).
+    pub fn create_proposal(
+        ctx: Context<CreateProposal>,
+        proposal_id: u64,
+        args: ProposalArgs,
+    ) -> Result<()> {
+        instructions::governance::process_create_proposal(ctx, proposal_id, args)
+    }
+
+    /// Vote for an option with cards passed as (card, record) pairs in
+    /// remaining accounts, one vote per card. Each card votes once per
+    /// proposal, whoever owns it. Lent cards and cards minted since the
+    /// proposal opened are rejected.
+    pub fn cast_vote<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CastVote<'info>>,
+        option: u8,
+    ) -> Result<()> {
+        instructions::governance::process_cast_vote(ctx, option)
+    }
+
+    /// Tally a proposal after its window closes and fix the winner.
+    /// Anyone can call it.
+    pub fn finalize_proposal(ctx: Context<FinalizeProposal>) -> Result<()> {
+        instructions::governance::process_finalize_proposal(ctx)
+    }
+
+    /// Lock a card and mint a 1/1 Metaplex NFT for it, so it can be held
+    /// in wallets and traded on marketplaces. The NFT is named
+    /// `CRYPT #<mint_id> — <title>` with symbol `CRYPT`, and its URI is
+    /// `<collection uri base>/<mint_id>.json`, where the card's off-chain
+    /// JSON with its attributes is hosted.
+    pub fn wrap_card(ctx: Context<WrapCard>) -> Result<()> {
+        instructions::wrap::process_wrap(ctx)
+    }
+
+    /// Resync a wrapped card's NFT name, symbol and URI with the card and the
+    /// collection. Anyone can call it.
+    pub fn update_wrapped_metadata(ctx: Context<UpdateWrappedMetadata>) -> Result<()> {
+        instructions::wrap::process_update_wrapped_metadata(ctx)
+    }
+
+    /// Burn a wrapped card's NFT and give the card to the NFT holder.
+    pub fn unwrap_card(ctx: Context<UnwrapCard>) -> Result<()> {
+        instructions::wrap::process_unwrap(ctx)
+    }
+
+    /// Add or replace a time-boxed boost event (authority only).
+    pub fn set_boost_event(ctx: Context<SetBoostEvent>, event: BoostEvent) -> Result<()> {
+        instructions::boost::process_set_boost(ctx, event)
+    }
+
+    /// Remove a boost event (authority only).
+    pub fn remove_boost_event(ctx: Context<RemoveBoostEvent>, event_id: u16) -> Result<()> {
+        instructions::boost::process_remove_boost(ctx, event_id)
+    }
 }
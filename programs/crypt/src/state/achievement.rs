@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+
+/// A set-completion achievement defined by the collection authority,
+/// e.g. "one of every card type" or "three Legendaries".
+/// PDA: seeds = [b"achievement", set_id.to_le_bytes().as_ref()]
+#[account]
+pub struct AchievementSet {
+    /// Collection this achievement belongs to
+    pub collection: Pubkey,
+    /// Authority-chosen identifier for the set
+    pub set_id: u32,
+    /// Display name (e.g., "FULL CRYPT")
+    pub name: String,
+    /// Minimum number of cards required of each card type (indexed by card_type)
+    pub min_per_type: [u8; 5],
+    /// Minimum number of cards required at or above each rarity tier (indexed by rarity)
+    pub min_per_rarity: [u8; 3],
+    /// Number of badges claimed against this set
+    pub total_claimed: u64,
+    /// Timestamp of set creation
+    pub created_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl AchievementSet {
+    pub const SIZE: usize = 32  // collection
+        + 4                     // set_id
+        + (4 + 32)             // name
+        + 5                     // min_per_type
+        + 3                     // min_per_rarity
+        + 8                     // total_claimed
+        + 8                     // created_at
+        + 1;                    // bump
+}
+
+/// A non-transferable badge proving a wallet completed an achievement set.
+/// There is no instruction that changes `owner`, so badges stay with the claimer.
+/// PDA: seeds = [b"badge", achievement.key().as_ref(), owner.key().as_ref()]
+#[account]
+pub struct Badge {
+    /// Wallet that earned the badge
+    pub owner: Pubkey,
+    /// The AchievementSet this badge was claimed against
+    pub achievement: Pubkey,
+    /// Copied from the set for cheap client-side filtering
+    pub set_id: u32,
+    /// Number of cards presented for the claim
+    pub card_count: u8,
+    /// Timestamp of the claim
+    pub claimed_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Badge {
+    pub const SIZE: usize = 32 + 32 + 4 + 1 + 8 + 1;
+}
+
+/// Records that a card has been spent toward an achievement,
+/// so the same card can't be presented for that badge again — even by a new owner.
+/// PDA: seeds = [b"achievement_card", achievement.key().as_ref(), card.key().as_ref()]
+#[account]
+pub struct AchievementCardRecord {
+    /// The AchievementSet the card was used for
+    pub achievement: Pubkey,
+    /// The card account that was presented
+    pub card: Pubkey,
+    /// Wallet that presented the card
+    pub claimed_by: Pubkey,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl AchievementCardRecord {
+    pub const SIZE: usize = 32 + 32 + 32 + 1;
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+/// Most events the boost table holds at once.
+pub const MAX_BOOST_EVENTS: usize = 8;
+
+/// A time-boxed minting event, e.g. "HALLOWEEN: Rug cards +15".
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct BoostEvent {
+    /// Authority-chosen identifier, recorded on boosted cards (never 0)
+    pub event_id: u16,
+    /// Display name (e.g., "HALLOWEEN")
+    pub name: String,
+    /// Unix timestamp the event opens (inclusive)
+    pub starts_at: i64,
+    /// Unix timestamp the event closes (exclusive)
+    pub ends_at: i64,
+    /// Only cards of this type are boosted (None = every type)
+    pub card_type: Option<u8>,
+    /// Added to the minted card's rarity score
+    pub score_bonus: u32,
+    /// Rarity tiers added on top, capped at Legendary
+    pub tier_bonus: u8,
+}
+
+impl BoostEvent {
+    pub const SIZE: usize = 2   // event_id
+        + (4 + 32)             // name
+        + 8                     // starts_at
+        + 8                     // ends_at
+        + (1 + 1)              // card_type (Option<u8>)
+        + 4                     // score_bonus
+        + 1;                    // tier_bonus
+
+    pub fn is_active(&self, now: i64) -> bool {
+        self.starts_at <= now && now < self.ends_at
+    }
+
+    pub fn applies_to(&self, card_type: u8) -> bool {
+        self.card_type.map_or(true, |t| t == card_type)
+    }
+}
+
+/// The collection's table of boost events, managed by the collection
+/// authority and read by `mint_card`.
+/// PDA: seeds = [b"boosts"]
+#[account]
+pub struct BoostTable {
+    /// Collection this table belongs to
+    pub collection: Pubkey,
+    /// Configured events, in the order they were added
+    pub events: Vec<BoostEvent>,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl BoostTable {
+    pub const SIZE: usize = 32  // collection
+        + 4 + MAX_BOOST_EVENTS * BoostEvent::SIZE   // events (Vec)
+        + 1;                    // bump
+}
@@ -1,69 +1,10 @@
 use anchor_lang::prelude::*;
 
-/// Rarity tiers for Crypt Cards.
-/// Scoring is based on transaction value, type, and historical significance.
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
-pub enum Rarity {
-    Common = 0,     // Score 0-39:  dust trades, small transfers
-    Rare = 1,       // Score 40-74: memecoin apes, notable sales, big moves
-    Legendary = 2,  // Score 75+:   whale trades, rug survivals, creator moments
-}
-
-impl Rarity {
-    pub fn from_u8(v: u8) -> Option<Self> {
-        match v {
-            0 => Some(Rarity::Common),
-            1 => Some(Rarity::Rare),
-            2 => Some(Rarity::Legendary),
-            _ => None,
-        }
-    }
+pub use crypt_core::{CardType, Rarity};
 
-    pub fn as_str(&self) -> &'static str {
-        match self {
-            Rarity::Common => "COMMON",
-            Rarity::Rare => "RARE",
-            Rarity::Legendary => "LEGENDARY",
-        }
-    }
-
-    pub fn can_upgrade_to(&self, target: &Rarity) -> bool {
-        (*target as u8) > (*self as u8)
-    }
-}
-
-/// Card types derived from Solana transaction classification.
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
-pub enum CardType {
-    Swap = 0,           // DEX trades (Jupiter, Raydium, Orca)
-    Rug = 1,            // Rug pulls, failed tokens, -99% trades
-    Mint = 2,           // NFT mints, token creation events
-    DiamondHands = 3,   // Long holds through volatility
-    BigMove = 4,        // Whale transfers, cold storage moves
-}
-
-impl CardType {
-    pub fn from_u8(v: u8) -> Option<Self> {
-        match v {
-            0 => Some(CardType::Swap),
-            1 => Some(CardType::Rug),
-            2 => Some(CardType::Mint),
-            3 => Some(CardType::DiamondHands),
-            4 => Some(CardType::BigMove),
-            _ => None,
-        }
-    }
-
-    pub fn as_str(&self) -> &'static str {
-        match self {
-            CardType::Swap => "SWAP",
-            CardType::Rug => "RUG",
-            CardType::Mint => "MINT",
-            CardType::DiamondHands => "DIAMOND_HANDS",
-            CardType::BigMove => "BIG_MOVE",
-        }
-    }
-}
+/// Current CryptCard layout version.
+/// Version 0 is the original layout, which had no version byte.
+pub const CARD_VERSION: u8 = 5;
 
 /// A Crypt Card — an on-chain NFT representing a moment from wallet history.
 /// PDA: seeds = [b"card", tx_hash.as_bytes(), minter.key().as_ref()]
@@ -100,6 +41,19 @@ pub struct CryptCard {
     pub soundtrack_id: String,
     /// PDA bump seed
     pub bump: u8,
+    /// Layout version — new fields are only ever appended after this one
+    pub version: u8,
+    /// Number of owner metadata edits made (v2+)
+    pub metadata_edits: u8,
+    /// Wallet the card is lent to, or default if not lent (v3+)
+    pub user: Pubkey,
+    /// Unix timestamp the loan ends (v3+)
+    pub user_expires_at: i64,
+    /// Boost event applied at mint, or 0 if none (v4+)
+    pub boost_event: u16,
+    /// SDK `SCORING_VERSION` the minter scored the card with, or 0 if
+    /// unknown (v5+)
+    pub scoring_version: u16,
 }
 
 impl CryptCard {
@@ -117,7 +71,32 @@ impl CryptCard {
         + 8                     // minted_at
         + 8                     // interaction_count
         + (4 + 32)            // soundtrack_id
-        + 1;                    // bump
+        + 1                     // bump
+        + 1                     // version
+        + 1                     // metadata_edits
+        + 32                    // user
+        + 8                     // user_expires_at
+        + 2                     // boost_event
+        + 2;                    // scoring_version
+
+    /// Size of the version 0 layout, before the version byte was added.
+    pub const LEGACY_SIZE: usize = Self::SIZE - 1 - 1 - 32 - 8 - 2 - 2;
+
+    pub fn needs_migration(&self) -> bool {
+        self.version < CARD_VERSION
+    }
+
+    /// Whether the card is lent out at `now`. An expired loan no longer
+    /// counts, even before anyone calls `reclaim_card`.
+    pub fn is_lent(&self, now: i64) -> bool {
+        self.user != Pubkey::default() && now < self.user_expires_at
+    }
+
+    /// Wallet that currently gets to use the card: the borrower during
+    /// a loan, otherwise the owner.
+    pub fn holder(&self, now: i64) -> Pubkey {
+        if self.is_lent(now) { self.user } else { self.owner }
+    }
 
     pub fn rarity_enum(&self) -> Rarity {
         Rarity::from_u8(self.rarity).unwrap_or(Rarity::Common)
@@ -1,5 +1,9 @@
 use anchor_lang::prelude::*;
 
+/// Current Collection layout version.
+/// Version 0 is the original layout, which had no version byte.
+pub const COLLECTION_VERSION: u8 = 1;
+
 /// Global collection state — tracks all minted Crypt Cards.
 /// PDA: seeds = [b"collection"]
 #[account]
@@ -22,6 +26,13 @@ pub struct Collection {
     pub created_at: i64,
     /// PDA bump seed
     pub bump: u8,
+    /// Layout version — new fields are only ever appended after this one
+    pub version: u8,
+    /// Whether owners are blocked from editing card metadata (v1+)
+    pub metadata_locked: bool,
+    /// Wallet that can hide cards and comments besides the authority,
+    /// or default for none (v1+)
+    pub moderator: Pubkey,
 }
 
 impl Collection {
@@ -33,9 +44,24 @@ impl Collection {
         + 32                    // treasury
         + 1                     // paused
         + 8                     // created_at
-        + 1;                    // bump
+        + 1                     // bump
+        + 1                     // version
+        + 1                     // metadata_locked
+        + 32;                   // moderator
+
+    /// Size of the version 0 layout, before the version byte was added.
+    pub const LEGACY_SIZE: usize = Self::SIZE - 1 - 1 - 32;
+
+    pub fn needs_migration(&self) -> bool {
+        self.version < COLLECTION_VERSION
+    }
 
     pub fn can_mint(&self) -> bool {
         !self.paused && (self.max_supply == 0 || self.total_minted < self.max_supply)
     }
+
+    /// The authority can always moderate; the moderator only if one is set.
+    pub fn is_moderator(&self, key: &Pubkey) -> bool {
+        *key == self.authority || (self.moderator != Pubkey::default() && *key == self.moderator)
+    }
 }
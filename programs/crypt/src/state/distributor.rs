@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+
+/// A merkle airdrop to card holders from a snapshot. Allocations are the
+/// leaves of `merkle_root`; each holder claims once with a proof.
+/// SOL distributions hold the lamports in this account; SPL ones in a
+/// token account owned by it.
+/// PDA: seeds = [b"distributor", distributor_id.to_le_bytes()]
+#[account]
+pub struct Distributor {
+    /// Caller-chosen ID, part of the PDA seeds
+    pub distributor_id: u64,
+    /// Collection authority that created and funded the distribution
+    pub authority: Pubkey,
+    /// Root of the allocation tree (see `distribution_leaf`)
+    pub merkle_root: [u8; 32],
+    /// SPL mint being distributed, or None for SOL
+    pub mint: Option<Pubkey>,
+    /// Token account holding an SPL distribution, or default for SOL
+    pub vault: Pubkey,
+    /// Amount funded, in lamports or token base units
+    pub total_amount: u64,
+    /// Amount claimed so far
+    pub claimed_amount: u64,
+    /// Number of claims made
+    pub num_claimed: u64,
+    /// Slot the holder snapshot was taken at (informational)
+    pub snapshot_slot: u64,
+    /// Unix timestamp after which claims stop and the authority can
+    /// take back what's left, or 0 for no deadline
+    pub claim_deadline: i64,
+    /// Unix timestamp the distributor was created
+    pub created_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Distributor {
+    pub const SIZE: usize = 8   // distributor_id
+        + 32                    // authority
+        + 32                    // merkle_root
+        + (1 + 32)             // mint (Option<Pubkey>)
+        + 32                    // vault
+        + 8                     // total_amount
+        + 8                     // claimed_amount
+        + 8                     // num_claimed
+        + 8                     // snapshot_slot
+        + 8                     // claim_deadline
+        + 8                     // created_at
+        + 1;                    // bump
+
+    /// Whether claims are still accepted at `now`.
+    pub fn is_open(&self, now: i64) -> bool {
+        self.claim_deadline == 0 || now <= self.claim_deadline
+    }
+}
+
+/// Marks a holder's allocation as claimed.
+/// PDA: seeds = [b"claim", distributor.key().as_ref(), claimant.key().as_ref()]
+#[account]
+pub struct ClaimStatus {
+    /// Distributor the claim was made against
+    pub distributor: Pubkey,
+    /// Wallet that claimed
+    pub claimant: Pubkey,
+    /// Amount paid out
+    pub amount: u64,
+    /// Unix timestamp of the claim
+    pub claimed_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ClaimStatus {
+    pub const SIZE: usize = 32  // distributor
+        + 32                    // claimant
+        + 8                     // amount
+        + 8                     // claimed_at
+        + 1;                    // bump
+}
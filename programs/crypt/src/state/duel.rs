@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use crate::utils::Duelist;
+
+/// An open duel between two cards, played with commit-reveal.
+/// The challenger commits when opening it, the opponent commits when
+/// accepting, then both reveal. Closed once resolved or cancelled.
+///
+/// Both cards fight as they were when the challenge was issued: their
+/// seeds, rarities and types are copied in here, and only the wallets
+/// recorded here can reveal. Transferring, upgrading or burning a card
+/// mid-duel doesn't change the fight.
+/// PDA: seeds = [b"duel", challenger_card.key().as_ref()]
+#[account]
+pub struct Duel {
+    /// Card that issued the challenge
+    pub challenger_card: Pubkey,
+    /// Card being challenged
+    pub opponent_card: Pubkey,
+    /// Wallet holding the challenger card; receives the rent on close
+    pub challenger: Pubkey,
+    /// Wallet holding the opponent card (default until accepted)
+    pub opponent: Pubkey,
+    /// Challenger card's soul seed at challenge time
+    pub challenger_seed: [u8; 32],
+    /// Opponent card's soul seed at challenge time
+    pub opponent_seed: [u8; 32],
+    /// Challenger card's rarity at challenge time
+    pub challenger_rarity: u8,
+    /// Opponent card's rarity at challenge time
+    pub opponent_rarity: u8,
+    /// Challenger card's type
+    pub challenger_card_type: u8,
+    /// Opponent card's type
+    pub opponent_card_type: u8,
+    /// duel_commitment(challenger_card, challenger nonce)
+    pub challenger_commitment: [u8; 32],
+    /// duel_commitment(opponent_card, opponent nonce)
+    pub opponent_commitment: [u8; 32],
+    /// Challenger's revealed nonce
+    pub challenger_reveal: Option<[u8; 32]>,
+    /// Opponent's revealed nonce
+    pub opponent_reveal: Option<[u8; 32]>,
+    /// Unix timestamp the challenge was issued
+    pub created_at: i64,
+    /// Unix timestamp the challenge was accepted (0 until then)
+    pub accepted_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Duel {
+    pub const SIZE: usize = 32  // challenger_card
+        + 32                    // opponent_card
+        + 32                    // challenger
+        + 32                    // opponent
+        + 32                    // challenger_seed
+        + 32                    // opponent_seed
+        + 1                     // challenger_rarity
+        + 1                     // opponent_rarity
+        + 1                     // challenger_card_type
+        + 1                     // opponent_card_type
+        + 32                    // challenger_commitment
+        + 32                    // opponent_commitment
+        + (1 + 32)             // challenger_reveal
+        + (1 + 32)             // opponent_reveal
+        + 8                     // created_at
+        + 8                     // accepted_at
+        + 1;                    // bump
+
+    pub fn is_accepted(&self) -> bool {
+        self.accepted_at != 0
+    }
+
+    /// The challenger as it entered the duel.
+    pub fn challenger_duelist(&self) -> Duelist {
+        Duelist::new(&self.challenger_seed, self.challenger_rarity, self.challenger_card_type)
+    }
+
+    /// The opponent as it was when challenged.
+    pub fn opponent_duelist(&self) -> Duelist {
+        Duelist::new(&self.opponent_seed, self.opponent_rarity, self.opponent_card_type)
+    }
+}
+
+/// Per-card duel history.
+/// PDA: seeds = [b"duel_record", card.key().as_ref()]
+#[account]
+pub struct DuelRecord {
+    /// Card this record belongs to
+    pub card: Pubkey,
+    /// Duels won
+    pub wins: u32,
+    /// Duels lost
+    pub losses: u32,
+    /// Duels drawn
+    pub draws: u32,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl DuelRecord {
+    pub const SIZE: usize = 32  // card
+        + 4                     // wins
+        + 4                     // losses
+        + 4                     // draws
+        + 1;                    // bump
+}
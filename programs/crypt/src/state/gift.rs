@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+/// Key-locked escrow for a gifted card.
+/// While it exists the card's `owner` is this PDA, so every owner-gated
+/// instruction rejects the sender. A claim must be signed by `claim_key`,
+/// an ephemeral keypair whose secret travels in the gift link; whoever
+/// lands such a claim before `expires_at` becomes the owner. After that
+/// the sender can take the card back.
+/// PDA: seeds = [b"gift", card.key().as_ref()]
+#[account]
+pub struct Gift {
+    /// The gifted card
+    pub card: Pubkey,
+    /// Wallet that created the gift and gets the card back if unclaimed
+    pub sender: Pubkey,
+    /// Public key of the ephemeral keypair that must sign the claim
+    pub claim_key: Pubkey,
+    /// Unix timestamp the gift was created
+    pub created_at: i64,
+    /// Unix timestamp after which the gift can no longer be claimed
+    pub expires_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Gift {
+    pub const SIZE: usize = 32  // card
+        + 32                    // sender
+        + 32                    // claim_key
+        + 8                     // created_at
+        + 8                     // expires_at
+        + 1;                    // bump
+}
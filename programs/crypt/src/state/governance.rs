@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+
+/// Most options a proposal can offer.
+pub const MAX_PROPOSAL_OPTIONS: usize = 8;
+
+/// Longest proposal title, in bytes.
+pub const MAX_PROPOSAL_TITLE_LEN: usize = 64;
+
+/// Longest option label, in bytes.
+pub const MAX_OPTION_LABEL_LEN: usize = 32;
+
+/// A community vote, e.g. "Season 2 theme". Holders vote with cards
+/// minted before it opened, one vote each; the tallies fill in as
+/// votes arrive and the winner is fixed once the window closes and the
+/// proposal is finalized.
+/// PDA: seeds = [b"proposal", proposal_id.to_le_bytes()]
+#[account]
+pub struct Proposal {
+    /// Caller-chosen ID, part of the PDA seeds
+    pub proposal_id: u64,
+    /// Collection authority that opened the proposal
+    pub authority: Pubkey,
+    /// What's being decided
+    pub title: String,
+    /// Option labels, indexed by the vote's `option`
+    pub options: Vec<String>,
+    /// Vote weight per option, same order as `options`
+    pub tallies: [u64; MAX_PROPOSAL_OPTIONS],
+    /// Number of cards that have voted
+    pub cards_voted: u64,
+    /// Unix timestamp voting opens (inclusive)
+    pub starts_at: i64,
+    /// Unix timestamp voting closes (exclusive)
+    pub ends_at: i64,
+    /// Whether the result has been fixed
+    pub finalized: bool,
+    /// Option with the most weight, or None for no votes or a tie
+    pub winning_option: Option<u8>,
+    /// Unix timestamp the proposal was created
+    pub created_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Proposal {
+    pub const SIZE: usize = 8   // proposal_id
+        + 32                    // authority
+        + (4 + MAX_PROPOSAL_TITLE_LEN)                               // title
+        + 4 + MAX_PROPOSAL_OPTIONS * (4 + MAX_OPTION_LABEL_LEN)      // options (Vec<String>)
+        + 8 * MAX_PROPOSAL_OPTIONS                                   // tallies
+        + 8                     // cards_voted
+        + 8                     // starts_at
+        + 8                     // ends_at
+        + 1                     // finalized
+        + (1 + 1)              // winning_option (Option<u8>)
+        + 8                     // created_at
+        + 1;                    // bump
+
+    /// Whether votes are accepted at `now`.
+    pub fn is_voting(&self, now: i64) -> bool {
+        !self.finalized && self.starts_at <= now && now < self.ends_at
+    }
+}
+
+/// Records that a card voted on a proposal. Keyed by the card rather
+/// than the wallet, so a card that changes hands can't vote again.
+/// PDA: seeds = [b"vote", proposal.key().as_ref(), card.key().as_ref()]
+#[account]
+pub struct VoteRecord {
+    /// Proposal voted on
+    pub proposal: Pubkey,
+    /// Card account that voted
+    pub card: Pubkey,
+    /// Wallet that owned the card when it voted
+    pub voter: Pubkey,
+    /// Option chosen
+    pub option: u8,
+    /// Weight the card contributed
+    pub weight: u64,
+    /// Unix timestamp of the vote
+    pub voted_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl VoteRecord {
+    pub const SIZE: usize = 32  // proposal
+        + 32                    // card
+        + 32                    // voter
+        + 1                     // option
+        + 8                     // weight
+        + 8                     // voted_at
+        + 1;                    // bump
+}
@@ -19,6 +19,17 @@ impl InteractionType {
             _ => None,
         }
     }
+
+    /// Likes, shares and bookmarks can be folded into `CardSocial`
+    /// counters and its interaction log; comments need their own account.
+    pub fn is_compact(&self) -> bool {
+        !matches!(self, InteractionType::Comment)
+    }
+
+    /// This type's bit in `ReactRecord::reacted`.
+    pub fn react_flag(&self) -> u8 {
+        1 << (*self as u8)
+    }
 }
 
 /// On-chain record of a social interaction with a Crypt Card.
@@ -42,3 +53,49 @@ pub struct Interaction {
 impl Interaction {
     pub const SIZE: usize = 32 + 32 + 1 + 32 + 8 + 1;
 }
+
+/// Per-card counters for compact interactions, each user counted once
+/// per type through `ReactRecord`, plus a hash chain over every compact
+/// interaction so off-chain records can be checked against the chain.
+/// One account per card, paid by its first reactor.
+/// PDA: seeds = [b"social", card.key().as_ref()]
+#[account]
+pub struct CardSocial {
+    /// The card these counters belong to
+    pub card: Pubkey,
+    pub likes: u64,
+    pub shares: u64,
+    pub bookmarks: u64,
+    /// Running hash of the interaction log (see `fold_interaction_log`)
+    pub log_root: [u8; 32],
+    /// Number of entries folded into `log_root`
+    pub log_len: u64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+/// Which compact interactions one user has made on one card, so each
+/// counts once. Two bytes of data, shared by all three reaction types.
+/// PDA: seeds = [b"react", card.key().as_ref(), user.key().as_ref()]
+#[account]
+pub struct ReactRecord {
+    /// Bitmask of `InteractionType::react_flag` values already recorded
+    pub reacted: u8,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl ReactRecord {
+    pub const SIZE: usize = 1   // reacted
+        + 1;                    // bump
+}
+
+impl CardSocial {
+    pub const SIZE: usize = 32  // card
+        + 8                     // likes
+        + 8                     // shares
+        + 8                     // bookmarks
+        + 32                    // log_root
+        + 8                     // log_len
+        + 1;                    // bump
+}
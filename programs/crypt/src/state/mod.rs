@@ -1,7 +1,27 @@
 pub mod collection;
 pub mod card;
 pub mod interaction;
+pub mod tombstone;
+pub mod achievement;
+pub mod staking;
+pub mod gift;
+pub mod duel;
+pub mod boost;
+pub mod moderation;
+pub mod distributor;
+pub mod governance;
+pub mod wrap;
 
 pub use collection::*;
 pub use card::*;
 pub use interaction::*;
+pub use tombstone::*;
+pub use achievement::*;
+pub use staking::*;
+pub use gift::*;
+pub use duel::*;
+pub use boost::*;
+pub use moderation::*;
+pub use distributor::*;
+pub use governance::*;
+pub use wrap::*;
@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+
+/// Why a user reported a card title or comment.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ReportReason {
+    Spam = 0,
+    Scam = 1,
+    Hate = 2,
+    Harassment = 3,
+    Nsfw = 4,
+    Other = 5,
+}
+
+impl ReportReason {
+    pub fn from_u8(v: u8) -> Option<Self> {
+        match v {
+            0 => Some(ReportReason::Spam),
+            1 => Some(ReportReason::Scam),
+            2 => Some(ReportReason::Hate),
+            3 => Some(ReportReason::Harassment),
+            4 => Some(ReportReason::Nsfw),
+            5 => Some(ReportReason::Other),
+            _ => None,
+        }
+    }
+}
+
+/// What a report or moderation action points at.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ModerationTarget {
+    /// A card — its title is the user-written text
+    Card = 0,
+    /// A comment `Interaction` account
+    Comment = 1,
+}
+
+/// One user's report against a card or comment. One per (target, reporter),
+/// so a single wallet can't pile up reports.
+/// PDA: seeds = [b"report", target.as_ref(), reporter.key().as_ref()]
+#[account]
+pub struct Report {
+    /// Reported card or comment account
+    pub target: Pubkey,
+    /// Wallet that filed the report
+    pub reporter: Pubkey,
+    /// ReportReason as u8
+    pub reason: u8,
+    /// Unix timestamp the report was filed
+    pub created_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Report {
+    pub const SIZE: usize = 32  // target
+        + 32                    // reporter
+        + 1                     // reason
+        + 8                     // created_at
+        + 1;                    // bump
+}
+
+/// Moderation state for a card or comment: report tally and the
+/// `hidden` flag set by the collection's moderator. Hidden content stays
+/// on-chain; indexers drop it from public feeds.
+/// PDA: seeds = [b"moderation", target.as_ref()]
+#[account]
+pub struct Moderation {
+    /// The card or comment account this applies to
+    pub target: Pubkey,
+    /// ModerationTarget as u8
+    pub kind: u8,
+    /// Card the target is, or the card the comment is on
+    pub card: Pubkey,
+    /// Whether a moderator has hidden the content
+    pub hidden: bool,
+    /// Number of reports filed
+    pub report_count: u32,
+    /// Reason of the most recent report or moderation action
+    pub last_reason: u8,
+    /// Moderator who last changed `hidden`, or default if never
+    pub moderator: Pubkey,
+    /// Unix timestamp of the last change
+    pub updated_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Moderation {
+    pub const SIZE: usize = 32  // target
+        + 1                     // kind
+        + 32                    // card
+        + 1                     // hidden
+        + 4                     // report_count
+        + 1                     // last_reason
+        + 32                    // moderator
+        + 8                     // updated_at
+        + 1;                    // bump
+}
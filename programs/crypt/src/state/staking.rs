@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+/// Collection-wide staking settings, set by the collection authority.
+/// PDA: seeds = [b"staking_config"]
+#[account]
+pub struct StakingConfig {
+    /// Collection this config belongs to
+    pub collection: Pubkey,
+    /// Points per day earned by a Common card with no interactions
+    pub base_rate_per_day: u64,
+    /// SPL mint points can be redeemed for — its mint authority must be this PDA
+    pub reward_mint: Option<Pubkey>,
+    /// Reward tokens (base units) minted per redeemed point
+    pub tokens_per_point: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl StakingConfig {
+    pub const SIZE: usize = 32  // collection
+        + 8                     // base_rate_per_day
+        + (1 + 32)             // reward_mint (Option<Pubkey>)
+        + 8                     // tokens_per_point
+        + 1;                    // bump
+}
+
+/// Custody record for a staked ("haunting") card.
+/// While it exists the card's `owner` is this PDA, so every owner-gated
+/// instruction (transfer, burn, upgrade) rejects the staker.
+/// PDA: seeds = [b"stake", card.key().as_ref()]
+#[account]
+pub struct StakeRecord {
+    /// The staked card
+    pub card: Pubkey,
+    /// Wallet that staked the card and receives it back on unstake
+    pub staker: Pubkey,
+    /// Unix timestamp the card was staked
+    pub staked_at: i64,
+    /// Unix timestamp points were last settled up to
+    pub last_claimed_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl StakeRecord {
+    pub const SIZE: usize = 32  // card
+        + 32                    // staker
+        + 8                     // staked_at
+        + 8                     // last_claimed_at
+        + 1;                    // bump
+}
+
+/// Per-wallet points balance earned from staking.
+/// PDA: seeds = [b"points", owner.key().as_ref()]
+#[account]
+pub struct PointsAccount {
+    /// Wallet the points belong to
+    pub owner: Pubkey,
+    /// Points available to redeem
+    pub balance: u64,
+    /// Lifetime points earned
+    pub total_earned: u64,
+    /// Lifetime points redeemed for reward tokens
+    pub total_redeemed: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl PointsAccount {
+    pub const SIZE: usize = 32  // owner
+        + 8                     // balance
+        + 8                     // total_earned
+        + 8                     // total_redeemed
+        + 1;                    // bump
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+/// A grave marker left behind when a Crypt Card is burned.
+/// Keeps the card's identity after the card account itself is closed.
+/// PDA: seeds = [b"tombstone", mint_id.to_le_bytes().as_ref()]
+#[account]
+pub struct Tombstone {
+    /// Owner of the card at the moment it was burned
+    pub last_owner: Pubkey,
+    /// Sequential mint ID of the burned card
+    pub mint_id: u64,
+    /// SHA-256 digest of the card's tx_hash
+    pub tx_hash_digest: [u8; 32],
+    /// Rarity tier at burn time
+    pub rarity: u8,
+    /// Transaction type classification
+    pub card_type: u8,
+    /// Unix timestamp of the burn
+    pub burned_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Tombstone {
+    pub const SIZE: usize = 32  // last_owner
+        + 8                     // mint_id
+        + 32                    // tx_hash_digest
+        + 1                     // rarity
+        + 1                     // card_type
+        + 8                     // burned_at
+        + 1;                    // bump
+}
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+/// Custody record for a card wrapped as a Metaplex NFT. While it exists
+/// the card's `owner` is this record, so owner-gated instructions are
+/// locked; whoever holds the NFT can unwrap the card back out.
+/// PDA: seeds = [b"wrap", nft_mint.key().as_ref()]
+#[account]
+pub struct WrapRecord {
+    /// The wrapped card account
+    pub card: Pubkey,
+    /// The 1/1 NFT mint standing in for the card
+    pub nft_mint: Pubkey,
+    /// Wallet that wrapped the card (gets this account's rent back)
+    pub wrapper: Pubkey,
+    /// Unix timestamp of the wrap
+    pub wrapped_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl WrapRecord {
+    pub const SIZE: usize = 32  // card
+        + 32                    // nft_mint
+        + 32                    // wrapper
+        + 8                     // wrapped_at
+        + 1;                    // bump
+}
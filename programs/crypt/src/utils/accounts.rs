@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+/// Create a program-owned PDA account from inside an instruction.
+/// Used for accounts whose addresses are only known at runtime
+/// (e.g., passed through `remaining_accounts`), where `#[account(init)]`
+/// can't be used.
+///
+/// Handles PDAs that were pre-funded by a third party, so nobody can block
+/// an address by sending it lamports ahead of time.
+pub fn create_pda_account<'info>(
+    payer: &AccountInfo<'info>,
+    new_account: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    let required = Rent::get()?.minimum_balance(space);
+    let current = new_account.lamports();
+    let signer = &[signer_seeds];
+
+    if current == 0 {
+        system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                system_program::CreateAccount {
+                    from: payer.clone(),
+                    to: new_account.clone(),
+                },
+                signer,
+            ),
+            required,
+            space as u64,
+            &crate::ID,
+        )?;
+        return Ok(());
+    }
+
+    if required > current {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: new_account.clone(),
+                },
+            ),
+            required - current,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::Allocate { account_to_allocate: new_account.clone() },
+            signer,
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::Assign { account_to_assign: new_account.clone() },
+            signer,
+        ),
+        &crate::ID,
+    )?;
+    Ok(())
+}
+
+/// Grow an account to `new_len` bytes, topping its rent up from `payer`.
+/// The new bytes are zeroed. Does nothing if the account is already big
+/// enough.
+pub fn grow_account<'info>(
+    payer: &AccountInfo<'info>,
+    account: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    new_len: usize,
+) -> Result<()> {
+    if account.data_len() >= new_len {
+        return Ok(());
+    }
+    let shortfall = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(account.lamports());
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+    account.realloc(new_len, true)?;
+    Ok(())
+}
+
+/// Zero an account's bytes past the end of `value`'s serialized form.
+/// Anchor writes an account back over its old bytes without clearing the
+/// rest, so a shorter string would leave stale bytes where appended fields
+/// are later read.
+pub fn zero_tail<T: AnchorSerialize>(account: &AccountInfo, value: &T) -> Result<()> {
+    let len = 8 + value.try_to_vec()?.len();
+    let mut data = account.try_borrow_mut_data()?;
+    if let Some(tail) = data.get_mut(len..) {
+        tail.fill(0);
+    }
+    Ok(())
+}
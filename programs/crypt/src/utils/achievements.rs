@@ -0,0 +1,97 @@
+//! Achievement set rules — decides whether a hand of cards completes a set.
+//!
+//! Type requirements count cards of exactly that type.
+//! Rarity requirements count cards at or above that tier, so a Legendary
+//! also counts toward a "three Rares" set.
+
+/// Check a list of `(rarity, card_type)` pairs against a set's requirements.
+pub fn meets_requirements(
+    min_per_type: &[u8; 5],
+    min_per_rarity: &[u8; 3],
+    cards: &[(u8, u8)],
+) -> bool {
+    let mut type_counts = [0u32; 5];
+    let mut rarity_counts = [0u32; 3];
+
+    for &(rarity, card_type) in cards {
+        if let Some(count) = type_counts.get_mut(card_type as usize) {
+            *count += 1;
+        }
+        for (tier, count) in rarity_counts.iter_mut().enumerate() {
+            if rarity as usize >= tier {
+                *count += 1;
+            }
+        }
+    }
+
+    type_counts.iter().zip(min_per_type).all(|(have, need)| *have >= *need as u32)
+        && rarity_counts.iter().zip(min_per_rarity).all(|(have, need)| *have >= *need as u32)
+}
+
+/// A set with no requirements would hand out badges for nothing.
+pub fn has_requirements(min_per_type: &[u8; 5], min_per_rarity: &[u8; 3]) -> bool {
+    min_per_type.iter().any(|&n| n > 0) || min_per_rarity.iter().any(|&n| n > 0)
+}
+
+/// Fewest cards that can complete a set. Type requirements add up, since
+/// a card has one type; rarity tiers overlap, so only the largest counts.
+pub fn min_cards(min_per_type: &[u8; 5], min_per_rarity: &[u8; 3]) -> u32 {
+    let types: u32 = min_per_type.iter().map(|&n| n as u32).sum();
+    let rarities = min_per_rarity.iter().map(|&n| n as u32).max().unwrap_or(0);
+    types.max(rarities)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FULL_SET: [u8; 5] = [1, 1, 1, 1, 1];
+    const THREE_LEGENDARIES: [u8; 3] = [0, 0, 3];
+
+    #[test]
+    fn test_one_of_every_type() {
+        let cards = [(0, 0), (0, 1), (1, 2), (0, 3), (2, 4)];
+        assert!(meets_requirements(&FULL_SET, &[0; 3], &cards));
+    }
+
+    #[test]
+    fn test_missing_type_fails() {
+        let cards = [(0, 0), (0, 1), (1, 2), (0, 3), (2, 3)];
+        assert!(!meets_requirements(&FULL_SET, &[0; 3], &cards));
+    }
+
+    #[test]
+    fn test_three_legendaries() {
+        let cards = [(2, 0), (2, 0), (2, 4)];
+        assert!(meets_requirements(&[0; 5], &THREE_LEGENDARIES, &cards));
+        assert!(!meets_requirements(&[0; 5], &THREE_LEGENDARIES, &cards[..2]));
+    }
+
+    #[test]
+    fn test_legendary_counts_toward_rare() {
+        let cards = [(1, 0), (2, 1)];
+        assert!(meets_requirements(&[0; 5], &[0, 2, 0], &cards));
+    }
+
+    #[test]
+    fn test_rare_does_not_count_toward_legendary() {
+        let cards = [(1, 0), (1, 1), (1, 2)];
+        assert!(!meets_requirements(&[0; 5], &THREE_LEGENDARIES, &cards));
+    }
+
+    #[test]
+    fn test_empty_set_has_no_requirements() {
+        assert!(!has_requirements(&[0; 5], &[0; 3]));
+        assert!(has_requirements(&FULL_SET, &[0; 3]));
+        assert!(has_requirements(&[0; 5], &THREE_LEGENDARIES));
+    }
+
+    #[test]
+    fn test_min_cards() {
+        assert_eq!(min_cards(&FULL_SET, &[0; 3]), 5);
+        assert_eq!(min_cards(&FULL_SET, &THREE_LEGENDARIES), 5);
+        assert_eq!(min_cards(&[0; 5], &[4, 3, 3]), 4);
+        assert_eq!(min_cards(&[3, 3, 3, 3, 0], &[0; 3]), 12);
+        assert_eq!(min_cards(&[0; 5], &[0, 0, 11]), 11);
+    }
+}
@@ -0,0 +1,82 @@
+//! Event boosts — how an active event changes a minted card's rarity.
+//!
+//! The first event in table order that is active at mint time and
+//! matches the card type applies. Its score bonus is added to the
+//! client-computed score, which can lift the rarity tier (never lower
+//! it); then its tier bonus is added, capped at Legendary. The SDK's
+//! `RarityScorer::score_boosted` mirrors these rules.
+//!
+//! The score is the minter's own claim, exactly as trusted as the rarity
+//! they mint with, so a score bonus is no stronger a guarantee than the
+//! tier the client picked. Only the tier bonus is independent of it.
+
+use crate::state::BoostEvent;
+use crate::utils::score_to_rarity;
+
+/// Highest rarity tier (Legendary).
+const MAX_RARITY: u8 = 2;
+
+/// The event that applies to a card of `card_type` minted at `now`.
+pub fn active_boost(events: &[BoostEvent], card_type: u8, now: i64) -> Option<&BoostEvent> {
+    events.iter().find(|e| e.is_active(now) && e.applies_to(card_type))
+}
+
+/// Rarity after applying `event` to a card minted with `rarity` and `score`.
+pub fn boosted_rarity(rarity: u8, score: u32, event: &BoostEvent) -> u8 {
+    let mut boosted = rarity;
+    if event.score_bonus > 0 {
+        boosted = boosted.max(score_to_rarity(score.saturating_add(event.score_bonus)));
+    }
+    boosted.saturating_add(event.tier_bonus).min(MAX_RARITY)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(event_id: u16, card_type: Option<u8>, score_bonus: u32, tier_bonus: u8) -> BoostEvent {
+        BoostEvent {
+            event_id,
+            name: "HALLOWEEN".into(),
+            starts_at: 1_000,
+            ends_at: 2_000,
+            card_type,
+            score_bonus,
+            tier_bonus,
+        }
+    }
+
+    #[test]
+    fn test_active_boost_window_and_type() {
+        let events = vec![event(1, Some(1), 15, 0)];
+        assert!(active_boost(&events, 1, 1_000).is_some());
+        assert!(active_boost(&events, 1, 1_999).is_some());
+        assert!(active_boost(&events, 1, 2_000).is_none(), "ends_at is exclusive");
+        assert!(active_boost(&events, 1, 999).is_none());
+        assert!(active_boost(&events, 0, 1_500).is_none(), "wrong card type");
+    }
+
+    #[test]
+    fn test_first_matching_event_wins() {
+        let events = vec![event(1, Some(0), 5, 0), event(2, None, 15, 0), event(3, None, 30, 0)];
+        assert_eq!(active_boost(&events, 1, 1_500).unwrap().event_id, 2);
+        assert_eq!(active_boost(&events, 0, 1_500).unwrap().event_id, 1);
+    }
+
+    #[test]
+    fn test_score_bonus_can_cross_threshold() {
+        // 30 + 15 = 45 → Rare
+        assert_eq!(boosted_rarity(0, 30, &event(1, None, 15, 0)), 1);
+        // 10 + 15 = 25 → still Common
+        assert_eq!(boosted_rarity(0, 10, &event(1, None, 15, 0)), 0);
+        // Never lowers a rarity the score alone wouldn't justify
+        assert_eq!(boosted_rarity(2, 0, &event(1, None, 15, 0)), 2);
+    }
+
+    #[test]
+    fn test_tier_bonus_capped() {
+        assert_eq!(boosted_rarity(0, 0, &event(1, None, 0, 1)), 1);
+        assert_eq!(boosted_rarity(1, 0, &event(1, None, 0, 2)), 2);
+        assert_eq!(boosted_rarity(2, 0, &event(1, None, 0, 1)), 2);
+    }
+}
@@ -0,0 +1,4 @@
+//! Duel rules — shared with the SDK's offline simulator through
+//! `crypt-core`, which documents how power, type counters and luck work.
+
+pub use crypt_core::duel::*;
@@ -0,0 +1,90 @@
+//! Governance — proposal checks, card eligibility and picking a winner.
+//!
+//! Every card counts once. A card's rarity is chosen by its minter and
+//! never checked on-chain, so it doesn't weigh the vote; and only cards
+//! minted before a proposal opened can vote on it, so fresh mints can't
+//! swing a live vote. A proposal with no votes, or with two options
+//! tied for the most weight, has no winner.
+
+use crate::state::{MAX_OPTION_LABEL_LEN, MAX_PROPOSAL_OPTIONS, MAX_PROPOSAL_TITLE_LEN};
+
+/// Most cards one `cast_vote` can carry.
+pub const MAX_VOTE_CARDS: usize = 10;
+
+/// Longest voting window allowed.
+pub const MAX_VOTING_PERIOD: i64 = 30 * 24 * 60 * 60;
+
+/// Vote weight of one card.
+pub const CARD_VOTE_WEIGHT: u64 = 1;
+
+/// Whether a card minted at `minted_at` may vote on a proposal created
+/// at `created_at`.
+pub fn predates_proposal(minted_at: i64, created_at: i64) -> bool {
+    minted_at < created_at
+}
+
+/// Whether a proposal's title, options and window are acceptable when
+/// created at `now`.
+pub fn is_valid_proposal(title: &str, options: &[String], starts_at: i64, ends_at: i64, now: i64) -> bool {
+    !title.is_empty()
+        && title.len() <= MAX_PROPOSAL_TITLE_LEN
+        && (2..=MAX_PROPOSAL_OPTIONS).contains(&options.len())
+        && options.iter().all(|o| !o.is_empty() && o.len() <= MAX_OPTION_LABEL_LEN)
+        && ends_at > now
+        && starts_at < ends_at
+        && ends_at - starts_at.max(now) <= MAX_VOTING_PERIOD
+}
+
+/// The option with strictly the most weight among the first `num_options`.
+pub fn winning_option(tallies: &[u64], num_options: usize) -> Option<u8> {
+    let tallies = &tallies[..num_options.min(tallies.len())];
+    let best = *tallies.iter().max()?;
+    if best == 0 || tallies.iter().filter(|&&t| t == best).count() > 1 {
+        return None;
+    }
+    tallies.iter().position(|&t| t == best).map(|i| i as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: i64 = 86_400;
+
+    fn options(n: usize) -> Vec<String> {
+        (0..n).map(|i| format!("OPTION {}", i)).collect()
+    }
+
+    #[test]
+    fn test_only_older_cards_vote() {
+        let created_at = 1_000_000;
+        assert!(predates_proposal(created_at - 1, created_at));
+        assert!(!predates_proposal(created_at, created_at), "Minted in the same second");
+        assert!(!predates_proposal(created_at + DAY, created_at));
+    }
+
+    #[test]
+    fn test_proposal_validation() {
+        let now = 1_000_000;
+        assert!(is_valid_proposal("SEASON 2 THEME", &options(2), now, now + DAY, now));
+        assert!(is_valid_proposal("SEASON 2 THEME", &options(8), now + DAY, now + 2 * DAY, now));
+
+        assert!(!is_valid_proposal("", &options(2), now, now + DAY, now));
+        assert!(!is_valid_proposal(&"X".repeat(65), &options(2), now, now + DAY, now));
+        assert!(!is_valid_proposal("ONE CHOICE", &options(1), now, now + DAY, now));
+        assert!(!is_valid_proposal("TOO MANY", &options(9), now, now + DAY, now));
+        assert!(!is_valid_proposal("BLANK", &["A".into(), "".into()], now, now + DAY, now));
+        assert!(!is_valid_proposal("ENDED", &options(2), now - 2 * DAY, now - DAY, now));
+        assert!(!is_valid_proposal("BACKWARDS", &options(2), now + DAY, now + DAY, now));
+        assert!(!is_valid_proposal("FOREVER", &options(2), now, now + 31 * DAY, now));
+    }
+
+    #[test]
+    fn test_winning_option() {
+        assert_eq!(winning_option(&[3, 7, 2, 0, 0, 0, 0, 0], 3), Some(1));
+        assert_eq!(winning_option(&[0; 8], 4), None, "no votes");
+        assert_eq!(winning_option(&[5, 5, 1, 0, 0, 0, 0, 0], 3), None, "tie");
+        // Weight past the proposal's options is ignored
+        assert_eq!(winning_option(&[1, 0, 9, 0, 0, 0, 0, 0], 2), Some(0));
+    }
+}
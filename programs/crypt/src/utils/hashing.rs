@@ -1,128 +1,102 @@
-use anchor_lang::prelude::*;
+// Soul seeds and upgrade proofs are shared with every client through crypt-core
+pub use crypt_core::soul::{compute_soul_seed, verify_upgrade_proof};
 
-/// Compute a deterministic 32-byte soul seed from a transaction hash.
-/// This seed drives the generative art engine — same tx_hash always
-/// produces the same Soul Signature art.
-///
-/// Uses a simple but effective hash cascade:
-///   1. XOR-fold the tx_hash bytes into 32 positions
-///   2. Apply bit mixing (multiply, shift, XOR) for avalanche
-///   3. Result is a uniformly distributed 32-byte seed
-///
-/// This is NOT cryptographic — it's a fast, deterministic seed generator
-/// optimized for generating visual parameters (colors, shapes, positions).
-pub fn compute_soul_seed(tx_hash: &str) -> [u8; 32] {
-    let bytes = tx_hash.as_bytes();
-    let mut seed = [0u8; 32];
-
-    // Phase 1: XOR-fold input bytes across 32 positions
-    for (i, &b) in bytes.iter().enumerate() {
-        seed[i % 32] ^= b;
-    }
-
-    // Phase 2: Bit mixing — ensure small changes cascade
-    for i in 0..32 {
-        let mut h: u64 = seed[i] as u64;
-        h = h.wrapping_mul(0x517cc1b727220a95);
-        h ^= h >> 17;
-        h = h.wrapping_mul(0x6c62272e07bb0142);
-        h ^= h >> 11;
-        seed[i] = (h & 0xFF) as u8;
-    }
-
-    // Phase 3: Chain adjacent bytes for additional diffusion
-    for i in 1..32 {
-        seed[i] ^= seed[i - 1].wrapping_add(37);
-    }
-    // Reverse pass
-    for i in (0..31).rev() {
-        seed[i] ^= seed[i + 1].wrapping_add(53);
-    }
-
-    seed
+/// Duel commitment: SHA-256 of the card address followed by the
+/// 32-byte nonce the player will reveal. Binding the card stops a
+/// commitment from being replayed in another duel.
+pub fn duel_commitment(card: &[u8; 32], nonce: &[u8; 32]) -> [u8; 32] {
+    anchor_lang::solana_program::hash::hashv(&[card, nonce]).to_bytes()
 }
 
-/// Verify an upgrade proof against card data.
-/// In production, this would validate a Merkle proof from an oracle.
-/// For the hackathon, we use a simplified HMAC-like verification.
-pub fn verify_upgrade_proof(
-    tx_hash: &str,
-    soul_seed: &[u8; 32],
-    new_rarity: u8,
-    proof: &[u8; 32],
-) -> bool {
-    let mut expected = compute_soul_seed(tx_hash);
+/// Shared duel roll: SHA-256 of both revealed nonces, challenger first.
+pub fn duel_roll(challenger_nonce: &[u8; 32], opponent_nonce: &[u8; 32]) -> [u8; 32] {
+    anchor_lang::solana_program::hash::hashv(&[challenger_nonce, opponent_nonce]).to_bytes()
+}
 
-    // Mix in soul seed and target rarity
-    for i in 0..32 {
-        expected[i] ^= soul_seed[i];
-    }
-    expected[0] ^= new_rarity;
+/// Leaf for one compact interaction: SHA-256 of the card, the user,
+/// the interaction type and the timestamp it was recorded at.
+pub fn interaction_leaf(card: &[u8; 32], user: &[u8; 32], interaction_type: u8, timestamp: i64) -> [u8; 32] {
+    anchor_lang::solana_program::hash::hashv(&[
+        card,
+        user,
+        &[interaction_type],
+        &timestamp.to_le_bytes(),
+    ])
+    .to_bytes()
+}
 
-    // Apply mixing
-    for i in 0..32 {
-        let mut h: u64 = expected[i] as u64;
-        h = h.wrapping_mul(0x9e3779b97f4a7c15);
-        h ^= h >> 13;
-        expected[i] = (h & 0xFF) as u8;
-    }
+/// Append a leaf to a card's interaction log: SHA-256 of the previous
+/// root followed by the leaf. The empty log has an all-zero root.
+pub fn fold_interaction_log(root: &[u8; 32], leaf: &[u8; 32]) -> [u8; 32] {
+    anchor_lang::solana_program::hash::hashv(&[root, leaf]).to_bytes()
+}
 
-    expected == *proof
+/// SHA-256 commitment to a card's editable metadata — title, narration
+/// hash and soundtrack — so metadata edits can be audited from events.
+pub fn metadata_hash(title: &str, narration_hash: &[u8; 32], soundtrack_id: &str) -> [u8; 32] {
+    anchor_lang::solana_program::hash::hashv(&[
+        &(title.len() as u32).to_le_bytes(),
+        title.as_bytes(),
+        narration_hash,
+        &(soundtrack_id.len() as u32).to_le_bytes(),
+        soundtrack_id.as_bytes(),
+    ])
+    .to_bytes()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crypt_core::vectors::{SOUL_SEED_VECTORS, UPGRADE_PROOF_VECTORS};
 
     #[test]
-    fn test_soul_seed_deterministic() {
-        let tx = "4xK7m9pR2abc123def456";
-        let seed1 = compute_soul_seed(tx);
-        let seed2 = compute_soul_seed(tx);
-        assert_eq!(seed1, seed2, "Same tx should produce same seed");
+    fn test_metadata_hash_covers_every_field() {
+        let base = metadata_hash("TITLE", &[1; 32], "track");
+        assert_eq!(base, metadata_hash("TITLE", &[1; 32], "track"));
+        assert_ne!(base, metadata_hash("TITLE2", &[1; 32], "track"));
+        assert_ne!(base, metadata_hash("TITLE", &[2; 32], "track"));
+        assert_ne!(base, metadata_hash("TITLE", &[1; 32], "track2"));
     }
 
     #[test]
-    fn test_soul_seed_different_inputs() {
-        let seed1 = compute_soul_seed("tx_hash_A");
-        let seed2 = compute_soul_seed("tx_hash_B");
-        assert_ne!(seed1, seed2, "Different txs should produce different seeds");
+    fn test_metadata_hash_length_prefixed() {
+        // Moving bytes between title and soundtrack must change the hash
+        assert_ne!(
+            metadata_hash("ab", &[0; 32], "c"),
+            metadata_hash("a", &[0; 32], "bc")
+        );
     }
 
     #[test]
-    fn test_soul_seed_avalanche() {
-        // Changing one character should change many bytes
-        let seed1 = compute_soul_seed("abcdef123456");
-        let seed2 = compute_soul_seed("abcdef123457");
-        let diff_count = seed1.iter()
-            .zip(seed2.iter())
-            .filter(|(a, b)| a != b)
-            .count();
-        assert!(diff_count > 16, "One char change should affect >50% of bytes, got {}/32", diff_count);
+    fn test_interaction_leaf_binds_every_field() {
+        let leaf = interaction_leaf(&[1; 32], &[2; 32], 0, 100);
+        assert_ne!(leaf, interaction_leaf(&[9; 32], &[2; 32], 0, 100));
+        assert_ne!(leaf, interaction_leaf(&[1; 32], &[9; 32], 0, 100));
+        assert_ne!(leaf, interaction_leaf(&[1; 32], &[2; 32], 3, 100));
+        assert_ne!(leaf, interaction_leaf(&[1; 32], &[2; 32], 0, 101));
     }
 
     #[test]
-    fn test_upgrade_proof_valid() {
-        let tx = "test_tx_hash";
-        let seed = compute_soul_seed(tx);
-        // Generate a valid proof
-        let mut expected = compute_soul_seed(tx);
-        for i in 0..32 { expected[i] ^= seed[i]; }
-        expected[0] ^= 2; // legendary
-        for i in 0..32 {
-            let mut h: u64 = expected[i] as u64;
-            h = h.wrapping_mul(0x9e3779b97f4a7c15);
-            h ^= h >> 13;
-            expected[i] = (h & 0xFF) as u8;
+    fn test_interaction_log_order_matters() {
+        let a = interaction_leaf(&[1; 32], &[2; 32], 0, 100);
+        let b = interaction_leaf(&[1; 32], &[3; 32], 0, 100);
+        let ab = fold_interaction_log(&fold_interaction_log(&[0; 32], &a), &b);
+        let ba = fold_interaction_log(&fold_interaction_log(&[0; 32], &b), &a);
+        assert_ne!(ab, ba);
+    }
+
+    #[test]
+    fn test_soul_seed_matches_shared_vectors() {
+        for (tx_hash, seed) in SOUL_SEED_VECTORS {
+            assert_eq!(compute_soul_seed(tx_hash), *seed, "seed for {:?}", tx_hash);
         }
-        assert!(verify_upgrade_proof(tx, &seed, 2, &expected));
     }
 
     #[test]
-    fn test_upgrade_proof_invalid() {
-        let tx = "test_tx_hash";
-        let seed = compute_soul_seed(tx);
-        let fake_proof = [0u8; 32];
-        assert!(!verify_upgrade_proof(tx, &seed, 2, &fake_proof));
+    fn test_upgrade_proof_matches_shared_vectors() {
+        for v in UPGRADE_PROOF_VECTORS {
+            assert!(verify_upgrade_proof(v.tx_hash, &v.soul_seed, v.new_rarity, &v.proof));
+            assert!(!verify_upgrade_proof(v.tx_hash, &v.soul_seed, v.new_rarity ^ 3, &v.proof));
+        }
     }
 }
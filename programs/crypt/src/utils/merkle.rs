@@ -0,0 +1,5 @@
+//! Merkle proofs for distributor claims — shared with the SDK and the
+//! indexer's snapshot tool through `crypt-core`, which documents the
+//! leaf and node encodings.
+
+pub use crypt_core::merkle::{distribution_leaf, merkle_node, verify_merkle_proof, MAX_PROOF_LEN};
@@ -0,0 +1,319 @@
+//! Metaplex Token Metadata CPI — just enough to mint, describe and burn
+//! the 1/1 NFT that stands in for a wrapped card.
+//!
+//! The instructions are built by hand rather than through
+//! `mpl-token-metadata`, so the program doesn't pull in that crate's
+//! dependency tree for four calls. Layouts follow Token Metadata's
+//! `CreateMetadataAccountV3`, `CreateMasterEditionV3`,
+//! `UpdateMetadataAccountV2` and `BurnNft`.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::sysvar;
+use crate::state::CryptCard;
+
+/// Metaplex Token Metadata program.
+pub const TOKEN_METADATA_ID: Pubkey = pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+
+/// SPL Associated Token Account program.
+pub const ASSOCIATED_TOKEN_ID: Pubkey = pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH4e8eJ4uNv8knL");
+
+pub use crypt_core::nft::{metadata_base, NFT_SYMBOL};
+
+/// Token Metadata's limit on the on-chain name, in bytes.
+pub const MAX_NFT_NAME_LEN: usize = 32;
+
+const CREATE_METADATA_ACCOUNT_V3: u8 = 33;
+const CREATE_MASTER_EDITION_V3: u8 = 17;
+const UPDATE_METADATA_ACCOUNT_V2: u8 = 15;
+const BURN_NFT: u8 = 29;
+const CREATE_ATA_IDEMPOTENT: u8 = 1;
+
+/// Token Metadata's limit on the URI, in bytes.
+pub const MAX_NFT_URI_LEN: usize = 200;
+
+/// What a wrapped card's NFT says about it on chain. Token Metadata has
+/// no room for attributes, so those live in the JSON at `uri`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CardNft {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+}
+
+impl CardNft {
+    /// Name and URI from `card`'s mint ID and title, under the
+    /// collection's [`metadata_base`], and the collection-wide symbol.
+    pub fn for_card(card: &CryptCard, collection_uri: &str) -> Self {
+        Self {
+            name: nft_name(card.mint_id, &card.title),
+            symbol: NFT_SYMBOL.to_string(),
+            uri: card_metadata_uri(collection_uri, card.mint_id),
+        }
+    }
+}
+
+/// Token Metadata's `DataV2`, without collection or uses.
+#[derive(AnchorSerialize)]
+struct MetadataData {
+    name: String,
+    symbol: String,
+    uri: String,
+    seller_fee_basis_points: u16,
+    creators: Option<Vec<Creator>>,
+    collection: Option<()>,
+    uses: Option<()>,
+}
+
+#[derive(AnchorSerialize)]
+struct Creator {
+    address: Pubkey,
+    verified: bool,
+    share: u8,
+}
+
+impl MetadataData {
+    /// A 1/1 whose only creator is `authority`, with no royalties.
+    fn for_card(authority: &Pubkey, nft: &CardNft) -> Self {
+        Self {
+            name: nft.name.clone(),
+            symbol: nft.symbol.clone(),
+            uri: nft.uri.clone(),
+            seller_fee_basis_points: 0,
+            creators: Some(vec![Creator { address: *authority, verified: true, share: 100 }]),
+            collection: None,
+            uses: None,
+        }
+    }
+}
+
+/// Metadata PDA for `mint`.
+pub fn metadata_address(mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"metadata", TOKEN_METADATA_ID.as_ref(), mint.as_ref()],
+        &TOKEN_METADATA_ID,
+    )
+    .0
+}
+
+/// Master edition PDA for `mint`.
+pub fn master_edition_address(mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"metadata", TOKEN_METADATA_ID.as_ref(), mint.as_ref(), b"edition"],
+        &TOKEN_METADATA_ID,
+    )
+    .0
+}
+
+/// `wallet`'s associated token account for `mint`.
+pub fn associated_token_address(wallet: &Pubkey, mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[wallet.as_ref(), anchor_spl::token::ID.as_ref(), mint.as_ref()],
+        &ASSOCIATED_TOKEN_ID,
+    )
+    .0
+}
+
+/// On-chain NFT name, `CRYPT #<id> — <title>` as the frontend builds it,
+/// cut at a character boundary to fit Token Metadata's 32 bytes.
+pub fn nft_name(mint_id: u64, title: &str) -> String {
+    let mut name = format!("CRYPT #{} — {}", mint_id, title);
+    if name.len() > MAX_NFT_NAME_LEN {
+        let mut end = MAX_NFT_NAME_LEN;
+        while !name.is_char_boundary(end) {
+            end -= 1;
+        }
+        name.truncate(end);
+    }
+    name.trim_end().to_string()
+}
+
+/// URI of card `mint_id`'s JSON under the collection's
+/// [`metadata_base`], e.g. `https://crypt.cards/7.json`.
+pub fn card_metadata_uri(collection_uri: &str, mint_id: u64) -> String {
+    format!("{}/{}.json", metadata_base(collection_uri), mint_id)
+}
+
+/// Create `wallet`'s associated token account for `mint` unless it exists.
+pub fn create_ata_idempotent_ix(payer: &Pubkey, wallet: &Pubkey, mint: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: ASSOCIATED_TOKEN_ID,
+        accounts: vec![
+            AccountMeta::new(*payer, true),
+            AccountMeta::new(associated_token_address(wallet, mint), false),
+            AccountMeta::new_readonly(*wallet, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new_readonly(anchor_lang::system_program::ID, false),
+            AccountMeta::new_readonly(anchor_spl::token::ID, false),
+        ],
+        data: vec![CREATE_ATA_IDEMPOTENT],
+    }
+}
+
+/// Metadata for a 1/1 whose only creator is `authority`, verified because
+/// it signs as update authority. No royalties; the metadata stays mutable
+/// so `update_wrapped_metadata` can resync it.
+pub fn create_metadata_ix(
+    mint: &Pubkey,
+    authority: &Pubkey,
+    payer: &Pubkey,
+    nft: &CardNft,
+) -> Result<Instruction> {
+    let mut data = vec![CREATE_METADATA_ACCOUNT_V3];
+    MetadataData::for_card(authority, nft).serialize(&mut data)?;
+    true.serialize(&mut data)?;                 // is_mutable
+    None::<()>.serialize(&mut data)?;           // collection_details
+
+    Ok(Instruction {
+        program_id: TOKEN_METADATA_ID,
+        accounts: vec![
+            AccountMeta::new(metadata_address(mint), false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new_readonly(*authority, true),    // mint authority
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(*authority, true),    // update authority
+            AccountMeta::new_readonly(anchor_lang::system_program::ID, false),
+            AccountMeta::new_readonly(sysvar::rent::ID, false),
+        ],
+        data,
+    })
+}
+
+/// Rewrite the name, symbol and URI of a wrapped card's metadata, leaving
+/// the update authority, primary sale flag and mutability as they are.
+pub fn update_metadata_ix(mint: &Pubkey, authority: &Pubkey, nft: &CardNft) -> Result<Instruction> {
+    let mut data = vec![UPDATE_METADATA_ACCOUNT_V2];
+    Some(MetadataData::for_card(authority, nft)).serialize(&mut data)?;
+    None::<Pubkey>.serialize(&mut data)?;       // update_authority
+    None::<bool>.serialize(&mut data)?;         // primary_sale_happened
+    None::<bool>.serialize(&mut data)?;         // is_mutable
+
+    Ok(Instruction {
+        program_id: TOKEN_METADATA_ID,
+        accounts: vec![
+            AccountMeta::new(metadata_address(mint), false),
+            AccountMeta::new_readonly(*authority, true),
+        ],
+        data,
+    })
+}
+
+/// Master edition with a max supply of zero, making the mint a 1/1.
+/// Token Metadata takes over the mint and freeze authorities.
+pub fn create_master_edition_ix(mint: &Pubkey, authority: &Pubkey, payer: &Pubkey) -> Result<Instruction> {
+    let mut data = vec![CREATE_MASTER_EDITION_V3];
+    Some(0u64).serialize(&mut data)?;
+
+    Ok(Instruction {
+        program_id: TOKEN_METADATA_ID,
+        accounts: vec![
+            AccountMeta::new(master_edition_address(mint), false),
+            AccountMeta::new(*mint, false),
+            AccountMeta::new_readonly(*authority, true),    // update authority
+            AccountMeta::new_readonly(*authority, true),    // mint authority
+            AccountMeta::new(*payer, true),
+            AccountMeta::new(metadata_address(mint), false),
+            AccountMeta::new_readonly(anchor_spl::token::ID, false),
+            AccountMeta::new_readonly(anchor_lang::system_program::ID, false),
+            AccountMeta::new_readonly(sysvar::rent::ID, false),
+        ],
+        data,
+    })
+}
+
+/// Burn the NFT in `token_account` and close its metadata, edition and
+/// token account. `owner` must sign and gets the rent back.
+pub fn burn_nft_ix(mint: &Pubkey, owner: &Pubkey, token_account: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: TOKEN_METADATA_ID,
+        accounts: vec![
+            AccountMeta::new(metadata_address(mint), false),
+            AccountMeta::new(*owner, true),
+            AccountMeta::new(*mint, false),
+            AccountMeta::new(*token_account, false),
+            AccountMeta::new(master_edition_address(mint), false),
+            AccountMeta::new_readonly(anchor_spl::token::ID, false),
+        ],
+        data: vec![BURN_NFT],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nft_name_matches_frontend() {
+        assert_eq!(nft_name(7, "APED IN"), "CRYPT #7 — APED IN");
+    }
+
+    #[test]
+    fn test_nft_name_fits_metadata_limit() {
+        let name = nft_name(123_456, "RUGGED — $SQUIDGAME AT THE TOP");
+        assert!(name.len() <= MAX_NFT_NAME_LEN);
+        assert!(name.starts_with("CRYPT #123456 — RUGGED"));
+
+        // Never splits a multi-byte character
+        let name = nft_name(1, "☠☠☠☠☠☠☠☠☠☠");
+        assert!(name.len() <= MAX_NFT_NAME_LEN);
+        assert!(name.ends_with('☠'));
+    }
+
+    fn nft() -> CardNft {
+        CardNft {
+            name: "CRYPT #1 — X".into(),
+            symbol: NFT_SYMBOL.into(),
+            uri: "https://crypt.cards/1.json".into(),
+        }
+    }
+
+    #[test]
+    fn test_metadata_ix_layout() {
+        let mint = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let ix = create_metadata_ix(&mint, &authority, &authority, &nft()).unwrap();
+        assert_eq!(ix.data[0], CREATE_METADATA_ACCOUNT_V3);
+        // name, symbol and URI, as Borsh strings
+        let mut rest = &ix.data[1..];
+        assert_eq!(String::deserialize(&mut rest).unwrap(), nft().name);
+        assert_eq!(String::deserialize(&mut rest).unwrap(), nft().symbol);
+        assert_eq!(String::deserialize(&mut rest).unwrap(), nft().uri);
+        // ...creators, then no collection, no uses, mutable, no collection details
+        assert!(ix.data.ends_with(&[0, 0, 1, 0]));
+        assert_eq!(ix.accounts[0].pubkey, metadata_address(&mint));
+    }
+
+    #[test]
+    fn test_update_metadata_ix_layout() {
+        let mint = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let ix = update_metadata_ix(&mint, &authority, &nft()).unwrap();
+        // Discriminator, then Some(data) with the same encoding as create
+        assert_eq!(&ix.data[..2], &[UPDATE_METADATA_ACCOUNT_V2, 1]);
+        let create = create_metadata_ix(&mint, &authority, &authority, &nft()).unwrap();
+        let data_len = create.data.len() - 1 - 2;
+        assert_eq!(&ix.data[2..2 + data_len], &create.data[1..1 + data_len]);
+        // ...and nothing else changes
+        assert_eq!(&ix.data[2 + data_len..], &[0, 0, 0]);
+        assert_eq!(ix.accounts.len(), 2);
+        assert!(ix.accounts[1].is_signer);
+    }
+
+    #[test]
+    fn test_card_metadata_uri() {
+        assert_eq!(card_metadata_uri("https://crypt.cards/collection.json", 7), "https://crypt.cards/7.json");
+        assert_eq!(card_metadata_uri("https://crypt.cards", 0), "https://crypt.cards/0.json");
+    }
+
+    #[test]
+    fn test_pdas_differ_per_mint() {
+        let a = Pubkey::new_unique();
+        let b = Pubkey::new_unique();
+        assert_ne!(metadata_address(&a), metadata_address(&b));
+        assert_ne!(metadata_address(&a), master_edition_address(&a));
+        assert_ne!(
+            associated_token_address(&a, &b),
+            associated_token_address(&b, &a)
+        );
+    }
+}
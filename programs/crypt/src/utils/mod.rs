@@ -1,7 +1,23 @@
 pub mod scoring;
 pub mod hashing;
 pub mod validation;
+pub mod achievements;
+pub mod accounts;
+pub mod staking;
+pub mod duel;
+pub mod boosts;
+pub mod merkle;
+pub mod governance;
+pub mod metaplex;
 
 pub use scoring::*;
 pub use hashing::*;
 pub use validation::*;
+pub use achievements::*;
+pub use accounts::*;
+pub use staking::*;
+pub use duel::*;
+pub use boosts::*;
+pub use merkle::*;
+pub use governance::*;
+pub use metaplex::*;
@@ -0,0 +1,73 @@
+//! Haunting (staking) accrual — how many points a staked card earns.
+//!
+//! A card earns `base_rate_per_day` points per day, scaled by how much
+//! attention it gets. Each recorded interaction adds 1% to the rate,
+//! capped at double. A user adds at most one comment through `interact`
+//! and one per kind of reaction (like, share, bookmark) through `react`.
+//!
+//! Rarity doesn't change the rate: it is chosen by the minter and never
+//! checked on-chain, and points can be redeemed for a real token. The
+//! interaction count is kept by the program itself.
+
+pub const SECONDS_PER_DAY: u64 = 86_400;
+
+/// Interactions beyond this stop increasing the rate.
+pub const MAX_INTERACTION_BONUS: u64 = 100;
+
+/// Points earned by one card over `elapsed` seconds.
+pub fn points_accrued(
+    base_rate_per_day: u64,
+    interaction_count: u64,
+    elapsed: i64,
+) -> u64 {
+    if elapsed <= 0 {
+        return 0;
+    }
+
+    let interaction_pct = 100 + interaction_count.min(MAX_INTERACTION_BONUS);
+    let points = (base_rate_per_day as u128)
+        .saturating_mul(interaction_pct as u128)
+        .saturating_mul(elapsed as u128)
+        / (100 * SECONDS_PER_DAY as u128);
+
+    points.min(u64::MAX as u128) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: i64 = SECONDS_PER_DAY as i64;
+
+    #[test]
+    fn test_earns_base_rate() {
+        assert_eq!(points_accrued(100, 0, DAY), 100);
+        assert_eq!(points_accrued(100, 0, 7 * DAY), 700);
+    }
+
+    #[test]
+    fn test_interactions_boost_rate() {
+        assert_eq!(points_accrued(100, 50, DAY), 150);
+        // Bonus caps at double
+        assert_eq!(points_accrued(100, 100, DAY), 200);
+        assert_eq!(points_accrued(100, 10_000, DAY), 200);
+    }
+
+    #[test]
+    fn test_partial_day_accrues_pro_rata() {
+        assert_eq!(points_accrued(100, 0, DAY / 2), 50);
+    }
+
+    #[test]
+    fn test_no_time_no_points() {
+        assert_eq!(points_accrued(100, 100, 0), 0);
+        // Clock skew never takes points away
+        assert_eq!(points_accrued(100, 100, -DAY), 0);
+    }
+
+    #[test]
+    fn test_large_values_do_not_overflow() {
+        assert_eq!(points_accrued(u64::MAX, u64::MAX, i64::MAX), u64::MAX);
+        assert_eq!(points_accrued(u64::MAX / 1_000, 0, DAY), u64::MAX / 1_000);
+    }
+}
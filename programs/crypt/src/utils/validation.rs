@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crypt_core::SCORING_VERSION;
 use crate::state::{Rarity, CardType};
 use crate::errors::CryptError;
 use crate::instructions::MintCardArgs;
@@ -7,7 +8,7 @@ use crate::instructions::MintCardArgs;
 pub fn validate_card_args(args: &MintCardArgs) -> Result<()> {
     // Validate tx_hash length
     require!(
-        args.tx_hash.len() > 0 && args.tx_hash.len() <= 88,
+        !args.tx_hash.is_empty() && args.tx_hash.len() <= 88,
         CryptError::TxHashTooLong
     );
 
@@ -35,9 +36,69 @@ pub fn validate_card_args(args: &MintCardArgs) -> Result<()> {
         CryptError::PlatformTooLong
     );
 
+    // Validate scoring version (0 = unknown)
+    require!(
+        args.scoring_version <= SCORING_VERSION,
+        CryptError::UnknownScoringVersion
+    );
+
     Ok(())
 }
 
+/// Edits are unlimited for this long after mint...
+pub const METADATA_EDIT_WINDOW: i64 = 7 * 24 * 60 * 60;
+
+/// ...and capped at this many in total once the window closes.
+pub const MAX_METADATA_EDITS: u8 = 3;
+
+/// Whether `now` is still inside a card's free edit window.
+pub fn in_edit_window(minted_at: i64, now: i64) -> bool {
+    now - minted_at <= METADATA_EDIT_WINDOW
+}
+
+/// Whether an owner may still edit a card's metadata. Only edits made
+/// after the window count toward `edits_used`.
+pub fn can_edit_metadata(minted_at: i64, now: i64, edits_used: u8) -> bool {
+    in_edit_window(minted_at, now) || edits_used < MAX_METADATA_EDITS
+}
+
+/// Validate the fields of a metadata update with the same limits as minting.
+pub fn validate_metadata_update(title: Option<&str>, soundtrack_id: Option<&str>) -> Result<()> {
+    if let Some(title) = title {
+        require!(title.len() <= 100, CryptError::TitleTooLong);
+    }
+    if let Some(soundtrack_id) = soundtrack_id {
+        require!(soundtrack_id.len() <= 32, CryptError::SoundtrackIdTooLong);
+    }
+    Ok(())
+}
+
+/// Shortest loan a lender can set.
+pub const MIN_LOAN_DURATION: i64 = 60 * 60;
+
+/// Longest loan a lender can set.
+pub const MAX_LOAN_DURATION: i64 = 30 * 24 * 60 * 60;
+
+/// Whether a loan duration (in seconds) is within the allowed range.
+pub fn is_valid_loan_duration(duration: i64) -> bool {
+    (MIN_LOAN_DURATION..=MAX_LOAN_DURATION).contains(&duration)
+}
+
+/// Shortest a gift can wait to be claimed.
+pub const MIN_GIFT_DURATION: i64 = 60 * 60;
+
+/// Longest a gift can wait to be claimed.
+pub const MAX_GIFT_DURATION: i64 = 90 * 24 * 60 * 60;
+
+/// Whether a gift claim window (in seconds) is within the allowed range.
+pub fn is_valid_gift_duration(duration: i64) -> bool {
+    (MIN_GIFT_DURATION..=MAX_GIFT_DURATION).contains(&duration)
+}
+
+/// How long both sides have to reveal once a duel is accepted. After
+/// this, a side that revealed wins by forfeit.
+pub const DUEL_REVEAL_WINDOW: i64 = 24 * 60 * 60;
+
 /// Validate a Solana transaction signature format.
 /// Base58-encoded, typically 87-88 characters.
 pub fn is_valid_tx_signature(sig: &str) -> bool {
@@ -61,7 +122,7 @@ mod tests {
 
     #[test]
     fn test_valid_tx_signature() {
-        assert!(is_valid_tx_signature("4xK7m9pR2abc123def4567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef12345"));
+        assert!(is_valid_tx_signature("4xK7m9pR2abc123def4567899abcdef1234567899abcdef1234567899abcdef1234567899abcdef12345"));
     }
 
     #[test]
@@ -78,4 +139,76 @@ mod tests {
     fn test_invalid_wallet_too_short() {
         assert!(!is_valid_wallet_address("abc"));
     }
+
+    fn mint_args(scoring_version: u16) -> MintCardArgs {
+        MintCardArgs {
+            tx_hash: "4xK7m9pR2abc".into(),
+            rarity: 1,
+            card_type: 0,
+            title: "APED IN".into(),
+            narration_hash: [0; 32],
+            platform: "JUPITER".into(),
+            pnl: "+69%".into(),
+            tx_timestamp: 1_700_000_000,
+            soundtrack_id: String::new(),
+            score: 50,
+            scoring_version,
+        }
+    }
+
+    #[test]
+    fn test_scoring_version_bounded() {
+        assert!(validate_card_args(&mint_args(0)).is_ok());
+        assert!(validate_card_args(&mint_args(SCORING_VERSION)).is_ok());
+        assert!(validate_card_args(&mint_args(SCORING_VERSION + 1)).is_err());
+    }
+
+    #[test]
+    fn test_metadata_edits_free_inside_window() {
+        let minted = 1_700_000_000;
+        assert!(can_edit_metadata(minted, minted + 60, 50));
+        assert!(can_edit_metadata(minted, minted + METADATA_EDIT_WINDOW, 50));
+    }
+
+    #[test]
+    fn test_metadata_edits_capped_after_window() {
+        let minted = 1_700_000_000;
+        let later = minted + METADATA_EDIT_WINDOW + 1;
+        assert!(can_edit_metadata(minted, later, MAX_METADATA_EDITS - 1));
+        assert!(!can_edit_metadata(minted, later, MAX_METADATA_EDITS));
+    }
+
+    #[test]
+    fn test_edit_window_boundary() {
+        let minted = 1_700_000_000;
+        assert!(in_edit_window(minted, minted + METADATA_EDIT_WINDOW));
+        assert!(!in_edit_window(minted, minted + METADATA_EDIT_WINDOW + 1));
+    }
+
+    #[test]
+    fn test_metadata_update_lengths() {
+        assert!(validate_metadata_update(Some("FIXED TITLE"), Some("12345")).is_ok());
+        assert!(validate_metadata_update(None, None).is_ok());
+        assert!(validate_metadata_update(Some(&"X".repeat(101)), None).is_err());
+        assert!(validate_metadata_update(None, Some(&"9".repeat(33))).is_err());
+    }
+
+    #[test]
+    fn test_loan_duration_bounds() {
+        assert!(is_valid_loan_duration(MIN_LOAN_DURATION));
+        assert!(is_valid_loan_duration(MAX_LOAN_DURATION));
+        assert!(!is_valid_loan_duration(MIN_LOAN_DURATION - 1));
+        assert!(!is_valid_loan_duration(MAX_LOAN_DURATION + 1));
+        assert!(!is_valid_loan_duration(-1));
+    }
+
+    #[test]
+    fn test_gift_duration_bounds() {
+        assert!(is_valid_gift_duration(MIN_GIFT_DURATION));
+        assert!(is_valid_gift_duration(MAX_GIFT_DURATION));
+        assert!(is_valid_gift_duration(MAX_LOAN_DURATION + 1));
+        assert!(!is_valid_gift_duration(MIN_GIFT_DURATION - 1));
+        assert!(!is_valid_gift_duration(MAX_GIFT_DURATION + 1));
+        assert!(!is_valid_gift_duration(0));
+    }
 }
@@ -0,0 +1,385 @@
+//! Anti-gaming heuristics — spotting transactions staged to farm scores.
+//!
+//! Big transfers and swaps score well, so they are worth faking:
+//! shuffling SOL between wallets one person controls, or swapping the
+//! same size back and forth. [`GamingDetector`] looks for three signs:
+//!
+//! - round trips: SOL sent to an address and the same amount coming
+//!   back from it shortly after
+//! - linked wallets: transfers with an address funded by the same
+//!   source as the wallet (or that funded it, or that it funded)
+//! - repeated swaps: several swaps of the same size close together
+//!
+//! Each flag carries the action the scorer takes and an explanation.
+//! Funders come from the caller (see [`GamingDetector::learn_funder`]),
+//! since one wallet's history can't show who funded its counterparties.
+
+use std::collections::HashMap;
+use crate::analytics::history::{WalletTx, LAMPORTS_PER_SOL};
+
+/// What the scorer does with a flagged transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GamingAction {
+    /// Subtract this many points
+    Penalty(u32),
+    /// Score the transaction zero
+    Exclude,
+}
+
+/// Why a transaction looks staged.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GamingSignal {
+    /// SOL sent to `counterparty` came back (or went back) within `secs`
+    RoundTrip { counterparty: String, sol_amount: f64, secs: i64 },
+    /// A transfer with `counterparty`, tied to the wallet by funding
+    LinkedWallet { counterparty: String, link: Link },
+    /// One of `count` swaps of about `sol_amount` SOL close together
+    RepeatedSwap { sol_amount: f64, count: usize },
+}
+
+/// How a linked wallet is tied to the scanned one.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Link {
+    /// Both were first funded by this address
+    SharedFunder(String),
+    /// The counterparty funded the wallet
+    FundedWallet,
+    /// The wallet funded the counterparty
+    FundedByWallet,
+}
+
+/// A transaction flagged by the detector.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GamingFlag {
+    pub signature: String,
+    pub signal: GamingSignal,
+    pub action: GamingAction,
+}
+
+impl GamingFlag {
+    /// One line for a card's factors, e.g.
+    /// `Round trip: 600 SOL with ALTw..1111 within 2h (excluded)`.
+    pub fn explain(&self) -> String {
+        let reason = match &self.signal {
+            GamingSignal::RoundTrip { counterparty, sol_amount, secs } => format!(
+                "Round trip: {:.0} SOL with {} within {}",
+                sol_amount, short_address(counterparty), duration(*secs)
+            ),
+            GamingSignal::LinkedWallet { counterparty, link } => match link {
+                Link::SharedFunder(funder) => format!(
+                    "Linked wallet: {} shares funder {}",
+                    short_address(counterparty), short_address(funder)
+                ),
+                Link::FundedWallet => format!("Linked wallet: {} funded this wallet", short_address(counterparty)),
+                Link::FundedByWallet => {
+                    format!("Linked wallet: {} was funded by this wallet", short_address(counterparty))
+                }
+            },
+            GamingSignal::RepeatedSwap { sol_amount, count } => {
+                format!("Repeated swap: {} swaps of {:.2} SOL", count, sol_amount)
+            }
+        };
+        match self.action {
+            GamingAction::Penalty(points) => format!("{} (-{})", reason, points),
+            GamingAction::Exclude => format!("{} (excluded)", reason),
+        }
+    }
+}
+
+/// Runs the anti-gaming heuristics over one wallet's history.
+#[derive(Debug, Clone)]
+pub struct GamingDetector {
+    pub wallet: String,
+    /// Transfers and swaps smaller than this are ignored
+    pub min_sol: f64,
+    /// How close two amounts must be, in percent, to count as the same
+    pub tolerance_pct: f64,
+    /// Longest time between the legs of a round trip
+    pub round_trip_secs: i64,
+    /// Swaps within this many seconds of each other are compared
+    pub repeat_window_secs: i64,
+    /// Same-size swaps in one window that make a pattern
+    pub repeat_count: usize,
+    pub linked_penalty: u32,
+    pub repeat_penalty: u32,
+    /// First funder of each known address
+    pub funders: HashMap<String, String>,
+}
+
+impl GamingDetector {
+    pub fn new(wallet: &str) -> Self {
+        Self {
+            wallet: wallet.to_string(),
+            min_sol: 1.0,
+            tolerance_pct: 2.0,
+            round_trip_secs: 86_400,
+            repeat_window_secs: 86_400,
+            repeat_count: 3,
+            // Cancels the biggest transfer bonus
+            linked_penalty: 80,
+            repeat_penalty: 40,
+            funders: HashMap::new(),
+        }
+    }
+
+    pub fn with_funder(mut self, address: &str, funder: &str) -> Self {
+        self.funders.insert(address.to_string(), funder.to_string());
+        self
+    }
+
+    /// Record `address`'s funder: the sender of the first SOL it
+    /// received in `txs`. `txs` must be oldest first and start at the
+    /// address's first transaction.
+    pub fn learn_funder(&mut self, address: &str, txs: &[WalletTx]) {
+        let funder = txs
+            .iter()
+            .flat_map(|tx| &tx.native_transfers)
+            .find(|t| t.to_user_account == address && !t.from_user_account.is_empty());
+        if let Some(t) = funder {
+            self.funders.insert(address.to_string(), t.from_user_account.clone());
+        }
+    }
+
+    /// Addresses the wallet sent or received at least `min_sol` from in
+    /// plain transfers, first seen first. Their funders are what the
+    /// linked-wallet check needs beyond the wallet's own.
+    pub fn counterparties(&self, txs: &[WalletTx]) -> Vec<String> {
+        let mut seen: Vec<String> = Vec::new();
+        for (_, counterparty, _, _) in self.transfer_legs(txs) {
+            if !seen.iter().any(|s| s == counterparty) {
+                seen.push(counterparty.to_string());
+            }
+        }
+        seen
+    }
+
+    /// Every flag in `txs`, in history order. A transaction can carry
+    /// more than one. `txs` must be oldest first.
+    pub fn detect(&self, txs: &[WalletTx]) -> Vec<GamingFlag> {
+        let mut flags = Vec::new();
+        flags.extend(self.round_trips(txs));
+        flags.extend(self.linked_wallets(txs));
+        flags.extend(self.repeated_swaps(txs));
+        let order: HashMap<&str, usize> = txs.iter().enumerate().map(|(i, tx)| (tx.signature.as_str(), i)).collect();
+        flags.sort_by_key(|f| order.get(f.signature.as_str()).copied());
+        flags
+    }
+
+    fn same_size(&self, a: f64, b: f64) -> bool {
+        (a - b).abs() <= a.max(b) * self.tolerance_pct / 100.0
+    }
+
+    /// SOL legs of plain transfers: (tx, counterparty, SOL, outgoing).
+    fn transfer_legs<'a>(&'a self, txs: &'a [WalletTx]) -> impl Iterator<Item = (&'a WalletTx, &'a str, f64, bool)> + 'a {
+        txs.iter()
+            .filter(|tx| matches!(tx.tx_type.as_str(), "TRANSFER" | "SOL_TRANSFER"))
+            .flat_map(move |tx| tx.native_transfers.iter().map(move |t| (tx, t)))
+            .filter_map(move |(tx, t)| {
+                let sol = t.amount as f64 / LAMPORTS_PER_SOL;
+                if sol < self.min_sol { return None; }
+                if t.from_user_account == self.wallet && !t.to_user_account.is_empty() {
+                    Some((tx, t.to_user_account.as_str(), sol, true))
+                } else if t.to_user_account == self.wallet && !t.from_user_account.is_empty() {
+                    Some((tx, t.from_user_account.as_str(), sol, false))
+                } else {
+                    None
+                }
+            })
+    }
+
+    fn round_trips(&self, txs: &[WalletTx]) -> Vec<GamingFlag> {
+        let legs: Vec<_> = self.transfer_legs(txs).collect();
+        let mut flags = Vec::new();
+        for (i, (tx, counterparty, sol, outgoing)) in legs.iter().enumerate() {
+            // The matching leg goes the other way, to or from the same address
+            let back = legs[i + 1..].iter().find(|(later, cp, later_sol, later_out)| {
+                cp == counterparty
+                    && later_out != outgoing
+                    && later.timestamp - tx.timestamp <= self.round_trip_secs
+                    && self.same_size(*sol, *later_sol)
+            });
+            let Some((later, ..)) = back else { continue };
+            let signal = GamingSignal::RoundTrip {
+                counterparty: counterparty.to_string(),
+                sol_amount: *sol,
+                secs: later.timestamp - tx.timestamp,
+            };
+            for flagged in [tx, later] {
+                if !flags.iter().any(|f: &GamingFlag| f.signature == flagged.signature) {
+                    flags.push(GamingFlag {
+                        signature: flagged.signature.clone(),
+                        signal: signal.clone(),
+                        action: GamingAction::Exclude,
+                    });
+                }
+            }
+        }
+        flags
+    }
+
+    fn linked_wallets(&self, txs: &[WalletTx]) -> Vec<GamingFlag> {
+        let own_funder = self.funders.get(&self.wallet);
+        let mut flags: Vec<GamingFlag> = Vec::new();
+        for (tx, counterparty, _, _) in self.transfer_legs(txs) {
+            let their_funder = self.funders.get(counterparty);
+            let link = if own_funder.is_some_and(|f| f == counterparty) {
+                Link::FundedWallet
+            } else if their_funder.is_some_and(|f| *f == self.wallet) {
+                Link::FundedByWallet
+            } else if let Some(f) = their_funder.filter(|f| own_funder == Some(*f)) {
+                Link::SharedFunder(f.clone())
+            } else {
+                continue;
+            };
+            if flags.iter().any(|f| f.signature == tx.signature) { continue; }
+            flags.push(GamingFlag {
+                signature: tx.signature.clone(),
+                signal: GamingSignal::LinkedWallet { counterparty: counterparty.to_string(), link },
+                action: GamingAction::Penalty(self.linked_penalty),
+            });
+        }
+        flags
+    }
+
+    fn repeated_swaps(&self, txs: &[WalletTx]) -> Vec<GamingFlag> {
+        let swaps: Vec<&WalletTx> = txs
+            .iter()
+            .filter(|tx| tx.tx_type == "SWAP" && tx.sol_amount() >= self.min_sol)
+            .collect();
+        swaps
+            .iter()
+            .filter_map(|tx| {
+                let count = swaps
+                    .iter()
+                    .filter(|other| {
+                        (other.timestamp - tx.timestamp).abs() <= self.repeat_window_secs
+                            && self.same_size(tx.sol_amount(), other.sol_amount())
+                    })
+                    .count();
+                (count >= self.repeat_count).then(|| GamingFlag {
+                    signature: tx.signature.clone(),
+                    signal: GamingSignal::RepeatedSwap { sol_amount: tx.sol_amount(), count },
+                    action: GamingAction::Penalty(self.repeat_penalty),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Address shortened for explanations, e.g. `FRND..1111`.
+fn short_address(address: &str) -> String {
+    if address.len() > 10 { format!("{}..{}", &address[..4], &address[address.len() - 4..]) } else { address.to_string() }
+}
+
+fn duration(secs: i64) -> String {
+    if secs < 3_600 { format!("{}m", secs / 60) } else { format!("{}h", secs / 3_600) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analytics::history::NativeTransfer;
+
+    const WALLET: &str = "CRYPTwa11et1111111111111111111111111111111";
+    const ALT: &str = "ALTwa11et11111111111111111111111111111111";
+    const FUNDER: &str = "FUNDER1111111111111111111111111111111111";
+
+    fn transfer(sig: &str, timestamp: i64, from: &str, to: &str, sol: f64) -> WalletTx {
+        WalletTx {
+            signature: sig.into(),
+            timestamp,
+            tx_type: "TRANSFER".into(),
+            source: "SYSTEM_PROGRAM".into(),
+            native_transfers: vec![NativeTransfer {
+                from_user_account: from.into(),
+                to_user_account: to.into(),
+                amount: (sol * 1e9) as u64,
+            }],
+            ..Default::default()
+        }
+    }
+
+    fn swap(sig: &str, timestamp: i64, sol: f64) -> WalletTx {
+        WalletTx { tx_type: "SWAP".into(), source: "JUPITER".into(), ..transfer(sig, timestamp, WALLET, "POOL", sol) }
+    }
+
+    fn signatures(flags: &[GamingFlag]) -> Vec<&str> {
+        flags.iter().map(|f| f.signature.as_str()).collect()
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let txs = [
+            transfer("out", 1_000, WALLET, ALT, 600.0),
+            transfer("unrelated", 2_000, WALLET, "SHOP", 600.0),
+            transfer("back", 1_000 + 7_200, ALT, WALLET, 599.0),
+            transfer("late", 1_000 + 200_000, WALLET, ALT, 600.0),
+        ];
+        let flags = GamingDetector::new(WALLET).detect(&txs);
+        assert_eq!(signatures(&flags), ["out", "back"]);
+        assert_eq!(flags[0].action, GamingAction::Exclude);
+        assert_eq!(flags[0].explain(), "Round trip: 600 SOL with ALTw..1111 within 2h (excluded)");
+    }
+
+    #[test]
+    fn test_swaps_to_pools_are_not_round_trips() {
+        let buy = swap("buy", 1_000, 50.0);
+        let sell = WalletTx { tx_type: "SWAP".into(), ..transfer("sell", 2_000, "POOL", WALLET, 50.0) };
+        assert!(GamingDetector::new(WALLET).detect(&[buy, sell]).is_empty());
+    }
+
+    #[test]
+    fn test_linked_wallets() {
+        let txs = [
+            transfer("sibling", 1_000, WALLET, ALT, 600.0),
+            transfer("to_funder", 2_000, WALLET, FUNDER, 10.0),
+            transfer("stranger", 3_000, WALLET, "STRANGER", 10.0),
+            transfer("dust", 4_000, WALLET, ALT, 0.5),
+        ];
+        let detector = GamingDetector::new(WALLET).with_funder(WALLET, FUNDER).with_funder(ALT, FUNDER);
+        let flags = detector.detect(&txs);
+        assert_eq!(signatures(&flags), ["sibling", "to_funder"]);
+        assert_eq!(flags[0].explain(), "Linked wallet: ALTw..1111 shares funder FUND..1111 (-80)");
+        assert_eq!(flags[1].explain(), "Linked wallet: FUND..1111 funded this wallet (-80)");
+
+        let child = GamingDetector::new(WALLET).with_funder(ALT, WALLET).detect(&txs[..1]);
+        assert_eq!(child[0].explain(), "Linked wallet: ALTw..1111 was funded by this wallet (-80)");
+    }
+
+    #[test]
+    fn test_learn_funder() {
+        let history = [
+            transfer("gas", 1_000, FUNDER, WALLET, 5.0),
+            transfer("later", 2_000, ALT, WALLET, 1.0),
+        ];
+        let mut detector = GamingDetector::new(WALLET);
+        detector.learn_funder(WALLET, &history);
+        assert_eq!(detector.funders.get(WALLET).map(String::as_str), Some(FUNDER));
+    }
+
+    #[test]
+    fn test_counterparties() {
+        let txs = [
+            transfer("out", 1_000, WALLET, ALT, 600.0),
+            transfer("in", 2_000, FUNDER, WALLET, 5.0),
+            transfer("again", 3_000, ALT, WALLET, 600.0),
+            transfer("dust", 4_000, WALLET, "STRANGER", 0.5),
+            swap("swap", 5_000, 50.0),
+        ];
+        assert_eq!(GamingDetector::new(WALLET).counterparties(&txs), [ALT, FUNDER]);
+    }
+
+    #[test]
+    fn test_repeated_swaps() {
+        let txs = [
+            swap("a", 1_000, 25.0),
+            swap("b", 2_000, 25.2),
+            swap("c", 3_000, 24.9),
+            swap("other_size", 4_000, 40.0),
+            swap("next_week", 700_000, 25.0),
+        ];
+        let flags = GamingDetector::new(WALLET).detect(&txs);
+        assert_eq!(signatures(&flags), ["a", "b", "c"]);
+        assert_eq!(flags[0].explain(), "Repeated swap: 3 swaps of 25.00 SOL (-40)");
+    }
+}
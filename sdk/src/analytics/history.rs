@@ -0,0 +1,268 @@
+//! Wallet history — the normalized transactions analytics work from.
+//!
+//! `WalletTx` has the same shape as a Helius enhanced transaction, so a
+//! Helius response deserializes straight into it; `null`s become empty
+//! values. Token series for [`PriceSeries`] are priced in SOL.
+
+use std::collections::HashMap;
+use serde::{Deserialize, Deserializer, Serialize};
+
+pub const LAMPORTS_PER_SOL: f64 = 1e9;
+
+/// Key of the SOL/USD series used for market-wide moves.
+pub const SOL_USD: &str = "SOL";
+
+/// Wrapped SOL mint. Its token amounts are already in SOL.
+pub const WSOL_MINT: &str = "So11111111111111111111111111111111111111112";
+
+fn nullable<'de, D, T>(d: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Default + Deserialize<'de>,
+{
+    Ok(Option::<T>::deserialize(d)?.unwrap_or_default())
+}
+
+/// One transaction from a wallet's history.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletTx {
+    #[serde(default, deserialize_with = "nullable")]
+    pub signature: String,
+    #[serde(default, deserialize_with = "nullable")]
+    pub timestamp: i64,
+    /// Helius transaction type, e.g. `SWAP` or `NFT_MINT`
+    #[serde(rename = "type", default, deserialize_with = "nullable")]
+    pub tx_type: String,
+    /// Program or marketplace, e.g. `JUPITER`
+    #[serde(default, deserialize_with = "nullable")]
+    pub source: String,
+    #[serde(default, deserialize_with = "nullable")]
+    pub native_transfers: Vec<NativeTransfer>,
+    #[serde(default, deserialize_with = "nullable")]
+    pub token_transfers: Vec<TokenTransfer>,
+    /// Edition details for NFT mints, when a metadata lookup found them
+    #[serde(default)]
+    pub nft: Option<NftMintInfo>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NativeTransfer {
+    #[serde(default, deserialize_with = "nullable")]
+    pub from_user_account: String,
+    #[serde(default, deserialize_with = "nullable")]
+    pub to_user_account: String,
+    /// Lamports
+    #[serde(default, deserialize_with = "nullable")]
+    pub amount: u64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenTransfer {
+    /// Empty when tokens are minted
+    #[serde(default, deserialize_with = "nullable")]
+    pub from_user_account: String,
+    #[serde(default, deserialize_with = "nullable")]
+    pub to_user_account: String,
+    #[serde(default, deserialize_with = "nullable")]
+    pub mint: String,
+    /// UI amount, decimals applied
+    #[serde(default, deserialize_with = "nullable")]
+    pub token_amount: f64,
+    /// Token accounts on either side; empty when not reported
+    #[serde(default, deserialize_with = "nullable")]
+    pub from_token_account: String,
+    #[serde(default, deserialize_with = "nullable")]
+    pub to_token_account: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NftMintInfo {
+    pub collection: String,
+    pub mint_number: u32,
+    /// 0 when unknown
+    #[serde(default)]
+    pub total_supply: u32,
+}
+
+impl WalletTx {
+    /// Largest single SOL transfer, as the scanner scores it.
+    pub fn sol_amount(&self) -> f64 {
+        self.native_transfers
+            .iter()
+            .map(|t| t.amount as f64 / LAMPORTS_PER_SOL)
+            .fold(0.0, f64::max)
+    }
+
+    /// Net SOL change for `wallet` (negative when it paid).
+    pub fn net_sol(&self, wallet: &str) -> f64 {
+        self.native_transfers.iter().fold(0.0, |acc, t| {
+            let amount = t.amount as f64 / LAMPORTS_PER_SOL;
+            if t.to_user_account == wallet { acc + amount }
+            else if t.from_user_account == wallet { acc - amount }
+            else { acc }
+        })
+    }
+
+    /// Net SOL change for `wallet` with wrapped SOL counted as SOL.
+    /// Lamports moved between the wallet and its own wSOL accounts —
+    /// wrapping before a swap, closing the account after — cancel out
+    /// rather than being counted next to the wSOL leg.
+    pub fn net_sol_with_wsol(&self, wallet: &str) -> f64 {
+        let mut own_accounts: Vec<&str> = Vec::new();
+        let mut wrapped = 0.0;
+        for t in self.token_transfers.iter().filter(|t| t.mint == WSOL_MINT) {
+            if t.to_user_account == wallet {
+                wrapped += t.token_amount;
+                own_accounts.push(&t.to_token_account);
+            } else if t.from_user_account == wallet {
+                wrapped -= t.token_amount;
+                own_accounts.push(&t.from_token_account);
+            }
+        }
+        own_accounts.retain(|a| !a.is_empty());
+
+        let native = self.native_transfers.iter().fold(0.0, |acc, t| {
+            let amount = t.amount as f64 / LAMPORTS_PER_SOL;
+            if t.to_user_account == wallet && !own_accounts.contains(&t.from_user_account.as_str()) { acc + amount }
+            else if t.from_user_account == wallet && !own_accounts.contains(&t.to_user_account.as_str()) { acc - amount }
+            else { acc }
+        });
+        native + wrapped
+    }
+
+    /// Net change of each token for `wallet`, in first-seen order.
+    /// Tokens that net to zero are left out.
+    pub fn token_deltas(&self, wallet: &str) -> Vec<(String, f64)> {
+        let mut deltas: Vec<(String, f64)> = Vec::new();
+        for t in &self.token_transfers {
+            let change = if t.to_user_account == wallet { t.token_amount }
+                else if t.from_user_account == wallet { -t.token_amount }
+                else { continue };
+            match deltas.iter_mut().find(|(mint, _)| *mint == t.mint) {
+                Some((_, delta)) => *delta += change,
+                None => deltas.push((t.mint.clone(), change)),
+            }
+        }
+        deltas.retain(|(_, delta)| *delta != 0.0);
+        deltas
+    }
+
+    /// Every address on the other side of a transfer with `wallet`.
+    pub fn counterparties<'a>(&'a self, wallet: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        let native = self.native_transfers.iter().map(|t| (t.from_user_account.as_str(), t.to_user_account.as_str()));
+        let token = self.token_transfers.iter().map(|t| (t.from_user_account.as_str(), t.to_user_account.as_str()));
+        native.chain(token).filter_map(move |(from, to)| {
+            if from == wallet && !to.is_empty() { Some(to) }
+            else if to == wallet && !from.is_empty() { Some(from) }
+            else { None }
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PricePoint {
+    pub timestamp: i64,
+    pub price: f64,
+}
+
+/// Price history per token mint, oldest point first.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PriceSeries {
+    series: HashMap<String, Vec<PricePoint>>,
+}
+
+impl PriceSeries {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, mint: &str, mut points: Vec<PricePoint>) {
+        points.sort_by_key(|p| p.timestamp);
+        self.series.insert(mint.to_string(), points);
+    }
+
+    pub fn with(mut self, mint: &str, points: Vec<PricePoint>) -> Self {
+        self.insert(mint, points);
+        self
+    }
+
+    pub fn get(&self, mint: &str) -> Option<&[PricePoint]> {
+        self.series.get(mint).map(Vec::as_slice).filter(|s| !s.is_empty())
+    }
+
+    /// Last known price at or before `timestamp`.
+    pub fn price_at(&self, mint: &str, timestamp: i64) -> Option<f64> {
+        self.get(mint)?.iter().take_while(|p| p.timestamp <= timestamp).last().map(|p| p.price)
+    }
+
+    pub fn latest(&self, mint: &str) -> Option<f64> {
+        self.get(mint)?.last().map(|p| p.price)
+    }
+
+    /// Lowest price from `from` to `to`, both inclusive.
+    pub fn lowest_between(&self, mint: &str, from: i64, to: i64) -> Option<f64> {
+        self.get(mint)?
+            .iter()
+            .filter(|p| p.timestamp >= from && p.timestamp <= to)
+            .map(|p| p.price)
+            .reduce(f64::min)
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, crate::CryptSdkError> {
+        let series: HashMap<String, Vec<PricePoint>> =
+            serde_json::from_str(json).map_err(|e| crate::CryptSdkError::Serialization(e.to_string()))?;
+        Ok(series.into_iter().fold(Self::new(), |prices, (mint, points)| prices.with(&mint, points)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HELIUS_SWAP: &str = r#"{
+        "signature": "5xSwap",
+        "type": "SWAP",
+        "source": "JUPITER",
+        "timestamp": 1700000000,
+        "description": null,
+        "nativeTransfers": [
+            { "fromUserAccount": "WALLET", "toUserAccount": "POOL", "amount": 2000000000 }
+        ],
+        "tokenTransfers": [
+            { "fromUserAccount": "POOL", "toUserAccount": "WALLET", "mint": "BONK", "tokenAmount": 600.0 },
+            { "fromUserAccount": "WALLET", "toUserAccount": "POOL", "mint": "BONK", "tokenAmount": 100.0 },
+            { "fromUserAccount": null, "toUserAccount": "POOL", "mint": "LP", "tokenAmount": 1.0 }
+        ]
+    }"#;
+
+    #[test]
+    fn test_parses_helius_payload() {
+        let tx: WalletTx = serde_json::from_str(HELIUS_SWAP).unwrap();
+        assert_eq!(tx.tx_type, "SWAP");
+        assert_eq!(tx.token_transfers[2].from_user_account, "");
+        assert!(tx.nft.is_none());
+        assert_eq!(tx.sol_amount(), 2.0);
+        assert_eq!(tx.net_sol("WALLET"), -2.0);
+        assert_eq!(tx.token_deltas("WALLET"), vec![("BONK".to_string(), 500.0)]);
+        assert_eq!(tx.counterparties("WALLET").collect::<Vec<_>>(), ["POOL", "POOL", "POOL"]);
+    }
+
+    #[test]
+    fn test_price_lookups() {
+        let prices = PriceSeries::new().with("WIF", vec![
+            PricePoint { timestamp: 300, price: 0.5 },
+            PricePoint { timestamp: 100, price: 1.0 },
+            PricePoint { timestamp: 200, price: 0.2 },
+        ]);
+        assert_eq!(prices.price_at("WIF", 50), None);
+        assert_eq!(prices.price_at("WIF", 250), Some(0.2));
+        assert_eq!(prices.latest("WIF"), Some(0.5));
+        assert_eq!(prices.lowest_between("WIF", 100, 150), Some(1.0));
+        assert_eq!(prices.lowest_between("WIF", 0, 1_000), Some(0.2));
+        assert_eq!(prices.latest("BONK"), None);
+    }
+}
@@ -0,0 +1,14 @@
+//! Wallet analytics — finding the moments in a wallet's history that
+//! make the best cards. Runs off-chain against indexed transactions.
+
+pub mod gaming;
+pub mod history;
+pub mod patterns;
+pub mod pnl;
+pub mod wallet_profile;
+
+pub use gaming::*;
+pub use history::*;
+pub use patterns::*;
+pub use pnl::*;
+pub use wallet_profile::*;
@@ -0,0 +1,615 @@
+//! Transaction pattern detection for interesting card moments.
+//! Identifies notable patterns like diamond hands, rug pulls,
+//! and early mints that make for the best cards.
+//!
+//! [`PatternDetector`] runs every detector over a wallet's history and
+//! reports each match with the signatures that support it. Patterns
+//! that depend on prices (diamond hands, rugs, crashes) need a
+//! [`PriceSeries`]; without one only trade-to-trade evidence is used.
+
+use std::collections::HashSet;
+use crate::analytics::history::{PriceSeries, WalletTx, SOL_USD};
+use crate::analytics::pnl::{CostBasis, PnlTracker};
+
+/// Detected transaction patterns that make good cards.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Pattern {
+    /// Bought and held through >50% drawdown
+    DiamondHands { token: String, max_drawdown_pct: u32 },
+    /// Token went to zero or near-zero after purchase
+    RugPull { token: String, loss_pct: u32 },
+    /// Was in the first N minters of a collection
+    EarlyMint { collection: String, mint_number: u32, total_supply: u32 },
+    /// Made a profitable flip (buy → sell within 24h)
+    QuickFlip { token: String, profit_pct: u32, hold_hours: u32 },
+    /// Massive single transaction (>100 SOL)
+    WhaleMove { sol_amount: f64 },
+    /// Moved to cold storage (large transfer to new/inactive address)
+    ColdStorage { sol_amount: f64 },
+    /// First transaction ever on the wallet
+    GenesisTransaction,
+    /// Survived a major market crash while holding
+    CrashSurvivor { drawdown_pct: u32 },
+    /// Participated in a token airdrop
+    AirdropReceiver { token: String, value_sol: f64 },
+    /// Created a token that reached significant volume
+    TokenLauncher { token: String },
+}
+
+impl Pattern {
+    /// Card type for this pattern.
+    pub fn card_type(&self) -> &'static str {
+        match self {
+            Self::DiamondHands { .. } => "DIAMOND_HANDS",
+            Self::RugPull { .. } => "RUG",
+            Self::EarlyMint { .. } => "MINT",
+            Self::QuickFlip { .. } => "SWAP",
+            Self::WhaleMove { .. } => "BIG_MOVE",
+            Self::ColdStorage { .. } => "BIG_MOVE",
+            Self::GenesisTransaction => "MINT",
+            Self::CrashSurvivor { .. } => "DIAMOND_HANDS",
+            Self::AirdropReceiver { .. } => "SWAP",
+            Self::TokenLauncher { .. } => "MINT",
+        }
+    }
+
+    /// Base rarity score bonus for this pattern.
+    pub fn rarity_bonus(&self) -> u32 {
+        match self {
+            Self::DiamondHands { max_drawdown_pct, .. } => {
+                if *max_drawdown_pct > 80 { 40 }
+                else if *max_drawdown_pct > 50 { 25 }
+                else { 15 }
+            }
+            Self::RugPull { loss_pct, .. } => {
+                if *loss_pct > 99 { 30 } // complete rug
+                else if *loss_pct > 90 { 20 }
+                else { 10 }
+            }
+            Self::EarlyMint { mint_number, .. } => {
+                if *mint_number <= 10 { 50 }
+                else if *mint_number <= 100 { 30 }
+                else { 15 }
+            }
+            Self::QuickFlip { profit_pct, .. } => {
+                if *profit_pct > 1000 { 40 }
+                else if *profit_pct > 100 { 25 }
+                else { 10 }
+            }
+            Self::WhaleMove { sol_amount } => {
+                if *sol_amount > 1000.0 { 50 }
+                else if *sol_amount > 100.0 { 30 }
+                else { 15 }
+            }
+            Self::ColdStorage { .. } => 20,
+            Self::GenesisTransaction => 35,
+            Self::CrashSurvivor { drawdown_pct } => {
+                if *drawdown_pct > 70 { 45 }
+                else { 25 }
+            }
+            Self::AirdropReceiver { value_sol, .. } => {
+                if *value_sol > 10.0 { 30 }
+                else { 10 }
+            }
+            Self::TokenLauncher { .. } => 40,
+        }
+    }
+
+    /// Generate a narration title for this pattern.
+    pub fn title(&self) -> String {
+        match self {
+            Self::DiamondHands { token, max_drawdown_pct } =>
+                format!("HELD {} THROUGH {}% DRAWDOWN", token, max_drawdown_pct),
+            Self::RugPull { token, loss_pct } =>
+                format!("{} — RUGGED ({}% LOSS)", token, loss_pct),
+            Self::EarlyMint { collection, mint_number, .. } =>
+                format!("EARLY MINT #{} — {}", mint_number, collection),
+            Self::QuickFlip { token, profit_pct, hold_hours } =>
+                format!("{} FLIPPED +{}% IN {}H", token, profit_pct, hold_hours),
+            Self::WhaleMove { sol_amount } =>
+                format!("{:.0} SOL WHALE MOVE", sol_amount),
+            Self::ColdStorage { sol_amount } =>
+                format!("{:.0} SOL → COLD STORAGE", sol_amount),
+            Self::GenesisTransaction =>
+                "GENESIS — FIRST TRANSACTION".to_string(),
+            Self::CrashSurvivor { drawdown_pct } =>
+                format!("SURVIVED {}% CRASH", drawdown_pct),
+            Self::AirdropReceiver { token, .. } =>
+                format!("{} AIRDROP CLAIMED", token),
+            Self::TokenLauncher { token } =>
+                format!("LAUNCHED {}", token),
+        }
+    }
+}
+
+/// Detect if a sequence of transactions matches a known pattern.
+pub fn detect_diamond_hands(
+    buy_price_sol: f64,
+    lowest_price_sol: f64,
+    current_price_sol: f64,
+    token: &str,
+) -> Option<Pattern> {
+    if buy_price_sol <= 0.0 { return None; }
+    let drawdown = ((buy_price_sol - lowest_price_sol) / buy_price_sol * 100.0) as u32;
+    if drawdown >= 30 && current_price_sol >= buy_price_sol * 0.5 {
+        Some(Pattern::DiamondHands {
+            token: token.to_string(),
+            max_drawdown_pct: drawdown,
+        })
+    } else { None }
+}
+
+pub fn detect_rug_pull(buy_price_sol: f64, current_price_sol: f64, token: &str) -> Option<Pattern> {
+    if buy_price_sol <= 0.0 { return None; }
+    let loss = ((buy_price_sol - current_price_sol) / buy_price_sol * 100.0).round() as u32;
+    if loss >= 90 {
+        Some(Pattern::RugPull { token: token.to_string(), loss_pct: loss })
+    } else { None }
+}
+
+pub fn detect_quick_flip(
+    buy_price_sol: f64,
+    sell_price_sol: f64,
+    hold_seconds: u64,
+    token: &str,
+) -> Option<Pattern> {
+    if buy_price_sol <= 0.0 || hold_seconds > 86400 { return None; }
+    let profit = ((sell_price_sol - buy_price_sol) / buy_price_sol * 100.0) as u32;
+    if profit >= 20 {
+        Some(Pattern::QuickFlip {
+            token: token.to_string(),
+            profit_pct: profit,
+            hold_hours: (hold_seconds / 3600) as u32,
+        })
+    } else { None }
+}
+
+/// A pattern found in a wallet's history.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Detection {
+    pub pattern: Pattern,
+    /// Transactions the pattern rests on, oldest first
+    pub signatures: Vec<String>,
+    /// When the pattern completed (its last supporting transaction)
+    pub timestamp: i64,
+}
+
+/// Runs every pattern detector over one wallet's history.
+#[derive(Debug, Clone)]
+pub struct PatternDetector {
+    pub wallet: String,
+    /// Whether the history starts at the wallet's first transaction,
+    /// which makes that transaction its genesis
+    pub from_genesis: bool,
+    /// Single transactions above this many SOL are whale moves
+    pub whale_sol: f64,
+    /// Transfers of at least this many SOL to an address the wallet
+    /// has never dealt with count as cold storage
+    pub cold_storage_sol: f64,
+    /// Latest mint number that can count as early
+    pub early_mint_max: u32,
+    /// SOL/USD drawdown, in percent, that makes a crash
+    pub crash_drawdown_pct: u32,
+    /// Most SOL a wallet can spend (fees) and still have been airdropped
+    pub airdrop_max_cost_sol: f64,
+    /// How sells are matched to buys for flips and rugs
+    pub cost_basis: CostBasis,
+}
+
+impl PatternDetector {
+    pub fn new(wallet: &str) -> Self {
+        Self {
+            wallet: wallet.to_string(),
+            from_genesis: false,
+            whale_sol: 100.0,
+            cold_storage_sol: 50.0,
+            early_mint_max: 1_000,
+            crash_drawdown_pct: 50,
+            airdrop_max_cost_sol: 0.01,
+            cost_basis: CostBasis::Fifo,
+        }
+    }
+
+    pub fn from_genesis(mut self, from_genesis: bool) -> Self {
+        self.from_genesis = from_genesis;
+        self
+    }
+
+    /// Every pattern in `txs`, ordered by when it completed. `txs` must
+    /// be oldest first.
+    pub fn detect(&self, txs: &[WalletTx], prices: Option<&PriceSeries>) -> Vec<Detection> {
+        let mut found = Vec::new();
+        found.extend(self.genesis(txs));
+        found.extend(self.early_mints(txs));
+        found.extend(self.whale_moves(txs));
+        found.extend(self.cold_storage(txs));
+        found.extend(self.airdrops(txs, prices));
+        found.extend(self.token_launches(txs, prices));
+        found.extend(self.trades(txs, prices));
+        if let Some(prices) = prices {
+            found.extend(self.crash_survivor(txs, prices));
+        }
+        found.sort_by_key(|d| d.timestamp);
+        found
+    }
+
+    fn genesis(&self, txs: &[WalletTx]) -> Option<Detection> {
+        let first = txs.first().filter(|_| self.from_genesis)?;
+        Some(detection(Pattern::GenesisTransaction, &[first]))
+    }
+
+    fn early_mints(&self, txs: &[WalletTx]) -> Vec<Detection> {
+        txs.iter()
+            .filter(|tx| matches!(tx.tx_type.as_str(), "NFT_MINT" | "COMPRESSED_NFT_MINT"))
+            .filter_map(|tx| {
+                let nft = tx.nft.as_ref()?;
+                // Within the first tenth of the supply, when it's known
+                let early = nft.mint_number <= self.early_mint_max
+                    && (nft.total_supply == 0 || nft.mint_number as u64 * 10 <= nft.total_supply as u64);
+                early.then(|| detection(
+                    Pattern::EarlyMint {
+                        collection: nft.collection.clone(),
+                        mint_number: nft.mint_number,
+                        total_supply: nft.total_supply,
+                    },
+                    &[tx],
+                ))
+            })
+            .collect()
+    }
+
+    fn whale_moves(&self, txs: &[WalletTx]) -> Vec<Detection> {
+        txs.iter()
+            .filter(|tx| tx.sol_amount() > self.whale_sol)
+            .map(|tx| detection(Pattern::WhaleMove { sol_amount: tx.sol_amount() }, &[tx]))
+            .collect()
+    }
+
+    fn cold_storage(&self, txs: &[WalletTx]) -> Vec<Detection> {
+        let mut seen: HashSet<&str> = HashSet::new();
+        let mut found = Vec::new();
+        for tx in txs {
+            if matches!(tx.tx_type.as_str(), "TRANSFER" | "SOL_TRANSFER") {
+                for t in &tx.native_transfers {
+                    let sol = t.amount as f64 / super::history::LAMPORTS_PER_SOL;
+                    if t.from_user_account == self.wallet
+                        && sol >= self.cold_storage_sol
+                        && !seen.contains(t.to_user_account.as_str())
+                    {
+                        found.push(detection(Pattern::ColdStorage { sol_amount: sol }, &[tx]));
+                    }
+                }
+            }
+            seen.extend(tx.counterparties(&self.wallet));
+        }
+        found
+    }
+
+    fn airdrops(&self, txs: &[WalletTx], prices: Option<&PriceSeries>) -> Vec<Detection> {
+        txs.iter()
+            .filter(|tx| !matches!(
+                tx.tx_type.as_str(),
+                "SWAP" | "NFT_SALE" | "NFT_MINT" | "COMPRESSED_NFT_MINT" | "TOKEN_MINT"
+            ))
+            .filter(|tx| tx.net_sol(&self.wallet) >= -self.airdrop_max_cost_sol)
+            .flat_map(|tx| {
+                let deltas = tx.token_deltas(&self.wallet);
+                // Anything sent back makes it a trade, not a gift
+                let paid = deltas.iter().any(|(_, delta)| *delta < 0.0);
+                deltas
+                    .into_iter()
+                    .filter(move |(_, delta)| !paid && *delta > 0.0)
+                    .map(move |(mint, amount)| {
+                        let price = prices.and_then(|p| p.price_at(&mint, tx.timestamp)).unwrap_or(0.0);
+                        detection(Pattern::AirdropReceiver { token: mint, value_sol: amount * price }, &[tx])
+                    })
+            })
+            .collect()
+    }
+
+    /// Tokens the wallet minted itself. With prices, only tokens that
+    /// went on to trade count; without, every launch does.
+    fn token_launches(&self, txs: &[WalletTx], prices: Option<&PriceSeries>) -> Vec<Detection> {
+        txs.iter()
+            .filter(|tx| tx.tx_type == "TOKEN_MINT")
+            .flat_map(|tx| {
+                tx.token_transfers
+                    .iter()
+                    .filter(|t| t.from_user_account.is_empty() && t.to_user_account == self.wallet)
+                    .filter(|t| prices.is_none_or(|p| p.get(&t.mint).is_some()))
+                    .map(move |t| detection(Pattern::TokenLauncher { token: t.mint.clone() }, &[tx]))
+            })
+            .collect()
+    }
+
+    /// Quick flips, rugs and diamond hands, from cost-basis PnL on each
+    /// token the wallet paid SOL for.
+    fn trades(&self, txs: &[WalletTx], prices: Option<&PriceSeries>) -> Vec<Detection> {
+        let tracker = PnlTracker::from_history(&self.wallet, self.cost_basis, txs);
+        let mut found = Vec::new();
+
+        for r in tracker.realizations().iter().filter(|r| r.cost_sol > 0.0) {
+            let (buy, sell) = (r.cost_sol / r.amount, r.proceeds_sol / r.amount);
+            if let Some(p) = detect_quick_flip(buy, sell, r.hold_seconds, &r.mint) {
+                found.push(Detection {
+                    pattern: p,
+                    signatures: r.buy_signatures.iter().chain([&r.signature]).cloned().collect(),
+                    timestamp: r.timestamp,
+                });
+            }
+        }
+
+        for position in tracker.positions().iter().filter(|p| p.bought_cost_sol > 0.0) {
+            let mint = &position.mint;
+            let support: Vec<&WalletTx> = txs.iter().filter(|tx| position.signatures.contains(&tx.signature)).collect();
+
+            // What the whole position came to per token bought: sales plus
+            // whatever is still held at the latest price
+            let held = position.amount();
+            let held_value = match prices.and_then(|p| p.latest(mint)) {
+                Some(price) => held * price,
+                None if held <= 0.0 => 0.0,
+                None => continue,
+            };
+            let avg_buy = position.avg_entry();
+            let exit = (position.proceeds_sol + held_value) / position.bought;
+
+            if let Some(p) = detect_rug_pull(avg_buy, exit, mint) {
+                found.push(detection(p, &support));
+                continue;
+            }
+
+            let first_buy = support.first().map_or(0, |tx| tx.timestamp);
+            let until = if position.is_closed() { support.last().map_or(i64::MAX, |tx| tx.timestamp) } else { i64::MAX };
+            let lowest = prices.and_then(|p| p.lowest_between(mint, first_buy, until));
+            if let Some(p) = lowest.and_then(|lowest| detect_diamond_hands(avg_buy, lowest, exit, mint)) {
+                found.push(detection(p, &support));
+            }
+        }
+        found
+    }
+
+    /// The worst SOL/USD drawdown the wallet was active on both sides of.
+    fn crash_survivor(&self, txs: &[WalletTx], prices: &PriceSeries) -> Option<Detection> {
+        let (first, last) = (txs.first()?, txs.last()?);
+        let series = prices.get(SOL_USD)?;
+        let window: Vec<_> = series
+            .iter()
+            .filter(|p| p.timestamp >= first.timestamp && p.timestamp <= last.timestamp)
+            .collect();
+
+        let mut peak = *window.first()?;
+        let mut worst: Option<(u32, i64, i64)> = None;
+        for &p in &window {
+            if p.price > peak.price {
+                peak = p;
+            }
+            if peak.price <= 0.0 {
+                continue;
+            }
+            let drawdown = ((peak.price - p.price) / peak.price * 100.0) as u32;
+            if worst.is_none_or(|(d, _, _)| drawdown > d) {
+                worst = Some((drawdown, peak.timestamp, p.timestamp));
+            }
+        }
+
+        let (drawdown_pct, peak_at, trough_at) = worst.filter(|(d, _, _)| *d >= self.crash_drawdown_pct)?;
+        let before = txs.iter().rev().find(|tx| tx.timestamp <= peak_at)?;
+        let after = txs.iter().find(|tx| tx.timestamp >= trough_at)?;
+        Some(detection(Pattern::CrashSurvivor { drawdown_pct }, &[before, after]))
+    }
+}
+
+fn detection(pattern: Pattern, txs: &[&WalletTx]) -> Detection {
+    Detection {
+        pattern,
+        signatures: txs.iter().map(|tx| tx.signature.clone()).collect(),
+        timestamp: txs.iter().map(|tx| tx.timestamp).max().unwrap_or(0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WALLET: &str = "CRYPTwa11et1111111111111111111111111111111";
+
+    fn history() -> Vec<WalletTx> {
+        serde_json::from_str(include_str!("../../fixtures/wallet_history.json")).unwrap()
+    }
+
+    fn prices() -> PriceSeries {
+        PriceSeries::from_json(include_str!("../../fixtures/prices.json")).unwrap()
+    }
+
+    fn detect_all() -> Vec<Detection> {
+        PatternDetector::new(WALLET).from_genesis(true).detect(&history(), Some(&prices()))
+    }
+
+    /// The single detection whose pattern matches `f`.
+    fn find(found: &[Detection], f: impl Fn(&Pattern) -> bool) -> Detection {
+        let matches: Vec<_> = found.iter().filter(|d| f(&d.pattern)).collect();
+        assert_eq!(matches.len(), 1, "expected one match in {:#?}", found);
+        matches[0].clone()
+    }
+
+    #[test]
+    fn test_fixture_quick_flip() {
+        let d = find(&detect_all(), |p| matches!(p, Pattern::QuickFlip { .. }));
+        let Pattern::QuickFlip { token, profit_pct, hold_hours } = &d.pattern else { unreachable!() };
+        assert_eq!(token, "BONK");
+        assert!((199..=200).contains(profit_pct));
+        assert_eq!(*hold_hours, 2);
+        assert_eq!(d.signatures, ["buy_bonk", "sell_bonk"]);
+    }
+
+    #[test]
+    fn test_fixture_rug_pull() {
+        let d = find(&detect_all(), |p| matches!(p, Pattern::RugPull { .. }));
+        assert_eq!(d.pattern, Pattern::RugPull { token: "SQUID".into(), loss_pct: 100 });
+        assert_eq!(d.signatures, ["buy_squid"]);
+    }
+
+    #[test]
+    fn test_fixture_diamond_hands() {
+        let d = find(&detect_all(), |p| matches!(p, Pattern::DiamondHands { .. }));
+        let Pattern::DiamondHands { token, max_drawdown_pct } = &d.pattern else { unreachable!() };
+        assert_eq!(token, "WIF");
+        assert!((79..=80).contains(max_drawdown_pct));
+        assert_eq!(d.signatures, ["buy_wif"]);
+    }
+
+    #[test]
+    fn test_fixture_early_mint() {
+        let d = find(&detect_all(), |p| matches!(p, Pattern::EarlyMint { .. }));
+        assert_eq!(d.pattern, Pattern::EarlyMint { collection: "DeGods".into(), mint_number: 5, total_supply: 10000 });
+        assert_eq!(d.signatures, ["early_mint"]);
+    }
+
+    #[test]
+    fn test_fixture_whale_and_cold_storage() {
+        let found = detect_all();
+        let whale = find(&found, |p| matches!(p, Pattern::WhaleMove { .. }));
+        let cold = find(&found, |p| matches!(p, Pattern::ColdStorage { .. }));
+        assert_eq!(whale.pattern, Pattern::WhaleMove { sol_amount: 250.0 });
+        assert_eq!(cold.pattern, Pattern::ColdStorage { sol_amount: 250.0 });
+        assert_eq!(whale.signatures, ["whale"]);
+        assert_eq!(cold.signatures, ["whale"]);
+    }
+
+    #[test]
+    fn test_fixture_cold_storage_needs_new_address() {
+        let mut txs = history();
+        // Sending back to the funder isn't cold storage
+        txs[6].native_transfers[0].to_user_account = "FUNDER".into();
+        let found = PatternDetector::new(WALLET).detect(&txs, None);
+        assert!(found.iter().any(|d| matches!(d.pattern, Pattern::WhaleMove { .. })));
+        assert!(!found.iter().any(|d| matches!(d.pattern, Pattern::ColdStorage { .. })));
+    }
+
+    #[test]
+    fn test_fixture_genesis() {
+        let d = find(&detect_all(), |p| *p == Pattern::GenesisTransaction);
+        assert_eq!(d.signatures, ["genesis"]);
+        let partial = PatternDetector::new(WALLET).detect(&history(), Some(&prices()));
+        assert!(!partial.iter().any(|d| d.pattern == Pattern::GenesisTransaction));
+    }
+
+    #[test]
+    fn test_fixture_crash_survivor() {
+        let d = find(&detect_all(), |p| matches!(p, Pattern::CrashSurvivor { .. }));
+        assert_eq!(d.pattern, Pattern::CrashSurvivor { drawdown_pct: 60 });
+        assert_eq!(d.signatures, ["launch", "late"]);
+
+        // Gone before the bottom, so nothing was survived
+        let txs = history();
+        let found = PatternDetector::new(WALLET).detect(&txs[..9], Some(&prices()));
+        assert!(!found.iter().any(|d| matches!(d.pattern, Pattern::CrashSurvivor { .. })));
+    }
+
+    #[test]
+    fn test_fixture_airdrop() {
+        let d = find(&detect_all(), |p| matches!(p, Pattern::AirdropReceiver { .. }));
+        assert_eq!(d.pattern, Pattern::AirdropReceiver { token: "JUP".into(), value_sol: 2.5 });
+        assert_eq!(d.signatures, ["airdrop"]);
+    }
+
+    #[test]
+    fn test_fixture_token_launcher() {
+        let d = find(&detect_all(), |p| matches!(p, Pattern::TokenLauncher { .. }));
+        assert_eq!(d.pattern, Pattern::TokenLauncher { token: "CRYPTTOKEN".into() });
+        assert_eq!(d.signatures, ["launch"]);
+
+        // A launch that never traded doesn't count once prices are known
+        let unlisted = PriceSeries::new();
+        let found = PatternDetector::new(WALLET).detect(&history(), Some(&unlisted));
+        assert!(!found.iter().any(|d| matches!(d.pattern, Pattern::TokenLauncher { .. })));
+    }
+
+    #[test]
+    fn test_detections_in_order() {
+        let found = detect_all();
+        assert_eq!(found.len(), 10);
+        assert!(found.windows(2).all(|w| w[0].timestamp <= w[1].timestamp));
+    }
+
+    #[test]
+    fn test_without_prices() {
+        let found = PatternDetector::new(WALLET).detect(&history(), None);
+        // Trade-to-trade evidence still finds the flip; nothing needs a price feed
+        assert!(found.iter().any(|d| matches!(d.pattern, Pattern::QuickFlip { .. })));
+        assert!(!found.iter().any(|d| matches!(
+            d.pattern,
+            Pattern::RugPull { .. } | Pattern::DiamondHands { .. } | Pattern::CrashSurvivor { .. }
+        )));
+    }
+
+    #[test]
+    fn test_diamond_hands_detected() {
+        let p = detect_diamond_hands(10.0, 2.0, 8.0, "SOL");
+        assert!(p.is_some());
+        if let Some(Pattern::DiamondHands { max_drawdown_pct, .. }) = p {
+            assert_eq!(max_drawdown_pct, 80);
+        }
+    }
+
+    #[test]
+    fn test_diamond_hands_not_enough_drawdown() {
+        let p = detect_diamond_hands(10.0, 8.0, 10.0, "SOL");
+        assert!(p.is_none());
+    }
+
+    #[test]
+    fn test_rug_pull_detected() {
+        let p = detect_rug_pull(5.0, 0.01, "SQUID");
+        assert!(p.is_some());
+        if let Some(Pattern::RugPull { loss_pct, .. }) = p {
+            assert!(loss_pct > 99);
+        }
+    }
+
+    #[test]
+    fn test_rug_pull_not_enough_loss() {
+        let p = detect_rug_pull(5.0, 3.0, "TOKEN");
+        assert!(p.is_none());
+    }
+
+    #[test]
+    fn test_quick_flip() {
+        let p = detect_quick_flip(1.0, 3.0, 3600, "BONK");
+        assert!(p.is_some());
+        if let Some(Pattern::QuickFlip { profit_pct, hold_hours, .. }) = p {
+            assert_eq!(profit_pct, 200);
+            assert_eq!(hold_hours, 1);
+        }
+    }
+
+    #[test]
+    fn test_quick_flip_too_long() {
+        let p = detect_quick_flip(1.0, 3.0, 100000, "BONK");
+        assert!(p.is_none());
+    }
+
+    #[test]
+    fn test_pattern_titles() {
+        let patterns = vec![
+            Pattern::DiamondHands { token: "SOL".into(), max_drawdown_pct: 75 },
+            Pattern::RugPull { token: "SQUID".into(), loss_pct: 99 },
+            Pattern::EarlyMint { collection: "DeGods".into(), mint_number: 5, total_supply: 10000 },
+            Pattern::WhaleMove { sol_amount: 500.0 },
+            Pattern::GenesisTransaction,
+        ];
+        for p in &patterns {
+            assert!(!p.title().is_empty());
+            assert!(p.rarity_bonus() > 0);
+            assert!(!p.card_type().is_empty());
+        }
+    }
+
+    #[test]
+    fn test_early_mint_rarity_bonus() {
+        let early = Pattern::EarlyMint { collection: "X".into(), mint_number: 5, total_supply: 10000 };
+        let late = Pattern::EarlyMint { collection: "X".into(), mint_number: 500, total_supply: 10000 };
+        assert!(early.rarity_bonus() > late.rarity_bonus());
+    }
+}
@@ -0,0 +1,544 @@
+//! Cost-basis PnL — per-token positions over a wallet's history.
+//!
+//! Every token the wallet receives opens a lot at what it paid in SOL;
+//! every SOL sale closes lots under the chosen [`CostBasis`] and records a
+//! [`Realization`]. Swaps that route through other tokens only show
+//! their net legs, so multi-hop trades price like direct ones.
+//! Token-for-token swaps carry the cost of what was given up over to
+//! what was received, and tokens that arrive for free (airdrops, mints
+//! without SOL) open at zero cost. Wrapped SOL counts as SOL, so a swap
+//! routed through a wSOL account prices the same as a native one.
+
+use std::collections::VecDeque;
+use crate::analytics::history::{PriceSeries, WalletTx, WSOL_MINT};
+
+/// How sold tokens are matched against the lots they came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CostBasis {
+    /// Oldest lots are sold first
+    #[default]
+    Fifo,
+    /// Every lot is sold down in proportion, at the average cost
+    Average,
+}
+
+/// Tokens received in one transaction, and what they cost.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lot {
+    pub amount: f64,
+    pub cost_sol: f64,
+    pub timestamp: i64,
+    pub signature: String,
+}
+
+/// PnL booked by one sale of one token.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Realization {
+    pub mint: String,
+    pub signature: String,
+    pub timestamp: i64,
+    /// Tokens matched against lots; sells beyond the known position
+    /// have no cost basis and are left out
+    pub amount: f64,
+    pub cost_sol: f64,
+    pub proceeds_sol: f64,
+    /// Seconds since the oldest lot sold was bought
+    pub hold_seconds: u64,
+    /// Transactions that bought the lots sold, oldest first
+    pub buy_signatures: Vec<String>,
+}
+
+impl Realization {
+    pub fn pnl_sol(&self) -> f64 {
+        self.proceeds_sol - self.cost_sol
+    }
+
+    pub fn pnl_pct(&self) -> f64 {
+        pct_of(self.pnl_sol(), self.cost_sol)
+    }
+
+    /// Card-style label, e.g. `+4,200%`.
+    pub fn label(&self) -> String {
+        format_pnl(self.pnl_pct())
+    }
+}
+
+/// One token's open lots and booked PnL.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Position {
+    pub mint: String,
+    /// Open lots, oldest first
+    pub lots: VecDeque<Lot>,
+    /// Everything ever received, and what it cost
+    pub bought: f64,
+    pub bought_cost_sol: f64,
+    /// Everything sold against lots, its cost, and what it fetched
+    pub sold: f64,
+    pub sold_cost_sol: f64,
+    pub proceeds_sol: f64,
+    /// Every transaction that moved this token, oldest first
+    pub signatures: Vec<String>,
+}
+
+impl Position {
+    fn new(mint: &str) -> Self {
+        Self {
+            mint: mint.to_string(),
+            lots: VecDeque::new(),
+            bought: 0.0,
+            bought_cost_sol: 0.0,
+            sold: 0.0,
+            sold_cost_sol: 0.0,
+            proceeds_sol: 0.0,
+            signatures: Vec::new(),
+        }
+    }
+
+    /// Tokens still held.
+    pub fn amount(&self) -> f64 {
+        self.lots.iter().map(|l| l.amount).sum()
+    }
+
+    /// Cost of the tokens still held.
+    pub fn cost_sol(&self) -> f64 {
+        self.lots.iter().map(|l| l.cost_sol).sum()
+    }
+
+    pub fn realized_sol(&self) -> f64 {
+        self.proceeds_sol - self.sold_cost_sol
+    }
+
+    /// Average price paid per token across every buy.
+    pub fn avg_entry(&self) -> f64 {
+        if self.bought > 0.0 { self.bought_cost_sol / self.bought } else { 0.0 }
+    }
+
+    /// Average price received per token across every sale.
+    pub fn avg_exit(&self) -> Option<f64> {
+        (self.sold > 0.0).then(|| self.proceeds_sol / self.sold)
+    }
+
+    /// Whether (almost) everything bought has been sold or sent away.
+    pub fn is_closed(&self) -> bool {
+        self.amount() <= self.bought * 0.01
+    }
+
+    fn buy(&mut self, lot: Lot) {
+        self.bought += lot.amount;
+        self.bought_cost_sol += lot.cost_sol;
+        self.lots.push_back(lot);
+    }
+
+    /// Take `amount` out of the open lots. Returns the tokens actually
+    /// matched, their cost, and the lots they came from.
+    fn take(&mut self, amount: f64, basis: CostBasis) -> (f64, f64, Vec<Lot>) {
+        let held = self.amount();
+        let amount = amount.min(held);
+        if amount <= 0.0 {
+            return (0.0, 0.0, Vec::new());
+        }
+
+        let mut cost = 0.0;
+        let mut from = Vec::new();
+        match basis {
+            CostBasis::Fifo => {
+                let mut left = amount;
+                while left > 0.0 {
+                    let Some(lot) = self.lots.front_mut() else { break };
+                    let used = left.min(lot.amount);
+                    let used_cost = lot.cost_sol * used / lot.amount;
+                    cost += used_cost;
+                    from.push(Lot { amount: used, cost_sol: used_cost, ..lot.clone() });
+                    lot.amount -= used;
+                    lot.cost_sol -= used_cost;
+                    left -= used;
+                    if lot.amount <= 0.0 {
+                        self.lots.pop_front();
+                    }
+                }
+            }
+            CostBasis::Average => {
+                let share = amount / held;
+                for lot in self.lots.iter_mut() {
+                    let used_cost = lot.cost_sol * share;
+                    cost += used_cost;
+                    from.push(Lot { amount: lot.amount * share, cost_sol: used_cost, ..lot.clone() });
+                    lot.amount -= lot.amount * share;
+                    lot.cost_sol -= used_cost;
+                }
+                self.lots.retain(|l| l.amount > 0.0);
+            }
+        }
+        (amount, cost, from)
+    }
+}
+
+/// A token's PnL at a given price.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TokenPnl {
+    pub mint: String,
+    pub held: f64,
+    pub cost_sol: f64,
+    pub realized_sol: f64,
+    /// `None` when there's no price for what's still held
+    pub unrealized_sol: Option<f64>,
+    /// Cost of what was sold
+    pub sold_cost_sol: f64,
+    /// Cost of everything ever bought
+    pub invested_sol: f64,
+}
+
+impl TokenPnl {
+    pub fn realized_pct(&self) -> f64 {
+        pct_of(self.realized_sol, self.sold_cost_sol)
+    }
+
+    pub fn unrealized_pct(&self) -> Option<f64> {
+        self.unrealized_sol.map(|u| pct_of(u, self.cost_sol))
+    }
+
+    pub fn total_sol(&self) -> f64 {
+        self.realized_sol + self.unrealized_sol.unwrap_or(0.0)
+    }
+
+    pub fn total_pct(&self) -> f64 {
+        pct_of(self.total_sol(), self.invested_sol)
+    }
+
+    /// Card-style label for the whole position, e.g. `-99%`.
+    pub fn label(&self) -> String {
+        format_pnl(self.total_pct())
+    }
+}
+
+/// Tracks every token position across a wallet's history.
+#[derive(Debug, Clone)]
+pub struct PnlTracker {
+    wallet: String,
+    basis: CostBasis,
+    positions: Vec<Position>,
+    realizations: Vec<Realization>,
+}
+
+impl PnlTracker {
+    pub fn new(wallet: &str, basis: CostBasis) -> Self {
+        Self { wallet: wallet.to_string(), basis, positions: Vec::new(), realizations: Vec::new() }
+    }
+
+    /// Track a whole history, oldest transaction first.
+    pub fn from_history(wallet: &str, basis: CostBasis, txs: &[WalletTx]) -> Self {
+        let mut tracker = Self::new(wallet, basis);
+        for tx in txs {
+            tracker.push(tx);
+        }
+        tracker
+    }
+
+    /// Apply the next transaction. Must be called oldest first.
+    pub fn push(&mut self, tx: &WalletTx) {
+        let mut deltas = tx.token_deltas(&self.wallet);
+        deltas.retain(|(mint, _)| mint != WSOL_MINT);
+        if deltas.is_empty() {
+            return;
+        }
+        let net_sol = tx.net_sol_with_wsol(&self.wallet);
+        let (ins, outs): (Vec<_>, Vec<_>) = deltas.into_iter().partition(|(_, delta)| *delta > 0.0);
+
+        // Tokens out for SOL in is a sale; anything else moves the cost
+        // basis along without booking PnL
+        let mut carried = 0.0;
+        let sale = ins.is_empty() && net_sol > 0.0;
+        for (mint, delta) in &outs {
+            let basis = self.basis;
+            let position = self.position_mut(mint, &tx.signature);
+            let (amount, cost, from) = position.take(-delta, basis);
+            if !sale {
+                carried += cost;
+                continue;
+            }
+
+            // Sells beyond the known position only earn for what matched
+            let proceeds = net_sol / outs.len() as f64 * amount / -delta;
+            position.sold += amount;
+            position.sold_cost_sol += cost;
+            position.proceeds_sol += proceeds;
+            if amount <= 0.0 {
+                continue;
+            }
+            let opened = from.iter().map(|l| l.timestamp).min().unwrap_or(tx.timestamp);
+            let mut buy_signatures: Vec<String> = Vec::new();
+            for lot in &from {
+                if !buy_signatures.contains(&lot.signature) {
+                    buy_signatures.push(lot.signature.clone());
+                }
+            }
+            self.realizations.push(Realization {
+                mint: mint.clone(),
+                signature: tx.signature.clone(),
+                timestamp: tx.timestamp,
+                amount,
+                cost_sol: cost,
+                proceeds_sol: proceeds,
+                hold_seconds: (tx.timestamp - opened).max(0) as u64,
+                buy_signatures,
+            });
+        }
+
+        // Received tokens split what was paid for them evenly
+        let cost = (carried + (-net_sol).max(0.0)) / ins.len().max(1) as f64;
+        for (mint, amount) in ins {
+            self.position_mut(&mint, &tx.signature).buy(Lot {
+                amount,
+                cost_sol: cost,
+                timestamp: tx.timestamp,
+                signature: tx.signature.clone(),
+            });
+        }
+    }
+
+    fn position_mut(&mut self, mint: &str, signature: &str) -> &mut Position {
+        let i = match self.positions.iter().position(|p| p.mint == mint) {
+            Some(i) => i,
+            None => {
+                self.positions.push(Position::new(mint));
+                self.positions.len() - 1
+            }
+        };
+        let position = &mut self.positions[i];
+        position.signatures.push(signature.to_string());
+        position
+    }
+
+    pub fn positions(&self) -> &[Position] {
+        &self.positions
+    }
+
+    pub fn position(&self, mint: &str) -> Option<&Position> {
+        self.positions.iter().find(|p| p.mint == mint)
+    }
+
+    /// Every sale, in history order.
+    pub fn realizations(&self) -> &[Realization] {
+        &self.realizations
+    }
+
+    /// Sales booked by one transaction.
+    pub fn realizations_for<'a>(&'a self, signature: &'a str) -> impl Iterator<Item = &'a Realization> + 'a {
+        self.realizations.iter().filter(move |r| r.signature == signature)
+    }
+
+    /// PnL for `mint`, valuing what's held at `price` SOL per token.
+    pub fn token_pnl(&self, mint: &str, price: Option<f64>) -> Option<TokenPnl> {
+        let p = self.position(mint)?;
+        let held = p.amount();
+        let cost_sol = p.cost_sol();
+        let unrealized_sol = if held > 0.0 { price.map(|price| held * price - cost_sol) } else { Some(0.0) };
+        Some(TokenPnl {
+            mint: p.mint.clone(),
+            held,
+            cost_sol,
+            realized_sol: p.realized_sol(),
+            unrealized_sol,
+            sold_cost_sol: p.sold_cost_sol,
+            invested_sol: p.bought_cost_sol,
+        })
+    }
+
+    /// Every token's PnL at its latest price.
+    pub fn report(&self, prices: Option<&PriceSeries>) -> Vec<TokenPnl> {
+        self.positions
+            .iter()
+            .filter_map(|p| self.token_pnl(&p.mint, prices.and_then(|s| s.latest(&p.mint))))
+            .collect()
+    }
+}
+
+fn pct_of(pnl: f64, cost: f64) -> f64 {
+    if cost > 0.0 { pnl / cost * 100.0 } else { 0.0 }
+}
+
+/// Format a percentage the way card `pnl` fields show it: signed, whole,
+/// with thousands separators (`+4,200%`, `-99%`).
+pub fn format_pnl(pct: f64) -> String {
+    let whole = pct.round();
+    let digits = (whole.abs() as u64).to_string();
+    let mut grouped = String::new();
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(c);
+    }
+    format!("{}{}%", if whole < 0.0 { "-" } else { "+" }, grouped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analytics::history::{NativeTransfer, PricePoint, TokenTransfer};
+
+    const W: &str = "WALLET";
+
+    /// A swap of `sol` SOL (negative when paid) against token legs.
+    fn swap(sig: &str, timestamp: i64, sol: f64, legs: &[(&str, f64)]) -> WalletTx {
+        let lamports = (sol.abs() * 1e9) as u64;
+        let native = if sol < 0.0 {
+            NativeTransfer { from_user_account: W.into(), to_user_account: "POOL".into(), amount: lamports }
+        } else {
+            NativeTransfer { from_user_account: "POOL".into(), to_user_account: W.into(), amount: lamports }
+        };
+        WalletTx {
+            signature: sig.into(),
+            timestamp,
+            tx_type: "SWAP".into(),
+            native_transfers: if sol == 0.0 { vec![] } else { vec![native] },
+            token_transfers: legs.iter().map(|(mint, amount)| {
+                let (from, to) = if *amount > 0.0 { ("POOL", W) } else { (W, "POOL") };
+                TokenTransfer {
+                    from_user_account: from.into(),
+                    to_user_account: to.into(),
+                    mint: mint.to_string(),
+                    token_amount: amount.abs(),
+                    ..Default::default()
+                }
+            }).collect(),
+            ..Default::default()
+        }
+    }
+
+    fn lots() -> Vec<WalletTx> {
+        vec![
+            swap("buy1", 0, -1.0, &[("BONK", 100.0)]),
+            swap("buy2", 3600, -3.0, &[("BONK", 100.0)]),
+            swap("sell", 7200, 4.0, &[("BONK", -150.0)]),
+        ]
+    }
+
+    #[test]
+    fn test_fifo_partial_sell() {
+        let t = PnlTracker::from_history(W, CostBasis::Fifo, &lots());
+        let r = &t.realizations()[0];
+        // 100 @ 0.01 + 50 @ 0.03
+        assert!((r.cost_sol - 2.5).abs() < 1e-9);
+        assert!((r.pnl_sol() - 1.5).abs() < 1e-9);
+        assert_eq!(r.label(), "+60%");
+        assert_eq!(r.hold_seconds, 7200);
+        assert_eq!(r.buy_signatures, ["buy1", "buy2"]);
+
+        let p = t.position("BONK").unwrap();
+        assert_eq!(p.amount(), 50.0);
+        assert!((p.cost_sol() - 1.5).abs() < 1e-9);
+        assert!(!p.is_closed());
+    }
+
+    #[test]
+    fn test_average_partial_sell() {
+        let t = PnlTracker::from_history(W, CostBasis::Average, &lots());
+        let r = &t.realizations()[0];
+        // 150 @ 0.02
+        assert!((r.cost_sol - 3.0).abs() < 1e-9);
+        assert_eq!(r.label(), "+33%");
+        assert!((t.position("BONK").unwrap().cost_sol() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_unrealized_and_total() {
+        let t = PnlTracker::from_history(W, CostBasis::Fifo, &lots());
+        let pnl = t.token_pnl("BONK", Some(0.05)).unwrap();
+        assert!((pnl.unrealized_sol.unwrap() - 1.0).abs() < 1e-9);
+        assert!((pnl.unrealized_pct().unwrap() - 66.666).abs() < 0.01);
+        assert!((pnl.realized_pct() - 60.0).abs() < 1e-9);
+        // 2.5 SOL on 4 invested
+        assert!((pnl.total_pct() - 62.5).abs() < 1e-9);
+        assert_eq!(t.token_pnl("BONK", None).unwrap().unrealized_sol, None);
+
+        let prices = PriceSeries::new().with("BONK", vec![PricePoint { timestamp: 0, price: 0.0 }]);
+        assert_eq!(t.report(Some(&prices))[0].label(), "+0%");
+    }
+
+    #[test]
+    fn test_multi_hop_swap_nets_out() {
+        // SOL -> USDC -> WIF in one transaction
+        let tx = swap("hop", 0, -2.0, &[("USDC", 300.0), ("USDC", -300.0), ("WIF", 50.0)]);
+        let t = PnlTracker::from_history(W, CostBasis::Fifo, &[tx]);
+        assert!(t.position("USDC").is_none());
+        assert_eq!(t.position("WIF").unwrap().cost_sol(), 2.0);
+    }
+
+    #[test]
+    fn test_fixture_wsol_round_trip() {
+        // Wraps 2 SOL to buy BONK, then sells for 5 wSOL and closes the
+        // wSOL account, as Jupiter does with SOL wrapping on
+        let txs: Vec<WalletTx> = serde_json::from_str(include_str!("../../fixtures/wsol_swaps.json")).unwrap();
+        let wallet = "CRYPTwa11et1111111111111111111111111111111";
+        assert_eq!(txs[0].net_sol_with_wsol(wallet), -2.0);
+        assert_eq!(txs[1].net_sol_with_wsol(wallet), 5.0);
+
+        let t = PnlTracker::from_history(wallet, CostBasis::Fifo, &txs);
+        assert!(t.position(WSOL_MINT).is_none());
+        let r = &t.realizations()[0];
+        assert!((r.cost_sol - 2.0).abs() < 1e-9);
+        assert!((r.proceeds_sol - 5.0).abs() < 1e-9);
+        assert_eq!(r.label(), "+150%");
+        assert!(t.position("BONK").unwrap().is_closed());
+    }
+
+    #[test]
+    fn test_wsol_routed_swaps_count_as_sol() {
+        // Paying from and getting paid into a standing wSOL balance
+        let txs = [
+            swap("buy", 0, 0.0, &[(WSOL_MINT, -2.0), ("BONK", 100.0)]),
+            swap("sell", 10, 0.0, &[("BONK", -100.0), (WSOL_MINT, 5.0)]),
+        ];
+        let t = PnlTracker::from_history(W, CostBasis::Fifo, &txs);
+        assert!(t.position(WSOL_MINT).is_none());
+        assert_eq!(t.realizations().len(), 1);
+        let r = &t.realizations()[0];
+        assert!((r.cost_sol - 2.0).abs() < 1e-9);
+        assert!((r.proceeds_sol - 5.0).abs() < 1e-9);
+        assert_eq!(r.label(), "+150%");
+
+        // A mix of lamports and wSOL nets out the same
+        let mixed = swap("mixed", 0, -0.5, &[(WSOL_MINT, -1.5), ("WIF", 10.0)]);
+        let t = PnlTracker::from_history(W, CostBasis::Fifo, &[mixed]);
+        assert!((t.position("WIF").unwrap().cost_sol() - 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_token_swap_carries_cost() {
+        let txs = [
+            swap("buy", 0, -2.0, &[("BONK", 100.0)]),
+            swap("rotate", 10, 0.0, &[("BONK", -100.0), ("WIF", 10.0)]),
+            swap("sell", 20, 5.0, &[("WIF", -10.0)]),
+        ];
+        let t = PnlTracker::from_history(W, CostBasis::Fifo, &txs);
+        assert_eq!(t.realizations().len(), 1);
+        let r = &t.realizations()[0];
+        assert_eq!(r.mint, "WIF");
+        assert!((r.cost_sol - 2.0).abs() < 1e-9);
+        assert_eq!(r.label(), "+150%");
+        assert!(t.position("BONK").unwrap().is_closed());
+    }
+
+    #[test]
+    fn test_sell_beyond_position() {
+        let txs = [
+            swap("buy", 0, -1.0, &[("BONK", 100.0)]),
+            swap("sell", 10, 4.0, &[("BONK", -200.0)]),
+        ];
+        let t = PnlTracker::from_history(W, CostBasis::Fifo, &txs);
+        let r = &t.realizations()[0];
+        assert_eq!(r.amount, 100.0);
+        assert!((r.proceeds_sol - 2.0).abs() < 1e-9);
+        assert_eq!(r.label(), "+100%");
+    }
+
+    #[test]
+    fn test_format_pnl() {
+        assert_eq!(format_pnl(4200.0), "+4,200%");
+        assert_eq!(format_pnl(-99.4), "-99%");
+        assert_eq!(format_pnl(1_234_567.0), "+1,234,567%");
+        assert_eq!(format_pnl(0.0), "+0%");
+        assert_eq!(format_pnl(999.6), "+1,000%");
+    }
+}
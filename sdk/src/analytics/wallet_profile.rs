@@ -0,0 +1,703 @@
+//! Wallet profiling — classifies wallets based on transaction history.
+//! Used to determine which transactions are most "card-worthy" for each wallet.
+
+use std::collections::HashSet;
+use crate::analytics::history::WalletTx;
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Known memecoin mints
+pub const MEMECOIN_MINTS: &[&str] = &[
+    "DezXAZ8z7PnrnRJjz3wXBoRgixCa6xjnB7YaB1pPB263", // BONK
+    "EKpQGSJtjMFqKZ9KQanSqYXRcF8fBopzLHYxdM65zcjm", // WIF
+    "7GCihgDB8fe6KNjn2MYtkzZcRjQy3t9GHdC8uHYmW2hr", // POPCAT
+    "MEW1gQWJ3nEXg2qgERiKu7FAFj79PHvQVREQUzScPP5",  // MEW
+    "A3eME5CetyZPBoWbRUwY3tSe25S6tb18ba9ZPbWk9eFJ",  // PENG
+    "WENWENvqqNya429ubCdR81ZmD69brwQaaBYY6p3LCpk",   // WEN
+];
+
+/// Wallet archetype based on transaction patterns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WalletArchetype {
+    /// High-frequency memecoin trader, many small trades
+    Degen,
+    /// Large position sizes, low frequency
+    Whale,
+    /// Primarily NFT activity (mints, sales, listings)
+    Collector,
+    /// Mostly staking and DeFi yield farming
+    Farmer,
+    /// Token creator, launched projects
+    Builder,
+    /// Mixed activity, no dominant pattern
+    Explorer,
+    /// New wallet, limited history
+    Newcomer,
+    /// Inactive, long gaps between transactions
+    Ghost,
+}
+
+impl WalletArchetype {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Degen => "DEGEN",
+            Self::Whale => "WHALE",
+            Self::Collector => "COLLECTOR",
+            Self::Farmer => "FARMER",
+            Self::Builder => "BUILDER",
+            Self::Explorer => "EXPLORER",
+            Self::Newcomer => "NEWCOMER",
+            Self::Ghost => "GHOST",
+        }
+    }
+
+    /// Get the archetype's flavor text for card narrations.
+    pub fn flavor(&self) -> &'static str {
+        match self {
+            Self::Degen => "A creature of pure instinct. Charts are suggestions. Sleep is optional.",
+            Self::Whale => "The water parts when this wallet moves. Everyone watches. Nobody leads.",
+            Self::Collector => "Every mint is a bet on culture. Every sale is a statement.",
+            Self::Farmer => "Patient capital. Compounding faith. The long game is the only game.",
+            Self::Builder => "From zero to contract address. Creating something from nothing.",
+            Self::Explorer => "No pattern. No thesis. Just motion. Sometimes that's enough.",
+            Self::Newcomer => "Fresh address. Clean slate. Everything ahead is unwritten.",
+            Self::Ghost => "Long silence between transactions. But when it moves, it means something.",
+        }
+    }
+}
+
+/// Statistics for wallet classification.
+#[derive(Debug, Clone, Default)]
+pub struct WalletStats {
+    pub total_txs: u64,
+    pub swap_count: u64,
+    pub nft_count: u64,
+    pub transfer_count: u64,
+    pub stake_count: u64,
+    pub token_creates: u64,
+    pub burns: u64,
+    pub memecoin_trades: u64,
+    pub max_sol_moved: f64,
+    pub total_sol_volume: f64,
+    pub avg_tx_value_sol: f64,
+    pub unique_tokens_traded: u64,
+    pub unique_nft_collections: u64,
+    pub first_tx_timestamp: i64,
+    pub last_tx_timestamp: i64,
+    pub active_days: u64,
+    pub longest_gap_days: u64,
+}
+
+/// Cut-offs for each archetype rule. The defaults are the values
+/// `classify()` has always used.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArchetypeThresholds {
+    /// Wallets with fewer transactions are newcomers
+    pub newcomer_max_txs: u64,
+    /// A gap longer than this many days...
+    pub ghost_gap_days: u64,
+    /// ...on a wallet with fewer transactions makes a ghost
+    pub ghost_max_txs: u64,
+    pub builder_token_creates: u64,
+    pub whale_avg_sol: f64,
+    pub whale_max_sol: f64,
+    /// Share of transactions that are swaps
+    pub degen_swap_ratio: f64,
+    /// Share of swaps that touch a memecoin
+    pub degen_memecoin_ratio: f64,
+    pub degen_unique_tokens: u64,
+    /// Share of transactions that are NFT activity
+    pub collector_nft_ratio: f64,
+    pub collector_collections: u64,
+    /// Share of transactions that are staking
+    pub farmer_stake_ratio: f64,
+}
+
+impl Default for ArchetypeThresholds {
+    fn default() -> Self {
+        Self {
+            newcomer_max_txs: 5,
+            ghost_gap_days: 90,
+            ghost_max_txs: 20,
+            builder_token_creates: 2,
+            whale_avg_sol: 50.0,
+            whale_max_sol: 500.0,
+            degen_swap_ratio: 0.6,
+            degen_memecoin_ratio: 0.3,
+            degen_unique_tokens: 20,
+            collector_nft_ratio: 0.4,
+            collector_collections: 10,
+            farmer_stake_ratio: 0.3,
+        }
+    }
+}
+
+/// How well a wallet fits one archetype.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArchetypeScore {
+    pub archetype: WalletArchetype,
+    /// 0.0 to 1.0; 0.5 is right at the rule's threshold
+    pub confidence: f64,
+    /// Whether the archetype's own rule passes; several can at once
+    pub matched: bool,
+    /// The stats behind the confidence, e.g. "62% of transactions are swaps"
+    pub reasons: Vec<String>,
+}
+
+/// Every archetype's score for a wallet.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArchetypeProfile {
+    /// The primary archetype first, then the rest by confidence
+    pub scores: Vec<ArchetypeScore>,
+}
+
+impl ArchetypeProfile {
+    /// The first archetype whose rule matches, in `classify()` order.
+    /// Its confidence can be below the secondary's when a higher-priority
+    /// rule only just passes.
+    pub fn primary(&self) -> &ArchetypeScore {
+        &self.scores[0]
+    }
+
+    /// The best-fitting archetype after the primary, if any fits at all.
+    pub fn secondary(&self) -> Option<&ArchetypeScore> {
+        self.scores.get(1).filter(|s| s.confidence > 0.0)
+    }
+
+    pub fn get(&self, archetype: WalletArchetype) -> Option<&ArchetypeScore> {
+        self.scores.iter().find(|s| s.archetype == archetype)
+    }
+}
+
+/// How strongly `value` clears `threshold`: 0.5 at the threshold, 1.0 at
+/// twice it.
+fn strength(value: f64, threshold: f64) -> f64 {
+    if threshold <= 0.0 { return if value > 0.0 { 1.0 } else { 0.5 }; }
+    (value / threshold * 0.5).clamp(0.0, 1.0)
+}
+
+fn pct(ratio: f64) -> u32 {
+    (ratio * 100.0).round() as u32
+}
+
+impl WalletStats {
+    fn ratio(&self, count: u64) -> f64 {
+        if self.total_txs > 0 { count as f64 / self.total_txs as f64 } else { 0.0 }
+    }
+
+    fn memecoin_ratio(&self) -> f64 {
+        if self.swap_count > 0 {
+            self.memecoin_trades as f64 / self.swap_count as f64
+        } else { 0.0 }
+    }
+
+    /// Classify the wallet into an archetype.
+    pub fn classify(&self) -> WalletArchetype {
+        self.classify_with(&ArchetypeThresholds::default())
+    }
+
+    /// Classify the wallet with custom thresholds. Rules are checked in
+    /// priority order and the first match wins.
+    pub fn classify_with(&self, t: &ArchetypeThresholds) -> WalletArchetype {
+        if self.total_txs < t.newcomer_max_txs {
+            return WalletArchetype::Newcomer;
+        }
+
+        if self.longest_gap_days > t.ghost_gap_days && self.total_txs < t.ghost_max_txs {
+            return WalletArchetype::Ghost;
+        }
+
+        // Calculate ratios
+        let swap_ratio = self.ratio(self.swap_count);
+        let nft_ratio = self.ratio(self.nft_count);
+        let stake_ratio = self.ratio(self.stake_count);
+        let memecoin_ratio = self.memecoin_ratio();
+
+        // Token creators
+        if self.token_creates >= t.builder_token_creates {
+            return WalletArchetype::Builder;
+        }
+
+        // Whale detection: large average trade size
+        if self.avg_tx_value_sol > t.whale_avg_sol || self.max_sol_moved > t.whale_max_sol {
+            return WalletArchetype::Whale;
+        }
+
+        // Degen: high swap frequency with memecoin exposure
+        if swap_ratio > t.degen_swap_ratio
+            && (memecoin_ratio > t.degen_memecoin_ratio || self.unique_tokens_traded > t.degen_unique_tokens)
+        {
+            return WalletArchetype::Degen;
+        }
+
+        // Collector: primarily NFT activity
+        if nft_ratio > t.collector_nft_ratio || self.unique_nft_collections > t.collector_collections {
+            return WalletArchetype::Collector;
+        }
+
+        // Farmer: staking/DeFi focused
+        if stake_ratio > t.farmer_stake_ratio {
+            return WalletArchetype::Farmer;
+        }
+
+        WalletArchetype::Explorer
+    }
+
+    /// Score every archetype with the default thresholds.
+    pub fn archetype_scores(&self) -> ArchetypeProfile {
+        self.archetype_scores_with(&ArchetypeThresholds::default())
+    }
+
+    /// Score every archetype. The primary is always what
+    /// [`classify_with`](Self::classify_with) returns; the rest are
+    /// ranked by confidence.
+    pub fn archetype_scores_with(&self, t: &ArchetypeThresholds) -> ArchetypeProfile {
+        let swap_ratio = self.ratio(self.swap_count);
+        let nft_ratio = self.ratio(self.nft_count);
+        let stake_ratio = self.ratio(self.stake_count);
+        let memecoin_ratio = self.memecoin_ratio();
+        let primary = self.classify_with(t);
+
+        let mut scores = Vec::with_capacity(8);
+        let mut push = |archetype, matched, confidence: f64, reasons: Vec<String>| {
+            scores.push(ArchetypeScore { archetype, confidence, matched, reasons });
+        };
+
+        push(
+            WalletArchetype::Newcomer,
+            self.total_txs < t.newcomer_max_txs,
+            strength(t.newcomer_max_txs as f64, self.total_txs.max(1) as f64),
+            vec![format!("{} transactions", self.total_txs)],
+        );
+        push(
+            WalletArchetype::Ghost,
+            self.longest_gap_days > t.ghost_gap_days && self.total_txs < t.ghost_max_txs,
+            strength(self.longest_gap_days as f64, t.ghost_gap_days as f64)
+                .min(strength(t.ghost_max_txs as f64, self.total_txs.max(1) as f64)),
+            vec![format!("longest gap of {} days", self.longest_gap_days)],
+        );
+        push(
+            WalletArchetype::Builder,
+            self.token_creates >= t.builder_token_creates,
+            strength(self.token_creates as f64, t.builder_token_creates as f64),
+            vec![format!("{} tokens launched", self.token_creates)],
+        );
+        push(
+            WalletArchetype::Whale,
+            self.avg_tx_value_sol > t.whale_avg_sol || self.max_sol_moved > t.whale_max_sol,
+            strength(self.avg_tx_value_sol, t.whale_avg_sol).max(strength(self.max_sol_moved, t.whale_max_sol)),
+            vec![
+                format!("average transaction of {:.1} SOL", self.avg_tx_value_sol),
+                format!("largest move of {:.1} SOL", self.max_sol_moved),
+            ],
+        );
+        push(
+            WalletArchetype::Degen,
+            swap_ratio > t.degen_swap_ratio
+                && (memecoin_ratio > t.degen_memecoin_ratio || self.unique_tokens_traded > t.degen_unique_tokens),
+            strength(swap_ratio, t.degen_swap_ratio).min(
+                strength(memecoin_ratio, t.degen_memecoin_ratio)
+                    .max(strength(self.unique_tokens_traded as f64, t.degen_unique_tokens as f64)),
+            ),
+            vec![
+                format!("{}% of transactions are swaps", pct(swap_ratio)),
+                format!("{}% of swaps are memecoins", pct(memecoin_ratio)),
+                format!("{} tokens traded", self.unique_tokens_traded),
+            ],
+        );
+        push(
+            WalletArchetype::Collector,
+            nft_ratio > t.collector_nft_ratio || self.unique_nft_collections > t.collector_collections,
+            strength(nft_ratio, t.collector_nft_ratio)
+                .max(strength(self.unique_nft_collections as f64, t.collector_collections as f64)),
+            vec![
+                format!("{}% of transactions are NFT activity", pct(nft_ratio)),
+                format!("{} NFT collections", self.unique_nft_collections),
+            ],
+        );
+        push(
+            WalletArchetype::Farmer,
+            stake_ratio > t.farmer_stake_ratio,
+            strength(stake_ratio, t.farmer_stake_ratio),
+            vec![format!("{}% of transactions are staking", pct(stake_ratio))],
+        );
+
+        // Explorers are whatever the activity archetypes don't explain:
+        // fully confident with no signal, not at all once one hits its threshold
+        let strongest = scores[2..].iter().map(|s| s.confidence).fold(0.0, f64::max);
+        let explained = scores.iter().any(|s| s.matched);
+        scores.push(ArchetypeScore {
+            archetype: WalletArchetype::Explorer,
+            confidence: (1.0 - 2.0 * strongest).max(0.0),
+            matched: !explained,
+            reasons: vec!["no dominant activity".to_string()],
+        });
+
+        scores.sort_by(|a, b| {
+            (b.archetype == primary).cmp(&(a.archetype == primary))
+                .then(b.confidence.total_cmp(&a.confidence))
+        });
+        ArchetypeProfile { scores }
+    }
+
+    /// Get the top transaction type for this wallet.
+    pub fn dominant_activity(&self) -> &'static str {
+        let activities = [
+            (self.swap_count, "TRADING"),
+            (self.nft_count, "NFT"),
+            (self.transfer_count, "TRANSFERS"),
+            (self.stake_count, "STAKING"),
+        ];
+        activities.iter()
+            .max_by_key(|(count, _)| count)
+            .map(|(_, name)| *name)
+            .unwrap_or("MIXED")
+    }
+
+    /// Calculate wallet "age" in days.
+    pub fn age_days(&self) -> u64 {
+        if self.last_tx_timestamp > self.first_tx_timestamp {
+            ((self.last_tx_timestamp - self.first_tx_timestamp) / 86400) as u64
+        } else { 0 }
+    }
+
+    /// Calculate average transactions per day.
+    pub fn tx_frequency(&self) -> f64 {
+        let days = self.age_days().max(1) as f64;
+        self.total_txs as f64 / days
+    }
+}
+
+/// Builds [`WalletStats`] from a wallet's transactions, in any order.
+#[derive(Debug, Clone)]
+pub struct WalletStatsBuilder {
+    wallet: String,
+    memecoins: HashSet<String>,
+    stats: WalletStats,
+    timestamps: Vec<i64>,
+    tokens: HashSet<String>,
+    collections: HashSet<String>,
+}
+
+impl WalletStatsBuilder {
+    pub fn new(wallet: &str) -> Self {
+        Self {
+            wallet: wallet.to_string(),
+            memecoins: MEMECOIN_MINTS.iter().map(|m| m.to_string()).collect(),
+            stats: WalletStats::default(),
+            timestamps: Vec::new(),
+            tokens: HashSet::new(),
+            collections: HashSet::new(),
+        }
+    }
+
+    /// Replace the default memecoin list.
+    pub fn with_memecoins(mut self, mints: &[&str]) -> Self {
+        self.memecoins = mints.iter().map(|m| m.to_string()).collect();
+        self
+    }
+
+    pub fn push(&mut self, tx: &WalletTx) {
+        let s = &mut self.stats;
+        s.total_txs += 1;
+        match tx.tx_type.as_str() {
+            "SWAP" => {
+                s.swap_count += 1;
+                let deltas = tx.token_deltas(&self.wallet);
+                if deltas.iter().any(|(mint, _)| self.memecoins.contains(mint)) {
+                    s.memecoin_trades += 1;
+                }
+                self.tokens.extend(deltas.into_iter().map(|(mint, _)| mint));
+            }
+            "TRANSFER" | "SOL_TRANSFER" => s.transfer_count += 1,
+            "STAKE_SOL" | "UNSTAKE_SOL" | "STAKE_TOKEN" | "UNSTAKE_TOKEN" | "CLAIM_REWARDS" => s.stake_count += 1,
+            "TOKEN_MINT" => s.token_creates += 1,
+            "BURN" | "BURN_NFT" => s.burns += 1,
+            t if t.contains("NFT") => s.nft_count += 1,
+            _ => {}
+        }
+        if let Some(nft) = &tx.nft {
+            self.collections.insert(nft.collection.clone());
+        }
+
+        let sol = tx.sol_amount();
+        s.max_sol_moved = s.max_sol_moved.max(sol);
+        s.total_sol_volume += sol;
+        if tx.timestamp > 0 {
+            self.timestamps.push(tx.timestamp);
+        }
+    }
+
+    pub fn extend<'a>(&mut self, txs: impl IntoIterator<Item = &'a WalletTx>) {
+        for tx in txs {
+            self.push(tx);
+        }
+    }
+
+    pub fn build(mut self) -> WalletStats {
+        let mut s = self.stats;
+        if s.total_txs > 0 {
+            s.avg_tx_value_sol = s.total_sol_volume / s.total_txs as f64;
+        }
+        s.unique_tokens_traded = self.tokens.len() as u64;
+        s.unique_nft_collections = self.collections.len() as u64;
+
+        self.timestamps.sort_unstable();
+        if let (Some(first), Some(last)) = (self.timestamps.first(), self.timestamps.last()) {
+            s.first_tx_timestamp = *first;
+            s.last_tx_timestamp = *last;
+        }
+        let mut days: Vec<i64> = self.timestamps.iter().map(|t| t / SECONDS_PER_DAY).collect();
+        days.dedup();
+        s.active_days = days.len() as u64;
+        s.longest_gap_days = self.timestamps
+            .windows(2)
+            .map(|w| ((w[1] - w[0]) / SECONDS_PER_DAY) as u64)
+            .max()
+            .unwrap_or(0);
+        s
+    }
+}
+
+impl WalletStats {
+    /// Stats for `wallet` from its transaction history.
+    pub fn from_history(wallet: &str, txs: &[WalletTx]) -> Self {
+        let mut builder = WalletStatsBuilder::new(wallet);
+        builder.extend(txs);
+        builder.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WALLET: &str = "CRYPTwa11et1111111111111111111111111111111";
+
+    fn history() -> Vec<WalletTx> {
+        serde_json::from_str(include_str!("../../fixtures/wallet_history.json")).unwrap()
+    }
+
+    #[test]
+    fn test_stats_from_history() {
+        let mut builder = WalletStatsBuilder::new(WALLET).with_memecoins(&["BONK", "WIF"]);
+        builder.extend(&history());
+        let stats = builder.build();
+        assert_eq!(stats.total_txs, 10);
+        assert_eq!(stats.swap_count, 4);
+        assert_eq!(stats.nft_count, 1);
+        assert_eq!(stats.transfer_count, 4);
+        assert_eq!(stats.stake_count, 0);
+        assert_eq!(stats.token_creates, 1);
+        assert_eq!(stats.memecoin_trades, 3);
+        assert_eq!(stats.unique_tokens_traded, 3);
+        assert_eq!(stats.unique_nft_collections, 1);
+        assert_eq!(stats.max_sol_moved, 250.0);
+        assert!((stats.total_sol_volume - 280.02).abs() < 1e-9);
+        assert!((stats.avg_tx_value_sol - 28.002).abs() < 1e-9);
+        assert_eq!(stats.first_tx_timestamp, 1700000000);
+        assert_eq!(stats.last_tx_timestamp, 1705184000);
+        assert_eq!(stats.active_days, 9);
+        assert_eq!(stats.longest_gap_days, 53);
+        assert_eq!(stats.classify(), WalletArchetype::Explorer);
+    }
+
+    #[test]
+    fn test_stats_ignore_order() {
+        let mut txs = history();
+        let forward = WalletStats::from_history(WALLET, &txs);
+        txs.reverse();
+        let backward = WalletStats::from_history(WALLET, &txs);
+        assert_eq!(forward.longest_gap_days, backward.longest_gap_days);
+        assert_eq!(forward.first_tx_timestamp, backward.first_tx_timestamp);
+        assert_eq!(forward.active_days, backward.active_days);
+    }
+
+    #[test]
+    fn test_stats_empty_history() {
+        let stats = WalletStats::from_history(WALLET, &[]);
+        assert_eq!(stats.total_txs, 0);
+        assert_eq!(stats.avg_tx_value_sol, 0.0);
+        assert_eq!(stats.classify(), WalletArchetype::Newcomer);
+    }
+
+    fn base_stats() -> WalletStats {
+        WalletStats {
+            total_txs: 50,
+            first_tx_timestamp: 1700000000,
+            last_tx_timestamp: 1705000000,
+            active_days: 30,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_newcomer() {
+        let stats = WalletStats { total_txs: 3, ..Default::default() };
+        assert_eq!(stats.classify(), WalletArchetype::Newcomer);
+    }
+
+    #[test]
+    fn test_ghost() {
+        let stats = WalletStats {
+            total_txs: 10,
+            longest_gap_days: 120,
+            ..Default::default()
+        };
+        assert_eq!(stats.classify(), WalletArchetype::Ghost);
+    }
+
+    #[test]
+    fn test_whale() {
+        let mut stats = base_stats();
+        stats.avg_tx_value_sol = 80.0;
+        stats.swap_count = 20;
+        assert_eq!(stats.classify(), WalletArchetype::Whale);
+    }
+
+    #[test]
+    fn test_degen() {
+        let mut stats = base_stats();
+        stats.swap_count = 40;
+        stats.memecoin_trades = 25;
+        stats.unique_tokens_traded = 30;
+        assert_eq!(stats.classify(), WalletArchetype::Degen);
+    }
+
+    #[test]
+    fn test_collector() {
+        let mut stats = base_stats();
+        stats.nft_count = 30;
+        stats.unique_nft_collections = 15;
+        assert_eq!(stats.classify(), WalletArchetype::Collector);
+    }
+
+    #[test]
+    fn test_farmer() {
+        let mut stats = base_stats();
+        stats.stake_count = 20;
+        assert_eq!(stats.classify(), WalletArchetype::Farmer);
+    }
+
+    #[test]
+    fn test_builder() {
+        let mut stats = base_stats();
+        stats.token_creates = 3;
+        assert_eq!(stats.classify(), WalletArchetype::Builder);
+    }
+
+    #[test]
+    fn test_explorer() {
+        let mut stats = base_stats();
+        stats.swap_count = 15;
+        stats.nft_count = 10;
+        stats.transfer_count = 15;
+        stats.stake_count = 10;
+        assert_eq!(stats.classify(), WalletArchetype::Explorer);
+    }
+
+    #[test]
+    fn test_age_days() {
+        let stats = WalletStats {
+            first_tx_timestamp: 1700000000,
+            last_tx_timestamp: 1700864000, // +10 days
+            ..Default::default()
+        };
+        assert_eq!(stats.age_days(), 10);
+    }
+
+    #[test]
+    fn test_tx_frequency() {
+        let stats = WalletStats {
+            total_txs: 100,
+            first_tx_timestamp: 1700000000,
+            last_tx_timestamp: 1700864000,
+            ..Default::default()
+        };
+        assert!((stats.tx_frequency() - 10.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_dominant_activity() {
+        let stats = WalletStats {
+            swap_count: 50, nft_count: 10, transfer_count: 5, stake_count: 3,
+            ..Default::default()
+        };
+        assert_eq!(stats.dominant_activity(), "TRADING");
+    }
+
+    #[test]
+    fn test_scores_rank_secondary() {
+        let mut stats = base_stats();
+        stats.swap_count = 33;
+        stats.memecoin_trades = 20;
+        stats.nft_count = 17;
+        stats.unique_nft_collections = 8;
+        let profile = stats.archetype_scores();
+        assert_eq!(profile.primary().archetype, WalletArchetype::Degen);
+        assert!(profile.primary().matched);
+        let secondary = profile.secondary().unwrap();
+        assert_eq!(secondary.archetype, WalletArchetype::Collector);
+        assert!(!secondary.matched);
+        assert!(secondary.confidence > 0.4 && secondary.confidence < 0.5);
+        assert!(secondary.reasons.contains(&"34% of transactions are NFT activity".to_string()));
+        assert_eq!(profile.scores.len(), 8);
+    }
+
+    #[test]
+    fn test_primary_matches_classify() {
+        let mut whale_degen = base_stats();
+        whale_degen.avg_tx_value_sol = 51.0;
+        whale_degen.swap_count = 50;
+        whale_degen.memecoin_trades = 50;
+        let mut farmer = base_stats();
+        farmer.stake_count = 20;
+        for stats in [base_stats(), whale_degen.clone(), farmer, WalletStats::default()] {
+            let profile = stats.archetype_scores();
+            assert_eq!(profile.primary().archetype, stats.classify());
+            assert!(profile.scores[1..].windows(2).all(|w| w[0].confidence >= w[1].confidence));
+        }
+
+        // Both rules pass; priority picks the whale even though the degen
+        // signal is stronger
+        let profile = whale_degen.archetype_scores();
+        assert_eq!(profile.primary().archetype, WalletArchetype::Whale);
+        let degen = profile.secondary().unwrap();
+        assert_eq!(degen.archetype, WalletArchetype::Degen);
+        assert!(degen.matched && degen.confidence > profile.primary().confidence);
+    }
+
+    #[test]
+    fn test_explorer_confidence() {
+        let empty = base_stats().archetype_scores();
+        assert_eq!(empty.primary().archetype, WalletArchetype::Explorer);
+        assert_eq!(empty.primary().confidence, 1.0);
+        let mut stats = base_stats();
+        stats.stake_count = 20;
+        assert_eq!(stats.archetype_scores().get(WalletArchetype::Explorer).unwrap().confidence, 0.0);
+    }
+
+    #[test]
+    fn test_custom_thresholds() {
+        let mut stats = base_stats();
+        stats.stake_count = 10;
+        assert_eq!(stats.classify(), WalletArchetype::Explorer);
+        let strict = ArchetypeThresholds { farmer_stake_ratio: 0.1, ..Default::default() };
+        assert_eq!(stats.classify_with(&strict), WalletArchetype::Farmer);
+        let profile = stats.archetype_scores_with(&strict);
+        assert_eq!(profile.primary().archetype, WalletArchetype::Farmer);
+        assert_eq!(profile.primary().confidence, 1.0);
+    }
+
+    #[test]
+    fn test_archetype_flavor_text() {
+        for archetype in [
+            WalletArchetype::Degen, WalletArchetype::Whale, WalletArchetype::Collector,
+            WalletArchetype::Farmer, WalletArchetype::Builder, WalletArchetype::Explorer,
+            WalletArchetype::Newcomer, WalletArchetype::Ghost,
+        ] {
+            assert!(!archetype.flavor().is_empty());
+            assert!(!archetype.as_str().is_empty());
+        }
+    }
+}
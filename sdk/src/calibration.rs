@@ -0,0 +1,275 @@
+//! Rarity calibration — picking thresholds from real score data.
+//!
+//! Fixed thresholds drift as wallet populations change. Calibration
+//! scores a corpus of scanned transactions (JSONL, one [`ScoreParams`]
+//! per line), builds a histogram, and picks the thresholds whose tier
+//! shares come closest to a target such as 70/25/5.
+
+use std::collections::BTreeMap;
+use serde::{Deserialize, Serialize};
+use crate::rules::ScoringRules;
+use crate::scoring::{RarityScorer, ScoreParams};
+use crate::types::Rarity;
+use crate::CryptSdkError;
+
+/// Parse a JSONL corpus. Blank lines are skipped.
+pub fn read_corpus(jsonl: &str) -> Result<Vec<ScoreParams>, CryptSdkError> {
+    jsonl
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            serde_json::from_str(line)
+                .map_err(|e| CryptSdkError::Serialization(format!("line {}: {}", i + 1, e)))
+        })
+        .collect()
+}
+
+/// Share of cards wanted in each tier. Shares are fractions summing to 1.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TargetShares {
+    pub common: f64,
+    pub rare: f64,
+    pub legendary: f64,
+}
+
+impl Default for TargetShares {
+    fn default() -> Self {
+        Self { common: 0.70, rare: 0.25, legendary: 0.05 }
+    }
+}
+
+impl TargetShares {
+    /// Parse percentages like `70/25/5`.
+    pub fn parse(s: &str) -> Option<Self> {
+        let parts: Vec<f64> = s.split('/').map(|p| p.trim().parse().ok()).collect::<Option<_>>()?;
+        let [common, rare, legendary] = parts[..] else { return None };
+        if parts.iter().any(|p| *p < 0.0) || (common + rare + legendary - 100.0).abs() > 0.01 {
+            return None;
+        }
+        Some(Self { common: common / 100.0, rare: rare / 100.0, legendary: legendary / 100.0 })
+    }
+}
+
+/// How many scores land in each tier.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TierCounts {
+    pub common: u64,
+    pub rare: u64,
+    pub legendary: u64,
+}
+
+impl TierCounts {
+    pub fn total(&self) -> u64 {
+        self.common + self.rare + self.legendary
+    }
+
+    /// Fraction of scores in `rarity`'s tier.
+    pub fn share(&self, rarity: Rarity) -> f64 {
+        let count = match rarity {
+            Rarity::Common => self.common,
+            Rarity::Rare => self.rare,
+            Rarity::Legendary => self.legendary,
+        };
+        if self.total() > 0 { count as f64 / self.total() as f64 } else { 0.0 }
+    }
+}
+
+/// Count of each score seen.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScoreHistogram {
+    counts: BTreeMap<u32, u64>,
+}
+
+impl ScoreHistogram {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_scores(scores: impl IntoIterator<Item = u32>) -> Self {
+        let mut histogram = Self::new();
+        for score in scores {
+            histogram.add(score);
+        }
+        histogram
+    }
+
+    pub fn add(&mut self, score: u32) {
+        *self.counts.entry(score).or_insert(0) += 1;
+    }
+
+    pub fn total(&self) -> u64 {
+        self.counts.values().sum()
+    }
+
+    /// `(score, count)` pairs, lowest score first.
+    pub fn counts(&self) -> impl Iterator<Item = (u32, u64)> + '_ {
+        self.counts.iter().map(|(score, count)| (*score, *count))
+    }
+
+    /// Fraction of scores at or above `threshold`.
+    pub fn share_at_least(&self, threshold: u32) -> f64 {
+        let total = self.total();
+        if total == 0 { return 0.0; }
+        self.counts.range(threshold..).map(|(_, count)| count).sum::<u64>() as f64 / total as f64
+    }
+
+    /// Tier counts under the given thresholds.
+    pub fn tiers(&self, rare: u32, legendary: u32) -> TierCounts {
+        self.counts().fold(TierCounts::default(), |mut tiers, (score, count)| {
+            match Rarity::from_score_with(score, rare, legendary) {
+                Rarity::Common => tiers.common += count,
+                Rarity::Rare => tiers.rare += count,
+                Rarity::Legendary => tiers.legendary += count,
+            }
+            tiers
+        })
+    }
+
+    /// The threshold at or below `below` whose share at or above it is
+    /// closest to `share`. Equalling `below` leaves the tier between
+    /// them empty. Ties go to the higher threshold, so tiers
+    /// err on the side of being rarer.
+    fn threshold_for(&self, share: f64, below: u32) -> u32 {
+        let past_top = self.counts.keys().next_back().map_or(0, |max| max + 1);
+        self.counts
+            .keys()
+            .copied()
+            .chain(std::iter::once(past_top))
+            .filter(|&t| t <= below)
+            .min_by(|a, b| {
+                let error = |t: u32| (self.share_at_least(t) - share).abs();
+                error(*a).total_cmp(&error(*b)).then(b.cmp(a))
+            })
+            .unwrap_or(0)
+    }
+}
+
+/// Thresholds fitted to a corpus, with the tier counts before and after.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Calibration {
+    pub histogram: ScoreHistogram,
+    pub targets: TargetShares,
+    /// Rules the corpus was scored with; the thresholds only hold under them
+    pub rules: ScoringRules,
+    pub rare_threshold: u32,
+    pub legendary_threshold: u32,
+    /// Tiers under the scorer's thresholds
+    pub before: TierCounts,
+    /// Tiers under the calibrated thresholds
+    pub after: TierCounts,
+}
+
+impl Calibration {
+    /// Score `corpus` with `scorer` and fit thresholds to `targets`.
+    /// Returns `None` for an empty corpus.
+    pub fn run(scorer: &RarityScorer, corpus: &[ScoreParams], targets: TargetShares) -> Option<Self> {
+        if corpus.is_empty() { return None; }
+        let histogram = ScoreHistogram::from_scores(corpus.iter().map(|p| scorer.score(p).score));
+
+        let legendary = histogram.threshold_for(targets.legendary, u32::MAX);
+        let rare = histogram.threshold_for(targets.rare + targets.legendary, legendary);
+        Some(Self {
+            before: histogram.tiers(scorer.rare_threshold, scorer.legendary_threshold),
+            after: histogram.tiers(rare, legendary),
+            histogram,
+            targets,
+            rules: scorer.rules.clone(),
+            rare_threshold: rare,
+            legendary_threshold: legendary,
+        })
+    }
+
+    /// A scorer with the calibrated thresholds and the rules they were
+    /// fitted under.
+    pub fn scorer(&self) -> RarityScorer {
+        RarityScorer::new(self.rare_threshold, self.legendary_threshold).with_rules(self.rules.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CORPUS: &str = include_str!("../fixtures/score_corpus.jsonl");
+
+    #[test]
+    fn test_reads_corpus() {
+        let corpus = read_corpus(CORPUS).unwrap();
+        assert_eq!(corpus.len(), 200);
+        assert!(!corpus[1].is_memecoin);
+        assert!(read_corpus("{\"tx_type\":\"SWAP\"}\nnot json").is_err());
+    }
+
+    #[test]
+    fn test_parse_targets() {
+        assert_eq!(TargetShares::parse("70/25/5"), Some(TargetShares::default()));
+        assert_eq!(TargetShares::parse("80/15/5").unwrap().rare, 0.15);
+        assert_eq!(TargetShares::parse("70/25"), None);
+        assert_eq!(TargetShares::parse("70/25/10"), None);
+    }
+
+    #[test]
+    fn test_thresholds_hit_exact_shares() {
+        // 100 scores, 0..100: the top 5 are 95+, the top 30 are 70+
+        let histogram = ScoreHistogram::from_scores(0..100);
+        assert_eq!(histogram.threshold_for(0.05, u32::MAX), 95);
+        assert_eq!(histogram.threshold_for(0.30, 95), 70);
+        assert_eq!(histogram.tiers(70, 95), TierCounts { common: 70, rare: 25, legendary: 5 });
+    }
+
+    #[test]
+    fn test_thresholds_stay_ordered() {
+        // Every score equal: nothing can separate the tiers
+        let histogram = ScoreHistogram::from_scores([50; 10]);
+        let legendary = histogram.threshold_for(0.05, u32::MAX);
+        assert_eq!(legendary, 51);
+        let rare = histogram.threshold_for(0.30, legendary);
+        assert!(rare <= legendary);
+
+        // Closest to 70/25/5 is everything Common, not everything Rare
+        let tiers = histogram.tiers(rare, legendary);
+        assert_eq!(tiers, TierCounts { common: 10, rare: 0, legendary: 0 });
+    }
+
+    #[test]
+    fn test_calibrates_corpus() {
+        let corpus = read_corpus(CORPUS).unwrap();
+        let calibration = Calibration::run(&RarityScorer::default(), &corpus, TargetShares::default()).unwrap();
+        assert_eq!(calibration.before.total(), 200);
+        assert!(calibration.rare_threshold < calibration.legendary_threshold);
+
+        // Within a few points of the targets
+        let after = calibration.after;
+        assert!((after.share(Rarity::Legendary) - 0.05).abs() < 0.03, "{:?}", after);
+        assert!((after.share(Rarity::Rare) - 0.25).abs() < 0.05, "{:?}", after);
+
+        let scorer = calibration.scorer();
+        assert_eq!(scorer.rare_threshold, calibration.rare_threshold);
+        let legendary = corpus.iter().filter(|p| scorer.score(p).rarity == Rarity::Legendary).count();
+        assert_eq!(legendary as u64, after.legendary);
+    }
+
+    #[test]
+    fn test_scorer_keeps_custom_rules() {
+        let corpus = read_corpus(CORPUS).unwrap();
+        let mut rules = ScoringRules { name: "flat".into(), ..ScoringRules::default() };
+        for rule in rules.types.iter_mut().chain([&mut rules.unknown]) {
+            rule.tiers.clear();
+        }
+        let custom = RarityScorer::default().with_rules(rules.clone());
+        let calibration = Calibration::run(&custom, &corpus, TargetShares::default()).unwrap();
+        assert_eq!(calibration.rules, rules);
+
+        // Tiers from the returned scorer match the calibrated counts
+        let scorer = calibration.scorer();
+        assert_eq!(scorer.rules, rules);
+        let rare = corpus.iter().filter(|p| scorer.score(p).rarity == Rarity::Rare).count();
+        assert_eq!(rare as u64, calibration.after.rare);
+    }
+
+    #[test]
+    fn test_empty_corpus() {
+        assert!(Calibration::run(&RarityScorer::default(), &[], TargetShares::default()).is_none());
+    }
+}
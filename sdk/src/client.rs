@@ -1,14 +1,23 @@
 //! Crypt RPC client for interacting with the on-chain program.
 
 use solana_client::rpc_client::RpcClient;
+use solana_client::rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig};
+use solana_client::rpc_filter::{Memcmp, RpcFilterType};
 use solana_sdk::pubkey::Pubkey;
+use sha2::{Digest, Sha256};
 use std::str::FromStr;
 use crate::error::CryptSdkError;
 use crate::types::*;
+use crate::scoring::BoostEvent;
+use crate::social::InteractionType;
+use crate::verify::{verify_card_account, VerificationReport, VerifyInputs};
 
 /// Program ID for the Crypt on-chain program.
 pub const PROGRAM_ID: &str = "CRYPTxGraveyardSo1ana1111111111111111111111";
 
+/// Tally slots in a Proposal account, used or not.
+const PROPOSAL_TALLY_SLOTS: usize = 8;
+
 /// High-level client for the Crypt Solana program.
 pub struct CryptClient {
     rpc: RpcClient,
@@ -57,6 +66,298 @@ impl CryptClient {
         )
     }
 
+    /// Get a tombstone PDA address from the burned card's mint ID.
+    pub fn tombstone_address(&self, mint_id: u64) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[b"tombstone", &mint_id.to_le_bytes()],
+            &self.program_id,
+        )
+    }
+
+    /// Derive an achievement set PDA.
+    pub fn achievement_address(&self, set_id: u32) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[b"achievement", &set_id.to_le_bytes()],
+            &self.program_id,
+        )
+    }
+
+    /// Derive the badge PDA for a wallet that completed an achievement set.
+    pub fn badge_address(&self, achievement: &Pubkey, owner: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[b"badge", achievement.as_ref(), owner.as_ref()],
+            &self.program_id,
+        )
+    }
+
+    /// Derive the record PDA marking a card as spent toward an achievement set.
+    /// Pass it after the card in `claim_achievement`'s remaining accounts.
+    pub fn achievement_card_address(&self, achievement: &Pubkey, card: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[b"achievement_card", achievement.as_ref(), card.as_ref()],
+            &self.program_id,
+        )
+    }
+
+    /// Derive the staking config PDA.
+    pub fn staking_config_address(&self) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[b"staking_config"], &self.program_id)
+    }
+
+    /// Derive the stake record PDA holding a staked card.
+    pub fn stake_address(&self, card: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[b"stake", card.as_ref()], &self.program_id)
+    }
+
+    /// Derive a wallet's staking points PDA.
+    pub fn points_address(&self, owner: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[b"points", owner.as_ref()], &self.program_id)
+    }
+
+    /// Derive the compact interaction counters PDA for a card.
+    pub fn card_social_address(&self, card: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[b"social", card.as_ref()], &self.program_id)
+    }
+
+    /// Fetch a card's like/share/bookmark counters. Cards nobody has
+    /// reacted to yet have empty counters.
+    pub fn get_card_social(&self, card: &Pubkey) -> Result<CardSocial, CryptSdkError> {
+        let (address, _) = self.card_social_address(card);
+        match self.rpc.get_account(&address) {
+            Ok(account) => decode_card_social(&account.data),
+            Err(_) => Ok(CardSocial { card: *card, ..Default::default() }),
+        }
+    }
+
+    /// Derive the PDA recording which reactions `user` has made on `card`.
+    pub fn react_record_address(&self, card: &Pubkey, user: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[b"react", card.as_ref(), user.as_ref()], &self.program_id)
+    }
+
+    /// The reactions `user` has already made on `card`; each can only
+    /// be made once.
+    pub fn get_reactions(&self, card: &Pubkey, user: &Pubkey) -> Result<Vec<InteractionType>, CryptSdkError> {
+        let (address, _) = self.react_record_address(card, user);
+        match self.rpc.get_account(&address) {
+            Ok(account) => decode_react_record(&account.data),
+            Err(_) => Ok(Vec::new()),
+        }
+    }
+
+    /// Derive the moderation PDA for a card or comment account.
+    pub fn moderation_address(&self, target: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[b"moderation", target.as_ref()], &self.program_id)
+    }
+
+    /// Derive the PDA of `reporter`'s report against a card or comment.
+    pub fn report_address(&self, target: &Pubkey, reporter: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[b"report", target.as_ref(), reporter.as_ref()],
+            &self.program_id,
+        )
+    }
+
+    /// Fetch the moderation state of a card or comment. Content that was
+    /// never reported or moderated comes back visible with no reports.
+    pub fn get_moderation(&self, target: &Pubkey) -> Result<Moderation, CryptSdkError> {
+        let (address, _) = self.moderation_address(target);
+        match self.rpc.get_account(&address) {
+            Ok(account) => decode_moderation(&account.data),
+            Err(_) => Ok(Moderation { target: *target, ..Default::default() }),
+        }
+    }
+
+    /// Derive a distributor PDA from its ID.
+    pub fn distributor_address(&self, distributor_id: u64) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[b"distributor", &distributor_id.to_le_bytes()],
+            &self.program_id,
+        )
+    }
+
+    /// Derive the PDA that marks `claimant`'s allocation as claimed.
+    pub fn claim_status_address(&self, distributor: &Pubkey, claimant: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[b"claim", distributor.as_ref(), claimant.as_ref()],
+            &self.program_id,
+        )
+    }
+
+    /// Fetch a distributor by ID.
+    pub fn get_distributor(&self, distributor_id: u64) -> Result<Distributor, CryptSdkError> {
+        let (address, _) = self.distributor_address(distributor_id);
+        let account = self.rpc.get_account(&address)?;
+        decode_distributor(&account.data)
+    }
+
+    /// Whether `claimant` already claimed from a distributor.
+    pub fn has_claimed(&self, distributor_id: u64, claimant: &Pubkey) -> bool {
+        let (distributor, _) = self.distributor_address(distributor_id);
+        let (status, _) = self.claim_status_address(&distributor, claimant);
+        self.account_exists(&status)
+    }
+
+    /// Derive a proposal PDA from its ID.
+    pub fn proposal_address(&self, proposal_id: u64) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[b"proposal", &proposal_id.to_le_bytes()],
+            &self.program_id,
+        )
+    }
+
+    /// Derive the PDA recording a card's vote on a proposal.
+    pub fn vote_record_address(&self, proposal: &Pubkey, card: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[b"vote", proposal.as_ref(), card.as_ref()],
+            &self.program_id,
+        )
+    }
+
+    /// Fetch a proposal by ID.
+    pub fn get_proposal(&self, proposal_id: u64) -> Result<Proposal, CryptSdkError> {
+        let (address, _) = self.proposal_address(proposal_id);
+        let account = self.rpc.get_account(&address)?;
+        decode_proposal(&account.data)
+    }
+
+    /// Fetch a card's vote on a proposal, if it has voted. Votes follow the
+    /// card, so a card bought after it voted comes back with the seller's vote.
+    pub fn get_vote(&self, proposal_id: u64, card: &Pubkey) -> Result<Option<VoteRecord>, CryptSdkError> {
+        let (proposal, _) = self.proposal_address(proposal_id);
+        let (address, _) = self.vote_record_address(&proposal, card);
+        match self.rpc.get_account(&address) {
+            Ok(account) => decode_vote_record(&account.data).map(Some),
+            Err(_) => Ok(None),
+        }
+    }
+
+    /// Derive the custody record PDA for a wrapped card's NFT mint.
+    pub fn wrap_record_address(&self, nft_mint: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[b"wrap", nft_mint.as_ref()], &self.program_id)
+    }
+
+    /// Derive the PDA that is mint and update authority of wrapped cards.
+    pub fn nft_authority_address(&self) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[b"nft_authority"], &self.program_id)
+    }
+
+    /// Fetch the wrap record of a card, if it's wrapped. A wrapped card's
+    /// owner is its wrap record, so the NFT mint is found from the card.
+    pub fn get_wrap_record(&self, card: &Pubkey) -> Result<Option<WrapRecord>, CryptSdkError> {
+        let owner = self.get_card(card)?.owner;
+        match self.rpc.get_account(&owner) {
+            Ok(account) => Ok(decode_wrap_record(&account.data).ok()),
+            Err(_) => Ok(None),
+        }
+    }
+
+    /// Derive the gift escrow PDA for a card.
+    pub fn gift_address(&self, card: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[b"gift", card.as_ref()], &self.program_id)
+    }
+
+    /// Fetch the pending gift for a card, if there is one.
+    pub fn get_gift(&self, card: &Pubkey) -> Result<Option<Gift>, CryptSdkError> {
+        let (address, _) = self.gift_address(card);
+        match self.rpc.get_account(&address) {
+            Ok(account) => decode_gift(&account.data).map(Some),
+            Err(_) => Ok(None),
+        }
+    }
+
+    /// Derive the boost table PDA.
+    pub fn boost_table_address(&self) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[b"boosts"], &self.program_id)
+    }
+
+    /// Fetch the collection's boost events, for `RarityScorer::with_events`.
+    /// Returns an empty list if no event was ever configured.
+    pub fn get_boost_events(&self) -> Result<Vec<BoostEvent>, CryptSdkError> {
+        let (address, _) = self.boost_table_address();
+        match self.rpc.get_account(&address) {
+            Ok(account) => decode_boost_table(&account.data),
+            Err(_) => Ok(Vec::new()),
+        }
+    }
+
+    /// Derive the open duel PDA for a challenging card.
+    pub fn duel_address(&self, challenger_card: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[b"duel", challenger_card.as_ref()], &self.program_id)
+    }
+
+    /// Derive a card's duel record PDA.
+    pub fn duel_record_address(&self, card: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[b"duel_record", card.as_ref()], &self.program_id)
+    }
+
+    /// Fetch a card's duel record. Cards that never dueled have an empty record.
+    pub fn get_duel_record(&self, card: &Pubkey) -> Result<DuelRecord, CryptSdkError> {
+        let (address, _) = self.duel_record_address(card);
+        match self.rpc.get_account(&address) {
+            Ok(account) => decode_duel_record(&account.data),
+            Err(_) => Ok(DuelRecord { card: *card, ..Default::default() }),
+        }
+    }
+
+    /// List every tombstone left behind by cards burned from `owner`'s wallet,
+    /// most recent burn first.
+    pub fn get_graveyard(&self, owner: &Pubkey) -> Result<Vec<Tombstone>, CryptSdkError> {
+        let config = RpcProgramAccountsConfig {
+            filters: Some(vec![
+                RpcFilterType::Memcmp(Memcmp::new_base58_encoded(0, &account_discriminator("Tombstone"))),
+                RpcFilterType::Memcmp(Memcmp::new_base58_encoded(8, owner.as_ref())),
+            ]),
+            account_config: RpcAccountInfoConfig::default(),
+            with_context: None,
+        };
+        let accounts = self.rpc.get_program_accounts_with_config(&self.program_id, config)?;
+
+        let mut graveyard = accounts
+            .iter()
+            .map(|(_, account)| decode_tombstone(&account.data))
+            .collect::<Result<Vec<_>, _>>()?;
+        graveyard.sort_by_key(|t| std::cmp::Reverse(t.burned_at));
+        Ok(graveyard)
+    }
+
+    /// Fetch and decode a card account.
+    pub fn get_card(&self, address: &Pubkey) -> Result<CryptCard, CryptSdkError> {
+        let account = self.rpc.get_account(address)
+            .map_err(|_| CryptSdkError::CardNotFound(address.to_string()))?;
+        decode_card(&account.data)
+    }
+
+    /// Verify a card off-chain and return a per-check report, mirroring
+    /// the program's `verify_card`. Pass `minter` for cards that have been
+    /// transferred since mint — the PDA is derived from the original minter.
+    pub fn verify_card(
+        &self,
+        address: &Pubkey,
+        tx_hash: &str,
+        narration: Option<&str>,
+        minter: Option<&Pubkey>,
+    ) -> Result<VerificationReport, CryptSdkError> {
+        let account = self.rpc.get_account(address)
+            .map_err(|_| CryptSdkError::CardNotFound(address.to_string()))?;
+        if account.owner != self.program_id {
+            return Err(CryptSdkError::VerificationFailed {
+                reason: format!("{} is not owned by the Crypt program", address),
+            });
+        }
+        let stats = self.get_collection_stats()?;
+        let (collection, _) = self.collection_address();
+
+        verify_card_account(&account.data, &VerifyInputs {
+            program_id: &self.program_id,
+            card_address: address,
+            tx_hash,
+            narration,
+            minter,
+            collection_address: &collection,
+            collection_total_minted: stats.total_minted,
+        })
+    }
+
     /// Fetch collection statistics from on-chain data.
     pub fn get_collection_stats(&self) -> Result<CollectionStats, CryptSdkError> {
         let (pda, _) = self.collection_address();
@@ -105,6 +406,362 @@ impl CryptClient {
     }
 }
 
+/// Anchor account discriminator: first 8 bytes of SHA-256("account:<Name>").
+pub fn account_discriminator(name: &str) -> [u8; 8] {
+    let digest = Sha256::digest(format!("account:{}", name).as_bytes());
+    let mut disc = [0u8; 8];
+    disc.copy_from_slice(&digest[..8]);
+    disc
+}
+
+/// Decode a Tombstone from raw account data (including the discriminator).
+pub fn decode_tombstone(data: &[u8]) -> Result<Tombstone, CryptSdkError> {
+    // 8 (disc) + 32 (last_owner) + 8 (mint_id) + 32 (digest) + 1 + 1 + 8
+    if data.len() < 90 || data[..8] != account_discriminator("Tombstone") {
+        return Err(CryptSdkError::Serialization("Not a Tombstone account".into()));
+    }
+    let data = &data[8..];
+
+    let last_owner = Pubkey::try_from(&data[0..32])
+        .map_err(|_| CryptSdkError::Serialization("Invalid last_owner".into()))?;
+    let mint_id = u64::from_le_bytes(data[32..40].try_into().unwrap());
+    let mut tx_hash_digest = [0u8; 32];
+    tx_hash_digest.copy_from_slice(&data[40..72]);
+    let rarity = Rarity::from_u8(data[72])
+        .ok_or_else(|| CryptSdkError::Serialization("Invalid rarity".into()))?;
+    let card_type = CardType::from_u8(data[73])
+        .ok_or_else(|| CryptSdkError::Serialization("Invalid card type".into()))?;
+    let burned_at = i64::from_le_bytes(data[74..82].try_into().unwrap());
+
+    Ok(Tombstone { last_owner, mint_id, tx_hash_digest, rarity, card_type, burned_at })
+}
+
+/// Decode the events of a BoostTable from raw account data (including the discriminator).
+pub fn decode_boost_table(data: &[u8]) -> Result<Vec<BoostEvent>, CryptSdkError> {
+    if data.len() < 8 || data[..8] != account_discriminator("BoostTable") {
+        return Err(CryptSdkError::Serialization("Not a BoostTable account".into()));
+    }
+    let mut r = AccountReader::new(&data[8..]);
+    let _collection = r.pubkey()?;
+    let len = r.u32()?;
+    (0..len)
+        .map(|_| {
+            let event_id = r.u16()?;
+            let name = r.string()?;
+            let starts_at = r.i64()?;
+            let ends_at = r.i64()?;
+            let card_type = match r.u8()? {
+                0 => None,
+                _ => Some(CardType::from_u8(r.u8()?)
+                    .ok_or_else(|| CryptSdkError::Serialization("Invalid card type".into()))?),
+            };
+            Ok(BoostEvent {
+                event_id,
+                name,
+                starts_at,
+                ends_at,
+                card_type,
+                score_bonus: r.u32()?,
+                tier_bonus: r.u8()?,
+            })
+        })
+        .collect()
+}
+
+/// Decode a DuelRecord from raw account data (including the discriminator).
+pub fn decode_duel_record(data: &[u8]) -> Result<DuelRecord, CryptSdkError> {
+    if data.len() < 8 || data[..8] != account_discriminator("DuelRecord") {
+        return Err(CryptSdkError::Serialization("Not a DuelRecord account".into()));
+    }
+    let mut r = AccountReader::new(&data[8..]);
+    Ok(DuelRecord {
+        card: r.pubkey()?,
+        wins: r.u32()?,
+        losses: r.u32()?,
+        draws: r.u32()?,
+    })
+}
+
+/// Decode a CardSocial account from raw account data (including the discriminator).
+pub fn decode_card_social(data: &[u8]) -> Result<CardSocial, CryptSdkError> {
+    if data.len() < 8 || data[..8] != account_discriminator("CardSocial") {
+        return Err(CryptSdkError::Serialization("Not a CardSocial account".into()));
+    }
+    let mut r = AccountReader::new(&data[8..]);
+    Ok(CardSocial {
+        card: r.pubkey()?,
+        likes: r.u64()?,
+        shares: r.u64()?,
+        bookmarks: r.u64()?,
+        log_root: r.bytes32()?,
+        log_len: r.u64()?,
+    })
+}
+
+/// Decode a ReactRecord account from raw account data (including the
+/// discriminator) into the reactions it records.
+pub fn decode_react_record(data: &[u8]) -> Result<Vec<InteractionType>, CryptSdkError> {
+    if data.len() < 8 || data[..8] != account_discriminator("ReactRecord") {
+        return Err(CryptSdkError::Serialization("Not a ReactRecord account".into()));
+    }
+    let reacted = AccountReader::new(&data[8..]).u8()?;
+    Ok([InteractionType::Like, InteractionType::Share, InteractionType::Bookmark]
+        .into_iter()
+        .filter(|kind| reacted & kind.react_flag() != 0)
+        .collect())
+}
+
+/// Decode a Moderation account from raw account data (including the discriminator).
+pub fn decode_moderation(data: &[u8]) -> Result<Moderation, CryptSdkError> {
+    if data.len() < 8 || data[..8] != account_discriminator("Moderation") {
+        return Err(CryptSdkError::Serialization("Not a Moderation account".into()));
+    }
+    let mut r = AccountReader::new(&data[8..]);
+    let target = r.pubkey()?;
+    let kind = r.u8()?;
+    let card = r.pubkey()?;
+    let hidden = r.u8()? != 0;
+    let report_count = r.u32()?;
+    let last_reason = r.u8()?;
+    let moderator = r.pubkey()?;
+    let updated_at = r.i64()?;
+    Ok(Moderation {
+        target,
+        is_comment: kind == 1,
+        card,
+        hidden,
+        report_count,
+        // A fresh account has reason 0 with nothing behind it
+        last_reason: (report_count > 0 || moderator != Pubkey::default())
+            .then(|| ReportReason::from_u8(last_reason))
+            .flatten(),
+        moderator: (moderator != Pubkey::default()).then_some(moderator),
+        updated_at,
+    })
+}
+
+/// Decode a Distributor from raw account data (including the discriminator).
+pub fn decode_distributor(data: &[u8]) -> Result<Distributor, CryptSdkError> {
+    if data.len() < 8 || data[..8] != account_discriminator("Distributor") {
+        return Err(CryptSdkError::Serialization("Not a Distributor account".into()));
+    }
+    let mut r = AccountReader::new(&data[8..]);
+    let distributor_id = r.u64()?;
+    let authority = r.pubkey()?;
+    let merkle_root = r.bytes32()?;
+    let mint = match r.u8()? {
+        0 => None,
+        _ => Some(r.pubkey()?),
+    };
+    let vault = r.pubkey()?;
+    Ok(Distributor {
+        distributor_id,
+        authority,
+        merkle_root,
+        mint,
+        vault: (vault != Pubkey::default()).then_some(vault),
+        total_amount: r.u64()?,
+        claimed_amount: r.u64()?,
+        num_claimed: r.u64()?,
+        snapshot_slot: r.u64()?,
+        claim_deadline: r.i64()?,
+        created_at: r.i64()?,
+    })
+}
+
+/// Decode a Proposal from raw account data (including the discriminator).
+/// Tallies are trimmed to the proposal's options.
+pub fn decode_proposal(data: &[u8]) -> Result<Proposal, CryptSdkError> {
+    if data.len() < 8 || data[..8] != account_discriminator("Proposal") {
+        return Err(CryptSdkError::Serialization("Not a Proposal account".into()));
+    }
+    let mut r = AccountReader::new(&data[8..]);
+    let proposal_id = r.u64()?;
+    let authority = r.pubkey()?;
+    let title = r.string()?;
+    let options = (0..r.u32()?).map(|_| r.string()).collect::<Result<Vec<_>, _>>()?;
+    let mut tallies = (0..PROPOSAL_TALLY_SLOTS).map(|_| r.u64()).collect::<Result<Vec<_>, _>>()?;
+    tallies.truncate(options.len());
+    let cards_voted = r.u64()?;
+    let starts_at = r.i64()?;
+    let ends_at = r.i64()?;
+    let finalized = r.u8()? != 0;
+    let winning_option = match r.u8()? {
+        0 => None,
+        _ => Some(r.u8()?),
+    };
+    Ok(Proposal {
+        proposal_id,
+        authority,
+        title,
+        options,
+        tallies,
+        cards_voted,
+        starts_at,
+        ends_at,
+        finalized,
+        winning_option,
+        created_at: r.i64()?,
+    })
+}
+
+/// Decode a VoteRecord from raw account data (including the discriminator).
+pub fn decode_vote_record(data: &[u8]) -> Result<VoteRecord, CryptSdkError> {
+    if data.len() < 8 || data[..8] != account_discriminator("VoteRecord") {
+        return Err(CryptSdkError::Serialization("Not a VoteRecord account".into()));
+    }
+    let mut r = AccountReader::new(&data[8..]);
+    Ok(VoteRecord {
+        proposal: r.pubkey()?,
+        card: r.pubkey()?,
+        voter: r.pubkey()?,
+        option: r.u8()?,
+        weight: r.u64()?,
+        voted_at: r.i64()?,
+    })
+}
+
+/// Decode a WrapRecord from raw account data (including the discriminator).
+pub fn decode_wrap_record(data: &[u8]) -> Result<WrapRecord, CryptSdkError> {
+    if data.len() < 8 || data[..8] != account_discriminator("WrapRecord") {
+        return Err(CryptSdkError::Serialization("Not a WrapRecord account".into()));
+    }
+    let mut r = AccountReader::new(&data[8..]);
+    Ok(WrapRecord {
+        card: r.pubkey()?,
+        nft_mint: r.pubkey()?,
+        wrapper: r.pubkey()?,
+        wrapped_at: r.i64()?,
+    })
+}
+
+/// Decode a Gift escrow from raw account data (including the discriminator).
+pub fn decode_gift(data: &[u8]) -> Result<Gift, CryptSdkError> {
+    if data.len() < 8 || data[..8] != account_discriminator("Gift") {
+        return Err(CryptSdkError::Serialization("Not a Gift account".into()));
+    }
+    let mut r = AccountReader::new(&data[8..]);
+    Ok(Gift {
+        card: r.pubkey()?,
+        sender: r.pubkey()?,
+        claim_key: r.pubkey()?,
+        created_at: r.i64()?,
+        expires_at: r.i64()?,
+    })
+}
+
+/// Decode a CryptCard from raw account data (including the discriminator).
+/// Handles every layout version: fields added after version 0 are only
+/// read when present, so legacy cards decode with `version == 0`.
+pub fn decode_card(data: &[u8]) -> Result<CryptCard, CryptSdkError> {
+    match decode_card_lenient(data)? {
+        (card, true) => Ok(card),
+        (_, false) => Err(CryptSdkError::Serialization("Invalid rarity or card type".into())),
+    }
+}
+
+/// Decode a card even if its rarity or card type is out of range, which
+/// then reads as Common / Swap. The flag is whether both were in range.
+pub(crate) fn decode_card_lenient(data: &[u8]) -> Result<(CryptCard, bool), CryptSdkError> {
+    if data.len() < 8 || data[..8] != account_discriminator("CryptCard") {
+        return Err(CryptSdkError::Serialization("Not a CryptCard account".into()));
+    }
+    let mut r = AccountReader::new(&data[8..]);
+
+    let owner = r.pubkey()?;
+    let mint_id = r.u64()?;
+    let tx_hash = r.string()?;
+    let rarity = Rarity::from_u8(r.u8()?);
+    let card_type = CardType::from_u8(r.u8()?);
+    let tier_in_range = rarity.is_some() && card_type.is_some();
+    let (rarity, card_type) = (rarity.unwrap_or(Rarity::Common), card_type.unwrap_or(CardType::Swap));
+    let title = r.string()?;
+    let narration_hash = r.bytes32()?;
+    let soul_seed = r.bytes32()?;
+    let platform = r.string()?;
+    let pnl = r.string()?;
+    let tx_timestamp = r.i64()?;
+    let minted_at = r.i64()?;
+    let interaction_count = r.u64()?;
+    let soundtrack_id = r.string()?;
+    let _bump = r.u8()?;
+
+    // v1+: version byte. A v0 account either ends here or is zero-padded.
+    let version = r.u8().unwrap_or(0);
+    let metadata_edits = if version >= 2 { r.u8()? } else { 0 };
+    let (user, user_expires_at) = if version >= 3 {
+        let user = r.pubkey()?;
+        let expires_at = r.i64()?;
+        ((user != Pubkey::default()).then_some(user), expires_at)
+    } else {
+        (None, 0)
+    };
+    let boost_event = if version >= 4 { Some(r.u16()?).filter(|&id| id != 0) } else { None };
+    let scoring_version = if version >= 5 { Some(r.u16()?).filter(|&v| v != 0) } else { None };
+
+    Ok((CryptCard {
+        owner, mint_id, tx_hash, rarity, card_type, title, narration_hash,
+        soul_seed, platform, pnl, tx_timestamp, minted_at, interaction_count,
+        soundtrack_id, version, metadata_edits, user, user_expires_at, boost_event,
+        scoring_version,
+    }, tier_in_range))
+}
+
+/// Cursor over Borsh-encoded account data.
+struct AccountReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> AccountReader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], CryptSdkError> {
+        let end = self.pos.checked_add(len)
+            .filter(|&end| end <= self.data.len())
+            .ok_or_else(|| CryptSdkError::Serialization("Account data too short".into()))?;
+        let bytes = &self.data[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    fn u8(&mut self) -> Result<u8, CryptSdkError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16, CryptSdkError> {
+        Ok(u16::from_le_bytes(self.take(2)?.try_into().unwrap()))
+    }
+
+    fn u32(&mut self) -> Result<u32, CryptSdkError> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn u64(&mut self) -> Result<u64, CryptSdkError> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+    }
+
+    fn i64(&mut self) -> Result<i64, CryptSdkError> {
+        Ok(i64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+    }
+
+    fn bytes32(&mut self) -> Result<[u8; 32], CryptSdkError> {
+        Ok(self.take(32)?.try_into().unwrap())
+    }
+
+    fn pubkey(&mut self) -> Result<Pubkey, CryptSdkError> {
+        Ok(Pubkey::new_from_array(self.bytes32()?))
+    }
+
+    fn string(&mut self) -> Result<String, CryptSdkError> {
+        let len = self.u32()? as usize;
+        String::from_utf8(self.take(len)?.to_vec())
+            .map_err(|_| CryptSdkError::Serialization("Invalid UTF-8 string".into()))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -112,9 +769,8 @@ mod tests {
     #[test]
     fn test_collection_pda_derivation() {
         let client = CryptClient::new("https://api.devnet.solana.com");
-        let (pda, bump) = client.collection_address();
+        let (pda, _bump) = client.collection_address();
         assert_ne!(pda, Pubkey::default());
-        assert!(bump <= 255);
     }
 
     #[test]
@@ -134,4 +790,412 @@ mod tests {
         );
         assert!(client.is_ok());
     }
+
+    #[test]
+    fn test_tombstone_pda_unique_per_mint_id() {
+        let client = CryptClient::new("https://api.devnet.solana.com");
+        let (pda1, _) = client.tombstone_address(1);
+        let (pda2, _) = client.tombstone_address(2);
+        assert_ne!(pda1, pda2);
+    }
+
+    #[test]
+    fn test_badge_pda_unique_per_owner_and_set() {
+        let client = CryptClient::new("https://api.devnet.solana.com");
+        let (set_a, _) = client.achievement_address(1);
+        let (set_b, _) = client.achievement_address(2);
+        assert_ne!(set_a, set_b);
+
+        let alice = Pubkey::new_unique();
+        let bob = Pubkey::new_unique();
+        assert_ne!(client.badge_address(&set_a, &alice).0, client.badge_address(&set_a, &bob).0);
+        assert_ne!(client.badge_address(&set_a, &alice).0, client.badge_address(&set_b, &alice).0);
+
+        let card = Pubkey::new_unique();
+        assert_ne!(
+            client.achievement_card_address(&set_a, &card).0,
+            client.achievement_card_address(&set_b, &card).0
+        );
+    }
+
+    #[test]
+    fn test_staking_pdas() {
+        let client = CryptClient::new("https://api.devnet.solana.com");
+        let card = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let (stake, _) = client.stake_address(&card);
+        let (points, _) = client.points_address(&owner);
+        assert_ne!(stake, points);
+        assert_ne!(stake, client.stake_address(&Pubkey::new_unique()).0);
+        assert_eq!(client.staking_config_address(), client.staking_config_address());
+    }
+
+    fn encode_card_v0(owner: &Pubkey, tx_hash: &str) -> Vec<u8> {
+        fn string(out: &mut Vec<u8>, s: &str) {
+            out.extend_from_slice(&(s.len() as u32).to_le_bytes());
+            out.extend_from_slice(s.as_bytes());
+        }
+        let mut data = account_discriminator("CryptCard").to_vec();
+        data.extend_from_slice(owner.as_ref());
+        data.extend_from_slice(&7u64.to_le_bytes());
+        string(&mut data, tx_hash);
+        data.extend_from_slice(&[2, 3]);
+        string(&mut data, "DIAMOND HANDS");
+        data.extend_from_slice(&[1u8; 32]);
+        data.extend_from_slice(&[2u8; 32]);
+        string(&mut data, "JUPITER");
+        string(&mut data, "+420%");
+        data.extend_from_slice(&1_700_000_000i64.to_le_bytes());
+        data.extend_from_slice(&1_700_000_100i64.to_le_bytes());
+        data.extend_from_slice(&12u64.to_le_bytes());
+        string(&mut data, "");
+        data.push(254);
+        data
+    }
+
+    #[test]
+    fn test_out_of_range_rarity_fails_verification_not_decoding() {
+        let program = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let tx_hash = "4xK7m9pR2abc";
+        let mut data = encode_card_v0(&owner, tx_hash);
+        data[8 + 32 + 8 + 4 + tx_hash.len()] = 9;
+        assert!(decode_card(&data).is_err());
+
+        let (address, _) = Pubkey::find_program_address(&[b"card", tx_hash.as_bytes(), owner.as_ref()], &program);
+        let (collection, _) = Pubkey::find_program_address(&[b"collection"], &program);
+        let report = verify_card_account(&data, &VerifyInputs {
+            program_id: &program,
+            card_address: &address,
+            tx_hash,
+            narration: None,
+            minter: None,
+            collection_address: &collection,
+            collection_total_minted: 10,
+        })
+        .unwrap();
+        assert!(!report.tier_in_range);
+        assert!(report.failures().contains(&"tier"));
+    }
+
+    #[test]
+    fn test_decode_legacy_card() {
+        let owner = Pubkey::new_unique();
+        let data = encode_card_v0(&owner, "4xK7m9pR2abc");
+        let card = decode_card(&data).unwrap();
+        assert_eq!(card.owner, owner);
+        assert_eq!(card.mint_id, 7);
+        assert_eq!(card.tx_hash, "4xK7m9pR2abc");
+        assert_eq!(card.rarity, Rarity::Legendary);
+        assert_eq!(card.card_type, CardType::DiamondHands);
+        assert_eq!(card.interaction_count, 12);
+        assert_eq!(card.version, 0);
+
+        // Legacy accounts are zero-padded out to their reserved size
+        let mut padded = data.clone();
+        padded.resize(data.len() + 64, 0);
+        assert_eq!(decode_card(&padded).unwrap().version, 0);
+    }
+
+    #[test]
+    fn test_decode_v1_card() {
+        let mut data = encode_card_v0(&Pubkey::new_unique(), "4xK7m9pR2abc");
+        data.push(1);
+        let card = decode_card(&data).unwrap();
+        assert_eq!(card.version, 1);
+        assert_eq!(card.metadata_edits, 0);
+    }
+
+    #[test]
+    fn test_decode_v2_card() {
+        let mut data = encode_card_v0(&Pubkey::new_unique(), "4xK7m9pR2abc");
+        data.extend_from_slice(&[2, 2]);
+        let card = decode_card(&data).unwrap();
+        assert_eq!(card.version, 2);
+        assert_eq!(card.metadata_edits, 2);
+        assert_eq!(card.user, None);
+    }
+
+    #[test]
+    fn test_decode_current_card() {
+        let user = Pubkey::new_unique();
+        let mut data = encode_card_v0(&Pubkey::new_unique(), "4xK7m9pR2abc");
+        data.extend_from_slice(&[CARD_VERSION, 2]);
+        data.extend_from_slice(user.as_ref());
+        data.extend_from_slice(&1_700_086_400i64.to_le_bytes());
+        data.extend_from_slice(&3u16.to_le_bytes());
+        data.extend_from_slice(&crate::scoring::SCORING_VERSION.to_le_bytes());
+        let card = decode_card(&data).unwrap();
+        assert_eq!(card.boost_event, Some(3));
+        assert_eq!(card.scoring_version, Some(crate::scoring::SCORING_VERSION));
+        assert_eq!(card.version, CARD_VERSION);
+        assert_eq!(card.metadata_edits, 2);
+        assert_eq!(card.user, Some(user));
+        assert!(card.is_lent(1_700_000_000));
+        assert!(!card.is_lent(1_700_086_400));
+    }
+
+    #[test]
+    fn test_decode_unlent_card() {
+        let mut data = encode_card_v0(&Pubkey::new_unique(), "4xK7m9pR2abc");
+        data.extend_from_slice(&[CARD_VERSION, 0]);
+        data.extend_from_slice(&[0u8; 40]);
+        data.extend_from_slice(&[0u8; 4]);
+        let card = decode_card(&data).unwrap();
+        assert_eq!(card.user, None);
+        assert!(!card.is_lent(0));
+        assert_eq!(card.boost_event, None);
+        assert_eq!(card.scoring_version, None);
+    }
+
+    #[test]
+    fn test_decode_boost_table() {
+        fn event(data: &mut Vec<u8>, id: u16, name: &str, card_type: Option<u8>) {
+            data.extend_from_slice(&id.to_le_bytes());
+            data.extend_from_slice(&(name.len() as u32).to_le_bytes());
+            data.extend_from_slice(name.as_bytes());
+            data.extend_from_slice(&1_000i64.to_le_bytes());
+            data.extend_from_slice(&2_000i64.to_le_bytes());
+            match card_type {
+                Some(t) => data.extend_from_slice(&[1, t]),
+                None => data.push(0),
+            }
+            data.extend_from_slice(&15u32.to_le_bytes());
+            data.push(1);
+        }
+        let mut data = account_discriminator("BoostTable").to_vec();
+        data.extend_from_slice(Pubkey::new_unique().as_ref());
+        data.extend_from_slice(&2u32.to_le_bytes());
+        event(&mut data, 1, "HALLOWEEN", Some(1));
+        event(&mut data, 2, "FOREVER", None);
+        data.push(255);
+
+        let events = decode_boost_table(&data).unwrap();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].name, "HALLOWEEN");
+        assert_eq!(events[0].card_type, Some(CardType::Rug));
+        assert_eq!(events[0].score_bonus, 15);
+        assert_eq!(events[0].tier_bonus, 1);
+        assert_eq!(events[1].card_type, None);
+    }
+
+    #[test]
+    fn test_decode_duel_record() {
+        let card = Pubkey::new_unique();
+        let mut data = account_discriminator("DuelRecord").to_vec();
+        data.extend_from_slice(card.as_ref());
+        data.extend_from_slice(&3u32.to_le_bytes());
+        data.extend_from_slice(&1u32.to_le_bytes());
+        data.extend_from_slice(&2u32.to_le_bytes());
+        data.push(254);
+
+        let record = decode_duel_record(&data).unwrap();
+        assert_eq!(record.card, card);
+        assert_eq!((record.wins, record.losses, record.draws), (3, 1, 2));
+    }
+
+    #[test]
+    fn test_decode_card_social() {
+        let card = Pubkey::new_unique();
+        let mut data = account_discriminator("CardSocial").to_vec();
+        data.extend_from_slice(card.as_ref());
+        data.extend_from_slice(&12u64.to_le_bytes());
+        data.extend_from_slice(&1u64.to_le_bytes());
+        data.extend_from_slice(&4u64.to_le_bytes());
+        data.extend_from_slice(&[9u8; 32]);
+        data.extend_from_slice(&17u64.to_le_bytes());
+        data.push(253);
+
+        let social = decode_card_social(&data).unwrap();
+        assert_eq!(social.card, card);
+        assert_eq!((social.likes, social.shares, social.bookmarks), (12, 1, 4));
+        assert_eq!(social.log_root, [9u8; 32]);
+        assert_eq!(social.log_len, 17);
+    }
+
+    #[test]
+    fn test_decode_react_record() {
+        let mut data = account_discriminator("ReactRecord").to_vec();
+        data.extend_from_slice(&[0b1001, 254]);
+        assert_eq!(
+            decode_react_record(&data).unwrap(),
+            [InteractionType::Like, InteractionType::Bookmark]
+        );
+        assert!(decode_react_record(&data[..8]).is_err());
+    }
+
+    #[test]
+    fn test_decode_moderation() {
+        let target = Pubkey::new_unique();
+        let card = Pubkey::new_unique();
+        let moderator = Pubkey::new_unique();
+        let mut data = account_discriminator("Moderation").to_vec();
+        data.extend_from_slice(target.as_ref());
+        data.push(1);
+        data.extend_from_slice(card.as_ref());
+        data.push(1);
+        data.extend_from_slice(&3u32.to_le_bytes());
+        data.push(1);
+        data.extend_from_slice(moderator.as_ref());
+        data.extend_from_slice(&1_700_000_000i64.to_le_bytes());
+        data.push(255);
+
+        let m = decode_moderation(&data).unwrap();
+        assert_eq!(m.target, target);
+        assert!(m.is_comment);
+        assert_eq!(m.card, card);
+        assert!(m.hidden);
+        assert_eq!(m.report_count, 3);
+        assert_eq!(m.last_reason, Some(ReportReason::Scam));
+        assert_eq!(m.moderator, Some(moderator));
+    }
+
+    #[test]
+    fn test_decode_distributor() {
+        let authority = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let vault = Pubkey::new_unique();
+        let mut data = account_discriminator("Distributor").to_vec();
+        data.extend_from_slice(&7u64.to_le_bytes());
+        data.extend_from_slice(authority.as_ref());
+        data.extend_from_slice(&[5u8; 32]);
+        data.push(1);
+        data.extend_from_slice(mint.as_ref());
+        data.extend_from_slice(vault.as_ref());
+        for v in [1_000u64, 400, 3, 250_000_000] {
+            data.extend_from_slice(&v.to_le_bytes());
+        }
+        data.extend_from_slice(&0i64.to_le_bytes());
+        data.extend_from_slice(&1_700_000_000i64.to_le_bytes());
+        data.push(254);
+
+        let d = decode_distributor(&data).unwrap();
+        assert_eq!(d.distributor_id, 7);
+        assert_eq!(d.authority, authority);
+        assert_eq!(d.mint, Some(mint));
+        assert_eq!(d.vault, Some(vault));
+        assert_eq!((d.total_amount, d.claimed_amount, d.num_claimed), (1_000, 400, 3));
+        assert_eq!(d.snapshot_slot, 250_000_000);
+        assert!(d.is_open(i64::MAX));
+    }
+
+    #[test]
+    fn test_decode_proposal() {
+        let authority = Pubkey::new_unique();
+        let mut data = account_discriminator("Proposal").to_vec();
+        data.extend_from_slice(&2u64.to_le_bytes());
+        data.extend_from_slice(authority.as_ref());
+        let title = "SEASON 2 THEME";
+        data.extend_from_slice(&(title.len() as u32).to_le_bytes());
+        data.extend_from_slice(title.as_bytes());
+        data.extend_from_slice(&3u32.to_le_bytes());
+        for option in ["RUGS", "APES", "BAGS"] {
+            data.extend_from_slice(&(option.len() as u32).to_le_bytes());
+            data.extend_from_slice(option.as_bytes());
+        }
+        for tally in [4u64, 11, 2, 0, 0, 0, 0, 0] {
+            data.extend_from_slice(&tally.to_le_bytes());
+        }
+        data.extend_from_slice(&9u64.to_le_bytes());
+        data.extend_from_slice(&1_700_000_000i64.to_le_bytes());
+        data.extend_from_slice(&1_700_604_800i64.to_le_bytes());
+        data.push(1);
+        data.extend_from_slice(&[1, 1]);
+        data.extend_from_slice(&1_699_990_000i64.to_le_bytes());
+        data.push(253);
+
+        let p = decode_proposal(&data).unwrap();
+        assert_eq!(p.proposal_id, 2);
+        assert_eq!(p.options, vec!["RUGS", "APES", "BAGS"]);
+        assert_eq!(p.tallies, vec![4, 11, 2]);
+        assert_eq!(p.total_weight(), 17);
+        assert_eq!(p.cards_voted, 9);
+        assert_eq!(p.winner(), Some("APES"));
+        assert!(!p.is_voting(1_700_100_000), "finalized");
+        assert_eq!(p.created_at, 1_699_990_000);
+    }
+
+    #[test]
+    fn test_vote_record_pda_per_card() {
+        let client = CryptClient::new("https://api.devnet.solana.com");
+        let (proposal, _) = client.proposal_address(1);
+        let (a, _) = client.vote_record_address(&proposal, &Pubkey::new_unique());
+        let (b, _) = client.vote_record_address(&proposal, &Pubkey::new_unique());
+        assert_ne!(a, b);
+        assert_ne!(proposal, client.proposal_address(2).0);
+    }
+
+    #[test]
+    fn test_decode_wrap_record() {
+        let card = Pubkey::new_unique();
+        let nft_mint = Pubkey::new_unique();
+        let mut data = account_discriminator("WrapRecord").to_vec();
+        data.extend_from_slice(card.as_ref());
+        data.extend_from_slice(nft_mint.as_ref());
+        data.extend_from_slice(Pubkey::new_unique().as_ref());
+        data.extend_from_slice(&1_700_000_000i64.to_le_bytes());
+        data.push(252);
+
+        let wrap = decode_wrap_record(&data).unwrap();
+        assert_eq!((wrap.card, wrap.nft_mint), (card, nft_mint));
+        assert_eq!(wrap.wrapped_at, 1_700_000_000);
+        assert!(decode_gift(&data).is_err());
+    }
+
+    #[test]
+    fn test_decode_gift() {
+        let card = Pubkey::new_unique();
+        let sender = Pubkey::new_unique();
+        let mut data = account_discriminator("Gift").to_vec();
+        data.extend_from_slice(card.as_ref());
+        data.extend_from_slice(sender.as_ref());
+        data.extend_from_slice(&[7u8; 32]);
+        data.extend_from_slice(&1_700_000_000i64.to_le_bytes());
+        data.extend_from_slice(&1_700_086_400i64.to_le_bytes());
+        data.push(255);
+
+        let gift = decode_gift(&data).unwrap();
+        assert_eq!(gift.card, card);
+        assert_eq!(gift.sender, sender);
+        assert_eq!(gift.claim_key, Pubkey::new_from_array([7u8; 32]));
+        assert!(gift.is_claimable(1_700_000_001));
+        assert!(!gift.is_claimable(1_700_086_400));
+        assert!(decode_gift(&data[..40]).is_err());
+    }
+
+    #[test]
+    fn test_decode_card_rejects_truncated() {
+        let data = encode_card_v0(&Pubkey::new_unique(), "4xK7m9pR2abc");
+        assert!(decode_card(&data[..60]).is_err());
+        assert!(decode_card(&[0u8; 4]).is_err());
+    }
+
+    #[test]
+    fn test_decode_tombstone() {
+        let owner = Pubkey::new_unique();
+        let digest: [u8; 32] = Sha256::digest(b"4xK7m9pR2abc").into();
+        let mut data = account_discriminator("Tombstone").to_vec();
+        data.extend_from_slice(owner.as_ref());
+        data.extend_from_slice(&42u64.to_le_bytes());
+        data.extend_from_slice(&digest);
+        data.push(2);
+        data.push(1);
+        data.extend_from_slice(&1_700_000_000i64.to_le_bytes());
+        data.push(255);
+
+        let tombstone = decode_tombstone(&data).unwrap();
+        assert_eq!(tombstone.last_owner, owner);
+        assert_eq!(tombstone.mint_id, 42);
+        assert_eq!(tombstone.rarity, Rarity::Legendary);
+        assert_eq!(tombstone.card_type, CardType::Rug);
+        assert_eq!(tombstone.burned_at, 1_700_000_000);
+        assert!(tombstone.matches_tx("4xK7m9pR2abc"));
+        assert!(!tombstone.matches_tx("someone_else"));
+    }
+
+    #[test]
+    fn test_decode_tombstone_rejects_other_accounts() {
+        let data = vec![0u8; 100];
+        assert!(decode_tombstone(&data).is_err());
+    }
 }
@@ -0,0 +1,154 @@
+//! Merkle airdrop claims.
+//!
+//! The indexer's snapshot tool writes a distribution file — the merkle
+//! root, the total to fund and one proof per holder. This module reads
+//! that file, looks up a wallet's allocation and checks its proof the
+//! same way `claim_distribution` does on-chain, so a claim can be
+//! validated before it's sent.
+
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+use std::str::FromStr;
+use crate::error::CryptSdkError;
+
+pub use crypt_core::merkle::{merkle_node, verify_merkle_proof, MAX_PROOF_LEN};
+
+/// Leaf for one allocation — see `crypt_core::merkle` for the encoding.
+pub fn distribution_leaf(claimant: &Pubkey, amount: u64) -> [u8; 32] {
+    crypt_core::merkle::distribution_leaf(&claimant.to_bytes(), amount)
+}
+
+/// Whether `proof` shows `(claimant, amount)` is in the tree under `root`.
+/// Proofs over `MAX_PROOF_LEN` fail here just as they do on-chain.
+pub fn verify_claim(root: &[u8; 32], claimant: &Pubkey, amount: u64, proof: &[[u8; 32]]) -> bool {
+    verify_merkle_proof(proof, root, &distribution_leaf(claimant, amount))
+}
+
+/// One holder's entry in a distribution file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DistributionClaim {
+    pub claimant: String,
+    pub amount: u64,
+    /// Sibling hashes from leaf to root, hex-encoded
+    pub proof: Vec<String>,
+}
+
+/// A distribution file as exported by the indexer snapshot tool.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DistributionFile {
+    /// Hex-encoded merkle root
+    pub merkle_root: String,
+    pub total_amount: u64,
+    pub claims: Vec<DistributionClaim>,
+}
+
+/// A wallet's allocation, decoded and ready for `claim_distribution`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Allocation {
+    pub amount: u64,
+    pub proof: Vec<[u8; 32]>,
+}
+
+impl DistributionFile {
+    pub fn from_json(json: &str) -> Result<Self, CryptSdkError> {
+        serde_json::from_str(json).map_err(|e| CryptSdkError::Serialization(e.to_string()))
+    }
+
+    pub fn root(&self) -> Result<[u8; 32], CryptSdkError> {
+        parse_hash(&self.merkle_root)
+    }
+
+    /// The allocation for `claimant`, if it has one. Errors if the file
+    /// is malformed or the proof doesn't lead to the file's root.
+    pub fn allocation(&self, claimant: &Pubkey) -> Result<Option<Allocation>, CryptSdkError> {
+        let Some(claim) = self.claims.iter().find(|c| {
+            Pubkey::from_str(&c.claimant).is_ok_and(|key| key == *claimant)
+        }) else {
+            return Ok(None);
+        };
+        let proof = claim.proof.iter().map(|h| parse_hash(h)).collect::<Result<Vec<_>, _>>()?;
+        if !verify_claim(&self.root()?, claimant, claim.amount, &proof) {
+            return Err(CryptSdkError::VerificationFailed {
+                reason: format!("proof for {} does not match the distribution root", claimant),
+            });
+        }
+        Ok(Some(Allocation { amount: claim.amount, proof }))
+    }
+}
+
+fn parse_hash(hex: &str) -> Result<[u8; 32], CryptSdkError> {
+    let bad = || CryptSdkError::Serialization(format!("invalid 32-byte hex hash: {}", hex));
+    if hex.len() != 64 || !hex.is_ascii() {
+        return Err(bad());
+    }
+    let mut out = [0u8; 32];
+    for (i, byte) in out.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).map_err(|_| bad())?;
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(bytes: &[u8; 32]) -> String {
+        bytes.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    /// Three claimants: (a, b) paired, c carried up.
+    fn file() -> (DistributionFile, [Pubkey; 3]) {
+        let keys = [Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique()];
+        let amounts = [500u64, 300, 200];
+        let [a, b, c] = [0, 1, 2].map(|i| distribution_leaf(&keys[i], amounts[i]));
+        let ab = merkle_node(&a, &b);
+        let root = merkle_node(&ab, &c);
+        let proofs = [vec![b, c], vec![a, c], vec![ab]];
+
+        let claims = (0..3)
+            .map(|i| DistributionClaim {
+                claimant: keys[i].to_string(),
+                amount: amounts[i],
+                proof: proofs[i].iter().map(hex).collect(),
+            })
+            .collect();
+        (DistributionFile { merkle_root: hex(&root), total_amount: 1_000, claims }, keys)
+    }
+
+    #[test]
+    fn test_allocation_lookup() {
+        let (file, keys) = file();
+        let allocation = file.allocation(&keys[2]).unwrap().unwrap();
+        assert_eq!(allocation.amount, 200);
+        assert_eq!(allocation.proof.len(), 1);
+        assert!(file.allocation(&Pubkey::new_unique()).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_tampered_amount_rejected() {
+        let (mut file, keys) = file();
+        file.claims[0].amount = 5_000;
+        assert!(file.allocation(&keys[0]).is_err());
+        assert!(file.allocation(&keys[1]).unwrap().is_some());
+    }
+
+    #[test]
+    fn test_json_roundtrip() {
+        let (file, keys) = file();
+        let json = serde_json::to_string(&file).unwrap();
+        let parsed = DistributionFile::from_json(&json).unwrap();
+        assert_eq!(parsed.allocation(&keys[0]).unwrap(), file.allocation(&keys[0]).unwrap());
+        assert!(DistributionFile::from_json("{}").is_err());
+    }
+
+    #[test]
+    fn test_overlong_proof_rejected() {
+        let claimant = Pubkey::new_unique();
+        let root_of = |proof: &[[u8; 32]]| {
+            proof.iter().fold(distribution_leaf(&claimant, 1), |node, sibling| merkle_node(&node, sibling))
+        };
+        let proof = vec![[7u8; 32]; MAX_PROOF_LEN + 1];
+        assert!(verify_claim(&root_of(&proof[1..]), &claimant, 1, &proof[1..]));
+        assert!(!verify_claim(&root_of(&proof), &claimant, 1, &proof));
+    }
+}
@@ -0,0 +1,162 @@
+//! Offline duel simulation.
+//!
+//! The rules come from `crypt-core`, the same code the program runs, so
+//! a simulation always matches what the program decides on-chain for the
+//! same cards and reveals.
+
+use sha2::{Digest, Sha256};
+use solana_sdk::pubkey::Pubkey;
+use crate::types::CryptCard;
+
+pub use crypt_core::duel::*;
+
+impl From<&CryptCard> for Duelist {
+    fn from(card: &CryptCard) -> Self {
+        Self::new(&card.soul_seed, card.rarity.as_u8(), card.card_type.as_u8())
+    }
+}
+
+/// Commitment to pass to `challenge_duel`/`accept_duel`: SHA-256 of the
+/// card address followed by the nonce you will later reveal.
+pub fn duel_commitment(card: &Pubkey, nonce: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(card.as_ref());
+    hasher.update(nonce);
+    hasher.finalize().into()
+}
+
+/// The shared roll both reveals produce, challenger first.
+pub fn duel_roll(challenger_nonce: &[u8; 32], opponent_nonce: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(challenger_nonce);
+    hasher.update(opponent_nonce);
+    hasher.finalize().into()
+}
+
+/// Play out a duel exactly as the program would.
+pub fn simulate_duel(
+    challenger: &CryptCard,
+    opponent: &CryptCard,
+    challenger_nonce: &[u8; 32],
+    opponent_nonce: &[u8; 32],
+) -> DuelOutcome {
+    resolve_duel(
+        &Duelist::from(challenger),
+        &Duelist::from(opponent),
+        &duel_roll(challenger_nonce, opponent_nonce),
+    )
+}
+
+/// Matchup odds before anyone commits.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DuelOdds {
+    pub challenger_power: u32,
+    pub opponent_power: u32,
+    pub challenger_win: f64,
+    pub opponent_win: f64,
+    pub draw: f64,
+}
+
+/// Power of each side and win chances over every luck combination,
+/// treating each side's luck as uniform in `0..=MAX_DUEL_LUCK`.
+pub fn duel_odds(challenger: &CryptCard, opponent: &CryptCard) -> DuelOdds {
+    let a = Duelist::from(challenger);
+    let b = Duelist::from(opponent);
+    let challenger_power = duel_power(&a, &b);
+    let opponent_power = duel_power(&b, &a);
+
+    let (mut wins, mut losses, mut draws) = (0u32, 0u32, 0u32);
+    for luck_a in 0..=MAX_DUEL_LUCK {
+        for luck_b in 0..=MAX_DUEL_LUCK {
+            match (challenger_power + luck_a).cmp(&(opponent_power + luck_b)) {
+                std::cmp::Ordering::Greater => wins += 1,
+                std::cmp::Ordering::Less => losses += 1,
+                std::cmp::Ordering::Equal => draws += 1,
+            }
+        }
+    }
+    let total = ((MAX_DUEL_LUCK + 1) * (MAX_DUEL_LUCK + 1)) as f64;
+
+    DuelOdds {
+        challenger_power,
+        opponent_power,
+        challenger_win: wins as f64 / total,
+        opponent_win: losses as f64 / total,
+        draw: draws as f64 / total,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soul::SoulSignature;
+    use crate::types::{CardType, Rarity, CARD_VERSION};
+
+    fn card(tx_hash: &str, rarity: Rarity, card_type: CardType) -> CryptCard {
+        CryptCard {
+            owner: Pubkey::new_unique(),
+            mint_id: 0,
+            tx_hash: tx_hash.into(),
+            rarity,
+            card_type,
+            title: String::new(),
+            narration_hash: [0; 32],
+            soul_seed: SoulSignature::compute(tx_hash),
+            platform: String::new(),
+            pnl: String::new(),
+            tx_timestamp: 0,
+            minted_at: 0,
+            interaction_count: 0,
+            soundtrack_id: String::new(),
+            version: CARD_VERSION,
+            metadata_edits: 0,
+            user: None,
+            user_expires_at: 0,
+            boost_event: None,
+            scoring_version: None,
+        }
+    }
+
+    #[test]
+    fn test_traits_match_soul_signature() {
+        for tx in ["4xK7m9pR2abc", "5yL8n0qS3def", "whale", "rug_survivor_99"] {
+            let seed = SoulSignature::compute(tx);
+            let from_soul = DuelTraits::from(&SoulSignature::extract_traits(&seed));
+            assert_eq!(from_soul, DuelTraits::from_seed(&seed), "Trait mismatch for {}", tx);
+        }
+    }
+
+    #[test]
+    fn test_simulation_is_deterministic() {
+        let a = card("4xK7m9pR2abc", Rarity::Rare, CardType::Swap);
+        let b = card("5yL8n0qS3def", Rarity::Rare, CardType::DiamondHands);
+        let first = simulate_duel(&a, &b, &[1; 32], &[2; 32]);
+        for _ in 0..10 {
+            assert_eq!(simulate_duel(&a, &b, &[1; 32], &[2; 32]), first);
+        }
+    }
+
+    #[test]
+    fn test_commitment_binds_card() {
+        let nonce = [9u8; 32];
+        let a = Pubkey::new_unique();
+        let b = Pubkey::new_unique();
+        assert_ne!(duel_commitment(&a, &nonce), duel_commitment(&b, &nonce));
+        assert_eq!(duel_commitment(&a, &nonce), duel_commitment(&a, &nonce));
+    }
+
+    #[test]
+    fn test_odds_sum_to_one() {
+        let a = card("4xK7m9pR2abc", Rarity::Common, CardType::Rug);
+        let b = card("5yL8n0qS3def", Rarity::Rare, CardType::Mint);
+        let odds = duel_odds(&a, &b);
+        assert!((odds.challenger_win + odds.opponent_win + odds.draw - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_mirror_match_is_even() {
+        let a = card("4xK7m9pR2abc", Rarity::Rare, CardType::Swap);
+        let odds = duel_odds(&a, &a);
+        assert!((odds.challenger_win - odds.opponent_win).abs() < 1e-9);
+    }
+}
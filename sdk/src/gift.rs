@@ -0,0 +1,141 @@
+//! Gift links — share a card with someone who hasn't connected a wallet.
+//!
+//! The sender generates a throwaway keypair, locks the card under its
+//! public key with `create_gift`, and shares a link carrying the card
+//! address and the keypair's secret. Whoever opens the link signs
+//! `claim_gift` with that keypair before the gift expires. The signature
+//! covers the claimant, so a claim seen in flight can't be redirected.
+//! The secret travels in the URL fragment, so it never reaches the web
+//! server, but anyone who sees the link can claim the card.
+
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{keypair_from_seed, Keypair, Signer};
+use std::str::FromStr;
+use crate::error::CryptSdkError;
+
+/// Default page that handles gift claims.
+pub const DEFAULT_CLAIM_URL: &str = "https://crypt.cards/gift";
+
+/// The 32-byte seed of the ephemeral keypair that unlocks a gift.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GiftKey(pub [u8; 32]);
+
+impl GiftKey {
+    /// Generate a fresh key from the OS random number generator.
+    pub fn generate() -> Self {
+        let mut bytes = [0u8; 32];
+        rand::rngs::OsRng.fill_bytes(&mut bytes);
+        Self(bytes)
+    }
+
+    /// The keypair that signs the claim.
+    pub fn keypair(&self) -> Keypair {
+        keypair_from_seed(&self.0).expect("32-byte seeds are always valid")
+    }
+
+    /// The claim key passed to `create_gift`.
+    pub fn pubkey(&self) -> Pubkey {
+        self.keypair().pubkey()
+    }
+
+    pub fn to_base58(&self) -> String {
+        bs58::encode(self.0).into_string()
+    }
+
+    pub fn from_base58(s: &str) -> Result<Self, CryptSdkError> {
+        let bytes = bs58::decode(s)
+            .into_vec()
+            .map_err(|e| CryptSdkError::Serialization(format!("Invalid gift key: {}", e)))?;
+        let bytes: [u8; 32] = bytes
+            .try_into()
+            .map_err(|_| CryptSdkError::Serialization("Gift key must be 32 bytes".into()))?;
+        Ok(Self(bytes))
+    }
+}
+
+/// Everything a recipient needs to claim a gift.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GiftLink {
+    pub card: Pubkey,
+    pub key: GiftKey,
+}
+
+impl GiftLink {
+    /// Create a link for `card` with a freshly generated key.
+    pub fn generate(card: Pubkey) -> Self {
+        Self { card, key: GiftKey::generate() }
+    }
+
+    /// Build the claim URL, e.g. `https://crypt.cards/gift#card=<addr>&key=<b58>`.
+    pub fn to_url(&self, base: &str) -> String {
+        format!(
+            "{}#card={}&key={}",
+            base.trim_end_matches('/'),
+            self.card,
+            self.key.to_base58()
+        )
+    }
+
+    /// Parse a claim URL produced by [`GiftLink::to_url`]. Also accepts
+    /// the bare `card=...&key=...` payload without a base URL.
+    pub fn parse(url: &str) -> Result<Self, CryptSdkError> {
+        let payload = url.rsplit_once('#').map(|(_, p)| p).unwrap_or(url);
+
+        let mut card = None;
+        let mut key = None;
+        for pair in payload.split('&') {
+            match pair.split_once('=') {
+                Some(("card", value)) => {
+                    card = Some(Pubkey::from_str(value)
+                        .map_err(|_| CryptSdkError::InvalidAddress(value.to_string()))?);
+                }
+                Some(("key", value)) => key = Some(GiftKey::from_base58(value)?),
+                _ => {}
+            }
+        }
+
+        match (card, key) {
+            (Some(card), Some(key)) => Ok(Self { card, key }),
+            _ => Err(CryptSdkError::Serialization("Gift link needs card and key".into())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_is_deterministic() {
+        let key = GiftKey([7u8; 32]);
+        assert_eq!(key.pubkey(), key.keypair().pubkey());
+        assert_eq!(key.pubkey(), GiftKey([7u8; 32]).pubkey());
+        assert_ne!(key.pubkey(), GiftKey([8u8; 32]).pubkey());
+    }
+
+    #[test]
+    fn test_generated_keys_differ() {
+        assert_ne!(GiftKey::generate(), GiftKey::generate());
+    }
+
+    #[test]
+    fn test_link_roundtrip() {
+        let link = GiftLink::generate(Pubkey::new_unique());
+        let url = link.to_url(DEFAULT_CLAIM_URL);
+        assert!(url.starts_with("https://crypt.cards/gift#card="));
+        assert_eq!(GiftLink::parse(&url).unwrap(), link);
+
+        let payload = url.split_once('#').unwrap().1;
+        assert_eq!(GiftLink::parse(payload).unwrap(), link);
+    }
+
+    #[test]
+    fn test_parse_rejects_incomplete_links() {
+        let card = Pubkey::new_unique();
+        assert!(GiftLink::parse(&format!("https://crypt.cards/gift#card={}", card)).is_err());
+        assert!(GiftLink::parse("https://crypt.cards/gift#card=nope&key=1111").is_err());
+        assert!(GiftLink::parse(&format!("#card={}&key=abc", card)).is_err());
+    }
+}
@@ -4,8 +4,18 @@
 //! - Scanning wallets and building Crypt Cards
 //! - Minting cards as on-chain accounts
 //! - Transferring and burning cards
-//! - Verifying soul signature authenticity
-//! - Computing rarity scores
+//! - Verifying card authenticity with a per-check report
+//! - Generating gift links for key-locked card gifts
+//! - Simulating card duels offline with the on-chain rules
+//! - Checking compact like/share/bookmark logs against the chain
+//! - Looking up and checking merkle airdrop allocations
+//! - Reading governance proposals and card votes
+//! - Building Metaplex JSON for cards wrapped as NFTs
+//! - Computing rarity scores, with bonuses for detected patterns
+//! - Loading scoring rules from JSON to experiment with the numbers
+//! - Calibrating rarity thresholds against a corpus of scanned transactions
+//! - Flagging wash trades and self-transfers staged to farm scores
+//! - Detecting card-worthy patterns in a wallet's history
 //!
 //! # Example
 //! ```rust,ignore
@@ -20,10 +30,26 @@ pub mod client;
 pub mod types;
 pub mod soul;
 pub mod scoring;
+pub mod rules;
+pub mod calibration;
 pub mod error;
+pub mod verify;
+pub mod gift;
+pub mod duel;
+pub mod social;
+pub mod distributor;
+pub mod nft;
+pub mod analytics;
 
 pub use client::CryptClient;
 pub use types::*;
 pub use soul::SoulSignature;
-pub use scoring::RarityScorer;
+pub use scoring::{BoostEvent, RarityScorer, WalletContext, SCORING_VERSION};
+pub use rules::ScoringRules;
+pub use calibration::Calibration;
 pub use error::CryptSdkError;
+pub use verify::VerificationReport;
+pub use gift::{GiftKey, GiftLink};
+pub use distributor::DistributionFile;
+pub use nft::{metadata_uri, NftMetadata};
+pub use analytics::PatternDetector;
@@ -0,0 +1,180 @@
+//! Metaplex JSON for wrapped cards.
+//!
+//! `wrap_card` mints a 1/1 NFT whose on-chain metadata only holds a
+//! name, symbol and URI; the attributes marketplaces show live in the
+//! JSON behind that URI. The program derives the URI from the card and
+//! the collection, so the JSON has to be hosted at [`metadata_uri`]
+//! before wrapping. This builds the same JSON as the frontend's
+//! `buildCardMetadata`, so a card wrapped from the CLI or a script looks
+//! the same as one wrapped in the app.
+
+use serde::{Deserialize, Serialize};
+use crate::types::CryptCard;
+
+pub use crypt_core::nft::NFT_SYMBOL;
+
+/// URI the program gives card `mint_id`'s NFT: its JSON under the
+/// collection URI's directory, e.g. `https://crypt.cards/7.json`.
+pub fn metadata_uri(collection_uri: &str, mint_id: u64) -> String {
+    format!("{}/{}.json", crypt_core::nft::metadata_base(collection_uri), mint_id)
+}
+
+/// Site linked from every wrapped card.
+pub const EXTERNAL_URL: &str = "https://crypt-phi-two.vercel.app";
+
+/// One marketplace trait.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NftAttribute {
+    pub trait_type: String,
+    pub value: String,
+}
+
+/// Soundtrack attached to a card, from Audius.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NftSoundtrack {
+    pub title: String,
+    pub artist: String,
+    pub audius_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NftFile {
+    pub uri: String,
+    #[serde(rename = "type")]
+    pub mime: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NftProperties {
+    pub category: String,
+    pub files: Vec<NftFile>,
+    pub soundtrack: Option<NftSoundtrack>,
+}
+
+/// Off-chain Metaplex metadata for a wrapped card.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NftMetadata {
+    pub name: String,
+    pub symbol: String,
+    pub description: String,
+    pub image: String,
+    pub external_url: String,
+    pub attributes: Vec<NftAttribute>,
+    pub properties: NftProperties,
+}
+
+impl NftMetadata {
+    /// Build the JSON for `card`. `narration` is the text behind the
+    /// card's narration hash, which only the client has.
+    pub fn for_card(
+        card: &CryptCard,
+        narration: Option<&str>,
+        artwork_url: Option<&str>,
+        soundtrack: Option<NftSoundtrack>,
+    ) -> Self {
+        let attribute = |trait_type: &str, value: String| NftAttribute {
+            trait_type: trait_type.to_string(),
+            value,
+        };
+        let pnl = if card.pnl.is_empty() { "N/A".to_string() } else { card.pnl.clone() };
+
+        Self {
+            name: format!("CRYPT #{} — {}", card.mint_id, card.title),
+            symbol: NFT_SYMBOL.to_string(),
+            description: narration
+                .filter(|n| !n.is_empty())
+                .unwrap_or("A resurrected blockchain moment.")
+                .to_string(),
+            image: artwork_url.unwrap_or_default().to_string(),
+            external_url: EXTERNAL_URL.to_string(),
+            // The frontend keys types and rarities in lower case
+            attributes: vec![
+                attribute("Type", card.card_type.as_str().to_lowercase()),
+                attribute("Rarity", card.rarity.as_str().to_lowercase()),
+                attribute("Platform", card.platform.clone()),
+                attribute("PnL", pnl),
+                attribute("Transaction", card.tx_hash.clone()),
+            ],
+            properties: NftProperties {
+                category: "image".to_string(),
+                files: artwork_url
+                    .map(|uri| vec![NftFile { uri: uri.to_string(), mime: "image/png".to_string() }])
+                    .unwrap_or_default(),
+                soundtrack,
+            },
+        }
+    }
+
+    /// Value of the trait named `trait_type`, if present.
+    pub fn attribute(&self, trait_type: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|a| a.trait_type == trait_type)
+            .map(|a| a.value.as_str())
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{CardType, Rarity};
+    use solana_sdk::pubkey::Pubkey;
+
+    fn card() -> CryptCard {
+        CryptCard {
+            owner: Pubkey::new_unique(),
+            mint_id: 42,
+            tx_hash: "5xK7mQ".into(),
+            rarity: Rarity::Legendary,
+            card_type: CardType::DiamondHands,
+            title: "HELD THROUGH IT ALL".into(),
+            narration_hash: [0; 32],
+            soul_seed: [0; 32],
+            platform: "JUPITER".into(),
+            pnl: String::new(),
+            tx_timestamp: 0,
+            minted_at: 0,
+            interaction_count: 0,
+            soundtrack_id: String::new(),
+            version: 5,
+            metadata_edits: 0,
+            user: None,
+            user_expires_at: 0,
+            boost_event: None,
+            scoring_version: None,
+        }
+    }
+
+    #[test]
+    fn test_attributes_match_frontend() {
+        let meta = NftMetadata::for_card(&card(), None, None, None);
+        let traits: Vec<_> = meta.attributes.iter().map(|a| a.trait_type.as_str()).collect();
+        assert_eq!(traits, ["Type", "Rarity", "Platform", "PnL", "Transaction"]);
+        assert_eq!(meta.attribute("Type"), Some("diamond_hands"));
+        assert_eq!(meta.attribute("Rarity"), Some("legendary"));
+        assert_eq!(meta.attribute("PnL"), Some("N/A"));
+        assert_eq!(meta.attribute("Transaction"), Some("5xK7mQ"));
+        assert_eq!(meta.name, "CRYPT #42 — HELD THROUGH IT ALL");
+        assert_eq!(meta.description, "A resurrected blockchain moment.");
+        assert!(meta.properties.files.is_empty());
+    }
+
+    #[test]
+    fn test_metadata_uri_matches_program() {
+        assert_eq!(metadata_uri("https://crypt.cards/collection.json", 42), "https://crypt.cards/42.json");
+    }
+
+    #[test]
+    fn test_json_shape() {
+        let meta = NftMetadata::for_card(&card(), Some("Never sold."), Some("https://art/42.png"), None);
+        let json: serde_json::Value = serde_json::from_str(&meta.to_json()).unwrap();
+        assert_eq!(json["symbol"], "CRYPT");
+        assert_eq!(json["description"], "Never sold.");
+        assert_eq!(json["properties"]["files"][0]["type"], "image/png");
+        assert!(json["properties"]["soundtrack"].is_null());
+    }
+}
@@ -0,0 +1,257 @@
+//! Declarative scoring rules — the numbers behind rarity scores, kept
+//! as data so they can be tuned without recompiling.
+//!
+//! Rules are JSON. Each entry covers one or more Helius transaction
+//! types and can have a base, SOL tiers, flag bonuses and a dust
+//! exemption. Tiers are checked in order and the first match wins, so
+//! list them largest first; a tier without `above_sol` matches any
+//! amount. [`DEFAULT_RULES`] holds the program's own rules from
+//! `crypt-core`, so scores under them match the chain. Scores report
+//! the scoring version they ran, capped at the rules' `version`, so
+//! sets without one report 0.
+
+use serde::{Deserialize, Serialize};
+use crypt_core::FactorDetail;
+use crate::scoring::{sol_to_lamports, ScoreParams};
+use crate::CryptSdkError;
+
+/// The bundled rule set, `rules/default.json`.
+pub const DEFAULT_RULES: &str = include_str!("../rules/default.json");
+
+/// A complete rule set for [`crate::RarityScorer`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScoringRules {
+    /// Shown with scores, e.g. `default` for the bundled rules
+    #[serde(default)]
+    pub name: String,
+    /// Latest scoring version the rules belong to, which caps the
+    /// version scores report. The bundled rules carry
+    /// [`crate::SCORING_VERSION`]; experimental sets should leave it at
+    /// 0 (unknown) so their scores can't pass for official ones.
+    #[serde(default)]
+    pub version: u16,
+    pub types: Vec<TypeRule>,
+    /// Rule for types no entry lists
+    pub unknown: TypeRule,
+    pub dust: DustRule,
+}
+
+/// Scoring for a group of transaction types.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TypeRule {
+    /// Helius types this rule covers, e.g. `TRANSFER` and `SOL_TRANSFER`
+    #[serde(default)]
+    pub types: Vec<String>,
+    #[serde(default)]
+    pub base: Option<RuleFactor>,
+    #[serde(default)]
+    pub tiers: Vec<Tier>,
+    #[serde(default)]
+    pub flags: Vec<FlagRule>,
+    /// Skip the dust penalty (mints and burns are worth a card whatever
+    /// they moved)
+    #[serde(default)]
+    pub dust_exempt: bool,
+}
+
+/// Points for amounts in a SOL bucket.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Tier {
+    /// Matches amounts strictly above this many SOL (None = any amount)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub above_sol: Option<f64>,
+    #[serde(flatten)]
+    pub factor: RuleFactor,
+}
+
+/// Transaction flags that can earn points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Flag {
+    Memecoin,
+    DefiSource,
+    /// The wallet came out ahead in SOL
+    Profit,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FlagRule {
+    pub flag: Flag,
+    #[serde(flatten)]
+    pub factor: RuleFactor,
+}
+
+/// Penalty for transactions that moved almost nothing.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DustRule {
+    /// Amounts strictly below this many SOL are dust
+    pub below_sol: f64,
+    #[serde(flatten)]
+    pub factor: RuleFactor,
+}
+
+/// One named point change, listed in a score's factors.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RuleFactor {
+    pub label: String,
+    pub points: i32,
+    /// Show the amount in the explanation, to this many decimals
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sol_decimals: Option<usize>,
+    /// Show the raw transaction type in the explanation
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub show_type: bool,
+}
+
+impl RuleFactor {
+    pub fn detail(&self) -> FactorDetail {
+        if self.show_type {
+            FactorDetail::TxType
+        } else if let Some(decimals) = self.sol_decimals {
+            FactorDetail::Sol { decimals }
+        } else {
+            FactorDetail::None
+        }
+    }
+}
+
+impl Default for ScoringRules {
+    fn default() -> Self {
+        Self::from_json(DEFAULT_RULES).expect("bundled scoring rules are valid")
+    }
+}
+
+impl ScoringRules {
+    pub fn from_json(json: &str) -> Result<Self, CryptSdkError> {
+        serde_json::from_str(json).map_err(|e| CryptSdkError::Serialization(e.to_string()))
+    }
+
+    pub fn to_json(&self) -> Result<String, CryptSdkError> {
+        serde_json::to_string_pretty(self).map_err(|e| CryptSdkError::Serialization(e.to_string()))
+    }
+
+    /// The rule covering `tx_type`: the first entry listing it, or `unknown`.
+    pub fn rule_for(&self, tx_type: &str) -> &TypeRule {
+        self.types
+            .iter()
+            .find(|r| r.types.iter().any(|t| t == tx_type))
+            .unwrap_or(&self.unknown)
+    }
+
+    /// Every factor `params` collect, in order: base, tier, flags, dust.
+    /// Amounts are compared in lamports, as the program does.
+    pub fn factors(&self, params: &ScoreParams) -> Vec<&RuleFactor> {
+        let rule = self.rule_for(&params.tx_type);
+        let lamports = sol_to_lamports(params.sol_amount);
+        let mut factors: Vec<&RuleFactor> = rule.base.iter().collect();
+
+        let tier = rule.tiers.iter().find(|t| t.above_sol.is_none_or(|sol| lamports > sol_to_lamports(sol)));
+        factors.extend(tier.map(|t| &t.factor));
+
+        for f in &rule.flags {
+            let set = match f.flag {
+                Flag::Memecoin => params.is_memecoin,
+                Flag::DefiSource => params.is_defi_source,
+                Flag::Profit => sol_to_lamports(params.net_sol.max(0.0)) > 0,
+            };
+            if set {
+                factors.push(&f.factor);
+            }
+        }
+
+        if !rule.dust_exempt && lamports < sol_to_lamports(self.dust.below_sol) {
+            factors.push(&self.dust.factor);
+        }
+        factors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crypt_core::{score_breakdown, ScoreInput, TxKind, LAMPORTS_PER_SOL};
+
+    const TYPES: &[&str] = &[
+        "SWAP", "NFT_MINT", "COMPRESSED_NFT_MINT", "NFT_SALE", "TRANSFER", "SOL_TRANSFER",
+        "STAKE_SOL", "UNSTAKE_SOL", "TOKEN_MINT", "BURN", "BURN_NFT", "AIRDROP",
+    ];
+
+    /// Zero, a whale amount, and one lamport either side of every cut-off.
+    fn amounts() -> Vec<u64> {
+        let sol = [2, 5, 10, 20, 50, 100, 500].map(|n: u64| n * LAMPORTS_PER_SOL);
+        let cutoffs = [10_000_000, 500_000_000].into_iter().chain(sol);
+        let mut amounts = vec![0, 1, 1_000 * LAMPORTS_PER_SOL];
+        for c in cutoffs {
+            amounts.extend([c - 1, c, c + 1]);
+        }
+        amounts
+    }
+
+    #[test]
+    fn test_default_rules_match_core() {
+        let rules = ScoringRules::default();
+        for tx_type in TYPES {
+            for lamports in amounts() {
+                for (memecoin, defi, net) in [(false, false, 0), (true, false, -1), (false, true, 1), (true, true, 5)] {
+                    let params = ScoreParams {
+                        tx_type: tx_type.to_string(),
+                        sol_amount: lamports as f64 / LAMPORTS_PER_SOL as f64,
+                        is_memecoin: memecoin,
+                        is_defi_source: defi,
+                        net_sol: net as f64 / LAMPORTS_PER_SOL as f64,
+                    };
+                    let core = score_breakdown(&ScoreInput {
+                        kind: TxKind::parse(tx_type),
+                        lamports,
+                        is_memecoin: memecoin,
+                        is_defi_source: defi,
+                        net_lamports: net,
+                    });
+                    let ours = rules.factors(&params);
+                    let case = format!("{} {} lamports {:?}", tx_type, lamports, (memecoin, defi, net));
+                    assert_eq!(ours.len(), core.factors().len(), "{}", case);
+                    for (rule, factor) in ours.iter().zip(core.factors()) {
+                        assert_eq!(rule.label, factor.label(), "{}", case);
+                        assert_eq!(rule.points, factor.points(), "{}", case);
+                        assert_eq!(rule.detail(), factor.detail(), "{}", case);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_default_rules_carry_scoring_version() {
+        let rules = ScoringRules::default();
+        assert_eq!(rules.name, "default");
+        assert_eq!(rules.version, crate::SCORING_VERSION);
+    }
+
+    #[test]
+    fn test_round_trips_through_json() {
+        let rules = ScoringRules::default();
+        assert_eq!(ScoringRules::from_json(&rules.to_json().unwrap()).unwrap(), rules);
+    }
+
+    #[test]
+    fn test_custom_rules() {
+        let rules = ScoringRules::from_json(r#"{
+            "types": [{
+                "types": ["SWAP"],
+                "tiers": [{ "above_sol": 1, "label": "Big", "points": 50 }],
+                "flags": [{ "flag": "memecoin", "label": "Meme", "points": 10 }]
+            }],
+            "unknown": {},
+            "dust": { "below_sol": 0.1, "label": "Dust", "points": -5 }
+        }"#).unwrap();
+        let params = |sol: f64| ScoreParams {
+            tx_type: "SWAP".into(), sol_amount: sol, is_memecoin: true, is_defi_source: false, net_sol: 0.0,
+        };
+        let labels = |sol| rules.factors(&params(sol)).iter().map(|f| f.label.clone()).collect::<Vec<_>>();
+        assert_eq!(labels(2.0), ["Big", "Meme"]);
+        assert_eq!(labels(0.05), ["Meme", "Dust"]);
+        assert!(rules.rule_for("BURN").types.is_empty());
+        assert_eq!((rules.name.as_str(), rules.version), ("", 0));
+        assert!(ScoringRules::from_json("{}").is_err());
+    }
+}
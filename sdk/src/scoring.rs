@@ -1,136 +1,290 @@
 //! Rarity scoring engine — determines card tier from transaction data.
 
-use crate::types::Rarity;
+use serde::{Deserialize, Serialize};
+use crypt_core::{FactorDetail, LAMPORTS_PER_SOL, LEGENDARY_THRESHOLD, RARE_THRESHOLD};
+use crate::analytics::{
+    Detection, GamingAction, GamingDetector, GamingFlag, Pattern, PatternDetector, PriceSeries, WalletTx,
+};
+use crate::rules::{RuleFactor, ScoringRules};
+use crate::types::{CardType, Rarity};
+
+pub use crypt_core::SCORING_VERSION;
+
+/// Version of a plain [`RarityScorer::score`].
+const BASE_VERSION: u16 = 1;
+
+/// Version once pattern bonuses are applied.
+const PATTERN_VERSION: u16 = 2;
+
+/// Most points pattern bonuses can add to one transaction.
+pub const MAX_PATTERN_BONUS: u32 = 50;
+
+/// A time-boxed minting event from the collection's boost table.
+/// Same fields and rules as the program's `BoostEvent`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BoostEvent {
+    pub event_id: u16,
+    pub name: String,
+    /// Opens at this unix timestamp (inclusive)
+    pub starts_at: i64,
+    /// Closes at this unix timestamp (exclusive)
+    pub ends_at: i64,
+    /// Only cards of this type are boosted (None = every type)
+    pub card_type: Option<CardType>,
+    pub score_bonus: u32,
+    pub tier_bonus: u8,
+}
+
+impl BoostEvent {
+    pub fn is_active(&self, now: i64) -> bool {
+        self.starts_at <= now && now < self.ends_at
+    }
+
+    pub fn applies_to(&self, card_type: CardType) -> bool {
+        self.card_type.is_none_or(|t| t == card_type)
+    }
+}
 
 /// Rarity scorer with configurable thresholds.
 pub struct RarityScorer {
     pub rare_threshold: u32,
     pub legendary_threshold: u32,
+    /// Boost events applied by [`RarityScorer::score_boosted`]
+    pub events: Vec<BoostEvent>,
+    /// Cap on the summed pattern bonuses for one transaction
+    pub max_pattern_bonus: u32,
+    /// Points per transaction type; the program's rules by default
+    pub rules: ScoringRules,
 }
 
 impl Default for RarityScorer {
     fn default() -> Self {
         Self {
-            rare_threshold: 40,
-            legendary_threshold: 75,
+            rare_threshold: RARE_THRESHOLD,
+            legendary_threshold: LEGENDARY_THRESHOLD,
+            events: Vec::new(),
+            max_pattern_bonus: MAX_PATTERN_BONUS,
+            rules: ScoringRules::default(),
         }
     }
 }
 
 impl RarityScorer {
     pub fn new(rare: u32, legendary: u32) -> Self {
-        Self { rare_threshold: rare, legendary_threshold: legendary }
+        Self { rare_threshold: rare, legendary_threshold: legendary, ..Self::default() }
     }
 
-    /// Compute a rarity score from transaction characteristics.
-    pub fn score(&self, params: &ScoreParams) -> ScoreResult {
-        let mut score: i32 = 0;
-        let mut factors: Vec<String> = Vec::new();
-
-        match params.tx_type.as_str() {
-            "SWAP" => {
-                score += 25;
-                factors.push("Base: SWAP (+25)".into());
-
-                if params.sol_amount > 100.0 {
-                    score += 80; factors.push(format!("Whale trade: {:.1} SOL (+80)", params.sol_amount));
-                } else if params.sol_amount > 50.0 {
-                    score += 60; factors.push(format!("Large trade: {:.1} SOL (+60)", params.sol_amount));
-                } else if params.sol_amount > 10.0 {
-                    score += 35; factors.push(format!("Notable trade: {:.1} SOL (+35)", params.sol_amount));
-                } else if params.sol_amount > 2.0 {
-                    score += 15; factors.push(format!("Solid trade: {:.1} SOL (+15)", params.sol_amount));
-                } else if params.sol_amount > 0.5 {
-                    score += 5; factors.push("Small trade (+5)".into());
-                } else {
-                    score -= 5; factors.push("Dust trade (-5)".into());
-                }
-
-                if params.is_defi_source {
-                    score += 5; factors.push("DeFi source (+5)".into());
-                }
-                if params.is_memecoin {
-                    score += 25; factors.push("Memecoin (+25)".into());
-                }
-            }
-            "NFT_MINT" | "COMPRESSED_NFT_MINT" => {
-                score += 35; factors.push("Base: NFT_MINT (+35)".into());
-                if params.sol_amount > 10.0 {
-                    score += 40; factors.push(format!("Premium mint: {:.1} SOL (+40)", params.sol_amount));
-                } else if params.sol_amount > 2.0 {
-                    score += 20; factors.push(format!("Paid mint: {:.1} SOL (+20)", params.sol_amount));
-                }
-            }
-            "NFT_SALE" => {
-                score += 30; factors.push("Base: NFT_SALE (+30)".into());
-                if params.sol_amount > 50.0 {
-                    score += 70; factors.push(format!("Whale sale: {:.1} SOL (+70)", params.sol_amount));
-                } else if params.sol_amount > 10.0 {
-                    score += 40; factors.push(format!("Big sale: {:.1} SOL (+40)", params.sol_amount));
-                } else if params.sol_amount > 2.0 {
-                    score += 15; factors.push(format!("Sale: {:.1} SOL (+15)", params.sol_amount));
-                } else {
-                    score -= 5; factors.push("Small sale (-5)".into());
-                }
-                if params.net_sol > 0.0 {
-                    score += 20; factors.push("Profit (+20)".into());
-                }
-            }
-            "TRANSFER" | "SOL_TRANSFER" => {
-                if params.sol_amount > 500.0 {
-                    score += 80; factors.push(format!("Massive: {:.0} SOL (+80)", params.sol_amount));
-                } else if params.sol_amount > 100.0 {
-                    score += 55; factors.push(format!("Whale move: {:.0} SOL (+55)", params.sol_amount));
-                } else if params.sol_amount > 20.0 {
-                    score += 25; factors.push(format!("Big move: {:.0} SOL (+25)", params.sol_amount));
-                } else if params.sol_amount > 5.0 {
-                    score += 10; factors.push("Transfer (+10)".into());
-                } else {
-                    score -= 15; factors.push("Small transfer (-15)".into());
-                }
-            }
-            "STAKE_SOL" | "UNSTAKE_SOL" => {
-                if params.sol_amount > 100.0 {
-                    score += 50; factors.push(format!("Whale stake: {:.0} SOL (+50)", params.sol_amount));
-                } else if params.sol_amount > 20.0 {
-                    score += 25; factors.push(format!("Stake: {:.0} SOL (+25)", params.sol_amount));
-                } else {
-                    score += 5; factors.push("Small stake (+5)".into());
-                }
-            }
-            "TOKEN_MINT" => {
-                score += 50; factors.push("Token creation (+50)".into());
-            }
-            "BURN" | "BURN_NFT" => {
-                score += 20; factors.push("Burn (+20)".into());
-            }
-            _ => {
-                score -= 20; factors.push(format!("Unknown type: {} (-20)", params.tx_type));
+    /// Use the collection's boost events (see `CryptClient::get_boost_events`).
+    pub fn with_events(mut self, events: Vec<BoostEvent>) -> Self {
+        self.events = events;
+        self
+    }
+
+    /// Score with `rules` instead of the program's (see `rules/default.json`).
+    pub fn with_rules(mut self, rules: ScoringRules) -> Self {
+        self.rules = rules;
+        self
+    }
+
+    pub fn with_max_pattern_bonus(mut self, max: u32) -> Self {
+        self.max_pattern_bonus = max;
+        self
+    }
+
+    fn rarity_for(&self, score: u32) -> Rarity {
+        Rarity::from_score_with(score, self.rare_threshold, self.legendary_threshold)
+    }
+
+    /// The version to report for a score that ran up to `stage`: 0 under
+    /// rules without a version.
+    fn version_for(&self, stage: u16) -> u16 {
+        stage.min(self.rules.version)
+    }
+
+    /// Score a card minted at `minted_at` as [`RarityScorer::score_in_context`]
+    /// does, then apply the boost event the program would apply: the
+    /// first active event matching `card_type`. Its score bonus can lift
+    /// the tier, then its tier bonus is added, capped at Legendary. The
+    /// boost is listed in `factors`. Transactions the gaming checks
+    /// exclude are never boosted.
+    ///
+    /// The lift uses the program's fixed 40/75 tiers (`score_to_rarity`),
+    /// not this scorer's thresholds, since that is what the mint applies.
+    ///
+    /// When minting, pass the unboosted `score`/`rarity` from
+    /// [`RarityScorer::score_in_context`] — the program applies the boost itself.
+    pub fn score_boosted(
+        &self,
+        params: &ScoreParams,
+        signature: &str,
+        wallet: &WalletContext,
+        card_type: CardType,
+        minted_at: i64,
+    ) -> ScoreResult {
+        let mut result = self.score_in_context(params, signature, wallet);
+        if wallet.flags_for(signature).any(|f| f.action == GamingAction::Exclude) {
+            return result;
+        }
+        let Some(event) = self.events.iter().find(|e| e.is_active(minted_at) && e.applies_to(card_type)) else {
+            return result;
+        };
+
+        if event.score_bonus > 0 {
+            result.score = result.score.saturating_add(event.score_bonus);
+            result.factors.push(format!(
+                "Event {} #{}: {} (+{})",
+                event.name, event.event_id, card_type.as_str(), event.score_bonus
+            ));
+            let lifted = Rarity::from_score(result.score);
+            if lifted.as_u8() > result.rarity.as_u8() {
+                result.rarity = lifted;
             }
         }
+        if event.tier_bonus > 0 {
+            let tier = result.rarity.as_u8().saturating_add(event.tier_bonus).min(Rarity::Legendary.as_u8());
+            result.rarity = Rarity::from_u8(tier).unwrap_or(Rarity::Legendary);
+            result.factors.push(format!(
+                "Event {} #{}: tier +{} → {}",
+                event.name, event.event_id, event.tier_bonus, result.rarity.as_str()
+            ));
+        }
+        result.boost_event = Some(event.event_id);
+        result
+    }
+
+    /// Score a transaction from a wallet whose history has been run
+    /// through the pattern and gaming detectors. Patterns the
+    /// transaction completed add their bonuses, as in
+    /// [`RarityScorer::score_with_patterns`]; then each gaming flag on
+    /// it takes its penalty or zeroes the score, with its explanation
+    /// listed in `factors`.
+    pub fn score_in_context(&self, params: &ScoreParams, signature: &str, wallet: &WalletContext) -> ScoreResult {
+        let mut result = self.score_with_patterns(params, wallet.patterns_for(signature));
+        for flag in wallet.flags_for(signature) {
+            result.factors.push(flag.explain());
+            result.score = match flag.action {
+                GamingAction::Penalty(points) => result.score.saturating_sub(points),
+                GamingAction::Exclude => 0,
+            };
+        }
+        result.rarity = self.rarity_for(result.score);
+        result.version = self.version_for(SCORING_VERSION);
+        result
+    }
 
-        if params.sol_amount < 0.01
-            && !matches!(params.tx_type.as_str(), "NFT_MINT" | "COMPRESSED_NFT_MINT" | "TOKEN_MINT" | "BURN" | "BURN_NFT")
-        {
-            score -= 25; factors.push("Dust penalty (-25)".into());
+    /// Score a transaction and add each pattern's `rarity_bonus()`, up
+    /// to `max_pattern_bonus` in total. Every bonus, and the cap when it
+    /// bites, is listed in `factors`; the bonus can lift the tier.
+    pub fn score_with_patterns<'a>(
+        &self,
+        params: &ScoreParams,
+        patterns: impl IntoIterator<Item = &'a Pattern>,
+    ) -> ScoreResult {
+        let mut result = self.score(params);
+        let mut bonus = 0u32;
+        for pattern in patterns {
+            let points = pattern.rarity_bonus();
+            result.factors.push(format!("Pattern: {} ({:+})", pattern.title(), points));
+            bonus = bonus.saturating_add(points);
+        }
+        if bonus > self.max_pattern_bonus {
+            result.factors.push(format!("Pattern bonus cap (-{})", bonus - self.max_pattern_bonus));
+            bonus = self.max_pattern_bonus;
         }
+        result.score = result.score.saturating_add(bonus);
+        result.rarity = self.rarity_for(result.score);
+        result.version = self.version_for(PATTERN_VERSION);
+        result
+    }
 
-        let final_score = score.max(0) as u32;
-        let rarity = if final_score >= self.legendary_threshold { Rarity::Legendary }
-            else if final_score >= self.rare_threshold { Rarity::Rare }
-            else { Rarity::Common };
+    /// Compute a rarity score from transaction characteristics with the
+    /// scorer's rules. Amounts are rounded to the lamport before scoring.
+    pub fn score(&self, params: &ScoreParams) -> ScoreResult {
+        let matched = self.rules.factors(params);
+        let factors = matched.iter().map(|f| explain(f, params)).collect();
 
-        ScoreResult { score: final_score, rarity, factors }
+        let final_score = matched.iter().map(|f| f.points).sum::<i32>().max(0) as u32;
+        let rarity = self.rarity_for(final_score);
+
+        ScoreResult {
+            score: final_score,
+            rarity,
+            factors,
+            boost_event: None,
+            version: self.version_for(BASE_VERSION),
+            rules: self.rules.name.clone(),
+        }
     }
 }
 
-/// Input parameters for scoring.
-#[derive(Debug, Clone)]
+/// What the scorer knows about the wallet a transaction came from:
+/// the patterns found in its history and any transactions that look
+/// staged to farm scores.
+#[derive(Debug, Clone, Default)]
+pub struct WalletContext {
+    pub detections: Vec<Detection>,
+    pub flags: Vec<GamingFlag>,
+}
+
+impl WalletContext {
+    pub fn new(detections: Vec<Detection>) -> Self {
+        Self { detections, flags: Vec::new() }
+    }
+
+    /// Run `detector` over `txs`, which must be oldest first.
+    pub fn from_history(detector: &PatternDetector, txs: &[WalletTx], prices: Option<&PriceSeries>) -> Self {
+        Self::new(detector.detect(txs, prices))
+    }
+
+    /// Add the flags `gaming` raises over `txs` (oldest first).
+    pub fn with_gaming(mut self, gaming: &GamingDetector, txs: &[WalletTx]) -> Self {
+        self.flags = gaming.detect(txs);
+        self
+    }
+
+    /// Gaming flags on the transaction `signature`.
+    pub fn flags_for<'a>(&'a self, signature: &'a str) -> impl Iterator<Item = &'a GamingFlag> + 'a {
+        self.flags.iter().filter(move |f| f.signature == signature)
+    }
+
+    /// Patterns completed by the transaction `signature`. A pattern
+    /// counts toward its last supporting transaction only, so a flip
+    /// scores on the sell rather than on both legs.
+    pub fn patterns_for<'a>(&'a self, signature: &'a str) -> impl Iterator<Item = &'a Pattern> + 'a {
+        self.detections
+            .iter()
+            .filter(move |d| d.signatures.last().is_some_and(|s| s == signature))
+            .map(|d| &d.pattern)
+    }
+}
+
+/// Lamports in `sol`, rounded; negative amounts count as zero.
+pub(crate) fn sol_to_lamports(sol: f64) -> u64 {
+    (sol * LAMPORTS_PER_SOL as f64).round() as u64
+}
+
+/// One line of a score breakdown, e.g. `Whale trade: 150.0 SOL (+80)`.
+fn explain(factor: &RuleFactor, params: &ScoreParams) -> String {
+    let text = match factor.detail() {
+        FactorDetail::None => factor.label.clone(),
+        FactorDetail::Sol { decimals } => format!("{}: {:.*} SOL", factor.label, decimals, params.sol_amount),
+        FactorDetail::TxType => format!("{}: {}", factor.label, params.tx_type),
+    };
+    format!("{} ({:+})", text, factor.points)
+}
+
+/// Input parameters for scoring. Deserializes from scan corpora, where
+/// everything but `tx_type` can be left out.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScoreParams {
     pub tx_type: String,
+    #[serde(default)]
     pub sol_amount: f64,
+    #[serde(default)]
     pub is_memecoin: bool,
+    #[serde(default)]
     pub is_defi_source: bool,
+    #[serde(default)]
     pub net_sol: f64,
 }
 
@@ -140,6 +294,14 @@ pub struct ScoreResult {
     pub score: u32,
     pub rarity: Rarity,
     pub factors: Vec<String>,
+    /// Boost event that applied, from [`RarityScorer::score_boosted`]
+    pub boost_event: Option<u16>,
+    /// Scoring version that produced the score: 1 for a plain score, 2
+    /// with pattern bonuses, 3 ([`SCORING_VERSION`]) with gaming checks
+    /// too; 0 under rules that don't set a version
+    pub version: u16,
+    /// Name of the rule set that produced the score
+    pub rules: String,
 }
 
 #[cfg(test)]
@@ -159,12 +321,193 @@ mod tests {
     #[test] fn test_dust_swap() { assert_eq!(scorer().score(&params("SWAP", 0.001)).rarity, Rarity::Common); }
     #[test] fn test_nft_premium() { assert_eq!(scorer().score(&params("NFT_MINT", 20.0)).rarity, Rarity::Legendary); }
     #[test] fn test_token_creation() { assert_eq!(scorer().score(&params("TOKEN_MINT", 0.0)).rarity, Rarity::Rare); }
+    #[test]
+    fn test_matches_shared_vectors() {
+        for v in crypt_core::vectors::SCORE_VECTORS {
+            let params = ScoreParams {
+                tx_type: v.tx_type.to_string(),
+                sol_amount: v.lamports as f64 / LAMPORTS_PER_SOL as f64,
+                is_memecoin: v.is_memecoin,
+                is_defi_source: v.is_defi_source,
+                net_sol: v.net_lamports as f64 / LAMPORTS_PER_SOL as f64,
+            };
+            let result = scorer().score(&params);
+            assert_eq!(result.score, v.score, "{} {} SOL", v.tx_type, params.sol_amount);
+            assert_eq!(result.rarity.as_u8(), v.rarity);
+        }
+    }
+
+    #[test]
+    fn test_factor_text() {
+        let result = scorer().score(&ScoreParams { is_defi_source: true, ..params("SWAP", 150.0) });
+        assert_eq!(result.factors, ["Base: SWAP (+25)", "Whale trade: 150.0 SOL (+80)", "DeFi source (+5)"]);
+        let result = scorer().score(&params("TRANSFER", 1.0));
+        assert_eq!(result.factors, ["Small transfer (-15)"]);
+        let result = scorer().score(&params("AIRDROP", 0.0));
+        assert_eq!(result.factors, ["Unknown type: AIRDROP (-20)", "Dust penalty (-25)"]);
+    }
+
+    #[test]
+    fn test_result_reports_version_run() {
+        let result = scorer().score(&params("SWAP", 5.0));
+        assert_eq!((result.rules.as_str(), result.version), ("default", 1));
+        assert_eq!(scorer().score_with_patterns(&params("SWAP", 5.0), []).version, 2);
+        let wallet = WalletContext::default();
+        assert_eq!(scorer().score_in_context(&params("SWAP", 5.0), "sig", &wallet).version, SCORING_VERSION);
+
+        let rules = ScoringRules { name: "experiment".into(), version: 0, ..ScoringRules::default() };
+        let scorer = scorer().with_rules(rules);
+        let result = scorer.score_in_context(&params("SWAP", 5.0), "sig", &WalletContext::default());
+        assert_eq!((result.rules.as_str(), result.version), ("experiment", 0));
+    }
+
     #[test] fn test_factors_populated() { assert!(!scorer().score(&params("SWAP", 5.0)).factors.is_empty()); }
 
+    fn halloween(card_type: Option<CardType>, score_bonus: u32, tier_bonus: u8) -> BoostEvent {
+        BoostEvent {
+            event_id: 7,
+            name: "HALLOWEEN".into(),
+            starts_at: 1_000,
+            ends_at: 2_000,
+            card_type,
+            score_bonus,
+            tier_bonus,
+        }
+    }
+
+    /// Score a clean SWAP of `sol` as a `card_type` card minted at `minted_at`.
+    fn boosted(scorer: &RarityScorer, sol: f64, card_type: CardType, minted_at: i64) -> ScoreResult {
+        scorer.score_boosted(&params("SWAP", sol), "sig", &WalletContext::default(), card_type, minted_at)
+    }
+
+    #[test]
+    fn test_boost_lifts_tier_and_explains() {
+        // SWAP 1 SOL: 25 + 5 = 30 → Common; +15 → 45 → Rare
+        let scorer = scorer().with_events(vec![halloween(Some(CardType::Rug), 15, 0)]);
+        let result = boosted(&scorer, 1.0, CardType::Rug, 1_500);
+        assert_eq!(result.score, 45);
+        assert_eq!(result.rarity, Rarity::Rare);
+        assert_eq!(result.boost_event, Some(7));
+        assert!(result.factors.iter().any(|f| f == "Event HALLOWEEN #7: RUG (+15)"));
+    }
+
+    #[test]
+    fn test_boost_respects_window_and_type() {
+        let scorer = scorer().with_events(vec![halloween(Some(CardType::Rug), 15, 0)]);
+        let outside = boosted(&scorer, 1.0, CardType::Rug, 2_000);
+        assert_eq!(outside.boost_event, None);
+        let wrong_type = boosted(&scorer, 1.0, CardType::Swap, 1_500);
+        assert_eq!(wrong_type.boost_event, None);
+        assert_eq!(wrong_type.score, 30);
+    }
+
+    #[test]
+    fn test_boost_lift_uses_program_tiers() {
+        // Custom thresholds would make 45 Common; the program still lifts it to Rare
+        let scorer = RarityScorer::new(60, 90).with_events(vec![halloween(None, 15, 0)]);
+        let result = boosted(&scorer, 1.0, CardType::Rug, 1_500);
+        assert_eq!(result.score, 45);
+        assert_eq!(result.rarity, Rarity::Rare);
+    }
+
+    #[test]
+    fn test_tier_bonus_capped() {
+        let scorer = scorer().with_events(vec![halloween(None, 0, 2)]);
+        assert_eq!(boosted(&scorer, 1.0, CardType::Mint, 1_500).rarity, Rarity::Legendary);
+        assert_eq!(boosted(&scorer, 150.0, CardType::Mint, 1_500).rarity, Rarity::Legendary);
+    }
+
+    #[test]
+    fn test_boost_keeps_context() {
+        // SWAP 1 SOL: 30, genesis +35 → 65, boost +15 → 80
+        let scorer = scorer().with_events(vec![halloween(None, 15, 0)]);
+        let wallet = WalletContext {
+            detections: vec![detection(Pattern::GenesisTransaction, &["first"])],
+            flags: vec![gaming_flag("wash", GamingAction::Exclude)],
+        };
+        let result = scorer.score_boosted(&params("SWAP", 1.0), "first", &wallet, CardType::Rug, 1_500);
+        assert_eq!(result.score, 80);
+        assert_eq!(result.rarity, Rarity::Legendary);
+        assert_eq!(result.version, SCORING_VERSION);
+
+        // Staged transactions stay at zero, unboosted
+        let staged = scorer.score_boosted(&params("SWAP", 1.0), "wash", &wallet, CardType::Rug, 1_500);
+        assert_eq!((staged.score, staged.boost_event), (0, None));
+    }
+
+    fn detection(pattern: Pattern, signatures: &[&str]) -> Detection {
+        Detection { pattern, signatures: signatures.iter().map(|s| s.to_string()).collect(), timestamp: 0 }
+    }
+
+    #[test]
+    fn test_genesis_lifts_dust_swap() {
+        // SWAP 0.3 SOL: 25 - 5 = 20 → Common; genesis +35 → 55 → Rare
+        let wallet = WalletContext::new(vec![detection(Pattern::GenesisTransaction, &["first"])]);
+        let result = scorer().score_in_context(&params("SWAP", 0.3), "first", &wallet);
+        assert_eq!(result.score, 55);
+        assert_eq!(result.rarity, Rarity::Rare);
+        assert_eq!(result.factors.last().unwrap(), "Pattern: GENESIS — FIRST TRANSACTION (+35)");
+        assert_eq!(result.version, SCORING_VERSION);
+
+        let other = scorer().score_in_context(&params("SWAP", 0.3), "second", &wallet);
+        assert_eq!(other.score, 20);
+    }
+
+    #[test]
+    fn test_pattern_counts_on_last_signature() {
+        let flip = Pattern::QuickFlip { token: "WIF".into(), profit_pct: 150, hold_hours: 3 };
+        let wallet = WalletContext::new(vec![detection(flip, &["buy", "sell"])]);
+        assert_eq!(wallet.patterns_for("buy").count(), 0);
+        assert_eq!(wallet.patterns_for("sell").count(), 1);
+    }
+
+    #[test]
+    fn test_pattern_bonus_capped() {
+        // NFT_MINT free: 35 → Rare; genesis 35 + early mint #5 50 = 85, capped at 50
+        let early = Pattern::EarlyMint { collection: "DEGODS".into(), mint_number: 5, total_supply: 10_000 };
+        let result = scorer().score_with_patterns(&params("NFT_MINT", 0.0), &[Pattern::GenesisTransaction, early]);
+        assert_eq!(result.score, 35 + MAX_PATTERN_BONUS);
+        assert_eq!(result.rarity, Rarity::Legendary);
+        assert!(result.factors.iter().any(|f| f == "Pattern: EARLY MINT #5 — DEGODS (+50)"));
+        assert_eq!(result.factors.last().unwrap(), "Pattern bonus cap (-35)");
+
+        let strict = scorer().with_max_pattern_bonus(10);
+        assert_eq!(strict.score_with_patterns(&params("NFT_MINT", 0.0), &[Pattern::GenesisTransaction]).score, 45);
+    }
+
+    fn gaming_flag(signature: &str, action: GamingAction) -> GamingFlag {
+        GamingFlag {
+            signature: signature.into(),
+            signal: crate::analytics::GamingSignal::RepeatedSwap { sol_amount: 150.0, count: 3 },
+            action,
+        }
+    }
+
+    #[test]
+    fn test_gaming_penalties() {
+        // SWAP 150 SOL: 25 + 80 = 105, whale move +30, repeated swap -40
+        let wallet = WalletContext {
+            detections: vec![detection(Pattern::WhaleMove { sol_amount: 150.0 }, &["wash"])],
+            flags: vec![gaming_flag("wash", GamingAction::Penalty(40)), gaming_flag("round", GamingAction::Exclude)],
+        };
+        let result = scorer().score_in_context(&params("SWAP", 150.0), "wash", &wallet);
+        assert_eq!(result.score, 105 + 30 - 40);
+        assert_eq!(result.rarity, Rarity::Legendary);
+        assert_eq!(result.factors.last().unwrap(), "Repeated swap: 3 swaps of 150.00 SOL (-40)");
+
+        let excluded = scorer().score_in_context(&params("SWAP", 150.0), "round", &wallet);
+        assert_eq!(excluded.score, 0);
+        assert_eq!(excluded.rarity, Rarity::Common);
+
+        let clean = scorer().score_in_context(&params("SWAP", 150.0), "other", &wallet);
+        assert_eq!(clean.score, 105);
+    }
+
     #[test]
     fn test_custom_thresholds() {
         let strict = RarityScorer::new(60, 90);
-        let result = strict.score(&params("SWAP", 15.0));
-        assert_eq!(result.rarity, Rarity::Common); // 60 score, threshold is 60
+        let result = strict.score(&params("SWAP", 5.0));
+        assert_eq!(result.rarity, Rarity::Common); // 40 score: Rare by default, Common under 60
+        assert_eq!(strict.score(&params("SWAP", 15.0)).rarity, Rarity::Rare); // 60 reaches 60
     }
 }
@@ -0,0 +1,128 @@
+//! Compact interactions — likes, shares and bookmarks.
+//!
+//! These don't get a full account each. A two-byte `ReactRecord` per
+//! (card, user) lets each user make each reaction once; the program bumps
+//! a counter on the card's `CardSocial` account and folds a leaf into its
+//! interaction log, `root = SHA-256(root || leaf)`, starting from all
+//! zeroes. Given
+//! the `CompactInteraction` events for a card, `replay_log` rebuilds the
+//! root so an off-chain record can be checked against the chain.
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use solana_sdk::pubkey::Pubkey;
+use crate::types::CardSocial;
+
+/// Interaction types, as the program numbers them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum InteractionType {
+    Like = 0,
+    Comment = 1,
+    Share = 2,
+    Bookmark = 3,
+}
+
+impl InteractionType {
+    pub fn from_u8(v: u8) -> Option<Self> {
+        match v {
+            0 => Some(Self::Like),
+            1 => Some(Self::Comment),
+            2 => Some(Self::Share),
+            3 => Some(Self::Bookmark),
+            _ => None,
+        }
+    }
+
+    /// Everything but comments can go through `react` instead of `interact`.
+    pub fn is_compact(&self) -> bool {
+        *self != Self::Comment
+    }
+
+    /// This type's bit in a `ReactRecord`.
+    pub fn react_flag(&self) -> u8 {
+        1 << (*self as u8)
+    }
+}
+
+/// One compact interaction, as recorded by a `CompactInteraction` event.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InteractionRecord {
+    pub card: Pubkey,
+    pub user: Pubkey,
+    pub interaction_type: InteractionType,
+    pub timestamp: i64,
+}
+
+impl InteractionRecord {
+    /// The leaf the program folds into the card's log for this record.
+    pub fn leaf(&self) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(self.card.as_ref());
+        hasher.update(self.user.as_ref());
+        hasher.update([self.interaction_type as u8]);
+        hasher.update(self.timestamp.to_le_bytes());
+        hasher.finalize().into()
+    }
+}
+
+/// Append one leaf to a log root.
+pub fn fold_log(root: &[u8; 32], leaf: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(root);
+    hasher.update(leaf);
+    hasher.finalize().into()
+}
+
+/// Rebuild a log root from records in the order they were recorded.
+pub fn replay_log<'a>(records: impl IntoIterator<Item = &'a InteractionRecord>) -> ([u8; 32], u64) {
+    records.into_iter().fold(([0u8; 32], 0), |(root, len), record| {
+        (fold_log(&root, &record.leaf()), len + 1)
+    })
+}
+
+/// Whether `records` are exactly the interactions folded into `social`.
+pub fn verify_log(social: &CardSocial, records: &[InteractionRecord]) -> bool {
+    replay_log(records) == (social.log_root, social.log_len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(card: Pubkey, kind: InteractionType, timestamp: i64) -> InteractionRecord {
+        InteractionRecord { card, user: Pubkey::new_unique(), interaction_type: kind, timestamp }
+    }
+
+    #[test]
+    fn test_only_comments_need_accounts() {
+        assert!(InteractionType::Like.is_compact());
+        assert!(InteractionType::Share.is_compact());
+        assert!(InteractionType::Bookmark.is_compact());
+        assert!(!InteractionType::Comment.is_compact());
+        assert_eq!(InteractionType::from_u8(4), None);
+    }
+
+    #[test]
+    fn test_replay_matches_counters() {
+        let card = Pubkey::new_unique();
+        let records = vec![
+            record(card, InteractionType::Like, 10),
+            record(card, InteractionType::Bookmark, 11),
+            record(card, InteractionType::Like, 12),
+        ];
+        let (log_root, log_len) = replay_log(&records);
+        let social = CardSocial { card, likes: 2, shares: 0, bookmarks: 1, log_root, log_len };
+
+        assert!(verify_log(&social, &records));
+        assert!(!verify_log(&social, &records[..2]));
+
+        let mut swapped = records.clone();
+        swapped.swap(0, 2);
+        assert!(!verify_log(&social, &swapped));
+    }
+
+    #[test]
+    fn test_empty_log_is_zero_root() {
+        assert_eq!(replay_log(&[]), ([0u8; 32], 0));
+    }
+}
@@ -6,40 +6,17 @@
 
 use sha2::{Sha256, Digest};
 
+pub use crypt_core::SoulTraits;
+
 /// Soul Signature generator.
 pub struct SoulSignature;
 
 impl SoulSignature {
     /// Compute a deterministic 32-byte seed from a transaction hash.
-    /// This is the core algorithm that ensures 1:1 unique art per card.
+    /// The program's algorithm, from `crypt-core`, so it ensures 1:1
+    /// unique art per card.
     pub fn compute(tx_hash: &str) -> [u8; 32] {
-        let bytes = tx_hash.as_bytes();
-        let mut seed = [0u8; 32];
-
-        // Phase 1: XOR-fold
-        for (i, &b) in bytes.iter().enumerate() {
-            seed[i % 32] ^= b;
-        }
-
-        // Phase 2: Bit mixing (fast avalanche)
-        for i in 0..32 {
-            let mut h: u64 = seed[i] as u64;
-            h = h.wrapping_mul(0x517cc1b727220a95);
-            h ^= h >> 17;
-            h = h.wrapping_mul(0x6c62272e07bb0142);
-            h ^= h >> 11;
-            seed[i] = (h & 0xFF) as u8;
-        }
-
-        // Phase 3: Bidirectional diffusion
-        for i in 1..32 {
-            seed[i] ^= seed[i - 1].wrapping_add(37);
-        }
-        for i in (0..31).rev() {
-            seed[i] ^= seed[i + 1].wrapping_add(53);
-        }
-
-        seed
+        crypt_core::compute_soul_seed(tx_hash)
     }
 
     /// Compute a SHA-256 narration hash.
@@ -52,21 +29,10 @@ impl SoulSignature {
         hash
     }
 
-    /// Extract visual trait parameters from a soul seed.
+    /// Extract visual trait parameters from a soul seed, as the
+    /// program reads them for duels.
     pub fn extract_traits(seed: &[u8; 32]) -> SoulTraits {
-        SoulTraits {
-            eye_style: seed[0] % 8,
-            glow_eyes: seed[1] > 102,
-            hat_type: seed[2] % 30,
-            glasses_type: seed[3] % 12,
-            mouth_item: seed[4] % 8,
-            neck_item: seed[5] % 6,
-            teeth_style: seed[6] % 6,
-            nose_style: seed[7] % 4,
-            has_scar: seed[8] > 153,
-            has_crack: seed[9] > 153,
-            has_eyepatch: seed[10] > 225,
-        }
+        SoulTraits::from_seed(seed)
     }
 
     /// Verify that a stored seed matches a transaction hash.
@@ -77,70 +43,7 @@ impl SoulSignature {
 
     /// Generate an upgrade proof for rarity changes.
     pub fn upgrade_proof(tx_hash: &str, soul_seed: &[u8; 32], new_rarity: u8) -> [u8; 32] {
-        let mut proof = Self::compute(tx_hash);
-        for i in 0..32 { proof[i] ^= soul_seed[i]; }
-        proof[0] ^= new_rarity;
-        for i in 0..32 {
-            let mut h: u64 = proof[i] as u64;
-            h = h.wrapping_mul(0x9e3779b97f4a7c15);
-            h ^= h >> 13;
-            proof[i] = (h & 0xFF) as u8;
-        }
-        proof
-    }
-}
-
-/// Visual traits extracted from a soul seed.
-#[derive(Debug, Clone)]
-pub struct SoulTraits {
-    pub eye_style: u8,
-    pub glow_eyes: bool,
-    pub hat_type: u8,
-    pub glasses_type: u8,
-    pub mouth_item: u8,
-    pub neck_item: u8,
-    pub teeth_style: u8,
-    pub nose_style: u8,
-    pub has_scar: bool,
-    pub has_crack: bool,
-    pub has_eyepatch: bool,
-}
-
-impl SoulTraits {
-    /// Get human-readable hat name.
-    pub fn hat_name(&self) -> &'static str {
-        const HATS: [&str; 30] = [
-            "Cowboy", "Top Hat", "Beanie", "Baseball Cap", "Crown",
-            "Pirate Hat", "Sailor Hat", "Trucker Cap", "Fedora", "Wizard Hat",
-            "Headband", "Mohawk", "Viking Helmet", "Chef Hat", "Bandana",
-            "Halo", "Bucket Hat", "Santa Hat", "Afro", "Devil Horns",
-            "Army Helmet", "Sombrero", "Backwards Cap", "Durag", "Bowler Hat",
-            "Straw Hat", "Space Helmet", "Fire Crown", "Propeller Hat", "Toque",
-        ];
-        HATS.get(self.hat_type as usize).unwrap_or(&"None")
-    }
-
-    /// Get human-readable glasses name.
-    pub fn glasses_name(&self) -> &'static str {
-        const GLASSES: [&str; 12] = [
-            "Pit Vipers", "Aviators", "3D Glasses", "Heart Glasses",
-            "Nerd Glasses", "Monocle", "Cyclops Visor", "Thug Life",
-            "Star Glasses", "VR Headset", "Laser Eyes", "Lennon Rounds",
-        ];
-        GLASSES.get(self.glasses_type as usize).unwrap_or(&"None")
-    }
-
-    /// Get total accessory count for rarity weighting.
-    pub fn accessory_count(&self) -> u8 {
-        let mut count = 0u8;
-        if self.hat_type > 0 { count += 1; }
-        if self.glasses_type > 0 { count += 1; }
-        if self.mouth_item > 0 { count += 1; }
-        if self.neck_item > 0 { count += 1; }
-        if self.has_scar { count += 1; }
-        if self.has_crack { count += 1; }
-        if self.has_eyepatch { count += 1; }
-        count
+        crypt_core::upgrade_proof(tx_hash, soul_seed, new_rarity)
     }
 }
 
@@ -155,6 +58,16 @@ mod tests {
         assert_eq!(s1, s2);
     }
 
+    #[test]
+    fn test_matches_shared_vectors() {
+        for (tx_hash, seed) in crypt_core::vectors::SOUL_SEED_VECTORS {
+            assert!(SoulSignature::verify(tx_hash, seed), "seed for {:?}", tx_hash);
+        }
+        for v in crypt_core::vectors::UPGRADE_PROOF_VECTORS {
+            assert_eq!(SoulSignature::upgrade_proof(v.tx_hash, &v.soul_seed, v.new_rarity), v.proof);
+        }
+    }
+
     #[test]
     fn test_verify_valid() {
         let tx = "4xK7m9pR2abc";
@@ -1,55 +1,11 @@
 use serde::{Deserialize, Serialize};
 use solana_sdk::pubkey::Pubkey;
 
-/// Rarity tiers for Crypt Cards.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-pub enum Rarity {
-    Common,
-    Rare,
-    Legendary,
-}
-
-impl Rarity {
-    pub fn from_u8(v: u8) -> Option<Self> {
-        match v { 0 => Some(Self::Common), 1 => Some(Self::Rare), 2 => Some(Self::Legendary), _ => None }
-    }
-
-    pub fn as_u8(&self) -> u8 {
-        match self { Self::Common => 0, Self::Rare => 1, Self::Legendary => 2 }
-    }
-
-    pub fn as_str(&self) -> &'static str {
-        match self { Self::Common => "COMMON", Self::Rare => "RARE", Self::Legendary => "LEGENDARY" }
-    }
-
-    pub fn color_code(&self) -> &'static str {
-        match self { Self::Common => "#888888", Self::Rare => "#00d4b0", Self::Legendary => "#9660ff" }
-    }
-}
+pub use crypt_core::{CardType, Rarity};
 
-/// Card types derived from transaction classification.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-pub enum CardType {
-    Swap,
-    Rug,
-    Mint,
-    DiamondHands,
-    BigMove,
-}
-
-impl CardType {
-    pub fn from_u8(v: u8) -> Option<Self> {
-        match v { 0 => Some(Self::Swap), 1 => Some(Self::Rug), 2 => Some(Self::Mint), 3 => Some(Self::DiamondHands), 4 => Some(Self::BigMove), _ => None }
-    }
-
-    pub fn as_u8(&self) -> u8 {
-        match self { Self::Swap => 0, Self::Rug => 1, Self::Mint => 2, Self::DiamondHands => 3, Self::BigMove => 4 }
-    }
-
-    pub fn as_str(&self) -> &'static str {
-        match self { Self::Swap => "SWAP", Self::Rug => "RUG", Self::Mint => "MINT", Self::DiamondHands => "DIAMOND_HANDS", Self::BigMove => "BIG_MOVE" }
-    }
-}
+/// Newest on-chain card layout version this SDK understands.
+/// Version 0 is the original layout, which had no version byte.
+pub const CARD_VERSION: u8 = 5;
 
 /// A Crypt Card with all metadata.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -68,6 +24,25 @@ pub struct CryptCard {
     pub minted_at: i64,
     pub interaction_count: u64,
     pub soundtrack_id: String,
+    /// On-chain layout version (0 = legacy card that still needs `migrate_card`)
+    pub version: u8,
+    /// Owner metadata edits used so far (always 0 before v2)
+    pub metadata_edits: u8,
+    /// Wallet the card is lent to, if any (v3+)
+    pub user: Option<Pubkey>,
+    /// Unix timestamp the loan ends (0 when not lent)
+    pub user_expires_at: i64,
+    /// Boost event applied at mint, if any (v4+)
+    pub boost_event: Option<u16>,
+    /// `SCORING_VERSION` the card was scored with, if recorded (v5+)
+    pub scoring_version: Option<u16>,
+}
+
+impl CryptCard {
+    /// Whether the card is lent out at `now`. Expired loans don't count.
+    pub fn is_lent(&self, now: i64) -> bool {
+        self.user.is_some() && now < self.user_expires_at
+    }
 }
 
 /// Parameters for minting a new card.
@@ -105,3 +80,196 @@ pub struct ScanResult {
     pub rare_count: usize,
     pub common_count: usize,
 }
+
+/// A tombstone left behind by a burned card.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Tombstone {
+    pub last_owner: Pubkey,
+    pub mint_id: u64,
+    pub tx_hash_digest: [u8; 32],
+    pub rarity: Rarity,
+    pub card_type: CardType,
+    pub burned_at: i64,
+}
+
+/// A card's duel history.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DuelRecord {
+    pub card: Pubkey,
+    pub wins: u32,
+    pub losses: u32,
+    pub draws: u32,
+}
+
+/// Counters and interaction log for a card's likes, shares and bookmarks.
+/// Counters are totals, not unique users.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CardSocial {
+    pub card: Pubkey,
+    pub likes: u64,
+    pub shares: u64,
+    pub bookmarks: u64,
+    pub log_root: [u8; 32],
+    pub log_len: u64,
+}
+
+/// Why a card title or comment was reported, as the program numbers them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReportReason {
+    Spam = 0,
+    Scam = 1,
+    Hate = 2,
+    Harassment = 3,
+    Nsfw = 4,
+    Other = 5,
+}
+
+impl ReportReason {
+    pub fn from_u8(v: u8) -> Option<Self> {
+        match v {
+            0 => Some(Self::Spam),
+            1 => Some(Self::Scam),
+            2 => Some(Self::Hate),
+            3 => Some(Self::Harassment),
+            4 => Some(Self::Nsfw),
+            5 => Some(Self::Other),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Spam => "SPAM",
+            Self::Scam => "SCAM",
+            Self::Hate => "HATE",
+            Self::Harassment => "HARASSMENT",
+            Self::Nsfw => "NSFW",
+            Self::Other => "OTHER",
+        }
+    }
+}
+
+/// Moderation state of a card or comment.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Moderation {
+    /// Card or comment account
+    pub target: Pubkey,
+    /// True when the target is a comment rather than a card
+    pub is_comment: bool,
+    /// The card, or the card the comment is on
+    pub card: Pubkey,
+    pub hidden: bool,
+    pub report_count: u32,
+    pub last_reason: Option<ReportReason>,
+    /// Moderator who last changed `hidden`, if anyone has
+    pub moderator: Option<Pubkey>,
+    pub updated_at: i64,
+}
+
+/// A merkle airdrop to card holders.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Distributor {
+    pub distributor_id: u64,
+    pub authority: Pubkey,
+    pub merkle_root: [u8; 32],
+    /// SPL mint being distributed, or None for SOL
+    pub mint: Option<Pubkey>,
+    /// Token account holding an SPL distribution
+    pub vault: Option<Pubkey>,
+    pub total_amount: u64,
+    pub claimed_amount: u64,
+    pub num_claimed: u64,
+    pub snapshot_slot: u64,
+    /// 0 for no deadline
+    pub claim_deadline: i64,
+    pub created_at: i64,
+}
+
+impl Distributor {
+    /// Whether claims are still accepted at `now`.
+    pub fn is_open(&self, now: i64) -> bool {
+        self.claim_deadline == 0 || now <= self.claim_deadline
+    }
+}
+
+/// A card-weighted governance proposal.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Proposal {
+    pub proposal_id: u64,
+    pub authority: Pubkey,
+    pub title: String,
+    pub options: Vec<String>,
+    /// Vote weight per option, same order as `options`
+    pub tallies: Vec<u64>,
+    pub cards_voted: u64,
+    pub starts_at: i64,
+    pub ends_at: i64,
+    pub finalized: bool,
+    /// Set once finalized; None for no votes or a tie
+    pub winning_option: Option<u8>,
+    pub created_at: i64,
+}
+
+impl Proposal {
+    /// Whether votes are accepted at `now`.
+    pub fn is_voting(&self, now: i64) -> bool {
+        !self.finalized && self.starts_at <= now && now < self.ends_at
+    }
+
+    /// Total vote weight cast so far.
+    pub fn total_weight(&self) -> u64 {
+        self.tallies.iter().sum()
+    }
+
+    /// Label of the winning option, once finalized.
+    pub fn winner(&self) -> Option<&str> {
+        self.winning_option.and_then(|i| self.options.get(i as usize)).map(String::as_str)
+    }
+}
+
+/// A card's vote on a proposal.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VoteRecord {
+    pub proposal: Pubkey,
+    pub card: Pubkey,
+    /// Wallet that owned the card when it voted
+    pub voter: Pubkey,
+    pub option: u8,
+    pub weight: u64,
+    pub voted_at: i64,
+}
+
+/// Custody record for a card wrapped as a Metaplex NFT.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WrapRecord {
+    pub card: Pubkey,
+    pub nft_mint: Pubkey,
+    pub wrapper: Pubkey,
+    pub wrapped_at: i64,
+}
+
+/// A card held in a key-locked gift escrow.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Gift {
+    pub card: Pubkey,
+    pub sender: Pubkey,
+    /// Public key of the ephemeral keypair that must sign the claim
+    pub claim_key: Pubkey,
+    pub created_at: i64,
+    pub expires_at: i64,
+}
+
+impl Gift {
+    /// Whether the gift can still be claimed at `now`.
+    pub fn is_claimable(&self, now: i64) -> bool {
+        now < self.expires_at
+    }
+}
+
+impl Tombstone {
+    /// Check whether this tombstone belongs to the card minted from `tx_hash`.
+    pub fn matches_tx(&self, tx_hash: &str) -> bool {
+        use sha2::{Digest, Sha256};
+        Sha256::digest(tx_hash.as_bytes())[..] == self.tx_hash_digest[..]
+    }
+}
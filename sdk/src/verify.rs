@@ -0,0 +1,231 @@
+//! Card verification — the off-chain mirror of the program's `verify_card`.
+//!
+//! Produces the same per-check `VerificationReport` without sending a
+//! transaction, so frontends can show exactly which check failed.
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use solana_sdk::pubkey::Pubkey;
+use crate::client::decode_card_lenient;
+use crate::soul::SoulSignature;
+use crate::types::CryptCard;
+use crate::CryptSdkError;
+
+/// One pass/fail result per authenticity check.
+/// `narration_matches` is `None` when no narration text was supplied.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VerificationReport {
+    /// Stored tx_hash equals the one supplied
+    pub tx_hash_matches: bool,
+    /// soul_seed is the deterministic seed of the stored tx_hash
+    pub soul_seed_valid: bool,
+    /// SHA-256 of the supplied narration equals narration_hash
+    pub narration_matches: Option<bool>,
+    /// Card address is the `[b"card", tx_hash, minter]` PDA
+    pub pda_valid: bool,
+    /// Stored rarity and card type are known values. A range check only:
+    /// the scoring inputs behind rarity aren't stored, so whether the
+    /// rarity was earned is not checked.
+    pub tier_in_range: bool,
+    /// Collection is the program's canonical PDA and has minted this card's ID
+    pub canonical_collection: bool,
+}
+
+impl VerificationReport {
+    pub fn passed(&self) -> bool {
+        self.tx_hash_matches
+            && self.soul_seed_valid
+            && self.narration_matches.unwrap_or(true)
+            && self.pda_valid
+            && self.tier_in_range
+            && self.canonical_collection
+    }
+
+    /// Names of the checks that failed, in report order.
+    pub fn failures(&self) -> Vec<&'static str> {
+        let checks = [
+            ("tx_hash", self.tx_hash_matches),
+            ("soul_seed", self.soul_seed_valid),
+            ("narration", self.narration_matches.unwrap_or(true)),
+            ("pda", self.pda_valid),
+            ("tier", self.tier_in_range),
+            ("collection", self.canonical_collection),
+        ];
+        checks.iter().filter(|(_, ok)| !ok).map(|(name, _)| *name).collect()
+    }
+}
+
+/// Everything the checks need besides the decoded card itself.
+pub struct VerifyInputs<'a> {
+    pub program_id: &'a Pubkey,
+    pub card_address: &'a Pubkey,
+    pub tx_hash: &'a str,
+    pub narration: Option<&'a str>,
+    /// Defaults to the card's current owner — the minter isn't stored on-chain
+    pub minter: Option<&'a Pubkey>,
+    pub collection_address: &'a Pubkey,
+    pub collection_total_minted: u64,
+}
+
+/// Build a verification report for a decoded card.
+pub fn verify_card_data(card: &CryptCard, inputs: &VerifyInputs) -> VerificationReport {
+    let minter = inputs.minter.unwrap_or(&card.owner);
+    // Seeds over 32 bytes can't form a PDA at all
+    let pda_valid = card.tx_hash.len() <= 32 && {
+        let (expected, _) = Pubkey::find_program_address(
+            &[b"card", card.tx_hash.as_bytes(), minter.as_ref()],
+            inputs.program_id,
+        );
+        expected == *inputs.card_address
+    };
+
+    let (canonical, _) = Pubkey::find_program_address(&[b"collection"], inputs.program_id);
+
+    VerificationReport {
+        tx_hash_matches: card.tx_hash == inputs.tx_hash,
+        soul_seed_valid: SoulSignature::verify(&card.tx_hash, &card.soul_seed),
+        narration_matches: inputs.narration
+            .map(|text| Sha256::digest(text.as_bytes())[..] == card.narration_hash[..]),
+        pda_valid,
+        // A decoded card only holds known tiers; see `verify_card_account`
+        tier_in_range: true,
+        canonical_collection: *inputs.collection_address == canonical
+            && card.mint_id < inputs.collection_total_minted,
+    }
+}
+
+/// Build a verification report from raw card account data. An
+/// out-of-range rarity or card type fails `tier_in_range` instead of
+/// failing to decode.
+pub fn verify_card_account(data: &[u8], inputs: &VerifyInputs) -> Result<VerificationReport, CryptSdkError> {
+    let (card, tier_in_range) = decode_card_lenient(data)?;
+    Ok(VerificationReport { tier_in_range, ..verify_card_data(&card, inputs) })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{CardType, Rarity, CARD_VERSION};
+
+    const TX: &str = "4xK7m9pR2abc";
+
+    fn card(owner: Pubkey, narration: &str) -> CryptCard {
+        CryptCard {
+            owner,
+            mint_id: 3,
+            tx_hash: TX.into(),
+            rarity: Rarity::Rare,
+            card_type: CardType::Swap,
+            title: "APED".into(),
+            narration_hash: Sha256::digest(narration.as_bytes()).into(),
+            soul_seed: SoulSignature::compute(TX),
+            platform: "JUPITER".into(),
+            pnl: "+69%".into(),
+            tx_timestamp: 0,
+            minted_at: 0,
+            interaction_count: 0,
+            soundtrack_id: String::new(),
+            version: CARD_VERSION,
+            metadata_edits: 0,
+            user: None,
+            user_expires_at: 0,
+            boost_event: None,
+            scoring_version: None,
+        }
+    }
+
+    fn inputs<'a>(
+        program: &'a Pubkey,
+        address: &'a Pubkey,
+        collection: &'a Pubkey,
+        narration: Option<&'a str>,
+    ) -> VerifyInputs<'a> {
+        VerifyInputs {
+            program_id: program,
+            card_address: address,
+            tx_hash: TX,
+            narration,
+            minter: None,
+            collection_address: collection,
+            collection_total_minted: 10,
+        }
+    }
+
+    #[test]
+    fn test_authentic_card_passes_every_check() {
+        let program = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let (address, _) = Pubkey::find_program_address(&[b"card", TX.as_bytes(), owner.as_ref()], &program);
+        let (collection, _) = Pubkey::find_program_address(&[b"collection"], &program);
+
+        let report = verify_card_data(
+            &card(owner, "the story"),
+            &inputs(&program, &address, &collection, Some("the story")),
+        );
+        assert!(report.passed(), "{:?}", report.failures());
+        assert_eq!(report.narration_matches, Some(true));
+    }
+
+    #[test]
+    fn test_each_check_fails_independently() {
+        let program = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let (address, _) = Pubkey::find_program_address(&[b"card", TX.as_bytes(), owner.as_ref()], &program);
+        let (collection, _) = Pubkey::find_program_address(&[b"collection"], &program);
+        let c = card(owner, "the story");
+
+        let report = verify_card_data(&c, &inputs(&program, &address, &collection, Some("edited")));
+        assert_eq!(report.failures(), vec!["narration"]);
+
+        let wrong_address = Pubkey::new_unique();
+        let report = verify_card_data(&c, &inputs(&program, &wrong_address, &collection, None));
+        assert_eq!(report.failures(), vec!["pda"]);
+
+        let fake_collection = Pubkey::new_unique();
+        let report = verify_card_data(&c, &inputs(&program, &address, &fake_collection, None));
+        assert_eq!(report.failures(), vec!["collection"]);
+
+        let mut tampered = c.clone();
+        tampered.soul_seed[0] ^= 1;
+        let report = verify_card_data(&tampered, &inputs(&program, &address, &collection, None));
+        assert_eq!(report.failures(), vec!["soul_seed"]);
+    }
+
+    #[test]
+    fn test_narration_optional() {
+        let program = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let (address, _) = Pubkey::find_program_address(&[b"card", TX.as_bytes(), owner.as_ref()], &program);
+        let (collection, _) = Pubkey::find_program_address(&[b"collection"], &program);
+
+        let report = verify_card_data(&card(owner, "x"), &inputs(&program, &address, &collection, None));
+        assert_eq!(report.narration_matches, None);
+        assert!(report.passed());
+    }
+
+    #[test]
+    fn test_transferred_card_needs_minter() {
+        let program = Pubkey::new_unique();
+        let minter = Pubkey::new_unique();
+        let (address, _) = Pubkey::find_program_address(&[b"card", TX.as_bytes(), minter.as_ref()], &program);
+        let (collection, _) = Pubkey::find_program_address(&[b"collection"], &program);
+        let c = card(Pubkey::new_unique(), "x");
+
+        let mut i = inputs(&program, &address, &collection, None);
+        assert!(!verify_card_data(&c, &i).pda_valid);
+        i.minter = Some(&minter);
+        assert!(verify_card_data(&c, &i).pda_valid);
+    }
+
+    #[test]
+    fn test_unminted_id_not_canonical() {
+        let program = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let (address, _) = Pubkey::find_program_address(&[b"card", TX.as_bytes(), owner.as_ref()], &program);
+        let (collection, _) = Pubkey::find_program_address(&[b"collection"], &program);
+
+        let mut i = inputs(&program, &address, &collection, None);
+        i.collection_total_minted = 3;
+        assert!(!verify_card_data(&card(owner, "x"), &i).canonical_collection);
+    }
+}
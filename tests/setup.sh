@@ -0,0 +1,18 @@
+#!/usr/bin/env bash
+# Prepare target/deploy for the program tests: build the program and
+# fetch the Metaplex Token Metadata program the wrap tests load next to
+# it. Needs the Anchor and Solana CLIs, and mainnet access the first time.
+#
+#   tests/setup.sh && (cd tests && SBF_OUT_DIR=../target/deploy cargo test)
+set -euo pipefail
+
+root="$(cd "$(dirname "$0")/.." && pwd)"
+deploy="$root/target/deploy"
+token_metadata="metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"
+
+cd "$root"
+anchor build
+
+if [ ! -f "$deploy/mpl_token_metadata.so" ]; then
+    solana program dump -u m "$token_metadata" "$deploy/mpl_token_metadata.so"
+fi
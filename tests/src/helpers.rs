@@ -1,12 +1,25 @@
 //! Test helpers and utilities for Crypt integration tests.
 
+use anchor_lang::{AccountDeserialize, InstructionData, ToAccountMetas};
+use crypt::instructions::MintCardArgs;
+use crypt::state::CryptCard;
+use solana_program_test::{ProgramTest, ProgramTestContext};
 use solana_sdk::{
+    clock::Clock,
+    instruction::Instruction,
     signature::{Keypair, Signer},
     pubkey::Pubkey,
     system_instruction,
+    system_program,
     transaction::Transaction,
 };
 
+/// Seconds in a day, for warping the program-test clock.
+pub const DAY: i64 = 86_400;
+
+/// Collection URI the program-test collection is initialized with.
+pub const COLLECTION_URI: &str = "https://crypt.cards/collection.json";
+
 /// Generate a deterministic test keypair from a seed.
 pub fn test_keypair(seed: u8) -> Keypair {
     let mut bytes = [0u8; 64];
@@ -35,6 +48,56 @@ pub fn card_pda(program_id: &Pubkey, tx_hash: &str, minter: &Pubkey) -> (Pubkey,
     )
 }
 
+/// Derive a card's compact interaction counters PDA.
+pub fn card_social_pda(program_id: &Pubkey, card: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"social", card.as_ref()], program_id)
+}
+
+/// Derive the PDA recording a user's reactions on a card.
+pub fn react_record_pda(program_id: &Pubkey, card: &Pubkey, user: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"react", card.as_ref(), user.as_ref()], program_id)
+}
+
+/// Derive the moderation PDA for a card or comment.
+pub fn moderation_pda(program_id: &Pubkey, target: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"moderation", target.as_ref()], program_id)
+}
+
+/// Derive a reporter's report PDA for a card or comment.
+pub fn report_pda(program_id: &Pubkey, target: &Pubkey, reporter: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"report", target.as_ref(), reporter.as_ref()], program_id)
+}
+
+/// Derive a distributor PDA from its ID.
+pub fn distributor_pda(program_id: &Pubkey, distributor_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"distributor", &distributor_id.to_le_bytes()], program_id)
+}
+
+/// Derive a distributor claim marker PDA.
+pub fn claim_status_pda(program_id: &Pubkey, distributor: &Pubkey, claimant: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"claim", distributor.as_ref(), claimant.as_ref()], program_id)
+}
+
+/// Derive a proposal PDA from its ID.
+pub fn proposal_pda(program_id: &Pubkey, proposal_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"proposal", &proposal_id.to_le_bytes()], program_id)
+}
+
+/// Derive the PDA recording a card's vote on a proposal.
+pub fn vote_record_pda(program_id: &Pubkey, proposal: &Pubkey, card: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"vote", proposal.as_ref(), card.as_ref()], program_id)
+}
+
+/// Derive the custody record PDA for a wrapped card's NFT mint.
+pub fn wrap_record_pda(program_id: &Pubkey, nft_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"wrap", nft_mint.as_ref()], program_id)
+}
+
+/// Derive the mint and update authority PDA for wrapped cards.
+pub fn nft_authority_pda(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"nft_authority"], program_id)
+}
+
 /// Derive an interaction PDA.
 pub fn interaction_pda(program_id: &Pubkey, card: &Pubkey, user: &Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(
@@ -43,6 +106,73 @@ pub fn interaction_pda(program_id: &Pubkey, card: &Pubkey, user: &Pubkey) -> (Pu
     )
 }
 
+/// Derive a tombstone PDA from a burned card's mint ID.
+pub fn tombstone_pda(program_id: &Pubkey, mint_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"tombstone", &mint_id.to_le_bytes()],
+        program_id,
+    )
+}
+
+/// Derive an achievement set PDA.
+pub fn achievement_pda(program_id: &Pubkey, set_id: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"achievement", &set_id.to_le_bytes()],
+        program_id,
+    )
+}
+
+/// Derive a badge PDA for a wallet and achievement set.
+pub fn badge_pda(program_id: &Pubkey, achievement: &Pubkey, owner: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"badge", achievement.as_ref(), owner.as_ref()],
+        program_id,
+    )
+}
+
+/// Derive the record PDA marking a card as spent toward an achievement set.
+pub fn achievement_card_pda(program_id: &Pubkey, achievement: &Pubkey, card: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"achievement_card", achievement.as_ref(), card.as_ref()],
+        program_id,
+    )
+}
+
+/// Derive the staking config PDA.
+pub fn staking_config_pda(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"staking_config"], program_id)
+}
+
+/// Derive the stake record PDA for a staked card.
+pub fn stake_pda(program_id: &Pubkey, card: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"stake", card.as_ref()], program_id)
+}
+
+/// Derive a wallet's staking points PDA.
+pub fn points_pda(program_id: &Pubkey, owner: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"points", owner.as_ref()], program_id)
+}
+
+/// Derive the gift escrow PDA for a card.
+pub fn gift_pda(program_id: &Pubkey, card: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"gift", card.as_ref()], program_id)
+}
+
+/// Derive the open duel PDA for a challenging card.
+pub fn duel_pda(program_id: &Pubkey, challenger_card: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"duel", challenger_card.as_ref()], program_id)
+}
+
+/// Derive a card's duel record PDA.
+pub fn duel_record_pda(program_id: &Pubkey, card: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"duel_record", card.as_ref()], program_id)
+}
+
+/// Derive the collection's boost table PDA.
+pub fn boost_table_pda(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"boosts"], program_id)
+}
+
 /// Generate a mock transaction hash for testing.
 pub fn mock_tx_hash(id: u32) -> String {
     format!("{}MockTxHash{:06}abcdef1234567890abcdef1234567890abcdef1234567890{}", 
@@ -60,32 +190,121 @@ pub fn mock_narration_hash(text: &str) -> [u8; 32] {
     hash
 }
 
-/// Compute soul seed (mirrors on-chain logic).
-pub fn compute_soul_seed(tx_hash: &str) -> [u8; 32] {
-    let bytes = tx_hash.as_bytes();
-    let mut seed = [0u8; 32];
+/// Soul seed and upgrade proof, from the same crate the program uses.
+pub use crypt_core::{compute_soul_seed, upgrade_proof};
 
-    for (i, &b) in bytes.iter().enumerate() {
-        seed[i % 32] ^= b;
-    }
+/// Send `ix` in its own transaction paid by the context's payer.
+/// Returns whether it landed.
+pub async fn send(ctx: &mut ProgramTestContext, ix: Instruction, signers: &[&Keypair]) -> bool {
+    let blockhash = ctx.banks_client.get_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&ctx.payer.pubkey()),
+        signers,
+        blockhash,
+    );
+    ctx.banks_client.process_transaction(tx).await.is_ok()
+}
 
-    for i in 0..32 {
-        let mut h: u64 = seed[i] as u64;
-        h = h.wrapping_mul(0x517cc1b727220a95);
-        h ^= h >> 17;
-        h = h.wrapping_mul(0x6c62272e07bb0142);
-        h ^= h >> 11;
-        seed[i] = (h & 0xFF) as u8;
-    }
+/// A fresh wallet funded with 1 SOL by the payer. The payer also covers
+/// fees, so a fan's balance only moves by the rent it pays.
+pub async fn fan(ctx: &mut ProgramTestContext) -> Keypair {
+    let payer = ctx.payer.insecure_clone();
+    let fan = Keypair::new();
+    let fund = system_instruction::transfer(&payer.pubkey(), &fan.pubkey(), 1_000_000_000);
+    assert!(send(ctx, fund, &[&payer]).await);
+    fan
+}
 
-    for i in 1..32 {
-        seed[i] ^= seed[i - 1].wrapping_add(37);
-    }
-    for i in (0..31).rev() {
-        seed[i] ^= seed[i + 1].wrapping_add(53);
+/// Deserialize the Anchor account at `address`, which must exist.
+pub async fn fetch<T: AccountDeserialize>(ctx: &mut ProgramTestContext, address: Pubkey) -> T {
+    let account = ctx.banks_client.get_account(address).await.unwrap().unwrap();
+    T::try_deserialize(&mut account.data.as_slice()).unwrap()
+}
+
+/// The card at `address`.
+pub async fn fetch_card(ctx: &mut ProgramTestContext, address: Pubkey) -> CryptCard {
+    fetch(ctx, address).await
+}
+
+/// Move the clock forward by `days`.
+pub async fn warp_days(ctx: &mut ProgramTestContext, days: i64) {
+    let mut clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    clock.unix_timestamp += days * DAY;
+    ctx.set_sysvar(&clock);
+}
+
+/// Set the clock to `timestamp`.
+pub async fn warp_to(ctx: &mut ProgramTestContext, timestamp: i64) {
+    let mut clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+    clock.unix_timestamp = timestamp;
+    ctx.set_sysvar(&clock);
+}
+
+/// Start the BPF build from target/deploy and initialize the collection,
+/// with the payer as authority and treasury, no supply cap and no fee.
+pub async fn start() -> ProgramTestContext {
+    start_with(ProgramTest::new("crypt", crypt::ID, None)).await
+}
+
+/// [`start`] with extra programs loaded into `program_test`.
+pub async fn start_with(program_test: ProgramTest) -> ProgramTestContext {
+    let mut ctx = program_test.start_with_context().await;
+    let payer = ctx.payer.insecure_clone();
+    let init = Instruction {
+        program_id: crypt::ID,
+        accounts: crypt::accounts::InitializeCollection {
+            collection: collection_pda(&crypt::ID).0,
+            authority: payer.pubkey(),
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: crypt::instruction::InitializeCollection {
+            args: crypt::instructions::InitCollectionArgs {
+                uri: COLLECTION_URI.into(),
+                max_supply: 0,
+                mint_fee: 0,
+            },
+        }
+        .data(),
+    };
+    assert!(send(&mut ctx, init, &[&payer]).await);
+    ctx
+}
+
+/// Args for a Rare whale-move card from `tx_hash`. Override fields with
+/// struct update syntax where a test cares about them.
+pub fn card_args(tx_hash: &str) -> MintCardArgs {
+    MintCardArgs {
+        tx_hash: tx_hash.into(),
+        rarity: 1,
+        card_type: 4,
+        title: "WHALE MOVE".into(),
+        narration_hash: mock_narration_hash(tx_hash),
+        platform: "JUPITER".into(),
+        pnl: "+1,337%".into(),
+        tx_timestamp: 1_700_000_000,
+        soundtrack_id: String::new(),
+        score: 0,
+        scoring_version: 0,
     }
+}
 
-    seed
+/// Mint a card from `args` to `minter`, paying the fee to `treasury`.
+pub fn mint_card_ix(treasury: Pubkey, minter: Pubkey, args: MintCardArgs) -> Instruction {
+    Instruction {
+        program_id: crypt::ID,
+        accounts: crypt::accounts::MintCard {
+            card: card_pda(&crypt::ID, &args.tx_hash, &minter).0,
+            collection: collection_pda(&crypt::ID).0,
+            treasury,
+            minter,
+            system_program: system_program::ID,
+            boost_table: boost_table_pda(&crypt::ID).0,
+        }
+        .to_account_metas(None),
+        data: crypt::instruction::MintCard { args }.data(),
+    }
 }
 
 /// Format SOL amount for display.
@@ -160,6 +379,13 @@ mod tests {
         assert_avalanche(&s1, &s2, 16);
     }
 
+    #[test]
+    fn test_soul_seed_matches_shared_vectors() {
+        for (tx_hash, seed) in crypt_core::vectors::SOUL_SEED_VECTORS {
+            assert_eq!(compute_soul_seed(tx_hash), *seed);
+        }
+    }
+
     #[test]
     fn test_format_sol() {
         assert_eq!(format_sol(1_000_000_000), "1.00 SOL");
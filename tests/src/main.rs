@@ -8,6 +8,19 @@
 //! - Rarity scoring and upgrades
 //! - Soul signature verification
 //! - Social interactions (likes, comments)
+//! - Compact likes/bookmarks and their rent savings
+//! - Content reports and moderation
+//! - Merkle airdrop distributor
+//! - Card-weighted governance
+//! - Wrapping cards as Metaplex NFTs
+//! - Achievement sets and badges
+//! - Staking (haunting) and points accrual
+//! - Card layout versioning and migration
+//! - Owner metadata edits
+//! - Card lending and expiry
+//! - Key-locked gift links
+//! - Card duels with commit-reveal
+//! - Time-boxed event boosts
 //! - Edge cases and error handling
 
 mod test_collection;
@@ -17,6 +30,19 @@ mod test_burn;
 mod test_scoring;
 mod test_soul;
 mod test_social;
+mod test_compact_social;
+mod test_moderation;
+mod test_distributor;
+mod test_governance;
+mod test_wrap;
 mod test_verify;
 mod test_upgrade;
+mod test_achievements;
+mod test_staking;
+mod test_migrate;
+mod test_metadata;
+mod test_lending;
+mod test_gift;
+mod test_duel;
+mod test_boost;
 mod helpers;
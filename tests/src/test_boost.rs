@@ -0,0 +1,142 @@
+//! Tests for event boosts — configures the boost table and mints into
+//! and outside the event window under program-test.
+//!
+//! Loads the BPF build from target/deploy, so run `anchor build` first.
+
+#[cfg(test)]
+mod tests {
+    use crate::helpers::*;
+    use anchor_lang::{InstructionData, ToAccountMetas};
+    use crypt::state::BoostEvent;
+    use solana_program_test::ProgramTestContext;
+    use solana_sdk::{
+        clock::Clock,
+        instruction::Instruction,
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        system_program,
+    };
+
+    const RUG: u8 = 1;
+    const SWAP: u8 = 0;
+
+    async fn setup() -> (ProgramTestContext, i64) {
+        let mut ctx = start().await;
+        let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+        (ctx, clock.unix_timestamp)
+    }
+
+    fn set_event_ix(authority: Pubkey, event: BoostEvent) -> Instruction {
+        Instruction {
+            program_id: crypt::ID,
+            accounts: crypt::accounts::SetBoostEvent {
+                collection: collection_pda(&crypt::ID).0,
+                boost_table: boost_table_pda(&crypt::ID).0,
+                authority,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: crypt::instruction::SetBoostEvent { event }.data(),
+        }
+    }
+
+    fn halloween(now: i64, card_type: Option<u8>, score_bonus: u32, tier_bonus: u8) -> BoostEvent {
+        BoostEvent {
+            event_id: 31,
+            name: "HALLOWEEN".into(),
+            starts_at: now - 60,
+            ends_at: now + 86_400,
+            card_type,
+            score_bonus,
+            tier_bonus,
+        }
+    }
+
+    fn mint_ix(tx_hash: &str, minter: Pubkey, card_type: u8, score: u32) -> Instruction {
+        let args = crypt::instructions::MintCardArgs {
+            rarity: 0,
+            card_type,
+            title: "SPOOKY RUG".into(),
+            platform: "RAYDIUM".into(),
+            pnl: "-99%".into(),
+            score,
+            scoring_version: 3,
+            ..card_args(tx_hash)
+        };
+        mint_card_ix(minter, minter, args)
+    }
+
+    #[tokio::test]
+    async fn test_score_bonus_lifts_matching_card() {
+        let (mut ctx, now) = setup().await;
+        let payer = ctx.payer.insecure_clone();
+        assert!(send(&mut ctx, set_event_ix(payer.pubkey(), halloween(now, Some(RUG), 15, 0)), &[&payer]).await);
+
+        // 30 + 15 = 45 → Rare
+        assert!(send(&mut ctx, mint_ix("boost_rug_1", payer.pubkey(), RUG, 30), &[&payer]).await);
+        let card = fetch_card(&mut ctx, card_pda(&crypt::ID, "boost_rug_1", &payer.pubkey()).0).await;
+        assert_eq!(card.rarity, 1);
+        assert_eq!(card.boost_event, 31);
+        assert_eq!(card.scoring_version, 3);
+    }
+
+    #[tokio::test]
+    async fn test_other_card_types_unboosted() {
+        let (mut ctx, now) = setup().await;
+        let payer = ctx.payer.insecure_clone();
+        assert!(send(&mut ctx, set_event_ix(payer.pubkey(), halloween(now, Some(RUG), 15, 0)), &[&payer]).await);
+
+        assert!(send(&mut ctx, mint_ix("boost_swap_1", payer.pubkey(), SWAP, 30), &[&payer]).await);
+        let card = fetch_card(&mut ctx, card_pda(&crypt::ID, "boost_swap_1", &payer.pubkey()).0).await;
+        assert_eq!(card.rarity, 0);
+        assert_eq!(card.boost_event, 0);
+    }
+
+    #[tokio::test]
+    async fn test_tier_bonus_applied() {
+        let (mut ctx, now) = setup().await;
+        let payer = ctx.payer.insecure_clone();
+        assert!(send(&mut ctx, set_event_ix(payer.pubkey(), halloween(now, None, 0, 1)), &[&payer]).await);
+
+        assert!(send(&mut ctx, mint_ix("boost_tier_1", payer.pubkey(), SWAP, 0), &[&payer]).await);
+        let card = fetch_card(&mut ctx, card_pda(&crypt::ID, "boost_tier_1", &payer.pubkey()).0).await;
+        assert_eq!(card.rarity, 1);
+    }
+
+    #[tokio::test]
+    async fn test_expired_event_does_not_apply() {
+        let (mut ctx, now) = setup().await;
+        let payer = ctx.payer.insecure_clone();
+        let mut past = halloween(now, None, 0, 2);
+        past.starts_at = now - 7_200;
+        past.ends_at = now - 3_600;
+        assert!(send(&mut ctx, set_event_ix(payer.pubkey(), past), &[&payer]).await);
+
+        assert!(send(&mut ctx, mint_ix("boost_late_1", payer.pubkey(), RUG, 0), &[&payer]).await);
+        let card = fetch_card(&mut ctx, card_pda(&crypt::ID, "boost_late_1", &payer.pubkey()).0).await;
+        assert_eq!(card.rarity, 0);
+        assert_eq!(card.boost_event, 0);
+    }
+
+    #[tokio::test]
+    async fn test_boost_table_cannot_be_skipped() {
+        let (mut ctx, now) = setup().await;
+        let payer = ctx.payer.insecure_clone();
+        assert!(send(&mut ctx, set_event_ix(payer.pubkey(), halloween(now, None, 0, 1)), &[&payer]).await);
+
+        let mut skip = mint_ix("boost_skip_1", payer.pubkey(), SWAP, 0);
+        let table = boost_table_pda(&crypt::ID).0;
+        let slot = skip.accounts.iter().position(|m| m.pubkey == table).unwrap();
+        skip.accounts[slot].pubkey = crypt::ID;
+        assert!(!send(&mut ctx, skip, &[&payer]).await, "Mint must pass the boost table");
+    }
+
+    #[tokio::test]
+    async fn test_only_authority_sets_events() {
+        let (mut ctx, now) = setup().await;
+        let payer = ctx.payer.insecure_clone();
+        let stranger = Keypair::new();
+        let ix = set_event_ix(stranger.pubkey(), halloween(now, None, 15, 0));
+        assert!(!send(&mut ctx, ix, &[&payer, &stranger]).await);
+    }
+}
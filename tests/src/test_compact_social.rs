@@ -0,0 +1,206 @@
+//! Tests for compact interactions — likes, shares and bookmarks folded
+//! into per-card counters with a small per-user record — and the rent
+//! they save over one `Interaction` account each.
+//!
+//! Loads the BPF build from target/deploy, so run `anchor build` first.
+
+#[cfg(test)]
+mod tests {
+    use crate::helpers::*;
+    use anchor_lang::{AccountDeserialize, InstructionData, ToAccountMetas};
+    use crypt::state::{CardSocial, Interaction, ReactRecord};
+    use crypt::utils::{fold_interaction_log, interaction_leaf};
+    use solana_program_test::ProgramTestContext;
+    use solana_sdk::{
+        clock::Clock,
+        instruction::Instruction,
+        pubkey::Pubkey,
+        rent::Rent,
+        signature::Signer,
+        system_program,
+    };
+
+    const LIKE: u8 = 0;
+    const COMMENT: u8 = 1;
+    const BOOKMARK: u8 = 3;
+
+    const TX_HASH: &str = "social_test_tx_1";
+
+    async fn balance(ctx: &mut ProgramTestContext, address: Pubkey) -> u64 {
+        ctx.banks_client.get_balance(address).await.unwrap()
+    }
+
+    /// Initialize the collection and mint one card to the payer.
+    async fn setup() -> (ProgramTestContext, Pubkey) {
+        let mut ctx = start().await;
+        let payer = ctx.payer.insecure_clone();
+        let args = crypt::instructions::MintCardArgs {
+            rarity: 0,
+            card_type: 0,
+            title: "APED IN".into(),
+            pnl: "+69%".into(),
+            ..card_args(TX_HASH)
+        };
+        assert!(send(&mut ctx, mint_card_ix(payer.pubkey(), payer.pubkey(), args), &[&payer]).await);
+        (ctx, card_pda(&crypt::ID, TX_HASH, &payer.pubkey()).0)
+    }
+
+    fn interact_ix(card: Pubkey, user: Pubkey, interaction_type: u8) -> Instruction {
+        Instruction {
+            program_id: crypt::ID,
+            accounts: crypt::accounts::Interact {
+                interaction: interaction_pda(&crypt::ID, &card, &user).0,
+                card,
+                user,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: crypt::instruction::Interact {
+                args: crypt::instructions::InteractArgs {
+                    interaction_type,
+                    comment_hash: Some(mock_narration_hash("gm")),
+                },
+            }
+            .data(),
+        }
+    }
+
+    fn react_ix(card: Pubkey, user: Pubkey, interaction_type: u8) -> Instruction {
+        Instruction {
+            program_id: crypt::ID,
+            accounts: crypt::accounts::React {
+                card_social: card_social_pda(&crypt::ID, &card).0,
+                react_record: react_record_pda(&crypt::ID, &card, &user).0,
+                card,
+                user,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: crypt::instruction::React { interaction_type }.data(),
+        }
+    }
+
+    async fn fetch_social(ctx: &mut ProgramTestContext, card: Pubkey) -> CardSocial {
+        fetch(ctx, card_social_pda(&crypt::ID, &card).0).await
+    }
+
+    #[test]
+    fn test_rent_saved_per_interaction() {
+        let rent = Rent::default();
+        let per_account = rent.minimum_balance(8 + Interaction::SIZE);
+        let per_record = rent.minimum_balance(8 + ReactRecord::SIZE);
+        let per_card = rent.minimum_balance(8 + CardSocial::SIZE);
+
+        // Each fan pays for one small record; the counters are paid once per card
+        for likes in [1u64, 10, 100, 1_000] {
+            let compact = likes * per_record + per_card;
+            let saved = (likes * per_account) as i64 - compact as i64;
+            println!(
+                "{} likes: {} lamports as accounts, {} compact, {} saved ({} per like)",
+                likes,
+                likes * per_account,
+                compact,
+                saved,
+                saved / likes as i64,
+            );
+        }
+        // A record plus the shared counters already undercut one Interaction
+        assert!(per_record + per_card < 2 * per_account);
+        assert!(per_record < per_account / 2);
+    }
+
+    #[tokio::test]
+    async fn test_measured_rent_comment_vs_like() {
+        let (mut ctx, card) = setup().await;
+        let payer = ctx.payer.insecure_clone();
+        let rent = Rent::default();
+
+        let commenter = fan(&mut ctx).await;
+        let before = balance(&mut ctx, commenter.pubkey()).await;
+        assert!(send(&mut ctx, interact_ix(card, commenter.pubkey(), COMMENT), &[&payer, &commenter]).await);
+        let comment_cost = before - balance(&mut ctx, commenter.pubkey()).await;
+        assert_eq!(comment_cost, rent.minimum_balance(8 + Interaction::SIZE));
+
+        let first = fan(&mut ctx).await;
+        let before = balance(&mut ctx, first.pubkey()).await;
+        assert!(send(&mut ctx, react_ix(card, first.pubkey(), LIKE), &[&payer, &first]).await);
+        let first_like_cost = before - balance(&mut ctx, first.pubkey()).await;
+        let record_rent = rent.minimum_balance(8 + ReactRecord::SIZE);
+        assert_eq!(first_like_cost, rent.minimum_balance(8 + CardSocial::SIZE) + record_rent);
+
+        let second = fan(&mut ctx).await;
+        let before = balance(&mut ctx, second.pubkey()).await;
+        assert!(send(&mut ctx, react_ix(card, second.pubkey(), LIKE), &[&payer, &second]).await);
+        let like_cost = before - balance(&mut ctx, second.pubkey()).await;
+        assert_eq!(like_cost, record_rent);
+
+        // The same fan's other reactions reuse their record
+        let before = balance(&mut ctx, second.pubkey()).await;
+        assert!(send(&mut ctx, react_ix(card, second.pubkey(), BOOKMARK), &[&payer, &second]).await);
+        assert_eq!(balance(&mut ctx, second.pubkey()).await, before);
+
+        println!(
+            "comment: {} lamports, first like: {}, later likes: {} — {} saved per like",
+            comment_cost, first_like_cost, like_cost, comment_cost - like_cost,
+        );
+    }
+
+    #[tokio::test]
+    async fn test_counters_and_log() {
+        let (mut ctx, card) = setup().await;
+        let payer = ctx.payer.insecure_clone();
+        let fan = fan(&mut ctx).await;
+
+        assert!(send(&mut ctx, react_ix(card, fan.pubkey(), LIKE), &[&payer, &fan]).await);
+        let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+        let leaf = interaction_leaf(&card.to_bytes(), &fan.pubkey().to_bytes(), LIKE, clock.unix_timestamp);
+        let root = fold_interaction_log(&[0u8; 32], &leaf);
+        assert_eq!(fetch_social(&mut ctx, card).await.log_root, root);
+
+        assert!(send(&mut ctx, react_ix(card, fan.pubkey(), BOOKMARK), &[&payer, &fan]).await);
+        let social = fetch_social(&mut ctx, card).await;
+        assert_eq!(social.card, card);
+        assert_eq!((social.likes, social.shares, social.bookmarks), (1, 0, 1));
+        assert_eq!(social.log_len, 2);
+        assert_ne!(social.log_root, root);
+    }
+
+    #[tokio::test]
+    async fn test_each_reaction_counts_once() {
+        let (mut ctx, card) = setup().await;
+        let payer = ctx.payer.insecure_clone();
+        let fan = fan(&mut ctx).await;
+
+        assert!(send(&mut ctx, react_ix(card, fan.pubkey(), LIKE), &[&payer, &fan]).await);
+        assert!(!send(&mut ctx, react_ix(card, fan.pubkey(), LIKE), &[&payer, &fan]).await, "Repeat like");
+        assert!(send(&mut ctx, react_ix(card, fan.pubkey(), BOOKMARK), &[&payer, &fan]).await);
+
+        let social = fetch_social(&mut ctx, card).await;
+        assert_eq!((social.likes, social.bookmarks, social.log_len), (1, 1, 2));
+        assert_eq!(fetch_card(&mut ctx, card).await.interaction_count, 2);
+
+        let address = react_record_pda(&crypt::ID, &card, &fan.pubkey()).0;
+        let account = ctx.banks_client.get_account(address).await.unwrap().unwrap();
+        let record = ReactRecord::try_deserialize(&mut account.data.as_slice()).unwrap();
+        assert_eq!(record.reacted, 0b1001);
+    }
+
+    #[tokio::test]
+    async fn test_interact_still_accepts_likes() {
+        let (mut ctx, card) = setup().await;
+        let payer = ctx.payer.insecure_clone();
+        let liker = fan(&mut ctx).await;
+        let commenter = fan(&mut ctx).await;
+
+        // Older clients can still like through an Interaction account, but
+        // only the reaction counts, so one user can't count twice
+        assert!(send(&mut ctx, interact_ix(card, liker.pubkey(), LIKE), &[&payer, &liker]).await);
+        assert_eq!(fetch_card(&mut ctx, card).await.interaction_count, 0);
+        assert!(send(&mut ctx, react_ix(card, liker.pubkey(), LIKE), &[&payer, &liker]).await);
+        assert_eq!(fetch_card(&mut ctx, card).await.interaction_count, 1);
+        // ...but comments can't be folded into the counters
+        assert!(!send(&mut ctx, react_ix(card, commenter.pubkey(), COMMENT), &[&payer, &commenter]).await);
+        assert!(send(&mut ctx, interact_ix(card, commenter.pubkey(), COMMENT), &[&payer, &commenter]).await);
+        assert_eq!(fetch_card(&mut ctx, card).await.interaction_count, 2);
+    }
+}
@@ -0,0 +1,179 @@
+//! Tests for the merkle distributor — SOL airdrops to snapshot holders,
+//! claimed with proofs, and clawback after the deadline.
+//!
+//! Loads the BPF build from target/deploy, so run `anchor build` first.
+
+#[cfg(test)]
+mod tests {
+    use crate::helpers::*;
+    use anchor_lang::{InstructionData, ToAccountMetas};
+    use crypt::state::Distributor;
+    use crypt::utils::{distribution_leaf, merkle_node};
+    use solana_program_test::ProgramTestContext;
+    use solana_sdk::{
+        clock::Clock,
+        instruction::Instruction,
+        pubkey::Pubkey,
+        signature::Signer,
+        system_program,
+    };
+
+    const ID: u64 = 1;
+
+    /// Two holders: a tree of two leaves, where each proof is the other leaf.
+    struct Airdrop {
+        root: [u8; 32],
+        claims: Vec<(Pubkey, u64, Vec<[u8; 32]>)>,
+    }
+
+    fn airdrop(alice: Pubkey, bob: Pubkey) -> Airdrop {
+        let a = distribution_leaf(&alice.to_bytes(), 3_000_000);
+        let b = distribution_leaf(&bob.to_bytes(), 1_000_000);
+        Airdrop {
+            root: merkle_node(&a, &b),
+            claims: vec![(alice, 3_000_000, vec![b]), (bob, 1_000_000, vec![a])],
+        }
+    }
+
+    fn create_ix(authority: Pubkey, root: [u8; 32], total_amount: u64, claim_deadline: i64) -> Instruction {
+        Instruction {
+            program_id: crypt::ID,
+            accounts: crypt::accounts::CreateDistributor {
+                collection: collection_pda(&crypt::ID).0,
+                distributor: distributor_pda(&crypt::ID, ID).0,
+                authority,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: crypt::instruction::CreateDistributor {
+                distributor_id: ID,
+                args: crypt::instructions::DistributorArgs {
+                    merkle_root: root,
+                    total_amount,
+                    snapshot_slot: 1_234,
+                    claim_deadline,
+                },
+            }
+            .data(),
+        }
+    }
+
+    fn claim_ix(claimant: Pubkey, amount: u64, proof: Vec<[u8; 32]>) -> Instruction {
+        let distributor = distributor_pda(&crypt::ID, ID).0;
+        Instruction {
+            program_id: crypt::ID,
+            accounts: crypt::accounts::ClaimDistribution {
+                distributor,
+                claim_status: claim_status_pda(&crypt::ID, &distributor, &claimant).0,
+                claimant,
+                system_program: system_program::ID,
+                vault: None,
+                destination: None,
+                token_program: None,
+            }
+            .to_account_metas(None),
+            data: crypt::instruction::ClaimDistribution { amount, proof }.data(),
+        }
+    }
+
+    fn clawback_ix(authority: Pubkey) -> Instruction {
+        Instruction {
+            program_id: crypt::ID,
+            accounts: crypt::accounts::ClawbackDistribution {
+                distributor: distributor_pda(&crypt::ID, ID).0,
+                authority,
+                vault: None,
+                destination: None,
+                token_program: None,
+            }
+            .to_account_metas(None),
+            data: crypt::instruction::ClawbackDistribution {}.data(),
+        }
+    }
+
+    async fn fetch_distributor(ctx: &mut ProgramTestContext) -> Distributor {
+        fetch(ctx, distributor_pda(&crypt::ID, ID).0).await
+    }
+
+    #[tokio::test]
+    async fn test_holders_claim_once() {
+        let mut ctx = start().await;
+        let payer = ctx.payer.insecure_clone();
+        let alice = fan(&mut ctx).await;
+        let bob = fan(&mut ctx).await;
+        let drop = airdrop(alice.pubkey(), bob.pubkey());
+
+        assert!(send(&mut ctx, create_ix(payer.pubkey(), drop.root, 4_000_000, 0), &[&payer]).await);
+
+        for (i, who) in [&alice, &bob].into_iter().enumerate() {
+            let (claimant, amount, proof) = drop.claims[i].clone();
+            let before = ctx.banks_client.get_balance(claimant).await.unwrap();
+            assert!(send(&mut ctx, claim_ix(claimant, amount, proof.clone()), &[&payer, who]).await);
+            let after = ctx.banks_client.get_balance(claimant).await.unwrap();
+            // Received the allocation, minus rent for the claim marker
+            assert!(after > before && after - before < amount);
+
+            assert!(!send(&mut ctx, claim_ix(claimant, amount, proof), &[&payer, who]).await);
+        }
+
+        let distributor = fetch_distributor(&mut ctx).await;
+        assert_eq!(distributor.claimed_amount, 4_000_000);
+        assert_eq!(distributor.num_claimed, 2);
+        assert_eq!(distributor.snapshot_slot, 1_234);
+    }
+
+    #[tokio::test]
+    async fn test_bad_proofs_rejected() {
+        let mut ctx = start().await;
+        let payer = ctx.payer.insecure_clone();
+        let alice = fan(&mut ctx).await;
+        let bob = fan(&mut ctx).await;
+        let mallory = fan(&mut ctx).await;
+        let drop = airdrop(alice.pubkey(), bob.pubkey());
+        assert!(send(&mut ctx, create_ix(payer.pubkey(), drop.root, 4_000_000, 0), &[&payer]).await);
+
+        let (_, amount, proof) = drop.claims[0].clone();
+        // Inflated amount
+        assert!(!send(&mut ctx, claim_ix(alice.pubkey(), amount * 2, proof.clone()), &[&payer, &alice]).await);
+        // Someone else's proof
+        assert!(!send(&mut ctx, claim_ix(mallory.pubkey(), amount, proof), &[&payer, &mallory]).await);
+    }
+
+    #[tokio::test]
+    async fn test_only_authority_creates() {
+        let mut ctx = start().await;
+        let payer = ctx.payer.insecure_clone();
+        let stranger = fan(&mut ctx).await;
+        let ix = create_ix(stranger.pubkey(), [1u8; 32], 1_000_000, 0);
+        assert!(!send(&mut ctx, ix, &[&payer, &stranger]).await);
+    }
+
+    #[tokio::test]
+    async fn test_clawback_after_deadline() {
+        let mut ctx = start().await;
+        let payer = ctx.payer.insecure_clone();
+        let alice = fan(&mut ctx).await;
+        let bob = fan(&mut ctx).await;
+        let drop = airdrop(alice.pubkey(), bob.pubkey());
+
+        let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+        let deadline = clock.unix_timestamp + 7 * DAY;
+        assert!(send(&mut ctx, create_ix(payer.pubkey(), drop.root, 4_000_000, deadline), &[&payer]).await);
+
+        let (claimant, amount, proof) = drop.claims[0].clone();
+        assert!(send(&mut ctx, claim_ix(claimant, amount, proof), &[&payer, &alice]).await);
+        assert!(!send(&mut ctx, clawback_ix(payer.pubkey()), &[&payer]).await);
+
+        let mut clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+        clock.unix_timestamp = deadline + 1;
+        ctx.set_sysvar(&clock);
+
+        // Bob missed the window
+        let (claimant, amount, proof) = drop.claims[1].clone();
+        assert!(!send(&mut ctx, claim_ix(claimant, amount, proof), &[&payer, &bob]).await);
+
+        assert!(send(&mut ctx, clawback_ix(payer.pubkey()), &[&payer]).await);
+        let address = distributor_pda(&crypt::ID, ID).0;
+        assert!(ctx.banks_client.get_account(address).await.unwrap().is_none());
+    }
+}
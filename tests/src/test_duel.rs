@@ -0,0 +1,263 @@
+//! Tests for card duels — runs the program under program-test. Outcomes
+//! are checked against the shared rules in crypt-core.
+//!
+//! Loads the BPF build from target/deploy, so run `anchor build` first.
+
+#[cfg(test)]
+mod tests {
+    use crate::helpers::*;
+    use anchor_lang::{AccountDeserialize, InstructionData, ToAccountMetas};
+    use crypt::state::{Duel, DuelRecord};
+    use crypt::utils::{resolve_duel, DuelOutcome, Duelist};
+    use sha2::{Digest, Sha256};
+    use solana_program_test::ProgramTestContext;
+    use solana_sdk::{
+        instruction::Instruction,
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        system_program,
+    };
+
+    const CHALLENGER_NONCE: [u8; 32] = [1u8; 32];
+    const OPPONENT_NONCE: [u8; 32] = [2u8; 32];
+
+    // Card PDA seeds cap at 32 bytes, so keep test hashes short
+    const TX_A: &str = "duel_test_tx_a";
+    const TX_B: &str = "duel_test_tx_b";
+
+    async fn fetch_record(ctx: &mut ProgramTestContext, card: Pubkey) -> DuelRecord {
+        fetch(ctx, duel_record_pda(&crypt::ID, &card).0).await
+    }
+
+    fn mint_ix(tx_hash: &str, treasury: Pubkey, minter: Pubkey, rarity: u8, card_type: u8) -> Instruction {
+        let args = crypt::instructions::MintCardArgs {
+            rarity,
+            card_type,
+            title: "DUELIST".into(),
+            pnl: "+100%".into(),
+            ..card_args(tx_hash)
+        };
+        mint_card_ix(treasury, minter, args)
+    }
+
+    /// Mint card A to the payer and card B to a funded second wallet.
+    async fn setup() -> (ProgramTestContext, Keypair, Pubkey, Pubkey) {
+        let mut ctx = start().await;
+        let payer = ctx.payer.insecure_clone();
+        let rival = fan(&mut ctx).await;
+
+        assert!(send(&mut ctx, mint_ix(TX_A, payer.pubkey(), payer.pubkey(), 1, 0), &[&payer]).await);
+        assert!(send(&mut ctx, mint_ix(TX_B, payer.pubkey(), rival.pubkey(), 1, 3), &[&payer, &rival]).await);
+
+        let card_a = card_pda(&crypt::ID, TX_A, &payer.pubkey()).0;
+        let card_b = card_pda(&crypt::ID, TX_B, &rival.pubkey()).0;
+        (ctx, rival, card_a, card_b)
+    }
+
+    fn commitment(card: &Pubkey, nonce: &[u8; 32]) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(card.as_ref());
+        hasher.update(nonce);
+        hasher.finalize().into()
+    }
+
+    fn challenge_ix(card: Pubkey, opponent_card: Pubkey, challenger: Pubkey) -> Instruction {
+        Instruction {
+            program_id: crypt::ID,
+            accounts: crypt::accounts::ChallengeDuel {
+                challenger_card: card,
+                opponent_card,
+                duel: duel_pda(&crypt::ID, &card).0,
+                challenger_record: duel_record_pda(&crypt::ID, &card).0,
+                challenger,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: crypt::instruction::ChallengeDuel {
+                card_id: 0,
+                commitment: commitment(&card, &CHALLENGER_NONCE),
+            }
+            .data(),
+        }
+    }
+
+    fn accept_ix(challenger_card: Pubkey, card: Pubkey, opponent: Pubkey) -> Instruction {
+        Instruction {
+            program_id: crypt::ID,
+            accounts: crypt::accounts::AcceptDuel {
+                duel: duel_pda(&crypt::ID, &challenger_card).0,
+                opponent_card: card,
+                opponent_record: duel_record_pda(&crypt::ID, &card).0,
+                opponent,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: crypt::instruction::AcceptDuel {
+                card_id: 0,
+                commitment: commitment(&card, &OPPONENT_NONCE),
+            }
+            .data(),
+        }
+    }
+
+    fn resolve_accounts(card_a: Pubkey, card_b: Pubkey, challenger: Pubkey, signer: Pubkey) -> Vec<solana_sdk::instruction::AccountMeta> {
+        crypt::accounts::ResolveDuel {
+            duel: duel_pda(&crypt::ID, &card_a).0,
+            challenger_card: card_a,
+            opponent_card: card_b,
+            challenger_record: duel_record_pda(&crypt::ID, &card_a).0,
+            opponent_record: duel_record_pda(&crypt::ID, &card_b).0,
+            challenger,
+            signer,
+        }
+        .to_account_metas(None)
+    }
+
+    fn reveal_ix(card_a: Pubkey, card_b: Pubkey, challenger: Pubkey, signer: Pubkey, nonce: [u8; 32]) -> Instruction {
+        Instruction {
+            program_id: crypt::ID,
+            accounts: resolve_accounts(card_a, card_b, challenger, signer),
+            data: crypt::instruction::RevealDuel { nonce }.data(),
+        }
+    }
+
+    fn settle_ix(card_a: Pubkey, card_b: Pubkey, challenger: Pubkey, signer: Pubkey) -> Instruction {
+        Instruction {
+            program_id: crypt::ID,
+            accounts: resolve_accounts(card_a, card_b, challenger, signer),
+            data: crypt::instruction::SettleDuel {}.data(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_full_duel_matches_shared_rules() {
+        let (mut ctx, rival, card_a, card_b) = setup().await;
+        let payer = ctx.payer.insecure_clone();
+
+        assert!(send(&mut ctx, challenge_ix(card_a, card_b, payer.pubkey()), &[&payer]).await);
+        assert!(send(&mut ctx, accept_ix(card_a, card_b, rival.pubkey()), &[&payer, &rival]).await);
+        assert!(send(&mut ctx, reveal_ix(card_a, card_b, payer.pubkey(), payer.pubkey(), CHALLENGER_NONCE), &[&payer]).await);
+        assert!(send(&mut ctx, reveal_ix(card_a, card_b, payer.pubkey(), rival.pubkey(), OPPONENT_NONCE), &[&payer, &rival]).await);
+
+        let a = fetch_card(&mut ctx, card_a).await;
+        let b = fetch_card(&mut ctx, card_b).await;
+        let mut hasher = Sha256::new();
+        hasher.update(CHALLENGER_NONCE);
+        hasher.update(OPPONENT_NONCE);
+        let expected = resolve_duel(
+            &Duelist::new(&a.soul_seed, a.rarity, a.card_type),
+            &Duelist::new(&b.soul_seed, b.rarity, b.card_type),
+            &hasher.finalize().into(),
+        );
+
+        let record_a = fetch_record(&mut ctx, card_a).await;
+        let record_b = fetch_record(&mut ctx, card_b).await;
+        match expected {
+            DuelOutcome::ChallengerWins => {
+                assert_eq!((record_a.wins, record_b.losses), (1, 1));
+            }
+            DuelOutcome::OpponentWins => {
+                assert_eq!((record_b.wins, record_a.losses), (1, 1));
+            }
+            DuelOutcome::Draw => {
+                assert_eq!((record_a.draws, record_b.draws), (1, 1));
+            }
+        }
+
+        let duel = ctx.banks_client.get_account(duel_pda(&crypt::ID, &card_a).0).await.unwrap();
+        assert!(duel.is_none(), "Resolved duel should be closed");
+    }
+
+    #[tokio::test]
+    async fn test_reveal_must_match_commitment() {
+        let (mut ctx, rival, card_a, card_b) = setup().await;
+        let payer = ctx.payer.insecure_clone();
+        assert!(send(&mut ctx, challenge_ix(card_a, card_b, payer.pubkey()), &[&payer]).await);
+        assert!(send(&mut ctx, accept_ix(card_a, card_b, rival.pubkey()), &[&payer, &rival]).await);
+
+        let wrong = reveal_ix(card_a, card_b, payer.pubkey(), payer.pubkey(), [9u8; 32]);
+        assert!(!send(&mut ctx, wrong, &[&payer]).await);
+    }
+
+    #[tokio::test]
+    async fn test_non_holder_cannot_accept() {
+        let (mut ctx, _rival, card_a, card_b) = setup().await;
+        let payer = ctx.payer.insecure_clone();
+        let stranger = Keypair::new();
+        assert!(send(&mut ctx, challenge_ix(card_a, card_b, payer.pubkey()), &[&payer]).await);
+        assert!(!send(&mut ctx, accept_ix(card_a, card_b, stranger.pubkey()), &[&payer, &stranger]).await);
+    }
+
+    #[tokio::test]
+    async fn test_forfeit_after_reveal_window() {
+        let (mut ctx, rival, card_a, card_b) = setup().await;
+        let payer = ctx.payer.insecure_clone();
+        assert!(send(&mut ctx, challenge_ix(card_a, card_b, payer.pubkey()), &[&payer]).await);
+        assert!(send(&mut ctx, accept_ix(card_a, card_b, rival.pubkey()), &[&payer, &rival]).await);
+        assert!(send(&mut ctx, reveal_ix(card_a, card_b, payer.pubkey(), payer.pubkey(), CHALLENGER_NONCE), &[&payer]).await);
+
+        let early = settle_ix(card_a, card_b, payer.pubkey(), payer.pubkey());
+        assert!(!send(&mut ctx, early, &[&payer]).await, "Reveal window still open");
+
+        warp_days(&mut ctx, 2).await;
+        assert!(send(&mut ctx, settle_ix(card_a, card_b, payer.pubkey(), payer.pubkey()), &[&payer]).await);
+
+        assert_eq!(fetch_record(&mut ctx, card_a).await.wins, 1);
+        assert_eq!(fetch_record(&mut ctx, card_b).await.losses, 1);
+    }
+
+    #[tokio::test]
+    async fn test_challenger_can_cancel_before_accept() {
+        let (mut ctx, _rival, card_a, card_b) = setup().await;
+        let payer = ctx.payer.insecure_clone();
+        assert!(send(&mut ctx, challenge_ix(card_a, card_b, payer.pubkey()), &[&payer]).await);
+
+        let cancel = Instruction {
+            program_id: crypt::ID,
+            accounts: crypt::accounts::CancelDuel {
+                duel: duel_pda(&crypt::ID, &card_a).0,
+                challenger: payer.pubkey(),
+            }
+            .to_account_metas(None),
+            data: crypt::instruction::CancelDuel {}.data(),
+        };
+        assert!(send(&mut ctx, cancel, &[&payer]).await);
+        let duel = ctx.banks_client.get_account(duel_pda(&crypt::ID, &card_a).0).await.unwrap();
+        assert!(duel.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_duel_uses_challenge_time_snapshot() {
+        let (mut ctx, rival, card_a, card_b) = setup().await;
+        let payer = ctx.payer.insecure_clone();
+        assert!(send(&mut ctx, challenge_ix(card_a, card_b, payer.pubkey()), &[&payer]).await);
+
+        let b = fetch_card(&mut ctx, card_b).await;
+        let account = ctx.banks_client.get_account(duel_pda(&crypt::ID, &card_a).0).await.unwrap().unwrap();
+        let duel = Duel::try_deserialize(&mut account.data.as_slice()).unwrap();
+        assert_eq!(duel.opponent_duelist(), Duelist::new(&b.soul_seed, b.rarity, b.card_type));
+
+        // The opponent card is burned mid-duel; the duel still resolves
+        assert!(send(&mut ctx, accept_ix(card_a, card_b, rival.pubkey()), &[&payer, &rival]).await);
+        let burn = Instruction {
+            program_id: crypt::ID,
+            accounts: crypt::accounts::BurnCard { card: card_b, owner: rival.pubkey() }.to_account_metas(None),
+            data: crypt::instruction::BurnCard { card_id: 0 }.data(),
+        };
+        assert!(send(&mut ctx, burn, &[&payer, &rival]).await);
+        assert!(send(&mut ctx, reveal_ix(card_a, card_b, payer.pubkey(), payer.pubkey(), CHALLENGER_NONCE), &[&payer]).await);
+        assert!(send(&mut ctx, reveal_ix(card_a, card_b, payer.pubkey(), rival.pubkey(), OPPONENT_NONCE), &[&payer, &rival]).await);
+
+        let duel = ctx.banks_client.get_account(duel_pda(&crypt::ID, &card_a).0).await.unwrap();
+        assert!(duel.is_none(), "Duel should resolve from its snapshot");
+        let record_b = fetch_record(&mut ctx, card_b).await;
+        assert_eq!(record_b.wins + record_b.losses + record_b.draws, 1);
+    }
+
+    #[tokio::test]
+    async fn test_card_cannot_duel_itself() {
+        let (mut ctx, _rival, card_a, _card_b) = setup().await;
+        let payer = ctx.payer.insecure_clone();
+        assert!(!send(&mut ctx, challenge_ix(card_a, card_a, payer.pubkey()), &[&payer]).await);
+    }
+}
@@ -0,0 +1,196 @@
+//! Tests for key-locked gift links — runs the program under program-test
+//! and warps the clock past gift expiry.
+//!
+//! Loads the BPF build from target/deploy, so run `anchor build` first.
+
+#[cfg(test)]
+mod tests {
+    use crate::helpers::*;
+    use anchor_lang::{InstructionData, ToAccountMetas};
+    use solana_program_test::ProgramTestContext;
+    use solana_sdk::{
+        instruction::Instruction,
+        pubkey::Pubkey,
+        signature::{keypair_from_seed, Keypair, Signer},
+        system_program,
+    };
+
+    const KEY_SEED: [u8; 32] = [42u8; 32];
+
+    // Card PDA seeds cap at 32 bytes, so keep test hashes short
+    const TX_HASH: &str = "gift_test_tx_1";
+
+    /// Initialize the collection and mint one Rare card to the payer.
+    async fn setup() -> (ProgramTestContext, Pubkey) {
+        let mut ctx = start().await;
+        let payer = ctx.payer.insecure_clone();
+        let mint = mint_card_ix(payer.pubkey(), payer.pubkey(), card_args(TX_HASH));
+        assert!(send(&mut ctx, mint, &[&payer]).await);
+        (ctx, card_pda(&crypt::ID, TX_HASH, &payer.pubkey()).0)
+    }
+
+    /// The ephemeral keypair a gift link carries.
+    fn claim_key() -> Keypair {
+        keypair_from_seed(&KEY_SEED).unwrap()
+    }
+
+    fn create_ix(card: Pubkey, sender: Pubkey, claim_key: Pubkey, duration: i64) -> Instruction {
+        Instruction {
+            program_id: crypt::ID,
+            accounts: crypt::accounts::CreateGift {
+                card,
+                gift: gift_pda(&crypt::ID, &card).0,
+                sender,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: crypt::instruction::CreateGift {
+                card_id: 0,
+                claim_key,
+                duration,
+            }
+            .data(),
+        }
+    }
+
+    fn claim_ix(card: Pubkey, sender: Pubkey, claim_key: Pubkey, claimant: Pubkey) -> Instruction {
+        Instruction {
+            program_id: crypt::ID,
+            accounts: crypt::accounts::ClaimGift {
+                card,
+                gift: gift_pda(&crypt::ID, &card).0,
+                sender,
+                claim_key,
+                claimant,
+            }
+            .to_account_metas(None),
+            data: crypt::instruction::ClaimGift { card_id: 0 }.data(),
+        }
+    }
+
+    fn reclaim_ix(card: Pubkey, sender: Pubkey) -> Instruction {
+        Instruction {
+            program_id: crypt::ID,
+            accounts: crypt::accounts::ReclaimGift {
+                card,
+                gift: gift_pda(&crypt::ID, &card).0,
+                sender,
+            }
+            .to_account_metas(None),
+            data: crypt::instruction::ReclaimGift { card_id: 0 }.data(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_gift_takes_custody() {
+        let (mut ctx, card) = setup().await;
+        let payer = ctx.payer.insecure_clone();
+
+        assert!(send(&mut ctx, create_ix(card, payer.pubkey(), claim_key().pubkey(), 7 * DAY), &[&payer]).await);
+
+        let escrowed = fetch_card(&mut ctx, card).await;
+        assert_eq!(escrowed.owner, gift_pda(&crypt::ID, &card).0);
+
+        let transfer = Instruction {
+            program_id: crypt::ID,
+            accounts: crypt::accounts::TransferCard {
+                card,
+                current_owner: payer.pubkey(),
+                new_owner: Pubkey::new_unique(),
+            }
+            .to_account_metas(None),
+            data: crypt::instruction::TransferCard { card_id: 0 }.data(),
+        };
+        assert!(!send(&mut ctx, transfer, &[&payer]).await, "Gifted card must not transfer");
+    }
+
+    #[tokio::test]
+    async fn test_claim_with_key_transfers_card() {
+        let (mut ctx, card) = setup().await;
+        let payer = ctx.payer.insecure_clone();
+        let friend = Keypair::new();
+        assert!(send(&mut ctx, create_ix(card, payer.pubkey(), claim_key().pubkey(), 7 * DAY), &[&payer]).await);
+
+        let key = claim_key();
+        let claim = claim_ix(card, payer.pubkey(), key.pubkey(), friend.pubkey());
+        assert!(send(&mut ctx, claim, &[&payer, &key, &friend]).await);
+
+        let claimed = fetch_card(&mut ctx, card).await;
+        assert_eq!(claimed.owner, friend.pubkey());
+        let gift = ctx.banks_client.get_account(gift_pda(&crypt::ID, &card).0).await.unwrap();
+        assert!(gift.is_none(), "Gift escrow should be closed");
+    }
+
+    #[tokio::test]
+    async fn test_claim_rejects_wrong_key() {
+        let (mut ctx, card) = setup().await;
+        let payer = ctx.payer.insecure_clone();
+        let friend = Keypair::new();
+        assert!(send(&mut ctx, create_ix(card, payer.pubkey(), claim_key().pubkey(), 7 * DAY), &[&payer]).await);
+
+        let wrong = Keypair::new();
+        let claim = claim_ix(card, payer.pubkey(), wrong.pubkey(), friend.pubkey());
+        assert!(!send(&mut ctx, claim, &[&payer, &wrong, &friend]).await);
+    }
+
+    #[tokio::test]
+    async fn test_claim_needs_key_signature() {
+        let (mut ctx, card) = setup().await;
+        let payer = ctx.payer.insecure_clone();
+        let friend = Keypair::new();
+        assert!(send(&mut ctx, create_ix(card, payer.pubkey(), claim_key().pubkey(), 7 * DAY), &[&payer]).await);
+
+        // Someone who saw the key's pubkey in a pending claim can't reuse it
+        let mut claim = claim_ix(card, payer.pubkey(), claim_key().pubkey(), friend.pubkey());
+        claim.accounts[3].is_signer = false;
+        assert!(!send(&mut ctx, claim, &[&payer, &friend]).await);
+        assert_eq!(fetch_card(&mut ctx, card).await.owner, gift_pda(&crypt::ID, &card).0);
+    }
+
+    #[tokio::test]
+    async fn test_gift_duration_floor() {
+        let (mut ctx, card) = setup().await;
+        let payer = ctx.payer.insecure_clone();
+        let short = crypt::utils::MIN_GIFT_DURATION - 1;
+        assert!(!send(&mut ctx, create_ix(card, payer.pubkey(), claim_key().pubkey(), short), &[&payer]).await);
+    }
+
+    #[tokio::test]
+    async fn test_claim_rejected_after_expiry() {
+        let (mut ctx, card) = setup().await;
+        let payer = ctx.payer.insecure_clone();
+        let friend = Keypair::new();
+        assert!(send(&mut ctx, create_ix(card, payer.pubkey(), claim_key().pubkey(), DAY), &[&payer]).await);
+
+        warp_days(&mut ctx, 1).await;
+        let key = claim_key();
+        let claim = claim_ix(card, payer.pubkey(), key.pubkey(), friend.pubkey());
+        assert!(!send(&mut ctx, claim, &[&payer, &key, &friend]).await);
+    }
+
+    #[tokio::test]
+    async fn test_sender_reclaims_only_after_expiry() {
+        let (mut ctx, card) = setup().await;
+        let payer = ctx.payer.insecure_clone();
+        assert!(send(&mut ctx, create_ix(card, payer.pubkey(), claim_key().pubkey(), 2 * DAY), &[&payer]).await);
+
+        assert!(!send(&mut ctx, reclaim_ix(card, payer.pubkey()), &[&payer]).await, "Gift still claimable");
+
+        warp_days(&mut ctx, 2).await;
+        assert!(send(&mut ctx, reclaim_ix(card, payer.pubkey()), &[&payer]).await);
+
+        let reclaimed = fetch_card(&mut ctx, card).await;
+        assert_eq!(reclaimed.owner, payer.pubkey());
+    }
+
+    #[tokio::test]
+    async fn test_only_sender_can_reclaim() {
+        let (mut ctx, card) = setup().await;
+        let payer = ctx.payer.insecure_clone();
+        assert!(send(&mut ctx, create_ix(card, payer.pubkey(), claim_key().pubkey(), DAY), &[&payer]).await);
+        warp_days(&mut ctx, 2).await;
+
+        let stranger = Keypair::new();
+        assert!(!send(&mut ctx, reclaim_ix(card, stranger.pubkey()), &[&payer, &stranger]).await);
+    }
+}
@@ -0,0 +1,262 @@
+//! Tests for card-weighted governance — proposals, one vote per card
+//! minted before the proposal opened, votes that follow the card across
+//! transfers, and finalizing the result.
+//!
+//! Loads the BPF build from target/deploy, so run `anchor build` first.
+
+#[cfg(test)]
+mod tests {
+    use crate::helpers::*;
+    use anchor_lang::{InstructionData, ToAccountMetas};
+    use crypt::state::Proposal;
+    use solana_program_test::ProgramTestContext;
+    use solana_sdk::{
+        clock::Clock,
+        instruction::{AccountMeta, Instruction},
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        system_program,
+    };
+
+    const PROPOSAL_ID: u64 = 1;
+
+    /// A second after the cards minted so far, open a three-option
+    /// proposal that runs for a week. Returns when it closes.
+    async fn open(ctx: &mut ProgramTestContext) -> i64 {
+        let payer = ctx.payer.insecure_clone();
+        let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+        let now = clock.unix_timestamp + 1;
+        warp_to(ctx, now).await;
+        let ends_at = now + 7 * DAY;
+        assert!(send(ctx, create_ix(payer.pubkey(), now, ends_at), &[&payer]).await);
+        ends_at
+    }
+
+    fn create_ix(authority: Pubkey, starts_at: i64, ends_at: i64) -> Instruction {
+        Instruction {
+            program_id: crypt::ID,
+            accounts: crypt::accounts::CreateProposal {
+                collection: collection_pda(&crypt::ID).0,
+                proposal: proposal_pda(&crypt::ID, PROPOSAL_ID).0,
+                authority,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: crypt::instruction::CreateProposal {
+                proposal_id: PROPOSAL_ID,
+                args: crypt::instructions::ProposalArgs {
+                    title: "SEASON 2 THEME".into(),
+                    options: vec!["RUGS".into(), "APES".into(), "BAGS".into()],
+                    starts_at,
+                    ends_at,
+                },
+            }
+            .data(),
+        }
+    }
+
+    /// Mint a card of `rarity` to `owner`.
+    async fn mint(ctx: &mut ProgramTestContext, owner: &Keypair, tx_hash: &str, rarity: u8) -> Pubkey {
+        let payer = ctx.payer.insecure_clone();
+        let args = crypt::instructions::MintCardArgs {
+            rarity,
+            card_type: 0,
+            title: "VOTER".into(),
+            pnl: "+1%".into(),
+            ..card_args(tx_hash)
+        };
+        assert!(send(ctx, mint_card_ix(payer.pubkey(), owner.pubkey(), args), &[&payer, owner]).await);
+        card_pda(&crypt::ID, tx_hash, &owner.pubkey()).0
+    }
+
+    fn vote_ix(voter: Pubkey, cards: &[Pubkey], option: u8) -> Instruction {
+        let proposal = proposal_pda(&crypt::ID, PROPOSAL_ID).0;
+        let mut accounts = crypt::accounts::CastVote {
+            proposal,
+            voter,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None);
+        for card in cards {
+            accounts.push(AccountMeta::new_readonly(*card, false));
+            accounts.push(AccountMeta::new(vote_record_pda(&crypt::ID, &proposal, card).0, false));
+        }
+        Instruction {
+            program_id: crypt::ID,
+            accounts,
+            data: crypt::instruction::CastVote { option }.data(),
+        }
+    }
+
+    fn finalize_ix() -> Instruction {
+        Instruction {
+            program_id: crypt::ID,
+            accounts: crypt::accounts::FinalizeProposal {
+                proposal: proposal_pda(&crypt::ID, PROPOSAL_ID).0,
+            }
+            .to_account_metas(None),
+            data: crypt::instruction::FinalizeProposal {}.data(),
+        }
+    }
+
+    async fn fetch_proposal(ctx: &mut ProgramTestContext) -> Proposal {
+        fetch(ctx, proposal_pda(&crypt::ID, PROPOSAL_ID).0).await
+    }
+
+    #[tokio::test]
+    async fn test_one_vote_per_card() {
+        let mut ctx = start().await;
+        let payer = ctx.payer.insecure_clone();
+        let alice = fan(&mut ctx).await;
+        let bob = fan(&mut ctx).await;
+
+        let common = mint(&mut ctx, &alice, "gov_common", 0).await;
+        let rare = mint(&mut ctx, &alice, "gov_rare", 1).await;
+        let legendary = mint(&mut ctx, &bob, "gov_legendary", 2).await;
+        let ends_at = open(&mut ctx).await;
+
+        assert!(send(&mut ctx, vote_ix(alice.pubkey(), &[common, rare], 0), &[&payer, &alice]).await);
+        assert!(send(&mut ctx, vote_ix(bob.pubkey(), &[legendary], 1), &[&payer, &bob]).await);
+
+        // Rarity is the minter's claim, so it doesn't weigh the vote
+        let proposal = fetch_proposal(&mut ctx).await;
+        assert_eq!(&proposal.tallies[..3], &[2, 1, 0]);
+        assert_eq!(proposal.cards_voted, 3);
+
+        // Too early to close
+        assert!(!send(&mut ctx, finalize_ix(), &[&payer]).await);
+
+        warp_to(&mut ctx, ends_at).await;
+        assert!(send(&mut ctx, finalize_ix(), &[&payer]).await);
+        let proposal = fetch_proposal(&mut ctx).await;
+        assert!(proposal.finalized);
+        assert_eq!(proposal.winning_option, Some(0));
+        assert!(!send(&mut ctx, finalize_ix(), &[&payer]).await, "Finalize runs once");
+    }
+
+    #[tokio::test]
+    async fn test_cards_minted_after_opening_cannot_vote() {
+        let mut ctx = start().await;
+        let payer = ctx.payer.insecure_clone();
+        let alice = fan(&mut ctx).await;
+        let old = mint(&mut ctx, &alice, "gov_old", 0).await;
+        let ends_at = open(&mut ctx).await;
+
+        // Minted in the same second the proposal opened
+        let fresh = mint(&mut ctx, &alice, "gov_fresh", 2).await;
+        assert!(!send(&mut ctx, vote_ix(alice.pubkey(), &[fresh], 0), &[&payer, &alice]).await);
+        // One fresh card fails the whole vote
+        assert!(!send(&mut ctx, vote_ix(alice.pubkey(), &[old, fresh], 0), &[&payer, &alice]).await);
+
+        warp_to(&mut ctx, ends_at - DAY).await;
+        let later = mint(&mut ctx, &alice, "gov_later", 2).await;
+        assert!(!send(&mut ctx, vote_ix(alice.pubkey(), &[later], 0), &[&payer, &alice]).await);
+
+        assert!(send(&mut ctx, vote_ix(alice.pubkey(), &[old], 0), &[&payer, &alice]).await);
+        assert_eq!(&fetch_proposal(&mut ctx).await.tallies[..3], &[1, 0, 0]);
+    }
+
+    #[tokio::test]
+    async fn test_card_votes_once_across_transfers() {
+        let mut ctx = start().await;
+        let payer = ctx.payer.insecure_clone();
+        let alice = fan(&mut ctx).await;
+        let bob = fan(&mut ctx).await;
+        let card = mint(&mut ctx, &alice, "gov_flip", 2).await;
+        open(&mut ctx).await;
+
+        assert!(send(&mut ctx, vote_ix(alice.pubkey(), &[card], 0), &[&payer, &alice]).await);
+        assert!(!send(&mut ctx, vote_ix(alice.pubkey(), &[card], 1), &[&payer, &alice]).await);
+
+        let transfer = Instruction {
+            program_id: crypt::ID,
+            accounts: crypt::accounts::TransferCard {
+                card,
+                current_owner: alice.pubkey(),
+                new_owner: bob.pubkey(),
+            }
+            .to_account_metas(None),
+            data: crypt::instruction::TransferCard { card_id: 0 }.data(),
+        };
+        assert!(send(&mut ctx, transfer, &[&payer, &alice]).await);
+
+        assert!(
+            !send(&mut ctx, vote_ix(bob.pubkey(), &[card], 1), &[&payer, &bob]).await,
+            "The new owner must not vote with a card that already voted"
+        );
+        // The old owner no longer holds it either
+        assert!(!send(&mut ctx, vote_ix(alice.pubkey(), &[card], 1), &[&payer, &alice]).await);
+
+        let proposal = fetch_proposal(&mut ctx).await;
+        assert_eq!(&proposal.tallies[..3], &[1, 0, 0]);
+    }
+
+    #[tokio::test]
+    async fn test_vote_rejections() {
+        let mut ctx = start().await;
+        let payer = ctx.payer.insecure_clone();
+        let alice = fan(&mut ctx).await;
+        let mallory = fan(&mut ctx).await;
+        let card = mint(&mut ctx, &alice, "gov_guard", 0).await;
+        open(&mut ctx).await;
+
+        // Someone else's card
+        assert!(!send(&mut ctx, vote_ix(mallory.pubkey(), &[card], 0), &[&payer, &mallory]).await);
+        // The same card twice in one vote
+        assert!(!send(&mut ctx, vote_ix(alice.pubkey(), &[card, card], 0), &[&payer, &alice]).await);
+        // Option out of range
+        assert!(!send(&mut ctx, vote_ix(alice.pubkey(), &[card], 3), &[&payer, &alice]).await);
+        // No cards at all
+        assert!(!send(&mut ctx, vote_ix(alice.pubkey(), &[], 0), &[&payer, &alice]).await);
+
+        assert!(send(&mut ctx, vote_ix(alice.pubkey(), &[card], 2), &[&payer, &alice]).await);
+    }
+
+    #[tokio::test]
+    async fn test_lent_card_cannot_vote() {
+        let mut ctx = start().await;
+        let payer = ctx.payer.insecure_clone();
+        let alice = fan(&mut ctx).await;
+        let borrower = fan(&mut ctx).await;
+        let card = mint(&mut ctx, &alice, "gov_lent", 2).await;
+        open(&mut ctx).await;
+
+        let lend = Instruction {
+            program_id: crypt::ID,
+            accounts: crypt::accounts::LendCard {
+                card,
+                owner: alice.pubkey(),
+                user: borrower.pubkey(),
+            }
+            .to_account_metas(None),
+            data: crypt::instruction::LendCard { card_id: 0, duration: DAY }.data(),
+        };
+        assert!(send(&mut ctx, lend, &[&payer, &alice]).await);
+
+        // Neither the owner nor the borrower can vote with it during the loan
+        assert!(!send(&mut ctx, vote_ix(alice.pubkey(), &[card], 0), &[&payer, &alice]).await);
+        assert!(!send(&mut ctx, vote_ix(borrower.pubkey(), &[card], 0), &[&payer, &borrower]).await);
+
+        // Once the loan runs out the owner votes as usual
+        let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+        warp_to(&mut ctx, clock.unix_timestamp + DAY).await;
+        assert!(send(&mut ctx, vote_ix(alice.pubkey(), &[card], 0), &[&payer, &alice]).await);
+        assert_eq!(fetch_proposal(&mut ctx).await.tallies[0], 1);
+    }
+
+    #[tokio::test]
+    async fn test_only_authority_opens_proposals() {
+        let mut ctx = start().await;
+        let payer = ctx.payer.insecure_clone();
+
+        let stranger = fan(&mut ctx).await;
+        let clock: Clock = ctx.banks_client.get_sysvar().await.unwrap();
+        let now = clock.unix_timestamp;
+        let ix = create_ix(stranger.pubkey(), now, now + DAY);
+        assert!(!send(&mut ctx, ix, &[&payer, &stranger]).await);
+
+        // Windows longer than 30 days are refused
+        let ix = create_ix(payer.pubkey(), now, now + 31 * DAY);
+        assert!(!send(&mut ctx, ix, &[&payer]).await);
+    }
+}
@@ -0,0 +1,246 @@
+//! Tests for card lending — runs the program under program-test and
+//! warps the clock past loan expiry.
+//!
+//! Loads the BPF build from target/deploy, so run `anchor build` first.
+
+#[cfg(test)]
+mod tests {
+    use crate::helpers::*;
+    use anchor_lang::{InstructionData, ToAccountMetas};
+    use crypt::utils::{MAX_LOAN_DURATION, MIN_LOAN_DURATION};
+    use solana_program_test::ProgramTestContext;
+    use solana_sdk::{
+        instruction::{AccountMeta, Instruction},
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        system_instruction, system_program,
+    };
+
+    // Card PDA seeds cap at 32 bytes, so keep test hashes short
+    const TX_HASH: &str = "lend_test_tx_1";
+
+    /// Initialize the collection and mint one Rare card to the payer.
+    async fn setup() -> (ProgramTestContext, Pubkey) {
+        let mut ctx = start().await;
+        let payer = ctx.payer.insecure_clone();
+        let mint = mint_card_ix(payer.pubkey(), payer.pubkey(), card_args(TX_HASH));
+        assert!(send(&mut ctx, mint, &[&payer]).await);
+        (ctx, card_pda(&crypt::ID, TX_HASH, &payer.pubkey()).0)
+    }
+
+    fn lend_ix(card: Pubkey, owner: Pubkey, user: Pubkey, duration: i64) -> Instruction {
+        Instruction {
+            program_id: crypt::ID,
+            accounts: crypt::accounts::LendCard { card, owner, user }.to_account_metas(None),
+            data: crypt::instruction::LendCard { card_id: 0, duration }.data(),
+        }
+    }
+
+    fn return_ix(card: Pubkey, user: Pubkey) -> Instruction {
+        Instruction {
+            program_id: crypt::ID,
+            accounts: crypt::accounts::ReturnCard { card, user }.to_account_metas(None),
+            data: crypt::instruction::ReturnCard { card_id: 0 }.data(),
+        }
+    }
+
+    fn reclaim_ix(card: Pubkey) -> Instruction {
+        Instruction {
+            program_id: crypt::ID,
+            accounts: crypt::accounts::ReclaimCard { card }.to_account_metas(None),
+            data: crypt::instruction::ReclaimCard { card_id: 0 }.data(),
+        }
+    }
+
+    fn transfer_ix(card: Pubkey, owner: Pubkey, new_owner: Pubkey) -> Instruction {
+        Instruction {
+            program_id: crypt::ID,
+            accounts: crypt::accounts::TransferCard {
+                card,
+                current_owner: owner,
+                new_owner,
+            }
+            .to_account_metas(None),
+            data: crypt::instruction::TransferCard { card_id: 0 }.data(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_lend_keeps_owner_and_sets_user() {
+        let (mut ctx, card) = setup().await;
+        let payer = ctx.payer.insecure_clone();
+        let borrower = Pubkey::new_unique();
+
+        assert!(send(&mut ctx, lend_ix(card, payer.pubkey(), borrower, 3 * DAY), &[&payer]).await);
+
+        let lent = fetch_card(&mut ctx, card).await;
+        assert_eq!(lent.owner, payer.pubkey());
+        assert_eq!(lent.user, borrower);
+        assert!(lent.user_expires_at > 0);
+    }
+
+    #[tokio::test]
+    async fn test_lent_card_cannot_transfer_burn_or_upgrade() {
+        let (mut ctx, card) = setup().await;
+        let payer = ctx.payer.insecure_clone();
+        assert!(send(&mut ctx, lend_ix(card, payer.pubkey(), Pubkey::new_unique(), DAY), &[&payer]).await);
+
+        let transfer = transfer_ix(card, payer.pubkey(), Pubkey::new_unique());
+        assert!(!send(&mut ctx, transfer, &[&payer]).await, "Lent card must not transfer");
+
+        let burn = Instruction {
+            program_id: crypt::ID,
+            accounts: crypt::accounts::BurnCard {
+                card,
+                owner: payer.pubkey(),
+            }
+            .to_account_metas(None),
+            data: crypt::instruction::BurnCard { card_id: 0 }.data(),
+        };
+        assert!(!send(&mut ctx, burn, &[&payer]).await, "Lent card must not burn");
+
+        let card_data = fetch_card(&mut ctx, card).await;
+        let proof = upgrade_proof(&card_data.tx_hash, &card_data.soul_seed, 2);
+        let upgrade = Instruction {
+            program_id: crypt::ID,
+            accounts: crypt::accounts::UpgradeRarity {
+                card,
+                owner: payer.pubkey(),
+            }
+            .to_account_metas(None),
+            data: crypt::instruction::UpgradeRarity {
+                card_id: 0,
+                new_rarity: 2,
+                proof,
+            }
+            .data(),
+        };
+        assert!(!send(&mut ctx, upgrade, &[&payer]).await, "Lent card must not upgrade");
+    }
+
+    #[tokio::test]
+    async fn test_borrower_can_return_early() {
+        let (mut ctx, card) = setup().await;
+        let payer = ctx.payer.insecure_clone();
+        let borrower = Keypair::new();
+        assert!(send(&mut ctx, lend_ix(card, payer.pubkey(), borrower.pubkey(), DAY), &[&payer]).await);
+
+        assert!(send(&mut ctx, return_ix(card, borrower.pubkey()), &[&payer, &borrower]).await);
+
+        let returned = fetch_card(&mut ctx, card).await;
+        assert_eq!(returned.user, Pubkey::default());
+        assert_eq!(returned.user_expires_at, 0);
+        assert!(send(&mut ctx, transfer_ix(card, payer.pubkey(), Pubkey::new_unique()), &[&payer]).await);
+    }
+
+    #[tokio::test]
+    async fn test_only_borrower_can_return() {
+        let (mut ctx, card) = setup().await;
+        let payer = ctx.payer.insecure_clone();
+        assert!(send(&mut ctx, lend_ix(card, payer.pubkey(), Pubkey::new_unique(), DAY), &[&payer]).await);
+
+        let stranger = Keypair::new();
+        assert!(!send(&mut ctx, return_ix(card, stranger.pubkey()), &[&payer, &stranger]).await);
+    }
+
+    #[tokio::test]
+    async fn test_reclaim_only_after_expiry() {
+        let (mut ctx, card) = setup().await;
+        let payer = ctx.payer.insecure_clone();
+        assert!(send(&mut ctx, lend_ix(card, payer.pubkey(), Pubkey::new_unique(), 2 * DAY), &[&payer]).await);
+
+        assert!(!send(&mut ctx, reclaim_ix(card), &[&payer]).await, "Loan still active");
+
+        warp_days(&mut ctx, 2).await;
+        assert!(send(&mut ctx, reclaim_ix(card), &[&payer]).await);
+
+        let reclaimed = fetch_card(&mut ctx, card).await;
+        assert_eq!(reclaimed.user, Pubkey::default());
+        assert_eq!(reclaimed.owner, payer.pubkey());
+    }
+
+    #[tokio::test]
+    async fn test_expired_loan_does_not_block_transfer() {
+        let (mut ctx, card) = setup().await;
+        let payer = ctx.payer.insecure_clone();
+        let new_owner = Pubkey::new_unique();
+        assert!(send(&mut ctx, lend_ix(card, payer.pubkey(), Pubkey::new_unique(), DAY), &[&payer]).await);
+
+        warp_days(&mut ctx, 1).await;
+        assert!(send(&mut ctx, transfer_ix(card, payer.pubkey(), new_owner), &[&payer]).await);
+
+        let transferred = fetch_card(&mut ctx, card).await;
+        assert_eq!(transferred.owner, new_owner);
+        assert_eq!(transferred.user, Pubkey::default(), "Stale loan cleared on transfer");
+    }
+
+    #[tokio::test]
+    async fn test_lent_card_cannot_claim_badge() {
+        let (mut ctx, card) = setup().await;
+        let payer = ctx.payer.insecure_clone();
+        let (achievement, _) = achievement_pda(&crypt::ID, 1);
+
+        // One Rare or better completes the set
+        let create = Instruction {
+            program_id: crypt::ID,
+            accounts: crypt::accounts::CreateAchievement {
+                collection: collection_pda(&crypt::ID).0,
+                achievement,
+                authority: payer.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: crypt::instruction::CreateAchievement {
+                args: crypt::instructions::CreateAchievementArgs {
+                    set_id: 1,
+                    name: "FIRST RARE".into(),
+                    min_per_type: [0; 5],
+                    min_per_rarity: [0, 1, 0],
+                },
+            }
+            .data(),
+        };
+        assert!(send(&mut ctx, create, &[&payer]).await);
+
+        let claim = |claimer: Pubkey| {
+            let mut accounts = crypt::accounts::ClaimAchievement {
+                achievement,
+                badge: badge_pda(&crypt::ID, &achievement, &claimer).0,
+                owner: claimer,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None);
+            accounts.push(AccountMeta::new_readonly(card, false));
+            accounts.push(AccountMeta::new(achievement_card_pda(&crypt::ID, &achievement, &card).0, false));
+            Instruction {
+                program_id: crypt::ID,
+                accounts,
+                data: crypt::instruction::ClaimAchievement {}.data(),
+            }
+        };
+
+        let borrower = Keypair::new();
+        let fund = system_instruction::transfer(&payer.pubkey(), &borrower.pubkey(), 100_000_000);
+        assert!(send(&mut ctx, fund, &[&payer]).await);
+        assert!(send(&mut ctx, lend_ix(card, payer.pubkey(), borrower.pubkey(), DAY), &[&payer]).await);
+        assert!(!send(&mut ctx, claim(payer.pubkey()), &[&payer]).await, "Owner can't claim with a lent card");
+        assert!(!send(&mut ctx, claim(borrower.pubkey()), &[&payer, &borrower]).await, "Nor can the borrower");
+
+        warp_days(&mut ctx, 1).await;
+        assert!(send(&mut ctx, claim(payer.pubkey()), &[&payer]).await);
+    }
+
+    #[tokio::test]
+    async fn test_lend_rejects_bad_duration_and_self() {
+        let (mut ctx, card) = setup().await;
+        let payer = ctx.payer.insecure_clone();
+        let borrower = Pubkey::new_unique();
+
+        let too_short = lend_ix(card, payer.pubkey(), borrower, MIN_LOAN_DURATION - 1);
+        assert!(!send(&mut ctx, too_short, &[&payer]).await);
+        let too_long = lend_ix(card, payer.pubkey(), borrower, MAX_LOAN_DURATION + 1);
+        assert!(!send(&mut ctx, too_long, &[&payer]).await);
+        let to_self = lend_ix(card, payer.pubkey(), payer.pubkey(), DAY);
+        assert!(!send(&mut ctx, to_self, &[&payer]).await);
+    }
+}
@@ -0,0 +1,128 @@
+//! Tests for owner metadata edits and their guard rails.
+//!
+//! The program-test cases load the BPF build from target/deploy, so run
+//! `anchor build` first.
+
+#[cfg(test)]
+mod tests {
+    use crate::helpers::*;
+    use anchor_lang::{AccountDeserialize, InstructionData, ToAccountMetas};
+    use crypt::state::CryptCard;
+    use crypt::utils::{
+        can_edit_metadata, metadata_hash, validate_metadata_update,
+        MAX_METADATA_EDITS, METADATA_EDIT_WINDOW,
+    };
+    use solana_program_test::ProgramTestContext;
+    use solana_sdk::{
+        instruction::Instruction,
+        pubkey::Pubkey,
+        signature::Signer,
+    };
+
+    const MINTED_AT: i64 = 1_700_000_000;
+
+    #[test]
+    fn test_fresh_card_editable() {
+        assert!(can_edit_metadata(MINTED_AT, MINTED_AT + 1, 0));
+    }
+
+    #[test]
+    fn test_window_edits_not_capped() {
+        let inside = MINTED_AT + METADATA_EDIT_WINDOW - 1;
+        assert!(can_edit_metadata(MINTED_AT, inside, MAX_METADATA_EDITS + 5));
+    }
+
+    #[test]
+    fn test_old_card_limited_edits() {
+        let after = MINTED_AT + METADATA_EDIT_WINDOW + 86_400;
+        for used in 0..MAX_METADATA_EDITS {
+            assert!(can_edit_metadata(MINTED_AT, after, used));
+        }
+        assert!(!can_edit_metadata(MINTED_AT, after, MAX_METADATA_EDITS));
+    }
+
+    #[test]
+    fn test_same_limits_as_minting() {
+        assert!(validate_metadata_update(Some(&"X".repeat(100)), None).is_ok());
+        assert!(validate_metadata_update(Some(&"X".repeat(101)), None).is_err());
+        assert!(validate_metadata_update(None, Some(&"1".repeat(32))).is_ok());
+        assert!(validate_metadata_update(None, Some(&"1".repeat(33))).is_err());
+    }
+
+    #[test]
+    fn test_replacing_taken_down_track_changes_hash() {
+        let narration = [7u8; 32];
+        let before = metadata_hash("WHALE MOVE", &narration, "audius_123");
+        let after = metadata_hash("WHALE MOVE", &narration, "audius_456");
+        assert_ne!(before, after, "Event hashes must reveal a soundtrack swap");
+    }
+
+    // Card PDA seeds cap at 32 bytes, so keep test hashes short
+    const TX_HASH: &str = "meta_test_tx_1";
+    const LONG_TITLE: &str = "A VERY LONG TITLE FOR A VERY LONG WHALE MOVE";
+
+    /// Initialize the collection and mint one card with a long title.
+    async fn setup() -> (ProgramTestContext, Pubkey) {
+        let mut ctx = start().await;
+        let payer = ctx.payer.insecure_clone();
+        let args = crypt::instructions::MintCardArgs {
+            title: LONG_TITLE.into(),
+            soundtrack_id: "audius_123".into(),
+            ..card_args(TX_HASH)
+        };
+        assert!(send(&mut ctx, mint_card_ix(payer.pubkey(), payer.pubkey(), args), &[&payer]).await);
+        (ctx, card_pda(&crypt::ID, TX_HASH, &payer.pubkey()).0)
+    }
+
+    fn rename_ix(card: Pubkey, owner: Pubkey, title: &str) -> Instruction {
+        Instruction {
+            program_id: crypt::ID,
+            accounts: crypt::accounts::UpdateCardMetadata {
+                collection: collection_pda(&crypt::ID).0,
+                card,
+                owner,
+            }
+            .to_account_metas(None),
+            data: crypt::instruction::UpdateCardMetadata {
+                card_id: 0,
+                args: crypt::instructions::UpdateCardMetadataArgs {
+                    title: Some(title.into()),
+                    narration_hash: None,
+                    soundtrack_id: None,
+                },
+            }
+            .data(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_shorter_title_leaves_no_stale_bytes() {
+        let (mut ctx, card) = setup().await;
+        let payer = ctx.payer.insecure_clone();
+
+        assert!(send(&mut ctx, rename_ix(card, payer.pubkey(), "WHALE"), &[&payer]).await);
+
+        let account = ctx.banks_client.get_account(card).await.unwrap().unwrap();
+        let renamed = CryptCard::try_deserialize(&mut account.data.as_slice()).unwrap();
+        assert_eq!(renamed.title, "WHALE");
+        assert_eq!(renamed.soundtrack_id, "audius_123");
+
+        let mut written = Vec::new();
+        anchor_lang::AccountSerialize::try_serialize(&renamed, &mut written).unwrap();
+        assert!(account.data[written.len()..].iter().all(|&b| b == 0));
+    }
+
+    #[tokio::test]
+    async fn test_edits_inside_window_are_not_counted() {
+        let (mut ctx, card) = setup().await;
+        let payer = ctx.payer.insecure_clone();
+
+        for title in ["ONE", "TWO", "THREE", "FOUR"] {
+            assert!(send(&mut ctx, rename_ix(card, payer.pubkey(), title), &[&payer]).await);
+        }
+
+        let edited = fetch_card(&mut ctx, card).await;
+        assert_eq!(edited.title, "FOUR");
+        assert_eq!(edited.metadata_edits, 0);
+    }
+}
@@ -0,0 +1,298 @@
+//! Tests for card and collection layout versioning, `migrate_card` and
+//! `migrate_collection`.
+//!
+//! Loads the BPF build from target/deploy, so run `anchor build` first.
+
+#[cfg(test)]
+mod tests {
+    use crate::helpers::*;
+    use anchor_lang::{AccountDeserialize, AccountSerialize, InstructionData, ToAccountMetas};
+    use crypt::state::{Collection, CryptCard, CARD_VERSION, COLLECTION_VERSION};
+    use solana_program_test::{ProgramTest, ProgramTestContext};
+    use solana_sdk::{
+        account::Account,
+        instruction::Instruction,
+        pubkey::Pubkey,
+        rent::Rent,
+        signature::{Keypair, Signer},
+        system_instruction, system_program,
+        transaction::Transaction,
+    };
+
+    fn legacy_card(owner: Pubkey) -> CryptCard {
+        let tx_hash = mock_tx_hash(29);
+        CryptCard {
+            owner,
+            mint_id: 29,
+            soul_seed: compute_soul_seed(&tx_hash),
+            tx_hash,
+            rarity: 1,
+            card_type: 0,
+            title: "420 SOL → BONK".into(),
+            narration_hash: mock_narration_hash("aped"),
+            platform: "JUPITER".into(),
+            pnl: "+4,200%".into(),
+            tx_timestamp: 1_700_000_000,
+            minted_at: 1_700_000_100,
+            interaction_count: 3,
+            soundtrack_id: String::new(),
+            bump: 254,
+            version: 0,
+            metadata_edits: 0,
+            user: Pubkey::default(),
+            user_expires_at: 0,
+            boost_event: 0,
+            scoring_version: 0,
+        }
+    }
+
+    /// Serialize a card the way the version 0 program did: no fields past
+    /// `bump`, account sized to the old layout.
+    fn legacy_account(card: &CryptCard) -> Account {
+        let mut data = Vec::new();
+        card.try_serialize(&mut data).unwrap();
+        data.truncate(data.len() - (CryptCard::SIZE - CryptCard::LEGACY_SIZE));
+        data.resize(8 + CryptCard::LEGACY_SIZE, 0);
+        Account {
+            lamports: Rent::default().minimum_balance(data.len()),
+            data,
+            owner: crypt::ID,
+            executable: false,
+            rent_epoch: 0,
+        }
+    }
+
+    async fn start_with_legacy_card() -> (ProgramTestContext, Pubkey, CryptCard) {
+        let mut program = ProgramTest::new("crypt", crypt::ID, None);
+        let card = legacy_card(Pubkey::new_unique());
+        let address = Pubkey::new_unique();
+        program.add_account(address, legacy_account(&card));
+        (program.start_with_context().await, address, card)
+    }
+
+    async fn migrate(ctx: &mut ProgramTestContext, card: Pubkey, payer: &Keypair) -> bool {
+        let ix = Instruction {
+            program_id: crypt::ID,
+            accounts: crypt::accounts::MigrateCard {
+                card,
+                payer: payer.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: crypt::instruction::MigrateCard {}.data(),
+        };
+        let blockhash = ctx.banks_client.get_latest_blockhash().await.unwrap();
+        let tx = Transaction::new_signed_with_payer(&[ix], Some(&payer.pubkey()), &[payer], blockhash);
+        ctx.banks_client.process_transaction(tx).await.is_ok()
+    }
+
+    #[test]
+    fn test_legacy_size_excludes_appended_fields() {
+        // version + metadata_edits + user + user_expires_at + boost_event + scoring_version
+        assert_eq!(CryptCard::SIZE - CryptCard::LEGACY_SIZE, 1 + 1 + 32 + 8 + 2 + 2);
+    }
+
+    #[tokio::test]
+    async fn test_migrate_grows_account_and_stamps_version() {
+        let (mut ctx, address, original) = start_with_legacy_card().await;
+        let payer = ctx.payer.insecure_clone();
+
+        assert!(migrate(&mut ctx, address, &payer).await);
+
+        let account = ctx.banks_client.get_account(address).await.unwrap().unwrap();
+        assert_eq!(account.data.len(), 8 + CryptCard::SIZE);
+        assert!(account.lamports >= Rent::default().minimum_balance(account.data.len()));
+
+        let card = CryptCard::try_deserialize(&mut account.data.as_slice()).unwrap();
+        assert_eq!(card.version, CARD_VERSION);
+        assert_eq!(card.metadata_edits, 0);
+        assert_eq!(card.user, Pubkey::default());
+        assert_eq!(card.scoring_version, 0, "Cards scored before v5 stay unknown");
+        assert_eq!(card.owner, original.owner);
+        assert_eq!(card.tx_hash, original.tx_hash);
+        assert_eq!(card.soul_seed, original.soul_seed);
+        assert_eq!(card.interaction_count, original.interaction_count);
+    }
+
+    #[tokio::test]
+    async fn test_migrate_is_permissionless() {
+        // The payer is not the card owner
+        let (mut ctx, address, original) = start_with_legacy_card().await;
+        let payer = ctx.payer.insecure_clone();
+        assert_ne!(payer.pubkey(), original.owner);
+        assert!(migrate(&mut ctx, address, &payer).await);
+    }
+
+    #[tokio::test]
+    async fn test_migrate_twice_fails() {
+        let (mut ctx, address, _) = start_with_legacy_card().await;
+        let payer = ctx.payer.insecure_clone();
+        assert!(migrate(&mut ctx, address, &payer).await);
+        assert!(!migrate(&mut ctx, address, &payer).await, "Current cards have nothing to migrate");
+    }
+
+    #[tokio::test]
+    async fn test_migrate_rejects_non_card_accounts() {
+        let mut program = ProgramTest::new("crypt", crypt::ID, None);
+        let address = Pubkey::new_unique();
+        program.add_account(address, Account {
+            lamports: 1_000_000_000,
+            data: vec![7u8; 8 + CryptCard::LEGACY_SIZE],
+            owner: crypt::ID,
+            executable: false,
+            rent_epoch: 0,
+        });
+        let mut ctx = program.start_with_context().await;
+        let payer = ctx.payer.insecure_clone();
+        assert!(!migrate(&mut ctx, address, &payer).await);
+    }
+
+    fn legacy_collection(uri_len: usize) -> Collection {
+        Collection {
+            authority: Pubkey::new_unique(),
+            total_minted: 42,
+            max_supply: 0,
+            uri: "u".repeat(uri_len),
+            mint_fee: 0,
+            treasury: Pubkey::new_unique(),
+            paused: false,
+            created_at: 1_700_000_000,
+            bump: 255,
+            version: 0,
+            metadata_locked: false,
+            moderator: Pubkey::default(),
+        }
+    }
+
+    /// Serialize a collection the way the version 0 program did, with
+    /// leftover bytes past `bump` like a uri that was later shortened.
+    fn legacy_collection_account(collection: &Collection) -> Account {
+        let mut data = Vec::new();
+        collection.try_serialize(&mut data).unwrap();
+        data.truncate(data.len() - (Collection::SIZE - Collection::LEGACY_SIZE));
+        data.resize(8 + Collection::LEGACY_SIZE, 0xff);
+        Account {
+            lamports: Rent::default().minimum_balance(data.len()),
+            data,
+            owner: crypt::ID,
+            executable: false,
+            rent_epoch: 0,
+        }
+    }
+
+    async fn migrate_collection(ctx: &mut ProgramTestContext, payer: &Keypair) -> bool {
+        let ix = Instruction {
+            program_id: crypt::ID,
+            accounts: crypt::accounts::MigrateCollection {
+                collection: collection_pda(&crypt::ID).0,
+                payer: payer.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: crypt::instruction::MigrateCollection {}.data(),
+        };
+        let blockhash = ctx.banks_client.get_latest_blockhash().await.unwrap();
+        let tx = Transaction::new_signed_with_payer(&[ix], Some(&payer.pubkey()), &[payer], blockhash);
+        ctx.banks_client.process_transaction(tx).await.is_ok()
+    }
+
+    #[test]
+    fn test_collection_legacy_size_excludes_appended_fields() {
+        // version + metadata_locked + moderator
+        assert_eq!(Collection::SIZE - Collection::LEGACY_SIZE, 1 + 1 + 32);
+    }
+
+    #[tokio::test]
+    async fn test_migrate_collection_with_long_uri() {
+        // 190 bytes of uri leaves too little padding for the appended fields
+        let original = legacy_collection(190);
+        let mut program = ProgramTest::new("crypt", crypt::ID, None);
+        program.add_account(collection_pda(&crypt::ID).0, legacy_collection_account(&original));
+        let mut ctx = program.start_with_context().await;
+        let payer = ctx.payer.insecure_clone();
+
+        assert!(migrate_collection(&mut ctx, &payer).await);
+
+        let address = collection_pda(&crypt::ID).0;
+        let account = ctx.banks_client.get_account(address).await.unwrap().unwrap();
+        assert_eq!(account.data.len(), 8 + Collection::SIZE);
+        let collection = Collection::try_deserialize(&mut account.data.as_slice()).unwrap();
+        assert_eq!(collection.version, COLLECTION_VERSION);
+        assert!(!collection.metadata_locked, "Leftover bytes are not read back");
+        assert_eq!(collection.moderator, Pubkey::default());
+        assert_eq!(collection.uri, original.uri);
+        assert_eq!(collection.authority, original.authority);
+        assert_eq!(collection.total_minted, original.total_minted);
+
+        assert!(!migrate_collection(&mut ctx, &payer).await, "Already on the current layout");
+    }
+
+    async fn hide(ctx: &mut ProgramTestContext, card: Pubkey, moderator: &Keypair) -> bool {
+        let ix = Instruction {
+            program_id: crypt::ID,
+            accounts: crypt::accounts::SetHidden {
+                collection: collection_pda(&crypt::ID).0,
+                moderation: moderation_pda(&crypt::ID, &card).0,
+                target: card,
+                card,
+                moderator: moderator.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: crypt::instruction::SetHidden { hidden: true, reason: 1 }.data(),
+        };
+        let payer = ctx.payer.insecure_clone();
+        let blockhash = ctx.banks_client.get_latest_blockhash().await.unwrap();
+        let tx = Transaction::new_signed_with_payer(&[ix], Some(&payer.pubkey()), &[&payer, moderator], blockhash);
+        ctx.banks_client.process_transaction(tx).await.is_ok()
+    }
+
+    #[tokio::test]
+    async fn test_moderation_waits_for_collection_migration() {
+        let authority = Keypair::new();
+        let intruder = Keypair::new();
+
+        // A v0 collection whose uri padding happens to hold a key where
+        // the moderator field now sits
+        let mut original = legacy_collection(20);
+        original.authority = authority.pubkey();
+        original.moderator = intruder.pubkey();
+        original.bump = collection_pda(&crypt::ID).1;
+        let mut data = Vec::new();
+        original.try_serialize(&mut data).unwrap();
+        data.resize(8 + Collection::LEGACY_SIZE, 0);
+
+        let mut card = legacy_card(authority.pubkey());
+        card.version = CARD_VERSION;
+        let mut card_data = Vec::new();
+        card.try_serialize(&mut card_data).unwrap();
+        card_data.resize(8 + CryptCard::SIZE, 0);
+        let card_address = Pubkey::new_unique();
+
+        let mut program = ProgramTest::new("crypt", crypt::ID, None);
+        for (address, data) in [(collection_pda(&crypt::ID).0, data), (card_address, card_data)] {
+            program.add_account(address, Account {
+                lamports: Rent::default().minimum_balance(data.len()),
+                data,
+                owner: crypt::ID,
+                executable: false,
+                rent_epoch: 0,
+            });
+        }
+        let mut ctx = program.start_with_context().await;
+        let payer = ctx.payer.insecure_clone();
+        for wallet in [&authority, &intruder] {
+            let fund = system_instruction::transfer(&payer.pubkey(), &wallet.pubkey(), 1_000_000_000);
+            let blockhash = ctx.banks_client.get_latest_blockhash().await.unwrap();
+            let tx = Transaction::new_signed_with_payer(&[fund], Some(&payer.pubkey()), &[&payer], blockhash);
+            ctx.banks_client.process_transaction(tx).await.unwrap();
+        }
+
+        assert!(!hide(&mut ctx, card_address, &intruder).await, "Stale bytes must not grant moderation");
+        assert!(!hide(&mut ctx, card_address, &authority).await, "Needs migration first");
+
+        assert!(migrate_collection(&mut ctx, &payer).await);
+        assert!(!hide(&mut ctx, card_address, &intruder).await);
+        assert!(hide(&mut ctx, card_address, &authority).await);
+    }
+}
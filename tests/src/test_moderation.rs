@@ -0,0 +1,188 @@
+//! Tests for content reports and moderation — reporting cards and
+//! comments, and hiding them as the collection moderator.
+//!
+//! Loads the BPF build from target/deploy, so run `anchor build` first.
+
+#[cfg(test)]
+mod tests {
+    use crate::helpers::*;
+    use anchor_lang::{InstructionData, ToAccountMetas};
+    use crypt::state::Moderation;
+    use solana_program_test::ProgramTestContext;
+    use solana_sdk::{
+        instruction::Instruction,
+        pubkey::Pubkey,
+        signature::Signer,
+        system_program,
+    };
+
+    const COMMENT: u8 = 1;
+    const SCAM: u8 = 1;
+    const HATE: u8 = 2;
+
+    const TX_HASH: &str = "mod_test_tx_1";
+
+    /// Initialize the collection and mint one card to the payer.
+    async fn setup() -> (ProgramTestContext, Pubkey) {
+        let mut ctx = start().await;
+        let payer = ctx.payer.insecure_clone();
+        let args = crypt::instructions::MintCardArgs {
+            rarity: 0,
+            card_type: 0,
+            title: "APED IN".into(),
+            pnl: "+69%".into(),
+            ..card_args(TX_HASH)
+        };
+        assert!(send(&mut ctx, mint_card_ix(payer.pubkey(), payer.pubkey(), args), &[&payer]).await);
+        (ctx, card_pda(&crypt::ID, TX_HASH, &payer.pubkey()).0)
+    }
+
+    fn interact_ix(card: Pubkey, user: Pubkey, interaction_type: u8) -> Instruction {
+        Instruction {
+            program_id: crypt::ID,
+            accounts: crypt::accounts::Interact {
+                interaction: interaction_pda(&crypt::ID, &card, &user).0,
+                card,
+                user,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: crypt::instruction::Interact {
+                args: crypt::instructions::InteractArgs {
+                    interaction_type,
+                    comment_hash: Some(mock_narration_hash("gm")),
+                },
+            }
+            .data(),
+        }
+    }
+
+    fn report_ix(target: Pubkey, card: Pubkey, reporter: Pubkey, reason: u8) -> Instruction {
+        Instruction {
+            program_id: crypt::ID,
+            accounts: crypt::accounts::ReportContent {
+                report: report_pda(&crypt::ID, &target, &reporter).0,
+                moderation: moderation_pda(&crypt::ID, &target).0,
+                target,
+                card,
+                reporter,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: crypt::instruction::ReportContent { reason }.data(),
+        }
+    }
+
+    fn hide_ix(target: Pubkey, card: Pubkey, moderator: Pubkey, hidden: bool) -> Instruction {
+        Instruction {
+            program_id: crypt::ID,
+            accounts: crypt::accounts::SetHidden {
+                collection: collection_pda(&crypt::ID).0,
+                moderation: moderation_pda(&crypt::ID, &target).0,
+                target,
+                card,
+                moderator,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: crypt::instruction::SetHidden { hidden, reason: HATE }.data(),
+        }
+    }
+
+    fn set_moderator_ix(authority: Pubkey, moderator: Pubkey) -> Instruction {
+        Instruction {
+            program_id: crypt::ID,
+            accounts: crypt::accounts::UpdateCollection {
+                collection: collection_pda(&crypt::ID).0,
+                authority,
+            }
+            .to_account_metas(None),
+            data: crypt::instruction::UpdateCollection {
+                args: crypt::instructions::UpdateCollectionArgs {
+                    uri: None,
+                    max_supply: None,
+                    mint_fee: None,
+                    paused: None,
+                    treasury: None,
+                    metadata_locked: None,
+                    moderator: Some(moderator),
+                },
+            }
+            .data(),
+        }
+    }
+
+    async fn fetch_moderation(ctx: &mut ProgramTestContext, target: Pubkey) -> Moderation {
+        fetch(ctx, moderation_pda(&crypt::ID, &target).0).await
+    }
+
+    #[tokio::test]
+    async fn test_report_card_once_per_wallet() {
+        let (mut ctx, card) = setup().await;
+        let payer = ctx.payer.insecure_clone();
+        let alice = fan(&mut ctx).await;
+        let bob = fan(&mut ctx).await;
+
+        assert!(send(&mut ctx, report_ix(card, card, alice.pubkey(), SCAM), &[&payer, &alice]).await);
+        assert!(send(&mut ctx, report_ix(card, card, bob.pubkey(), HATE), &[&payer, &bob]).await);
+        // Same wallet again: the report PDA already exists
+        assert!(!send(&mut ctx, report_ix(card, card, alice.pubkey(), HATE), &[&payer, &alice]).await);
+
+        let moderation = fetch_moderation(&mut ctx, card).await;
+        assert_eq!(moderation.kind, 0);
+        assert_eq!(moderation.report_count, 2);
+        assert_eq!(moderation.last_reason, HATE);
+        assert!(!moderation.hidden);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_reason_or_target_rejected() {
+        let (mut ctx, card) = setup().await;
+        let payer = ctx.payer.insecure_clone();
+        let alice = fan(&mut ctx).await;
+
+        assert!(!send(&mut ctx, report_ix(card, card, alice.pubkey(), 99), &[&payer, &alice]).await);
+        // A wallet isn't a card or a comment
+        let wallet = alice.pubkey();
+        assert!(!send(&mut ctx, report_ix(wallet, card, alice.pubkey(), SCAM), &[&payer, &alice]).await);
+    }
+
+    #[tokio::test]
+    async fn test_only_moderators_hide() {
+        let (mut ctx, card) = setup().await;
+        let payer = ctx.payer.insecure_clone();
+        let moderator = fan(&mut ctx).await;
+
+        assert!(!send(&mut ctx, hide_ix(card, card, moderator.pubkey(), true), &[&payer, &moderator]).await);
+
+        assert!(send(&mut ctx, set_moderator_ix(payer.pubkey(), moderator.pubkey()), &[&payer]).await);
+        assert!(send(&mut ctx, hide_ix(card, card, moderator.pubkey(), true), &[&payer, &moderator]).await);
+        let moderation = fetch_moderation(&mut ctx, card).await;
+        assert!(moderation.hidden);
+        assert_eq!(moderation.moderator, moderator.pubkey());
+
+        // The authority can always moderate, e.g. to restore
+        assert!(send(&mut ctx, hide_ix(card, card, payer.pubkey(), false), &[&payer]).await);
+        assert!(!fetch_moderation(&mut ctx, card).await.hidden);
+    }
+
+    #[tokio::test]
+    async fn test_hide_comment() {
+        let (mut ctx, card) = setup().await;
+        let payer = ctx.payer.insecure_clone();
+        let troll = fan(&mut ctx).await;
+
+        assert!(send(&mut ctx, interact_ix(card, troll.pubkey(), COMMENT), &[&payer, &troll]).await);
+        let comment = interaction_pda(&crypt::ID, &card, &troll.pubkey()).0;
+
+        assert!(send(&mut ctx, report_ix(comment, card, payer.pubkey(), HATE), &[&payer]).await);
+        assert!(send(&mut ctx, hide_ix(comment, card, payer.pubkey(), true), &[&payer]).await);
+
+        let moderation = fetch_moderation(&mut ctx, comment).await;
+        assert_eq!(moderation.kind, 1);
+        assert_eq!(moderation.card, card);
+        assert!(moderation.hidden);
+        // The card itself is untouched
+        assert!(ctx.banks_client.get_account(moderation_pda(&crypt::ID, &card).0).await.unwrap().is_none());
+    }
+}
@@ -2,58 +2,26 @@
 
 #[cfg(test)]
 mod tests {
-    /// Mirrors the on-chain scoring logic for testing.
+    use crypt_core::{compute_rarity_score, Rarity, LAMPORTS_PER_SOL};
+
+    /// The shared scorer, taking SOL like the frontend does.
     fn score(tx_type: &str, sol: f64, memecoin: bool, defi: bool, net: f64) -> u32 {
-        let mut s: i32 = 0;
-        match tx_type {
-            "SWAP" => {
-                s += 25;
-                if sol > 100.0 { s += 80; }
-                else if sol > 50.0 { s += 60; }
-                else if sol > 10.0 { s += 35; }
-                else if sol > 2.0 { s += 15; }
-                else if sol > 0.5 { s += 5; }
-                else { s -= 5; }
-                if defi { s += 5; }
-                if memecoin { s += 25; }
-            }
-            "NFT_MINT" | "COMPRESSED_NFT_MINT" => {
-                s += 35;
-                if sol > 10.0 { s += 40; }
-                else if sol > 2.0 { s += 20; }
-            }
-            "NFT_SALE" => {
-                s += 30;
-                if sol > 50.0 { s += 70; }
-                else if sol > 10.0 { s += 40; }
-                else if sol > 2.0 { s += 15; }
-                else { s -= 5; }
-                if net > 0.0 { s += 20; }
-            }
-            "TRANSFER" | "SOL_TRANSFER" => {
-                if sol > 500.0 { s += 80; }
-                else if sol > 100.0 { s += 55; }
-                else if sol > 20.0 { s += 25; }
-                else if sol > 5.0 { s += 10; }
-                else { s -= 15; }
-            }
-            "STAKE_SOL" | "UNSTAKE_SOL" => {
-                if sol > 100.0 { s += 50; }
-                else if sol > 20.0 { s += 25; }
-                else { s += 5; }
-            }
-            "TOKEN_MINT" => { s += 50; }
-            "BURN" | "BURN_NFT" => { s += 20; }
-            _ => { s -= 20; }
-        }
-        if sol < 0.01 && !matches!(tx_type, "NFT_MINT" | "COMPRESSED_NFT_MINT" | "TOKEN_MINT" | "BURN" | "BURN_NFT") {
-            s -= 25;
-        }
-        s.max(0) as u32
+        let lamports = |sol: f64| (sol * LAMPORTS_PER_SOL as f64).round() as i64;
+        compute_rarity_score(tx_type, lamports(sol).max(0) as u64, memecoin, defi, lamports(net))
     }
 
     fn rarity(s: u32) -> &'static str {
-        if s >= 75 { "LEGENDARY" } else if s >= 40 { "RARE" } else { "COMMON" }
+        Rarity::from_score(s).as_str()
+    }
+
+    // === SHARED VECTORS ===
+    #[test]
+    fn test_shared_vectors() {
+        for v in crypt_core::vectors::SCORE_VECTORS {
+            let sol = v.lamports as f64 / LAMPORTS_PER_SOL as f64;
+            let net = v.net_lamports as f64 / LAMPORTS_PER_SOL as f64;
+            assert_eq!(score(v.tx_type, sol, v.is_memecoin, v.is_defi_source, net), v.score);
+        }
     }
 
     // === SWAP SCORING ===
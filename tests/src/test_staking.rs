@@ -0,0 +1,217 @@
+//! Tests for staking ("haunting") — runs the program under program-test
+//! and warps the clock to check points accrual.
+//!
+//! Loads the BPF build from target/deploy, so run `anchor build` first.
+
+#[cfg(test)]
+mod tests {
+    use crate::helpers::*;
+    use anchor_lang::{InstructionData, ToAccountMetas};
+    use crypt::state::{CryptCard, PointsAccount};
+    use solana_program_test::ProgramTestContext;
+    use solana_sdk::{
+        instruction::Instruction,
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        system_program,
+    };
+
+    const BASE_RATE: u64 = 100;
+
+    // Card PDA seeds cap at 32 bytes, so keep test hashes short
+    const TX_HASH: &str = "haunt_test_tx_1";
+
+    /// Initialize the collection and staking config, then mint one card
+    /// of the given rarity to the payer.
+    async fn setup(rarity: u8) -> (ProgramTestContext, Pubkey) {
+        let mut ctx = start().await;
+        let payer = ctx.payer.insecure_clone();
+
+        let configure = Instruction {
+            program_id: crypt::ID,
+            accounts: crypt::accounts::ConfigureStaking {
+                collection: collection_pda(&crypt::ID).0,
+                staking_config: staking_config_pda(&crypt::ID).0,
+                authority: payer.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: crypt::instruction::ConfigureStaking {
+                args: crypt::instructions::ConfigureStakingArgs {
+                    base_rate_per_day: BASE_RATE,
+                    reward_mint: None,
+                    tokens_per_point: 0,
+                },
+            }
+            .data(),
+        };
+        assert!(send(&mut ctx, configure, &[&payer]).await);
+
+        let args = crypt::instructions::MintCardArgs {
+            rarity,
+            card_type: 3,
+            title: "HELD THROUGH THE DIP".into(),
+            pnl: "+420%".into(),
+            ..card_args(TX_HASH)
+        };
+        assert!(send(&mut ctx, mint_card_ix(payer.pubkey(), payer.pubkey(), args), &[&payer]).await);
+
+        (ctx, card_pda(&crypt::ID, TX_HASH, &payer.pubkey()).0)
+    }
+
+    fn stake_ix(card: Pubkey, staker: Pubkey) -> Instruction {
+        Instruction {
+            program_id: crypt::ID,
+            accounts: crypt::accounts::StakeCard {
+                staking_config: staking_config_pda(&crypt::ID).0,
+                card,
+                stake_record: stake_pda(&crypt::ID, &card).0,
+                staker,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: crypt::instruction::StakeCard { card_id: 0 }.data(),
+        }
+    }
+
+    fn claim_ix(card: Pubkey, staker: Pubkey) -> Instruction {
+        Instruction {
+            program_id: crypt::ID,
+            accounts: crypt::accounts::ClaimPoints {
+                staking_config: staking_config_pda(&crypt::ID).0,
+                card,
+                stake_record: stake_pda(&crypt::ID, &card).0,
+                points: points_pda(&crypt::ID, &staker).0,
+                staker,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: crypt::instruction::ClaimPoints { card_id: 0 }.data(),
+        }
+    }
+
+    fn unstake_ix(card: Pubkey, staker: Pubkey) -> Instruction {
+        Instruction {
+            program_id: crypt::ID,
+            accounts: crypt::accounts::UnstakeCard {
+                staking_config: staking_config_pda(&crypt::ID).0,
+                card,
+                stake_record: stake_pda(&crypt::ID, &card).0,
+                points: points_pda(&crypt::ID, &staker).0,
+                staker,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: crypt::instruction::UnstakeCard { card_id: 0 }.data(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_points_accrue_over_warped_days() {
+        let (mut ctx, card) = setup(0).await;
+        let payer = ctx.payer.insecure_clone();
+
+        assert!(send(&mut ctx, stake_ix(card, payer.pubkey()), &[&payer]).await);
+        warp_days(&mut ctx, 3).await;
+        assert!(send(&mut ctx, claim_ix(card, payer.pubkey()), &[&payer]).await);
+
+        let points: PointsAccount = fetch(&mut ctx, points_pda(&crypt::ID, &payer.pubkey()).0).await;
+        assert_eq!(points.owner, payer.pubkey());
+        assert_eq!(points.balance, 3 * BASE_RATE);
+    }
+
+    #[tokio::test]
+    async fn test_rarity_does_not_change_rate() {
+        let (mut ctx, card) = setup(2).await;
+        let payer = ctx.payer.insecure_clone();
+
+        assert!(send(&mut ctx, stake_ix(card, payer.pubkey()), &[&payer]).await);
+        warp_days(&mut ctx, 1).await;
+        assert!(send(&mut ctx, claim_ix(card, payer.pubkey()), &[&payer]).await);
+
+        // A Legendary is only the minter's claim, so it earns the base rate
+        let points: PointsAccount = fetch(&mut ctx, points_pda(&crypt::ID, &payer.pubkey()).0).await;
+        assert_eq!(points.balance, BASE_RATE);
+    }
+
+    #[tokio::test]
+    async fn test_claim_does_not_double_count() {
+        let (mut ctx, card) = setup(0).await;
+        let payer = ctx.payer.insecure_clone();
+
+        assert!(send(&mut ctx, stake_ix(card, payer.pubkey()), &[&payer]).await);
+        warp_days(&mut ctx, 1).await;
+        assert!(send(&mut ctx, claim_ix(card, payer.pubkey()), &[&payer]).await);
+        warp_days(&mut ctx, 1).await;
+        assert!(send(&mut ctx, claim_ix(card, payer.pubkey()), &[&payer]).await);
+
+        let points: PointsAccount = fetch(&mut ctx, points_pda(&crypt::ID, &payer.pubkey()).0).await;
+        assert_eq!(points.balance, 2 * BASE_RATE);
+        assert_eq!(points.total_earned, 2 * BASE_RATE);
+    }
+
+    #[tokio::test]
+    async fn test_staked_card_cannot_transfer_or_burn() {
+        let (mut ctx, card) = setup(1).await;
+        let payer = ctx.payer.insecure_clone();
+        assert!(send(&mut ctx, stake_ix(card, payer.pubkey()), &[&payer]).await);
+
+        let transfer = Instruction {
+            program_id: crypt::ID,
+            accounts: crypt::accounts::TransferCard {
+                card,
+                current_owner: payer.pubkey(),
+                new_owner: Pubkey::new_unique(),
+            }
+            .to_account_metas(None),
+            data: crypt::instruction::TransferCard { card_id: 0 }.data(),
+        };
+        assert!(!send(&mut ctx, transfer, &[&payer]).await, "Staked card must not transfer");
+
+        let burn = Instruction {
+            program_id: crypt::ID,
+            accounts: crypt::accounts::BurnCard {
+                card,
+                owner: payer.pubkey(),
+            }
+            .to_account_metas(None),
+            data: crypt::instruction::BurnCard { card_id: 0 }.data(),
+        };
+        assert!(!send(&mut ctx, burn, &[&payer]).await, "Staked card must not burn");
+    }
+
+    #[tokio::test]
+    async fn test_unstake_returns_card_and_settles() {
+        let (mut ctx, card) = setup(0).await;
+        let payer = ctx.payer.insecure_clone();
+
+        assert!(send(&mut ctx, stake_ix(card, payer.pubkey()), &[&payer]).await);
+        let staked: CryptCard = fetch(&mut ctx, card).await;
+        assert_eq!(staked.owner, stake_pda(&crypt::ID, &card).0);
+
+        warp_days(&mut ctx, 2).await;
+        assert!(send(&mut ctx, unstake_ix(card, payer.pubkey()), &[&payer]).await);
+
+        let returned: CryptCard = fetch(&mut ctx, card).await;
+        assert_eq!(returned.owner, payer.pubkey());
+        let points: PointsAccount = fetch(&mut ctx, points_pda(&crypt::ID, &payer.pubkey()).0).await;
+        assert_eq!(points.balance, 2 * BASE_RATE);
+
+        let record = ctx.banks_client
+            .get_account(stake_pda(&crypt::ID, &card).0)
+            .await
+            .unwrap();
+        assert!(record.is_none(), "Stake record should be closed");
+    }
+
+    #[tokio::test]
+    async fn test_only_staker_can_claim() {
+        let (mut ctx, card) = setup(0).await;
+        let payer = ctx.payer.insecure_clone();
+        assert!(send(&mut ctx, stake_ix(card, payer.pubkey()), &[&payer]).await);
+        warp_days(&mut ctx, 1).await;
+
+        let thief = Keypair::new();
+        assert!(!send(&mut ctx, claim_ix(card, thief.pubkey()), &[&payer, &thief]).await);
+    }
+}
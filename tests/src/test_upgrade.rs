@@ -3,31 +3,14 @@
 #[cfg(test)]
 mod tests {
     use crate::helpers::*;
-
-    fn generate_upgrade_proof(tx_hash: &str, soul_seed: &[u8; 32], new_rarity: u8) -> [u8; 32] {
-        let mut expected = compute_soul_seed(tx_hash);
-        for i in 0..32 { expected[i] ^= soul_seed[i]; }
-        expected[0] ^= new_rarity;
-        for i in 0..32 {
-            let mut h: u64 = expected[i] as u64;
-            h = h.wrapping_mul(0x9e3779b97f4a7c15);
-            h ^= h >> 13;
-            expected[i] = (h & 0xFF) as u8;
-        }
-        expected
-    }
-
-    fn verify_proof(tx_hash: &str, soul_seed: &[u8; 32], new_rarity: u8, proof: &[u8; 32]) -> bool {
-        let expected = generate_upgrade_proof(tx_hash, soul_seed, new_rarity);
-        expected == *proof
-    }
+    use crypt_core::verify_upgrade_proof;
 
     #[test]
     fn test_valid_upgrade_proof() {
         let tx = mock_tx_hash(42);
         let seed = compute_soul_seed(&tx);
-        let proof = generate_upgrade_proof(&tx, &seed, 2);
-        assert!(verify_proof(&tx, &seed, 2, &proof));
+        let proof = upgrade_proof(&tx, &seed, 2);
+        assert!(verify_upgrade_proof(&tx, &seed, 2, &proof));
     }
 
     #[test]
@@ -35,16 +18,16 @@ mod tests {
         let tx = mock_tx_hash(42);
         let seed = compute_soul_seed(&tx);
         let fake_proof = [0u8; 32];
-        assert!(!verify_proof(&tx, &seed, 2, &fake_proof));
+        assert!(!verify_upgrade_proof(&tx, &seed, 2, &fake_proof));
     }
 
     #[test]
     fn test_wrong_rarity_proof_fails() {
         let tx = mock_tx_hash(42);
         let seed = compute_soul_seed(&tx);
-        let proof_for_rare = generate_upgrade_proof(&tx, &seed, 1);
+        let proof_for_rare = upgrade_proof(&tx, &seed, 1);
         // Using rare proof for legendary should fail
-        assert!(!verify_proof(&tx, &seed, 2, &proof_for_rare));
+        assert!(!verify_upgrade_proof(&tx, &seed, 2, &proof_for_rare));
     }
 
     #[test]
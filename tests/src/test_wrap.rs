@@ -0,0 +1,275 @@
+//! Tests for wrapping cards as Metaplex NFTs — the full round trip of
+//! wrap, NFT transfer and unwrap to the new holder, and resyncing the
+//! NFT's metadata.
+//!
+//! Loads the BPF build and the Token Metadata program from target/deploy;
+//! `tests/setup.sh` builds the one and dumps the other there.
+
+#[cfg(test)]
+mod tests {
+    use crate::helpers::*;
+    use anchor_lang::{InstructionData, ToAccountMetas};
+    use crypt::utils::{
+        associated_token_address, create_ata_idempotent_ix, master_edition_address,
+        metadata_address, CardNft, ASSOCIATED_TOKEN_ID, NFT_SYMBOL, TOKEN_METADATA_ID,
+    };
+    use solana_program_test::{ProgramTest, ProgramTestContext};
+    use solana_sdk::{
+        instruction::{AccountMeta, Instruction},
+        pubkey,
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        system_program, sysvar,
+    };
+
+    const TOKEN_PROGRAM_ID: Pubkey = pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+    const TX_HASH: &str = "wrap_test_tx_1";
+
+    /// Initialize the collection and mint one card to a fresh wallet.
+    async fn setup() -> (ProgramTestContext, Keypair, Pubkey) {
+        let mut program_test = ProgramTest::new("crypt", crypt::ID, None);
+        program_test.add_program("mpl_token_metadata", TOKEN_METADATA_ID, None);
+        let mut ctx = start_with(program_test).await;
+        let payer = ctx.payer.insecure_clone();
+
+        let alice = fan(&mut ctx).await;
+        let args = crypt::instructions::MintCardArgs {
+            rarity: 2,
+            card_type: 3,
+            title: "HELD THROUGH IT ALL".into(),
+            pnl: "+420%".into(),
+            ..card_args(TX_HASH)
+        };
+        assert!(send(&mut ctx, mint_card_ix(payer.pubkey(), alice.pubkey(), args), &[&payer, &alice]).await);
+
+        (ctx, alice, card_pda(&crypt::ID, TX_HASH, &alice.pubkey()).0)
+    }
+
+    fn wrap_ix(card: Pubkey, wrapper: Pubkey, nft_mint: Pubkey) -> Instruction {
+        Instruction {
+            program_id: crypt::ID,
+            accounts: crypt::accounts::WrapCard {
+                card,
+                wrap_record: wrap_record_pda(&crypt::ID, &nft_mint).0,
+                collection: collection_pda(&crypt::ID).0,
+                nft_mint,
+                nft_token: associated_token_address(&wrapper, &nft_mint),
+                metadata: metadata_address(&nft_mint),
+                master_edition: master_edition_address(&nft_mint),
+                nft_authority: nft_authority_pda(&crypt::ID).0,
+                wrapper,
+                token_program: TOKEN_PROGRAM_ID,
+                associated_token_program: ASSOCIATED_TOKEN_ID,
+                token_metadata_program: TOKEN_METADATA_ID,
+                system_program: system_program::ID,
+                rent: sysvar::rent::ID,
+            }
+            .to_account_metas(None),
+            data: crypt::instruction::WrapCard {}.data(),
+        }
+    }
+
+    fn update_metadata_ix(card: Pubkey, nft_mint: Pubkey) -> Instruction {
+        Instruction {
+            program_id: crypt::ID,
+            accounts: crypt::accounts::UpdateWrappedMetadata {
+                card,
+                wrap_record: wrap_record_pda(&crypt::ID, &nft_mint).0,
+                collection: collection_pda(&crypt::ID).0,
+                metadata: metadata_address(&nft_mint),
+                nft_authority: nft_authority_pda(&crypt::ID).0,
+                token_metadata_program: TOKEN_METADATA_ID,
+            }
+            .to_account_metas(None),
+            data: crypt::instruction::UpdateWrappedMetadata {}.data(),
+        }
+    }
+
+    fn set_collection_uri_ix(authority: Pubkey, uri: &str) -> Instruction {
+        Instruction {
+            program_id: crypt::ID,
+            accounts: crypt::accounts::UpdateCollection {
+                collection: collection_pda(&crypt::ID).0,
+                authority,
+            }
+            .to_account_metas(None),
+            data: crypt::instruction::UpdateCollection {
+                args: crypt::instructions::UpdateCollectionArgs {
+                    uri: Some(uri.into()),
+                    max_supply: None,
+                    mint_fee: None,
+                    paused: None,
+                    treasury: None,
+                    metadata_locked: None,
+                    moderator: None,
+                },
+            }
+            .data(),
+        }
+    }
+
+    /// Name, symbol and URI from an NFT's Token Metadata account, which
+    /// follow the key, update authority and mint as null-padded Borsh strings.
+    async fn stored_nft(ctx: &mut ProgramTestContext, nft_mint: Pubkey) -> CardNft {
+        let metadata = ctx.banks_client.get_account(metadata_address(&nft_mint)).await.unwrap().unwrap();
+        let mut rest = &metadata.data[1 + 32 + 32..];
+        let mut field = || {
+            let len = u32::from_le_bytes(rest[..4].try_into().unwrap()) as usize;
+            let value = String::from_utf8(rest[4..4 + len].to_vec()).unwrap();
+            rest = &rest[4 + len..];
+            value.trim_end_matches('\0').to_string()
+        };
+        CardNft { name: field(), symbol: field(), uri: field() }
+    }
+
+    fn unwrap_ix(card: Pubkey, holder: Pubkey, wrapper: Pubkey, nft_mint: Pubkey) -> Instruction {
+        Instruction {
+            program_id: crypt::ID,
+            accounts: crypt::accounts::UnwrapCard {
+                card,
+                wrap_record: wrap_record_pda(&crypt::ID, &nft_mint).0,
+                nft_mint,
+                nft_token: associated_token_address(&holder, &nft_mint),
+                metadata: metadata_address(&nft_mint),
+                master_edition: master_edition_address(&nft_mint),
+                holder,
+                wrapper,
+                token_program: TOKEN_PROGRAM_ID,
+                token_metadata_program: TOKEN_METADATA_ID,
+            }
+            .to_account_metas(None),
+            data: crypt::instruction::UnwrapCard {}.data(),
+        }
+    }
+
+    /// SPL Token `Transfer` of the NFT between associated token accounts.
+    fn nft_transfer_ix(nft_mint: Pubkey, from: Pubkey, to: Pubkey) -> Instruction {
+        let mut data = vec![3u8];
+        data.extend_from_slice(&1u64.to_le_bytes());
+        Instruction {
+            program_id: TOKEN_PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(associated_token_address(&from, &nft_mint), false),
+                AccountMeta::new(associated_token_address(&to, &nft_mint), false),
+                AccountMeta::new_readonly(from, true),
+            ],
+            data,
+        }
+    }
+
+    /// Token balance of an SPL token account (amount sits after mint and owner).
+    async fn token_amount(ctx: &mut ProgramTestContext, address: Pubkey) -> u64 {
+        let account = ctx.banks_client.get_account(address).await.unwrap().unwrap();
+        u64::from_le_bytes(account.data[64..72].try_into().unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_wrap_and_unwrap_round_trip() {
+        let (mut ctx, alice, card) = setup().await;
+        let payer = ctx.payer.insecure_clone();
+        let bob = fan(&mut ctx).await;
+        let nft_mint = Keypair::new();
+        let mint = nft_mint.pubkey();
+
+        assert!(send(&mut ctx, wrap_ix(card, alice.pubkey(), mint), &[&payer, &alice, &nft_mint]).await);
+
+        // The card is in custody and the NFT is in alice's wallet
+        let wrapped = fetch_card(&mut ctx, card).await;
+        assert_eq!(wrapped.owner, wrap_record_pda(&crypt::ID, &mint).0);
+        assert_eq!(token_amount(&mut ctx, associated_token_address(&alice.pubkey(), &mint)).await, 1);
+
+        let metadata = ctx.banks_client.get_account(metadata_address(&mint)).await.unwrap().unwrap();
+        assert_eq!(metadata.owner, TOKEN_METADATA_ID);
+        assert!(ctx.banks_client.get_account(master_edition_address(&mint)).await.unwrap().is_some());
+
+        // Sell the NFT to bob
+        let ata = create_ata_idempotent_ix(&payer.pubkey(), &bob.pubkey(), &mint);
+        assert!(send(&mut ctx, ata, &[&payer]).await);
+        assert!(send(&mut ctx, nft_transfer_ix(mint, alice.pubkey(), bob.pubkey()), &[&payer, &alice]).await);
+
+        // Alice no longer holds it
+        assert!(!send(&mut ctx, unwrap_ix(card, alice.pubkey(), alice.pubkey(), mint), &[&payer, &alice]).await);
+
+        let alice_before = ctx.banks_client.get_balance(alice.pubkey()).await.unwrap();
+        assert!(send(&mut ctx, unwrap_ix(card, bob.pubkey(), alice.pubkey(), mint), &[&payer, &bob]).await);
+
+        assert_eq!(fetch_card(&mut ctx, card).await.owner, bob.pubkey());
+        assert!(ctx.banks_client.get_account(wrap_record_pda(&crypt::ID, &mint).0).await.unwrap().is_none());
+        assert!(ctx.banks_client.get_account(metadata_address(&mint)).await.unwrap().is_none());
+        // The wrap record's rent goes back to whoever wrapped
+        assert!(ctx.banks_client.get_balance(alice.pubkey()).await.unwrap() > alice_before);
+    }
+
+    #[tokio::test]
+    async fn test_nft_metadata_matches_card() {
+        let (mut ctx, alice, card) = setup().await;
+        let payer = ctx.payer.insecure_clone();
+        let nft_mint = Keypair::new();
+        let mint = nft_mint.pubkey();
+        assert!(send(&mut ctx, wrap_ix(card, alice.pubkey(), mint), &[&payer, &alice, &nft_mint]).await);
+
+        let wrapped = fetch_card(&mut ctx, card).await;
+        let stored = stored_nft(&mut ctx, mint).await;
+        assert_eq!(stored, CardNft::for_card(&wrapped, COLLECTION_URI));
+        assert_eq!(stored.name, format!("CRYPT #{} — {}", wrapped.mint_id, wrapped.title));
+        assert_eq!(stored.symbol, NFT_SYMBOL);
+        assert_eq!(stored.uri, format!("https://crypt.cards/{}.json", wrapped.mint_id));
+    }
+
+    #[tokio::test]
+    async fn test_wrapped_card_is_locked() {
+        let (mut ctx, alice, card) = setup().await;
+        let payer = ctx.payer.insecure_clone();
+        let nft_mint = Keypair::new();
+        assert!(send(&mut ctx, wrap_ix(card, alice.pubkey(), nft_mint.pubkey()), &[&payer, &alice, &nft_mint]).await);
+
+        let transfer = Instruction {
+            program_id: crypt::ID,
+            accounts: crypt::accounts::TransferCard {
+                card,
+                current_owner: alice.pubkey(),
+                new_owner: Pubkey::new_unique(),
+            }
+            .to_account_metas(None),
+            data: crypt::instruction::TransferCard { card_id: 0 }.data(),
+        };
+        assert!(!send(&mut ctx, transfer, &[&payer, &alice]).await, "Wrapped card must not transfer");
+
+        // Nor can it be wrapped a second time
+        let again = Keypair::new();
+        assert!(!send(&mut ctx, wrap_ix(card, alice.pubkey(), again.pubkey()), &[&payer, &alice, &again]).await);
+    }
+
+    #[tokio::test]
+    async fn test_update_wrapped_metadata_follows_collection() {
+        let (mut ctx, alice, card) = setup().await;
+        let payer = ctx.payer.insecure_clone();
+        let nft_mint = Keypair::new();
+        let mint = nft_mint.pubkey();
+        assert!(send(&mut ctx, wrap_ix(card, alice.pubkey(), mint), &[&payer, &alice, &nft_mint]).await);
+
+        let moved = "https://cdn.crypt.cards/v2/collection.json";
+        assert!(send(&mut ctx, set_collection_uri_ix(payer.pubkey(), moved), &[&payer]).await);
+        assert_eq!(stored_nft(&mut ctx, mint).await.uri, "https://crypt.cards/0.json");
+
+        // Anyone can resync, since nothing in it is up to the caller
+        assert!(send(&mut ctx, update_metadata_ix(card, mint), &[&payer]).await);
+        let card_data = fetch_card(&mut ctx, card).await;
+        assert_eq!(stored_nft(&mut ctx, mint).await, CardNft::for_card(&card_data, moved));
+
+        // Once unwrapped, there's no NFT left to update
+        let unwrap = unwrap_ix(card, alice.pubkey(), alice.pubkey(), mint);
+        assert!(send(&mut ctx, unwrap, &[&payer, &alice]).await);
+        assert!(!send(&mut ctx, update_metadata_ix(card, mint), &[&payer]).await);
+    }
+
+    #[tokio::test]
+    async fn test_only_owner_wraps() {
+        let (mut ctx, _alice, card) = setup().await;
+        let payer = ctx.payer.insecure_clone();
+        let mallory = fan(&mut ctx).await;
+        let nft_mint = Keypair::new();
+        let ix = wrap_ix(card, mallory.pubkey(), nft_mint.pubkey());
+        assert!(!send(&mut ctx, ix, &[&payer, &mallory, &nft_mint]).await);
+    }
+}